@@ -48,9 +48,31 @@ pub enum CallType {
     Closure,      // let f = foo; f()
     Async,        // foo().await
     Spawn,        // spawn(foo)
+    Callback,     // register(foo) - function passed as an argument
+    Possible,     // dynamic dispatch candidate (trait object / interface / duck typing)
     Unknown,
 }
 
+/// A concrete implementation of a method name, used to resolve dynamic
+/// dispatch: a method call through a trait object, interface, or duck-typed
+/// receiver could land on any of these.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MethodImpl {
+    /// The implementing type or class
+    pub owner: String,
+    /// The trait/interface being implemented, when syntactically visible
+    /// (e.g. `impl Handler for TcpServer`)
+    pub trait_name: Option<String>,
+    /// Owner-qualified node name in the graph (`Owner::method`)
+    pub function: String,
+    /// File where the implementation is defined
+    pub file_path: String,
+    /// Line of the implementation
+    pub line: usize,
+    /// Parameter count, for signature matching at call sites
+    pub params: usize,
+}
+
 /// Function complexity metrics
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct FunctionMetrics {
@@ -74,6 +96,9 @@ pub struct CallGraph {
     nodes: DashMap<String, CallNode>,
     /// File -> Functions defined in that file
     file_functions: DashMap<String, Vec<String>>,
+    /// Method name -> implementations of that method across all types,
+    /// used to fan out dynamic dispatch candidates at method call sites
+    method_impls: DashMap<String, Vec<MethodImpl>>,
 }
 
 impl Default for CallGraph {
@@ -87,21 +112,38 @@ impl CallGraph {
         Self {
             nodes: DashMap::new(),
             file_functions: DashMap::new(),
+            method_impls: DashMap::new(),
         }
     }
 
     /// Build call graph from parsed files
     pub fn build_from_files(&self, files: &[(String, String, Tree)]) -> Result<()> {
         // First pass: collect all function definitions
+        self.add_definitions(files)?;
+
+        // Second pass: find all call sites
+        self.add_calls(files)?;
+
+        Ok(())
+    }
+
+    /// Register function definitions from parsed files (first pass).
+    ///
+    /// Can be called per batch during incremental/bounded-memory indexing;
+    /// all definitions must be registered before `add_calls` so cross-file
+    /// call edges resolve.
+    pub fn add_definitions(&self, files: &[(String, String, Tree)]) -> Result<()> {
         for (path, content, tree) in files {
             self.extract_functions(path, content, tree)?;
         }
+        Ok(())
+    }
 
-        // Second pass: find all call sites
+    /// Record call edges from parsed files (second pass)
+    pub fn add_calls(&self, files: &[(String, String, Tree)]) -> Result<()> {
         for (path, content, tree) in files {
             self.extract_calls(path, content, tree)?;
         }
-
         Ok(())
     }
 
@@ -133,6 +175,26 @@ impl CallGraph {
             let node = cursor.node();
 
             if let Some(func) = self.try_extract_function(node, source, path) {
+                // Methods also get an owner-qualified node (`Owner::method`)
+                // so dynamic dispatch candidates stay distinguishable: bare
+                // names collide when several types implement the same method.
+                if let Some((owner, trait_name)) = enclosing_type_name(node, source) {
+                    let qualified = format!("{}::{}", owner, func.name);
+                    let mut impls = self.method_impls.entry(func.name.clone()).or_default();
+                    if !impls.iter().any(|i| i.function == qualified) {
+                        impls.push(MethodImpl {
+                            owner,
+                            trait_name,
+                            function: qualified.clone(),
+                            file_path: func.file_path.clone(),
+                            line: func.line,
+                            params: func.metrics.params,
+                        });
+                    }
+                    let mut qualified_node = func.clone();
+                    qualified_node.name = qualified;
+                    functions.push(qualified_node);
+                }
                 functions.push(func);
             }
 
@@ -230,6 +292,20 @@ impl CallGraph {
                             caller_node.calls.push(edge.clone());
                         }
 
+                        // Method calls may dispatch dynamically (trait object,
+                        // interface, duck typing): record a "possible" edge to
+                        // every implementor with a matching signature so
+                        // polymorphic call sites are not missed.
+                        if edge.call_type == CallType::Method {
+                            self.add_possible_edges(caller, &edge, node);
+                        }
+
+                        // Functions passed as arguments (callbacks, handlers
+                        // registered into frameworks) get edges from the
+                        // registration site so event-driven code isn't
+                        // reported as dead.
+                        self.add_callback_edges(caller, &edge, node, source);
+
                         // Add to callee's incoming calls
                         if let Some(mut callee_node) = self.nodes.get_mut(&edge.target) {
                             callee_node.called_by.push(CallEdge {
@@ -302,6 +378,132 @@ impl CallGraph {
         })
     }
 
+    /// Record dynamic dispatch candidates for a method call.
+    ///
+    /// The receiver type is unknown at this level, so the candidate set is
+    /// every registered implementation of the method name whose arity is
+    /// compatible with the call site (duck typing). Edges are marked
+    /// `CallType::Possible` to distinguish them from resolved calls.
+    fn add_possible_edges(&self, caller: &str, edge: &CallEdge, call_node: Node) {
+        let candidates: Vec<MethodImpl> = match self.method_impls.get(&edge.target) {
+            Some(impls) => impls.clone(),
+            None => return,
+        };
+
+        let arg_count = count_call_arguments(call_node);
+
+        for candidate in candidates {
+            if candidate.function == caller {
+                continue;
+            }
+            if !arity_matches(arg_count, candidate.params) {
+                continue;
+            }
+
+            if let Some(mut caller_node) = self.nodes.get_mut(caller) {
+                caller_node.calls.push(CallEdge {
+                    target: candidate.function.clone(),
+                    file_path: edge.file_path.clone(),
+                    line: edge.line,
+                    column: edge.column,
+                    call_type: CallType::Possible,
+                });
+            }
+
+            if let Some(mut impl_node) = self.nodes.get_mut(&candidate.function) {
+                impl_node.called_by.push(CallEdge {
+                    target: caller.to_string(),
+                    file_path: edge.file_path.clone(),
+                    line: edge.line,
+                    column: edge.column,
+                    call_type: CallType::Possible,
+                });
+            }
+        }
+    }
+
+    /// Record edges for functions passed as arguments at a call site.
+    ///
+    /// `register(handler)` gets an edge `caller -> handler` so callbacks and
+    /// framework-registered handlers keep incoming edges even though they are
+    /// never called by name. Only arguments naming a known function in the
+    /// graph are considered; `spawn(worker)` keeps the dedicated `Spawn` type.
+    fn add_callback_edges(&self, caller: &str, edge: &CallEdge, call_node: Node, source: &[u8]) {
+        let mut cursor = call_node.walk();
+        if !cursor.goto_first_child() {
+            return;
+        }
+
+        let mut args_node = None;
+        loop {
+            let child = cursor.node();
+            if matches!(child.kind(), "arguments" | "argument_list") {
+                args_node = Some(child);
+                break;
+            }
+            if !cursor.goto_next_sibling() {
+                break;
+            }
+        }
+
+        let args_node = match args_node {
+            Some(n) => n,
+            None => return,
+        };
+
+        let call_type = if edge.target == "spawn" {
+            CallType::Spawn
+        } else {
+            CallType::Callback
+        };
+
+        let mut arg_cursor = args_node.walk();
+        for arg in args_node.named_children(&mut arg_cursor) {
+            let name = match arg.kind() {
+                "identifier" => arg.utf8_text(source).ok().map(|s| s.to_string()),
+                // &handler / Module::handler / this.onClick
+                "reference_expression"
+                | "unary_expression"
+                | "scoped_identifier"
+                | "qualified_identifier"
+                | "field_expression"
+                | "member_expression" => self.get_last_identifier(arg, source),
+                _ => None,
+            };
+
+            let name = match name {
+                Some(n) => n,
+                None => continue,
+            };
+
+            // Only known functions; plain variables that happen to share a
+            // name are an accepted imprecision
+            if !self.nodes.contains_key(&name) {
+                continue;
+            }
+
+            if let Some(mut caller_node) = self.nodes.get_mut(caller) {
+                caller_node.calls.push(CallEdge {
+                    target: name.clone(),
+                    file_path: edge.file_path.clone(),
+                    line: arg.start_position().row + 1,
+                    column: arg.start_position().column + 1,
+                    call_type: call_type.clone(),
+                });
+            }
+
+            if let Some(mut callback_node) = self.nodes.get_mut(&name) {
+                callback_node.called_by.push(CallEdge {
+                    target: caller.to_string(),
+                    file_path: edge.file_path.clone(),
+                    line: arg.start_position().row + 1,
+                    column: arg.start_position().column + 1,
+                    call_type: call_type.clone(),
+                });
+            }
+        }
+    }
+
     fn get_last_identifier(&self, node: Node, source: &[u8]) -> Option<String> {
         let mut cursor = node.walk();
         let mut last_ident = None;
@@ -508,7 +710,7 @@ impl CallGraph {
             }
         }
 
-        candidates.sort_by(|a, b| b.1.cmp(&a.1));
+        candidates.sort_by_key(|c| std::cmp::Reverse(c.1));
         candidates
             .into_iter()
             .take(limit)
@@ -655,7 +857,7 @@ impl CallGraph {
             }
         }
 
-        hotspots.sort_by(|a, b| (b.1 + b.2).cmp(&(a.1 + a.2)));
+        hotspots.sort_by_key(|h| std::cmp::Reverse(h.1 + h.2));
         hotspots
     }
 
@@ -665,8 +867,171 @@ impl CallGraph {
         self.nodes.get(&actual_name).map(|n| n.metrics.clone())
     }
 
+    /// Compute a PageRank-style importance score per function.
+    ///
+    /// A call edge `caller -> callee` transfers importance to the callee, so
+    /// functions reached from many (themselves important) call sites score
+    /// highest. Dangling functions (no outgoing calls) distribute their score
+    /// uniformly, as in standard PageRank. Returns `(name, score)` pairs
+    /// sorted by descending score.
+    pub fn compute_pagerank(&self, damping: f64, iterations: usize) -> Vec<(String, f64)> {
+        let names: Vec<String> = self.nodes.iter().map(|e| e.key().clone()).collect();
+        let n = names.len();
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let index: HashMap<&str, usize> = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.as_str(), i))
+            .collect();
+
+        // Out-edges restricted to known nodes (unresolved targets would
+        // leak rank out of the graph)
+        let mut out_edges: Vec<Vec<usize>> = vec![Vec::new(); n];
+        for (i, name) in names.iter().enumerate() {
+            if let Some(node) = self.nodes.get(name) {
+                for call in &node.calls {
+                    if let Some(&j) = index.get(call.target.as_str()) {
+                        out_edges[i].push(j);
+                    }
+                }
+            }
+        }
+
+        let mut ranks = vec![1.0 / n as f64; n];
+        let base = (1.0 - damping) / n as f64;
+
+        for _ in 0..iterations {
+            let mut next = vec![base; n];
+            let mut dangling_sum = 0.0;
+
+            for (i, targets) in out_edges.iter().enumerate() {
+                if targets.is_empty() {
+                    dangling_sum += ranks[i];
+                } else {
+                    let share = ranks[i] / targets.len() as f64;
+                    for &j in targets {
+                        next[j] += damping * share;
+                    }
+                }
+            }
+
+            let dangling_share = damping * dangling_sum / n as f64;
+            for rank in next.iter_mut() {
+                *rank += dangling_share;
+            }
+
+            ranks = next;
+        }
+
+        let mut scored: Vec<(String, f64)> = names.into_iter().zip(ranks).collect();
+        scored.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.0.cmp(&b.0))
+        });
+        scored
+    }
+
+    /// Find mutually recursive function groups (strongly connected
+    /// components of the call graph).
+    ///
+    /// Uses an iterative Tarjan SCC so deep call chains cannot overflow the
+    /// stack. Only genuine cycles are returned: components with more than one
+    /// member, or a single function that calls itself. Members are sorted
+    /// within each group; groups are sorted by size (largest first).
+    pub fn find_recursion_cycles(&self) -> Vec<Vec<String>> {
+        let names: Vec<String> = self.nodes.iter().map(|e| e.key().clone()).collect();
+        let n = names.len();
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let index: HashMap<&str, usize> = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.as_str(), i))
+            .collect();
+
+        let mut adj: Vec<Vec<usize>> = vec![Vec::new(); n];
+        for (i, name) in names.iter().enumerate() {
+            if let Some(node) = self.nodes.get(name) {
+                for call in &node.calls {
+                    if let Some(&j) = index.get(call.target.as_str()) {
+                        adj[i].push(j);
+                    }
+                }
+            }
+        }
+
+        let mut index_of = vec![usize::MAX; n];
+        let mut lowlink = vec![0usize; n];
+        let mut on_stack = vec![false; n];
+        let mut stack: Vec<usize> = Vec::new();
+        let mut next_index = 0usize;
+        let mut cycles: Vec<Vec<String>> = Vec::new();
+
+        for start in 0..n {
+            if index_of[start] != usize::MAX {
+                continue;
+            }
+
+            // Explicit work stack of (vertex, next child position)
+            let mut work: Vec<(usize, usize)> = vec![(start, 0)];
+
+            while let Some(&(v, pos)) = work.last() {
+                if pos == 0 {
+                    index_of[v] = next_index;
+                    lowlink[v] = next_index;
+                    next_index += 1;
+                    stack.push(v);
+                    on_stack[v] = true;
+                }
+
+                if pos < adj[v].len() {
+                    work.last_mut().unwrap().1 += 1;
+                    let w = adj[v][pos];
+                    if index_of[w] == usize::MAX {
+                        work.push((w, 0));
+                    } else if on_stack[w] {
+                        lowlink[v] = lowlink[v].min(index_of[w]);
+                    }
+                } else {
+                    work.pop();
+                    if let Some(&(parent, _)) = work.last() {
+                        lowlink[parent] = lowlink[parent].min(lowlink[v]);
+                    }
+
+                    if lowlink[v] == index_of[v] {
+                        let mut component = Vec::new();
+                        loop {
+                            let w = stack.pop().expect("Tarjan stack underflow");
+                            on_stack[w] = false;
+                            component.push(w);
+                            if w == v {
+                                break;
+                            }
+                        }
+
+                        let is_cycle = component.len() > 1 || adj[v].contains(&v);
+                        if is_cycle {
+                            let mut members: Vec<String> =
+                                component.into_iter().map(|i| names[i].clone()).collect();
+                            members.sort();
+                            cycles.push(members);
+                        }
+                    }
+                }
+            }
+        }
+
+        cycles.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a[0].cmp(&b[0])));
+        cycles
+    }
+
     /// Export call graph in DOT format for visualization
-    #[allow(dead_code)]
     pub fn to_dot(&self, filter_file: Option<&str>) -> String {
         let mut dot = String::from("digraph CallGraph {\n");
         dot.push_str("  rankdir=LR;\n");
@@ -704,6 +1069,149 @@ impl CallGraph {
         dot
     }
 
+    /// Export call graph in GraphML format (loadable in Gephi, yEd, etc.)
+    ///
+    /// Nodes carry file, line, cyclomatic complexity, and LOC attributes;
+    /// edges carry the call type. Edges to functions outside the (possibly
+    /// file-filtered) node set are skipped so the document stays valid.
+    pub fn to_graphml(&self, filter_file: Option<&str>) -> String {
+        let mut included: HashSet<String> = HashSet::new();
+        for entry in self.nodes.iter() {
+            if let Some(file) = filter_file {
+                if !entry.value().file_path.contains(file) {
+                    continue;
+                }
+            }
+            included.insert(entry.key().clone());
+        }
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+        xml.push_str("  <key id=\"file\" for=\"node\" attr.name=\"file\" attr.type=\"string\"/>\n");
+        xml.push_str("  <key id=\"line\" for=\"node\" attr.name=\"line\" attr.type=\"int\"/>\n");
+        xml.push_str(
+            "  <key id=\"cyclomatic\" for=\"node\" attr.name=\"cyclomatic\" attr.type=\"int\"/>\n",
+        );
+        xml.push_str("  <key id=\"loc\" for=\"node\" attr.name=\"loc\" attr.type=\"int\"/>\n");
+        xml.push_str(
+            "  <key id=\"call_type\" for=\"edge\" attr.name=\"call_type\" attr.type=\"string\"/>\n",
+        );
+        xml.push_str("  <graph id=\"CallGraph\" edgedefault=\"directed\">\n");
+
+        for entry in self.nodes.iter() {
+            let node = entry.value();
+            if !included.contains(entry.key()) {
+                continue;
+            }
+
+            xml.push_str(&format!("    <node id=\"{}\">\n", xml_escape(&node.name)));
+            xml.push_str(&format!(
+                "      <data key=\"file\">{}</data>\n",
+                xml_escape(&node.file_path)
+            ));
+            xml.push_str(&format!("      <data key=\"line\">{}</data>\n", node.line));
+            xml.push_str(&format!(
+                "      <data key=\"cyclomatic\">{}</data>\n",
+                node.metrics.cyclomatic
+            ));
+            xml.push_str(&format!(
+                "      <data key=\"loc\">{}</data>\n",
+                node.metrics.loc
+            ));
+            xml.push_str("    </node>\n");
+        }
+
+        for entry in self.nodes.iter() {
+            let node = entry.value();
+            if !included.contains(entry.key()) {
+                continue;
+            }
+
+            for call in &node.calls {
+                if !included.contains(&call.target) {
+                    continue;
+                }
+                xml.push_str(&format!(
+                    "    <edge source=\"{}\" target=\"{}\">\n",
+                    xml_escape(&node.name),
+                    xml_escape(&call.target)
+                ));
+                xml.push_str(&format!(
+                    "      <data key=\"call_type\">{:?}</data>\n",
+                    call.call_type
+                ));
+                xml.push_str("    </edge>\n");
+            }
+        }
+
+        xml.push_str("  </graph>\n");
+        xml.push_str("</graphml>\n");
+        xml
+    }
+
+    /// Export call graph as Cytoscape.js JSON (`{"elements": {...}}`)
+    ///
+    /// Same metadata as the GraphML export, in the shape Cytoscape-based
+    /// dashboards consume directly.
+    pub fn to_cytoscape_json(&self, filter_file: Option<&str>) -> String {
+        let mut included: HashSet<String> = HashSet::new();
+        for entry in self.nodes.iter() {
+            if let Some(file) = filter_file {
+                if !entry.value().file_path.contains(file) {
+                    continue;
+                }
+            }
+            included.insert(entry.key().clone());
+        }
+
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+
+        for entry in self.nodes.iter() {
+            let node = entry.value();
+            if !included.contains(entry.key()) {
+                continue;
+            }
+
+            nodes.push(serde_json::json!({
+                "data": {
+                    "id": node.name,
+                    "file": node.file_path,
+                    "line": node.line,
+                    "cyclomatic": node.metrics.cyclomatic,
+                    "loc": node.metrics.loc,
+                }
+            }));
+
+            for call in &node.calls {
+                if !included.contains(&call.target) {
+                    continue;
+                }
+                edges.push(serde_json::json!({
+                    "data": {
+                        "id": format!("{}->{}:{}", node.name, call.target, call.line),
+                        "source": node.name,
+                        "target": call.target,
+                        "call_type": format!("{:?}", call.call_type),
+                        "line": call.line,
+                    }
+                }));
+            }
+        }
+
+        // Sort for deterministic output (DashMap iteration order varies)
+        nodes.sort_by_key(|n| n["data"]["id"].as_str().unwrap_or_default().to_string());
+        edges.sort_by_key(|e| e["data"]["id"].as_str().unwrap_or_default().to_string());
+
+        serde_json::to_string_pretty(&serde_json::json!({
+            "elements": {
+                "nodes": nodes,
+                "edges": edges,
+            }
+        }))
+        .unwrap_or_else(|_| "{}".to_string())
+    }
+
     /// Format call graph as markdown for AI consumption
     pub fn to_markdown(&self, function: Option<&str>) -> String {
         let mut md = String::new();
@@ -742,9 +1250,14 @@ impl CallGraph {
                         md.push_str("*No incoming calls (entry point or unused)*\n\n");
                     } else {
                         for caller in &node.called_by {
+                            let marker = if caller.call_type == CallType::Possible {
+                                " (possible - dynamic dispatch)"
+                            } else {
+                                ""
+                            };
                             md.push_str(&format!(
-                                "- `{}` at `{}:{}`\n",
-                                caller.target, caller.file_path, caller.line
+                                "- `{}` at `{}:{}`{}\n",
+                                caller.target, caller.file_path, caller.line, marker
                             ));
                         }
                     }
@@ -763,7 +1276,7 @@ impl CallGraph {
                     .iter()
                     .map(|e| (e.key().clone(), e.called_by.len()))
                     .collect();
-                by_callers.sort_by(|a, b| b.1.cmp(&a.1));
+                by_callers.sort_by_key(|c| std::cmp::Reverse(c.1));
 
                 for (name, count) in by_callers.iter().take(10) {
                     md.push_str(&format!("- `{}`: {} callers\n", name, count));
@@ -777,7 +1290,7 @@ impl CallGraph {
                     .iter()
                     .map(|e| (e.key().clone(), e.metrics.clone()))
                     .collect();
-                by_complexity.sort_by(|a, b| b.1.cyclomatic.cmp(&a.1.cyclomatic));
+                by_complexity.sort_by_key(|c| std::cmp::Reverse(c.1.cyclomatic));
 
                 for (name, metrics) in by_complexity.iter().take(10) {
                     md.push_str(&format!(
@@ -827,6 +1340,11 @@ impl CallGraph {
         self.nodes.len()
     }
 
+    /// Get the number of call edges (outgoing) in the call graph
+    pub fn edge_count(&self) -> usize {
+        self.nodes.iter().map(|node| node.calls.len()).sum()
+    }
+
     /// Get all nodes (for iteration)
     pub fn iter_nodes(
         &self,
@@ -868,6 +1386,96 @@ fn extract_function_name(node: Node, source: &[u8]) -> Option<String> {
     None
 }
 
+/// Find the type/trait/class enclosing a method definition, along with the
+/// trait or interface it implements when syntactically visible.
+///
+/// Returns `(owner, trait_name)`: for `impl Handler for TcpServer` that is
+/// `("TcpServer", Some("Handler"))`; for a trait/interface default method the
+/// owner is the trait itself; for plain classes the trait is unknown.
+fn enclosing_type_name(node: Node, source: &[u8]) -> Option<(String, Option<String>)> {
+    let mut current = node.parent();
+
+    while let Some(parent) = current {
+        match parent.kind() {
+            // Rust: impl Type { } / impl Trait for Type { }
+            "impl_item" => {
+                let owner = parent
+                    .child_by_field_name("type")?
+                    .utf8_text(source)
+                    .ok()?
+                    .to_string();
+                let trait_name = parent
+                    .child_by_field_name("trait")
+                    .and_then(|t| t.utf8_text(source).ok())
+                    .map(|s| s.to_string());
+                return Some((owner, trait_name));
+            }
+            // Trait/interface default methods belong to the trait itself
+            "trait_item" | "interface_declaration" | "interface_body" => {
+                let owner = parent
+                    .child_by_field_name("name")?
+                    .utf8_text(source)
+                    .ok()?
+                    .to_string();
+                return Some((owner.clone(), Some(owner)));
+            }
+            // Classes (JS/TS/Python/C++); implemented interfaces are not
+            // resolved here - duck-typed matching covers them
+            "class_declaration" | "class_definition" | "class_specifier" => {
+                let owner = parent
+                    .child_by_field_name("name")?
+                    .utf8_text(source)
+                    .ok()?
+                    .to_string();
+                return Some((owner, None));
+            }
+            _ => {}
+        }
+        current = parent.parent();
+    }
+
+    None
+}
+
+/// Count the arguments at a call site (for signature matching)
+fn count_call_arguments(node: Node) -> Option<usize> {
+    let mut cursor = node.walk();
+    if !cursor.goto_first_child() {
+        return None;
+    }
+
+    loop {
+        let child = cursor.node();
+        if matches!(child.kind(), "arguments" | "argument_list") {
+            return Some(child.named_child_count());
+        }
+        if !cursor.goto_next_sibling() {
+            break;
+        }
+    }
+
+    None
+}
+
+/// Escape a string for use in XML attribute values and text content
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Lenient arity check for dispatch candidates: grammars disagree on whether
+/// `self`/`this` counts as a parameter, so allow an off-by-one in the
+/// implementor's favor. An unknown call-site arity matches everything.
+fn arity_matches(call_args: Option<usize>, impl_params: usize) -> bool {
+    match call_args {
+        Some(n) => n == impl_params || n + 1 == impl_params,
+        None => true,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1793,4 +2401,432 @@ mod tests {
             assert!(graph.nodes.contains_key(*name));
         }
     }
+
+    #[test]
+    fn test_arity_matches() {
+        // Exact match
+        assert!(arity_matches(Some(2), 2));
+        // Off-by-one for implicit self/this
+        assert!(arity_matches(Some(1), 2));
+        // Unknown call-site arity matches everything
+        assert!(arity_matches(None, 5));
+        // Mismatches
+        assert!(!arity_matches(Some(3), 1));
+        assert!(!arity_matches(Some(0), 2));
+    }
+
+    fn parse_rust(source: &str) -> Tree {
+        let mut parser = tree_sitter::Parser::new();
+        parser
+            .set_language(&tree_sitter_rust::LANGUAGE.into())
+            .unwrap();
+        parser.parse(source, None).unwrap()
+    }
+
+    #[test]
+    fn test_trait_impls_get_qualified_nodes() {
+        let source = r#"
+trait Handler {
+    fn handle(&self);
+}
+
+struct TcpServer;
+struct UdpServer;
+
+impl Handler for TcpServer {
+    fn handle(&self) {}
+}
+
+impl Handler for UdpServer {
+    fn handle(&self) {}
+}
+"#;
+        let tree = parse_rust(source);
+        let graph = CallGraph::new();
+        graph
+            .build_from_files(&[("server.rs".to_string(), source.to_string(), tree)])
+            .unwrap();
+
+        // Each implementation gets its own owner-qualified node
+        assert!(graph.nodes.contains_key("TcpServer::handle"));
+        assert!(graph.nodes.contains_key("UdpServer::handle"));
+
+        // Both are registered as implementors, with the trait recorded
+        let impls = graph.method_impls.get("handle").unwrap();
+        assert_eq!(impls.len(), 2);
+        assert!(impls
+            .iter()
+            .all(|i| i.trait_name.as_deref() == Some("Handler")));
+    }
+
+    #[test]
+    fn test_dynamic_dispatch_possible_edges() {
+        let source = r#"
+trait Handler {
+    fn handle(&self);
+}
+
+struct TcpServer;
+struct UdpServer;
+
+impl Handler for TcpServer {
+    fn handle(&self) {}
+}
+
+impl Handler for UdpServer {
+    fn handle(&self) {}
+}
+
+fn dispatch(h: &dyn Handler) {
+    h.handle();
+}
+"#;
+        let tree = parse_rust(source);
+        let graph = CallGraph::new();
+        graph
+            .build_from_files(&[("server.rs".to_string(), source.to_string(), tree)])
+            .unwrap();
+
+        // The polymorphic call site shows up as a possible caller of
+        // every implementor
+        for implementor in ["TcpServer::handle", "UdpServer::handle"] {
+            let callers = graph.get_callers(implementor);
+            assert!(
+                callers
+                    .iter()
+                    .any(|c| c.target == "dispatch" && c.call_type == CallType::Possible),
+                "{} should list dispatch as a possible caller, got {:?}",
+                implementor,
+                callers
+            );
+        }
+
+        // And the caller fans out to both candidates
+        let callees = graph.get_callees("dispatch");
+        let possible: Vec<_> = callees
+            .iter()
+            .filter(|c| c.call_type == CallType::Possible)
+            .map(|c| c.target.as_str())
+            .collect();
+        assert!(possible.contains(&"TcpServer::handle"));
+        assert!(possible.contains(&"UdpServer::handle"));
+    }
+
+    #[test]
+    fn test_dynamic_dispatch_respects_arity() {
+        let source = r#"
+struct A;
+struct B;
+
+impl A {
+    fn process(&self, x: i32) {}
+}
+
+impl B {
+    fn process(&self, x: i32, y: i32) {}
+}
+
+fn run(a: &A) {
+    a.process(1);
+}
+"#;
+        let tree = parse_rust(source);
+        let graph = CallGraph::new();
+        graph
+            .build_from_files(&[("proc.rs".to_string(), source.to_string(), tree)])
+            .unwrap();
+
+        // One argument matches A::process (self + 1 param) but not
+        // B::process (self + 2 params)
+        let callees = graph.get_callees("run");
+        let possible: Vec<_> = callees
+            .iter()
+            .filter(|c| c.call_type == CallType::Possible)
+            .map(|c| c.target.as_str())
+            .collect();
+        assert!(possible.contains(&"A::process"));
+        assert!(!possible.contains(&"B::process"));
+    }
+
+    #[test]
+    fn test_callback_argument_edges() {
+        let source = r#"
+fn handler() {}
+
+fn register(f: fn()) {
+    f();
+}
+
+fn setup() {
+    register(handler);
+}
+"#;
+        let tree = parse_rust(source);
+        let graph = CallGraph::new();
+        graph
+            .build_from_files(&[("events.rs".to_string(), source.to_string(), tree)])
+            .unwrap();
+
+        // The registration site gets an edge to the callback
+        let callees = graph.get_callees("setup");
+        assert!(callees
+            .iter()
+            .any(|c| c.target == "handler" && c.call_type == CallType::Callback));
+
+        // The callback is no longer an orphan
+        let callers = graph.get_callers("handler");
+        assert!(callers
+            .iter()
+            .any(|c| c.target == "setup" && c.call_type == CallType::Callback));
+    }
+
+    #[test]
+    fn test_spawn_argument_edges() {
+        let source = r#"
+fn worker() {}
+
+fn main() {
+    spawn(worker);
+}
+"#;
+        let tree = parse_rust(source);
+        let graph = CallGraph::new();
+        graph
+            .build_from_files(&[("main.rs".to_string(), source.to_string(), tree)])
+            .unwrap();
+
+        let callers = graph.get_callers("worker");
+        assert!(callers
+            .iter()
+            .any(|c| c.target == "main" && c.call_type == CallType::Spawn));
+    }
+
+    #[test]
+    fn test_plain_variable_arguments_ignored() {
+        let source = r#"
+fn compute(x: i32) -> i32 {
+    x
+}
+
+fn main() {
+    let value = 1;
+    compute(value);
+}
+"#;
+        let tree = parse_rust(source);
+        let graph = CallGraph::new();
+        graph
+            .build_from_files(&[("main.rs".to_string(), source.to_string(), tree)])
+            .unwrap();
+
+        // `value` is not a known function, so no callback edge is added
+        let callees = graph.get_callees("main");
+        assert!(!callees.iter().any(|c| c.call_type == CallType::Callback));
+    }
+
+    #[test]
+    fn test_xml_escape() {
+        assert_eq!(xml_escape("plain"), "plain");
+        assert_eq!(
+            xml_escape("a<b>&\"c\"'d'"),
+            "a&lt;b&gt;&amp;&quot;c&quot;&apos;d&apos;"
+        );
+    }
+
+    #[test]
+    fn test_to_graphml_export() {
+        let source = r#"
+fn helper() {}
+
+fn main() {
+    helper();
+}
+"#;
+        let tree = parse_rust(source);
+        let graph = CallGraph::new();
+        graph
+            .build_from_files(&[("main.rs".to_string(), source.to_string(), tree)])
+            .unwrap();
+
+        let graphml = graph.to_graphml(None);
+
+        assert!(graphml.starts_with("<?xml version=\"1.0\""));
+        assert!(graphml.contains("<graphml"));
+        assert!(graphml.contains("<node id=\"main\">"));
+        assert!(graphml.contains("<node id=\"helper\">"));
+        assert!(graphml.contains("<data key=\"file\">main.rs</data>"));
+        assert!(graphml.contains("<edge source=\"main\" target=\"helper\">"));
+        assert!(graphml.contains("<data key=\"call_type\">Direct</data>"));
+    }
+
+    #[test]
+    fn test_to_cytoscape_json_export() {
+        let source = r#"
+fn helper() {}
+
+fn main() {
+    helper();
+}
+"#;
+        let tree = parse_rust(source);
+        let graph = CallGraph::new();
+        graph
+            .build_from_files(&[("main.rs".to_string(), source.to_string(), tree)])
+            .unwrap();
+
+        let json_str = graph.to_cytoscape_json(None);
+        let parsed: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+
+        let nodes = parsed["elements"]["nodes"].as_array().unwrap();
+        assert_eq!(nodes.len(), 2);
+        assert!(nodes
+            .iter()
+            .any(|n| n["data"]["id"] == "main" && n["data"]["file"] == "main.rs"));
+
+        let edges = parsed["elements"]["edges"].as_array().unwrap();
+        assert_eq!(edges.len(), 1);
+        assert_eq!(edges[0]["data"]["source"], "main");
+        assert_eq!(edges[0]["data"]["target"], "helper");
+        assert_eq!(edges[0]["data"]["call_type"], "Direct");
+    }
+
+    #[test]
+    fn test_export_file_filter_drops_external_edges() {
+        let source_a = r#"
+fn local() {}
+
+fn entry() {
+    local();
+    external();
+}
+"#;
+        let source_b = r#"
+fn external() {}
+"#;
+        let tree_a = parse_rust(source_a);
+        let tree_b = parse_rust(source_b);
+        let graph = CallGraph::new();
+        graph
+            .build_from_files(&[
+                ("a.rs".to_string(), source_a.to_string(), tree_a),
+                ("b.rs".to_string(), source_b.to_string(), tree_b),
+            ])
+            .unwrap();
+
+        let graphml = graph.to_graphml(Some("a.rs"));
+        assert!(graphml.contains("<node id=\"entry\">"));
+        assert!(!graphml.contains("<node id=\"external\">"));
+        // The edge to the filtered-out function is dropped, keeping the
+        // document self-consistent
+        assert!(!graphml.contains("target=\"external\""));
+        assert!(graphml.contains("target=\"local\""));
+    }
+
+    #[test]
+    fn test_pagerank_empty_graph() {
+        let graph = CallGraph::new();
+        assert!(graph.compute_pagerank(0.85, 10).is_empty());
+    }
+
+    #[test]
+    fn test_pagerank_ranks_hub_highest() {
+        let source = r#"
+fn util() {}
+
+fn a() {
+    util();
+}
+
+fn b() {
+    util();
+}
+
+fn c() {
+    util();
+}
+"#;
+        let tree = parse_rust(source);
+        let graph = CallGraph::new();
+        graph
+            .build_from_files(&[("lib.rs".to_string(), source.to_string(), tree)])
+            .unwrap();
+
+        let scored = graph.compute_pagerank(0.85, 30);
+        assert_eq!(scored.len(), 4);
+
+        // The function everyone calls is ranked first
+        assert_eq!(scored[0].0, "util");
+        assert!(scored[0].1 > scored[1].1);
+
+        // Scores form a probability distribution
+        let total: f64 = scored.iter().map(|(_, s)| s).sum();
+        assert!(
+            (total - 1.0).abs() < 1e-6,
+            "scores should sum to 1, got {}",
+            total
+        );
+    }
+
+    #[test]
+    fn test_recursion_cycles_none() {
+        let source = r#"
+fn helper() {}
+
+fn main() {
+    helper();
+}
+"#;
+        let tree = parse_rust(source);
+        let graph = CallGraph::new();
+        graph
+            .build_from_files(&[("main.rs".to_string(), source.to_string(), tree)])
+            .unwrap();
+
+        assert!(graph.find_recursion_cycles().is_empty());
+    }
+
+    #[test]
+    fn test_recursion_cycles_self_recursive() {
+        let source = r#"
+fn factorial(n: u64) -> u64 {
+    if n <= 1 { 1 } else { n * factorial(n - 1) }
+}
+"#;
+        let tree = parse_rust(source);
+        let graph = CallGraph::new();
+        graph
+            .build_from_files(&[("fact.rs".to_string(), source.to_string(), tree)])
+            .unwrap();
+
+        let cycles = graph.find_recursion_cycles();
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0], vec!["factorial".to_string()]);
+    }
+
+    #[test]
+    fn test_recursion_cycles_mutual() {
+        let source = r#"
+fn is_even(n: u64) -> bool {
+    if n == 0 { true } else { is_odd(n - 1) }
+}
+
+fn is_odd(n: u64) -> bool {
+    if n == 0 { false } else { is_even(n - 1) }
+}
+
+fn check(n: u64) -> bool {
+    is_even(n)
+}
+"#;
+        let tree = parse_rust(source);
+        let graph = CallGraph::new();
+        graph
+            .build_from_files(&[("parity.rs".to_string(), source.to_string(), tree)])
+            .unwrap();
+
+        let cycles = graph.find_recursion_cycles();
+        assert_eq!(cycles.len(), 1);
+        // Members are sorted within the group; `check` is outside the cycle
+        assert_eq!(cycles[0], vec!["is_even".to_string(), "is_odd".to_string()]);
+    }
 }
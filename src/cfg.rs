@@ -92,6 +92,8 @@ pub enum StatementKind {
     Return,
     /// Control flow (if, match, loop, etc.)
     ControlFlow,
+    /// Await expression (async suspension point)
+    Await,
     /// Pattern binding (from match arms, for loops, if-let, while-let)
     PatternBinding { variables: Vec<String> },
     /// Other
@@ -126,6 +128,28 @@ pub enum EdgeKind {
     LoopExit,
     /// Exception/error path
     Exception,
+    /// Async suspension point (await) or spawned task boundary
+    Suspension,
+}
+
+/// A branch condition taken along an execution path
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BranchCondition {
+    /// The condition expression (simplified)
+    pub condition: String,
+    /// Which way the branch went
+    pub taken: bool,
+    /// Line of the branching block
+    pub line: usize,
+}
+
+/// A single entry-to-exit path through a function's CFG
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionPath {
+    /// Blocks visited, in order
+    pub blocks: Vec<BlockId>,
+    /// Branch conditions taken along the path
+    pub conditions: Vec<BranchCondition>,
 }
 
 /// The control flow graph for a function
@@ -455,6 +479,86 @@ impl ControlFlowGraph {
         md
     }
 
+    /// Enumerate entry-to-exit paths through the CFG (bounded by `max_paths`).
+    ///
+    /// Loop back edges are not followed and blocks are visited at most once
+    /// per path, so loops contribute their zero- and one-iteration paths.
+    /// Each path records the branch conditions taken along the way, which is
+    /// enough for a caller to derive a test case covering that path.
+    pub fn enumerate_paths(&self, max_paths: usize) -> Vec<ExecutionPath> {
+        let mut paths = Vec::new();
+        let mut blocks = vec![self.entry_block];
+        let mut conditions = Vec::new();
+        self.enumerate_paths_recursive(
+            self.entry_block,
+            &mut blocks,
+            &mut conditions,
+            &mut paths,
+            max_paths,
+        );
+        paths
+    }
+
+    fn enumerate_paths_recursive(
+        &self,
+        current: BlockId,
+        blocks: &mut Vec<BlockId>,
+        conditions: &mut Vec<BranchCondition>,
+        paths: &mut Vec<ExecutionPath>,
+        max_paths: usize,
+    ) {
+        if paths.len() >= max_paths {
+            return;
+        }
+
+        if self.blocks.get(&current).is_some_and(|b| b.is_exit) {
+            paths.push(ExecutionPath {
+                blocks: blocks.clone(),
+                conditions: conditions.clone(),
+            });
+            return;
+        }
+
+        for edge in self.edges.iter().filter(|e| e.from == current) {
+            // Don't follow back edges or revisit blocks: each path takes
+            // each loop at most once
+            if edge.kind == EdgeKind::LoopBack || blocks.contains(&edge.to) {
+                continue;
+            }
+
+            let branch_taken = match edge.kind {
+                EdgeKind::TrueBranch => Some(true),
+                EdgeKind::FalseBranch => Some(false),
+                _ => None,
+            };
+
+            let pushed_condition = if let Some(taken) = branch_taken {
+                if let Some(Terminator::Branch { condition }) =
+                    self.blocks.get(&current).map(|b| &b.terminator)
+                {
+                    conditions.push(BranchCondition {
+                        condition: condition.clone(),
+                        taken,
+                        line: self.blocks.get(&current).map(|b| b.end_line).unwrap_or(0),
+                    });
+                    true
+                } else {
+                    false
+                }
+            } else {
+                false
+            };
+
+            blocks.push(edge.to);
+            self.enumerate_paths_recursive(edge.to, blocks, conditions, paths, max_paths);
+            blocks.pop();
+
+            if pushed_condition {
+                conditions.pop();
+            }
+        }
+    }
+
     /// Export to DOT format for visualization
     pub fn to_dot(&self) -> String {
         let mut dot = String::from("digraph CFG {\n");
@@ -487,7 +591,7 @@ impl ControlFlowGraph {
         // Add edges
         for edge in &self.edges {
             let style = match edge.kind {
-                EdgeKind::LoopBack => "dashed",
+                EdgeKind::LoopBack | EdgeKind::Suspension => "dashed",
                 EdgeKind::Exception => "dotted",
                 _ => "solid",
             };
@@ -495,6 +599,7 @@ impl ControlFlowGraph {
                 EdgeKind::TrueBranch => "green",
                 EdgeKind::FalseBranch => "red",
                 EdgeKind::LoopBack => "blue",
+                EdgeKind::Suspension => "purple",
                 _ => "black",
             };
 
@@ -517,6 +622,10 @@ pub struct CfgBuilder {
     cfg: ControlFlowGraph,
     /// Stack of loop headers for break/continue
     loop_stack: Vec<(BlockId, BlockId)>, // (header, exit)
+    /// Stack of exception handler entry blocks for enclosing try statements
+    handler_stack: Vec<Vec<BlockId>>,
+    /// Synthetic exit block for uncaught exceptions and error propagation
+    error_exit: Option<BlockId>,
 }
 
 impl CfgBuilder {
@@ -526,6 +635,8 @@ impl CfgBuilder {
             next_block_id: 0,
             cfg: ControlFlowGraph::new(function_name, file_path),
             loop_stack: Vec::new(),
+            handler_stack: Vec::new(),
+            error_exit: None,
         }
     }
 
@@ -614,6 +725,31 @@ impl CfgBuilder {
         self.loop_stack.last().map(|(_, e)| *e)
     }
 
+    /// Get or create the synthetic exit block representing error propagation
+    /// out of the function (uncaught throw/raise, Rust `?` or `panic!`)
+    fn error_exit_block(&mut self) -> BlockId {
+        if let Some(id) = self.error_exit {
+            id
+        } else {
+            let id = self.create_block("error_exit");
+            self.set_terminator(id, Terminator::Unreachable);
+            self.set_exit(id);
+            self.error_exit = Some(id);
+            id
+        }
+    }
+
+    /// Where does a raised exception go? The handlers of the innermost
+    /// enclosing try if one exists, otherwise the synthetic error exit.
+    fn exception_targets(&mut self) -> Vec<BlockId> {
+        if let Some(handlers) = self.handler_stack.last() {
+            if !handlers.is_empty() {
+                return handlers.clone();
+            }
+        }
+        vec![self.error_exit_block()]
+    }
+
     /// Build and finalize the CFG
     pub fn build(mut self) -> ControlFlowGraph {
         self.cfg.compute_dominators();
@@ -697,6 +833,25 @@ impl CfgBuilder {
             "for_statement" | "for_expression" => self.process_for(current, node, source),
             "loop_expression" => self.process_loop(current, node, source),
             "match_expression" => self.process_match(current, node, source),
+            "try_statement" => self.process_try(current, node, source),
+            "throw_statement" | "raise_statement" => {
+                self.add_statement(
+                    current,
+                    Statement {
+                        line,
+                        kind: StatementKind::ControlFlow,
+                        text,
+                    },
+                );
+                for target in self.exception_targets() {
+                    self.add_edge(current, target, EdgeKind::Exception);
+                }
+                self.set_terminator(current, Terminator::Unreachable);
+
+                // Code after a throw/raise is unreachable
+                let next = self.create_block("after_throw");
+                Ok(next)
+            }
             "return_statement" | "return_expression" => {
                 // Return creates an exit
                 self.add_statement(
@@ -755,6 +910,7 @@ impl CfgBuilder {
             | "let_statement"
             | "assignment_expression"
             | "expression_statement" => {
+                let is_await = statement_is_await(&text);
                 let stmt_kind = if kind.contains("let") {
                     StatementKind::Assignment {
                         variable: extract_variable_name(node, source).unwrap_or_default(),
@@ -763,6 +919,8 @@ impl CfgBuilder {
                     StatementKind::Assignment {
                         variable: text.split('=').next().unwrap_or("").trim().to_string(),
                     }
+                } else if is_await {
+                    StatementKind::Await
                 } else if text.contains('(') {
                     StatementKind::Call {
                         function: text.split('(').next().unwrap_or("").trim().to_string(),
@@ -771,6 +929,13 @@ impl CfgBuilder {
                     StatementKind::Expression
                 };
 
+                let may_raise = self.statement_may_raise(&text);
+                let spawned_body = if statement_is_spawn(&text) {
+                    find_spawned_body(node)
+                } else {
+                    None
+                };
+
                 self.add_statement(
                     current,
                     Statement {
@@ -779,6 +944,29 @@ impl CfgBuilder {
                         text,
                     },
                 );
+                if may_raise {
+                    for target in self.exception_targets() {
+                        self.add_edge(current, target, EdgeKind::Exception);
+                    }
+                }
+
+                // A spawned task runs concurrently: its body becomes a
+                // separate subgraph reachable only through a Suspension edge
+                if let Some(body) = spawned_body {
+                    let task_entry = self.create_block("spawned_task");
+                    self.add_edge(current, task_entry, EdgeKind::Suspension);
+                    let task_exit = self.process_block_node(task_entry, body, source)?;
+                    self.set_terminator(task_exit, Terminator::Return);
+                }
+
+                // An await is a suspension point: the block ends here and
+                // execution resumes in a new block when the future completes
+                if is_await {
+                    let resume = self.create_block("after_await");
+                    self.add_edge(current, resume, EdgeKind::Suspension);
+                    return Ok(resume);
+                }
+
                 Ok(current)
             }
             // Block - process contents
@@ -1098,6 +1286,147 @@ impl CfgBuilder {
 
         Ok(merge)
     }
+
+    /// Process a try statement (Python try/except, JS/Java/C# try/catch)
+    ///
+    /// Any statement in the try body may raise, so the body entry block gets
+    /// an `Exception` edge to each handler. Body, handlers, and the optional
+    /// else clause all converge on a merge block, flowing through the finally
+    /// block first when one is present.
+    fn process_try(&mut self, current: BlockId, node: Node, source: &[u8]) -> Result<BlockId> {
+        let line = node.start_position().row + 1;
+        self.add_statement(
+            current,
+            Statement {
+                line,
+                kind: StatementKind::ControlFlow,
+                text: "try".to_string(),
+            },
+        );
+
+        // Collect the body, handler, else, and finally clauses up front so
+        // handler blocks exist before the body is processed.
+        let mut body_node = None;
+        let mut handler_nodes = Vec::new();
+        let mut else_node = None;
+        let mut finally_node = None;
+
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            match child.kind() {
+                "except_clause" | "except_group_clause" | "catch_clause" => {
+                    handler_nodes.push(child)
+                }
+                "else_clause" => else_node = Some(child),
+                "finally_clause" => finally_node = Some(child),
+                "block" | "compound_statement" | "statement_block" if body_node.is_none() => {
+                    body_node = Some(child)
+                }
+                _ => {}
+            }
+        }
+
+        let body_block = self.create_block("try_body");
+        self.add_edge(current, body_block, EdgeKind::FallThrough);
+
+        let handler_blocks: Vec<BlockId> = handler_nodes
+            .iter()
+            .map(|_| self.create_block("handler"))
+            .collect();
+
+        // An exception anywhere in the body jumps to a handler
+        for &handler in &handler_blocks {
+            self.add_edge(body_block, handler, EdgeKind::Exception);
+        }
+
+        self.handler_stack.push(handler_blocks.clone());
+        let body_exit = if let Some(body) = body_node {
+            self.process_block_node(body_block, body, source)?
+        } else {
+            body_block
+        };
+        self.handler_stack.pop();
+
+        // Handlers themselves run outside the try's protection
+        let mut handler_exits = Vec::new();
+        for (handler_node, &handler_block) in handler_nodes.iter().zip(&handler_blocks) {
+            handler_exits.push(self.process_block_node(handler_block, *handler_node, source)?);
+        }
+
+        // Python's else clause runs only when no exception was raised
+        let normal_exit = if let Some(else_clause) = else_node {
+            let else_block = self.create_block("try_else");
+            self.add_edge(body_exit, else_block, EdgeKind::FallThrough);
+            self.process_block_node(else_block, else_clause, source)?
+        } else {
+            body_exit
+        };
+
+        let merge = self.create_block("try_merge");
+
+        if let Some(finally) = finally_node {
+            let finally_block = self.create_block("finally");
+            self.add_edge(normal_exit, finally_block, EdgeKind::FallThrough);
+            for &handler_exit in &handler_exits {
+                self.add_edge(handler_exit, finally_block, EdgeKind::FallThrough);
+            }
+            let finally_exit = self.process_block_node(finally_block, finally, source)?;
+            self.add_edge(finally_exit, merge, EdgeKind::FallThrough);
+        } else {
+            self.add_edge(normal_exit, merge, EdgeKind::FallThrough);
+            for &handler_exit in &handler_exits {
+                self.add_edge(handler_exit, merge, EdgeKind::FallThrough);
+            }
+        }
+
+        Ok(merge)
+    }
+
+    /// Heuristic: does a Rust statement potentially propagate an error or
+    /// panic (`?` operator, `panic!`, `.unwrap()`, `.expect(...)`)?
+    fn statement_may_raise(&self, text: &str) -> bool {
+        if !self.cfg.file_path.ends_with(".rs") {
+            return false;
+        }
+        text.contains('?')
+            || text.contains("panic!")
+            || text.contains(".unwrap()")
+            || text.contains(".expect(")
+    }
+}
+
+/// Heuristic: does a statement contain an await suspension point?
+fn statement_is_await(text: &str) -> bool {
+    text.contains(".await") || text.starts_with("await ") || text.contains("= await ")
+}
+
+/// Heuristic: does a statement spawn a concurrent task?
+fn statement_is_spawn(text: &str) -> bool {
+    text.contains("spawn(") || text.contains("spawn_blocking(") || text.contains("create_task(")
+}
+
+/// Find the body of the async block, closure, or lambda passed to a spawn call
+fn find_spawned_body(node: Node) -> Option<Node> {
+    if matches!(
+        node.kind(),
+        "async_block" | "closure_expression" | "arrow_function" | "function_expression" | "lambda"
+    ) {
+        let mut cursor = node.walk();
+        return node.named_children(&mut cursor).find(|child| {
+            matches!(
+                child.kind(),
+                "block" | "statement_block" | "compound_statement"
+            )
+        });
+    }
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        if let Some(found) = find_spawned_body(child) {
+            return Some(found);
+        }
+    }
+    None
 }
 
 /// Find the pattern node within a match arm
@@ -2533,4 +2862,221 @@ mod tests {
             bindings
         );
     }
+
+    #[test]
+    fn test_enumerate_paths_diamond() {
+        let mut cfg = ControlFlowGraph::new("test_func", "test.rs");
+
+        let entry = BasicBlock {
+            id: 0,
+            label: "entry".to_string(),
+            start_line: 1,
+            end_line: 1,
+            terminator: Terminator::Branch {
+                condition: "x > 0".to_string(),
+            },
+            statements: Vec::new(),
+            is_entry: true,
+            is_exit: false,
+        };
+        let then_block = BasicBlock {
+            id: 1,
+            label: "then".to_string(),
+            start_line: 2,
+            end_line: 2,
+            terminator: Terminator::FallThrough,
+            statements: Vec::new(),
+            is_entry: false,
+            is_exit: false,
+        };
+        let else_block = BasicBlock {
+            id: 2,
+            label: "else".to_string(),
+            start_line: 3,
+            end_line: 3,
+            terminator: Terminator::FallThrough,
+            statements: Vec::new(),
+            is_entry: false,
+            is_exit: false,
+        };
+        let exit = BasicBlock {
+            id: 3,
+            label: "exit".to_string(),
+            start_line: 4,
+            end_line: 4,
+            terminator: Terminator::Return,
+            statements: Vec::new(),
+            is_entry: false,
+            is_exit: true,
+        };
+        cfg.add_block(entry);
+        cfg.add_block(then_block);
+        cfg.add_block(else_block);
+        cfg.add_block(exit);
+        cfg.entry_block = 0;
+        cfg.exit_blocks.push(3);
+
+        cfg.add_edge(0, 1, EdgeKind::TrueBranch);
+        cfg.add_edge(0, 2, EdgeKind::FalseBranch);
+        cfg.add_edge(1, 3, EdgeKind::FallThrough);
+        cfg.add_edge(2, 3, EdgeKind::FallThrough);
+
+        let paths = cfg.enumerate_paths(10);
+        assert_eq!(paths.len(), 2, "diamond CFG should have exactly 2 paths");
+
+        // Both paths record the branch condition with opposite outcomes
+        let outcomes: Vec<bool> = paths
+            .iter()
+            .map(|p| {
+                assert_eq!(p.conditions.len(), 1);
+                assert_eq!(p.conditions[0].condition, "x > 0");
+                p.conditions[0].taken
+            })
+            .collect();
+        assert!(outcomes.contains(&true) && outcomes.contains(&false));
+
+        // Bounding works
+        let bounded = cfg.enumerate_paths(1);
+        assert_eq!(bounded.len(), 1);
+    }
+
+    #[test]
+    fn test_try_except_exception_edges() {
+        let mut parser = tree_sitter::Parser::new();
+        parser
+            .set_language(&tree_sitter_python::LANGUAGE.into())
+            .unwrap();
+
+        let source = r#"
+def risky():
+    try:
+        do_work()
+    except ValueError:
+        handle()
+    finally:
+        cleanup()
+"#;
+        let tree = parser.parse(source, None).unwrap();
+        let cfgs = analyze_function(&tree, source, "test.py").unwrap();
+        assert_eq!(cfgs.len(), 1);
+
+        let cfg = &cfgs[0];
+        assert!(
+            cfg.edges.iter().any(|e| e.kind == EdgeKind::Exception),
+            "try/except should produce an Exception edge"
+        );
+        assert!(
+            cfg.blocks.values().any(|b| b.label == "handler"),
+            "except clause should have a handler block"
+        );
+        assert!(
+            cfg.blocks.values().any(|b| b.label == "finally"),
+            "finally clause should have a finally block"
+        );
+        // The handler is reachable via the exception edge, so it must not
+        // be flagged as unreachable
+        let handler_id = cfg
+            .blocks
+            .values()
+            .find(|b| b.label == "handler")
+            .unwrap()
+            .id;
+        assert!(
+            !cfg.unreachable_blocks.contains(&handler_id),
+            "handler should be reachable through the Exception edge"
+        );
+    }
+
+    #[test]
+    fn test_rust_question_mark_exception_edge() {
+        let mut parser = tree_sitter::Parser::new();
+        parser
+            .set_language(&tree_sitter_rust::LANGUAGE.into())
+            .unwrap();
+
+        let source = r#"
+fn read(path: &str) -> std::io::Result<String> {
+    let content = std::fs::read_to_string(path)?;
+    Ok(content)
+}
+"#;
+        let tree = parser.parse(source, None).unwrap();
+        let cfgs = analyze_function(&tree, source, "test.rs").unwrap();
+        assert_eq!(cfgs.len(), 1);
+
+        let cfg = &cfgs[0];
+        assert!(
+            cfg.edges.iter().any(|e| e.kind == EdgeKind::Exception),
+            "`?` should produce an Exception edge to the error exit"
+        );
+        assert!(
+            cfg.blocks.values().any(|b| b.label == "error_exit"),
+            "error propagation should create a synthetic error_exit block"
+        );
+    }
+
+    #[test]
+    fn test_await_suspension_edges() {
+        let mut parser = tree_sitter::Parser::new();
+        parser
+            .set_language(&tree_sitter_rust::LANGUAGE.into())
+            .unwrap();
+
+        let source = r#"
+async fn fetch(url: String) {
+    let body = client.get(url).send().await;
+    process(body);
+}
+"#;
+        let tree = parser.parse(source, None).unwrap();
+        let cfgs = analyze_function(&tree, source, "test.rs").unwrap();
+        assert_eq!(cfgs.len(), 1);
+
+        let cfg = &cfgs[0];
+        assert!(
+            cfg.edges.iter().any(|e| e.kind == EdgeKind::Suspension),
+            "await should produce a Suspension edge"
+        );
+        assert!(
+            cfg.blocks.values().any(|b| b.label == "after_await"),
+            "await should split the block at the suspension point"
+        );
+    }
+
+    #[test]
+    fn test_spawned_task_subgraph() {
+        let mut parser = tree_sitter::Parser::new();
+        parser
+            .set_language(&tree_sitter_rust::LANGUAGE.into())
+            .unwrap();
+
+        let source = r#"
+fn start(worker: Worker) {
+    tokio::spawn(async move {
+        worker.run();
+    });
+    ready();
+}
+"#;
+        let tree = parser.parse(source, None).unwrap();
+        let cfgs = analyze_function(&tree, source, "test.rs").unwrap();
+        assert_eq!(cfgs.len(), 1);
+
+        let cfg = &cfgs[0];
+        let task_entry = cfg
+            .blocks
+            .values()
+            .find(|b| b.label == "spawned_task")
+            .expect("spawn should create a spawned_task block");
+        assert!(
+            cfg.edges
+                .iter()
+                .any(|e| e.to == task_entry.id && e.kind == EdgeKind::Suspension),
+            "spawned task should be connected via a Suspension edge"
+        );
+        assert!(
+            !cfg.unreachable_blocks.contains(&task_entry.id),
+            "spawned task body should not be flagged as unreachable"
+        );
+    }
 }
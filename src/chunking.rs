@@ -89,9 +89,28 @@ impl std::fmt::Display for ChunkType {
     }
 }
 
+/// Strategy for picking chunk boundaries
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ChunkingStrategy {
+    /// Split at AST symbol boundaries (functions, classes), falling back
+    /// to sliding windows for unparseable files
+    #[default]
+    AstBoundary,
+    /// Fixed-size sliding window with overlap, ignoring the AST; some
+    /// embedding providers prefer uniform chunk sizes
+    SlidingWindow,
+}
+
 /// Configuration for the chunker
-#[derive(Debug, Clone)]
+///
+/// Deserializable from the YAML config file; unset fields keep their
+/// defaults, and `language_overrides` tunes individual languages.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ChunkerConfig {
+    /// How chunk boundaries are chosen
+    pub strategy: ChunkingStrategy,
     /// Maximum lines per chunk
     pub max_chunk_lines: usize,
     /// Minimum lines per chunk (to avoid tiny chunks)
@@ -100,22 +119,75 @@ pub struct ChunkerConfig {
     pub overlap_lines: usize,
     /// Include surrounding context (imports, class definition)
     pub include_context: bool,
+    /// Extract import statements and attach them to chunks
+    pub include_imports: bool,
     /// Maximum context lines to include
     pub max_context_lines: usize,
+    /// Per-language overrides, keyed by language name ("rust", "python", ...)
+    pub language_overrides: HashMap<String, ChunkerOverrides>,
 }
 
 impl Default for ChunkerConfig {
     fn default() -> Self {
         Self {
+            strategy: ChunkingStrategy::default(),
             max_chunk_lines: 100,
             min_chunk_lines: 5,
             overlap_lines: 5,
             include_context: true,
+            include_imports: true,
             max_context_lines: 20,
+            language_overrides: HashMap::new(),
+        }
+    }
+}
+
+impl ChunkerConfig {
+    /// Resolve the effective configuration for one language by applying
+    /// its overrides, if any; unset override fields inherit the base value
+    pub fn for_language(&self, lang: &str) -> ChunkerConfig {
+        let mut config = self.clone();
+        if let Some(overrides) = self.language_overrides.get(lang) {
+            if let Some(strategy) = overrides.strategy {
+                config.strategy = strategy;
+            }
+            if let Some(max) = overrides.max_chunk_lines {
+                config.max_chunk_lines = max;
+            }
+            if let Some(min) = overrides.min_chunk_lines {
+                config.min_chunk_lines = min;
+            }
+            if let Some(overlap) = overrides.overlap_lines {
+                config.overlap_lines = overlap;
+            }
+            if let Some(context) = overrides.include_context {
+                config.include_context = context;
+            }
+            if let Some(imports) = overrides.include_imports {
+                config.include_imports = imports;
+            }
+            if let Some(max_context) = overrides.max_context_lines {
+                config.max_context_lines = max_context;
+            }
         }
+        config
     }
 }
 
+/// Per-language overrides for [`ChunkerConfig`]; unset fields inherit
+/// the base configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChunkerOverrides {
+    pub strategy: Option<ChunkingStrategy>,
+    pub max_chunk_lines: Option<usize>,
+    pub min_chunk_lines: Option<usize>,
+    pub overlap_lines: Option<usize>,
+    pub include_context: Option<bool>,
+    pub include_imports: Option<bool>,
+    pub max_context_lines: Option<usize>,
+}
+
 /// Parameters for creating a top-level code chunk
 struct ToplevelChunkParams<'a> {
     content: &'a str,
@@ -127,6 +199,15 @@ struct ToplevelChunkParams<'a> {
     chunk_id: usize,
 }
 
+/// Parameters for creating chunks from a symbol boundary
+struct BoundaryChunkParams<'a> {
+    content: &'a str,
+    file_path: &'a str,
+    lang: &'a str,
+    boundary: &'a SymbolBoundary,
+    imports: &'a [String],
+}
+
 /// Boundary information for a symbol in the AST
 #[derive(Debug, Clone)]
 struct SymbolBoundary {
@@ -166,14 +247,20 @@ impl AstChunker {
     /// Chunk a file respecting AST boundaries
     pub fn chunk_file(&self, content: &str, file_path: &str) -> Vec<CodeChunk> {
         let lang = self.detect_language(file_path);
+        let config = self.config.for_language(&lang);
         let path = std::path::Path::new(file_path);
 
+        // Sliding-window mode skips the AST entirely
+        if config.strategy == ChunkingStrategy::SlidingWindow {
+            return self.chunk_by_lines(content, file_path, &lang, &config);
+        }
+
         // Try to parse and chunk based on AST
         match self.parser.parse_file(path, content) {
-            Ok(parsed) => self.chunk_parsed_file(&parsed, content, file_path, &lang),
+            Ok(parsed) => self.chunk_parsed_file(&parsed, content, file_path, &lang, &config),
             Err(_) => {
                 // Fall back to line-based chunking
-                self.chunk_by_lines(content, file_path, &lang)
+                self.chunk_by_lines(content, file_path, &lang, &config)
             }
         }
     }
@@ -185,18 +272,23 @@ impl AstChunker {
         content: &str,
         file_path: &str,
         lang: &str,
+        config: &ChunkerConfig,
     ) -> Vec<CodeChunk> {
         let lines: Vec<&str> = content.lines().collect();
         let tree = match &parsed.tree {
             Some(t) => t,
-            None => return self.chunk_by_lines(content, file_path, lang),
+            None => return self.chunk_by_lines(content, file_path, lang, config),
         };
 
         // Extract symbol boundaries from AST
         let boundaries = self.extract_boundaries(tree, content, lang);
 
         // Extract imports for context
-        let imports = self.extract_imports(tree, content, lang);
+        let imports = if config.include_imports {
+            self.extract_imports(tree, content, lang)
+        } else {
+            Vec::new()
+        };
 
         // Create chunks from boundaries
         let mut chunks = Vec::new();
@@ -222,21 +314,19 @@ impl AstChunker {
 
             // Check if chunk is too large
             let chunk_lines = end.saturating_sub(start) + 1;
-            if chunk_lines > self.config.max_chunk_lines {
+            let params = BoundaryChunkParams {
+                content,
+                file_path,
+                lang,
+                boundary,
+                imports: &imports,
+            };
+            if chunk_lines > config.max_chunk_lines {
                 // Split large chunks
-                let split_chunks = self.split_large_chunk(
-                    content,
-                    file_path,
-                    lang,
-                    boundary,
-                    &imports,
-                    &mut chunk_id,
-                );
+                let split_chunks = self.split_large_chunk(params, &mut chunk_id, config);
                 chunks.extend(split_chunks);
             } else {
-                let chunk = self.create_chunk_from_boundary(
-                    content, file_path, lang, boundary, &imports, chunk_id,
-                );
+                let chunk = self.create_chunk_from_boundary(params, chunk_id, config);
                 chunks.push(chunk);
                 chunk_id += 1;
             }
@@ -251,16 +341,19 @@ impl AstChunker {
                 }
             } else if let Some(start) = uncovered_start {
                 let end = i - 1;
-                if end >= start + self.config.min_chunk_lines {
-                    chunks.push(self.create_toplevel_chunk(ToplevelChunkParams {
-                        content,
-                        file_path,
-                        lang,
-                        start,
-                        end,
-                        imports: &imports,
-                        chunk_id,
-                    }));
+                if end >= start + config.min_chunk_lines {
+                    chunks.push(self.create_toplevel_chunk(
+                        ToplevelChunkParams {
+                            content,
+                            file_path,
+                            lang,
+                            start,
+                            end,
+                            imports: &imports,
+                            chunk_id,
+                        },
+                        config,
+                    ));
                     chunk_id += 1;
                 }
                 uncovered_start = None;
@@ -270,16 +363,19 @@ impl AstChunker {
         // Handle remaining uncovered lines at end
         if let Some(start) = uncovered_start {
             let end = lines.len();
-            if end >= start + self.config.min_chunk_lines {
-                chunks.push(self.create_toplevel_chunk(ToplevelChunkParams {
-                    content,
-                    file_path,
-                    lang,
-                    start,
-                    end,
-                    imports: &imports,
-                    chunk_id,
-                }));
+            if end >= start + config.min_chunk_lines {
+                chunks.push(self.create_toplevel_chunk(
+                    ToplevelChunkParams {
+                        content,
+                        file_path,
+                        lang,
+                        start,
+                        end,
+                        imports: &imports,
+                        chunk_id,
+                    },
+                    config,
+                ));
             }
         }
 
@@ -287,7 +383,7 @@ impl AstChunker {
         chunks.sort_by_key(|c| c.start_line);
 
         // Merge adjacent small chunks if needed
-        self.merge_small_chunks(chunks)
+        self.merge_small_chunks(chunks, config)
     }
 
     /// Extract symbol boundaries from AST
@@ -553,13 +649,17 @@ impl AstChunker {
     /// Create a chunk from a symbol boundary
     fn create_chunk_from_boundary(
         &self,
-        content: &str,
-        file_path: &str,
-        lang: &str,
-        boundary: &SymbolBoundary,
-        imports: &[String],
+        params: BoundaryChunkParams,
         chunk_id: usize,
+        config: &ChunkerConfig,
     ) -> CodeChunk {
+        let BoundaryChunkParams {
+            content,
+            file_path,
+            lang,
+            boundary,
+            imports,
+        } = params;
         let start = boundary.doc_start.unwrap_or(boundary.start_line);
         let end = boundary.end_line;
 
@@ -604,7 +704,7 @@ impl AstChunker {
             symbol_context,
             chunk_type: boundary.chunk_type,
             doc_comment,
-            imports: if self.config.include_context {
+            imports: if config.include_context {
                 imports.to_vec()
             } else {
                 Vec::new()
@@ -615,13 +715,17 @@ impl AstChunker {
     /// Split a large chunk into smaller pieces
     fn split_large_chunk(
         &self,
-        content: &str,
-        file_path: &str,
-        lang: &str,
-        boundary: &SymbolBoundary,
-        imports: &[String],
+        params: BoundaryChunkParams,
         chunk_id: &mut usize,
+        config: &ChunkerConfig,
     ) -> Vec<CodeChunk> {
+        let BoundaryChunkParams {
+            content,
+            file_path,
+            lang,
+            boundary,
+            imports,
+        } = params;
         let lines: Vec<&str> = content.lines().collect();
         let start = boundary.doc_start.unwrap_or(boundary.start_line);
         let end = boundary.end_line;
@@ -629,7 +733,7 @@ impl AstChunker {
 
         let mut current_start = start;
         while current_start <= end {
-            let current_end = (current_start + self.config.max_chunk_lines - 1).min(end);
+            let current_end = (current_start + config.max_chunk_lines - 1).min(end);
 
             let chunk_content = lines
                 .get(current_start.saturating_sub(1)..current_end.min(lines.len()))
@@ -685,7 +789,7 @@ impl AstChunker {
                 } else {
                     None
                 },
-                imports: if self.config.include_context && is_first {
+                imports: if config.include_context && is_first {
                     imports.to_vec()
                 } else {
                     Vec::new()
@@ -695,7 +799,7 @@ impl AstChunker {
             *chunk_id += 1;
 
             // Move to next chunk with overlap
-            current_start = current_end + 1 - self.config.overlap_lines;
+            current_start = current_end + 1 - config.overlap_lines;
             if current_start <= current_end {
                 current_start = current_end + 1;
             }
@@ -705,7 +809,11 @@ impl AstChunker {
     }
 
     /// Create a chunk for top-level code
-    fn create_toplevel_chunk(&self, params: ToplevelChunkParams<'_>) -> CodeChunk {
+    fn create_toplevel_chunk(
+        &self,
+        params: ToplevelChunkParams<'_>,
+        config: &ChunkerConfig,
+    ) -> CodeChunk {
         let lines: Vec<&str> = params.content.lines().collect();
         let chunk_content = lines
             .get(params.start.saturating_sub(1)..params.end.min(lines.len()))
@@ -722,7 +830,7 @@ impl AstChunker {
             symbol_context: None,
             chunk_type: ChunkType::TopLevel,
             doc_comment: None,
-            imports: if self.config.include_context {
+            imports: if config.include_context {
                 params.imports.to_vec()
             } else {
                 Vec::new()
@@ -731,14 +839,20 @@ impl AstChunker {
     }
 
     /// Fall back to simple line-based chunking
-    fn chunk_by_lines(&self, content: &str, file_path: &str, lang: &str) -> Vec<CodeChunk> {
+    fn chunk_by_lines(
+        &self,
+        content: &str,
+        file_path: &str,
+        lang: &str,
+        config: &ChunkerConfig,
+    ) -> Vec<CodeChunk> {
         let lines: Vec<&str> = content.lines().collect();
         let mut chunks = Vec::new();
         let mut chunk_id = 0;
         let mut start = 1;
 
         while start <= lines.len() {
-            let end = (start + self.config.max_chunk_lines - 1).min(lines.len());
+            let end = (start + config.max_chunk_lines - 1).min(lines.len());
             let chunk_content = lines
                 .get(start.saturating_sub(1)..end)
                 .map(|ls| ls.join("\n"))
@@ -758,7 +872,7 @@ impl AstChunker {
             });
 
             chunk_id += 1;
-            start = end + 1 - self.config.overlap_lines;
+            start = (end + 1).saturating_sub(config.overlap_lines);
             if start <= end {
                 start = end + 1;
             }
@@ -768,7 +882,7 @@ impl AstChunker {
     }
 
     /// Merge adjacent small chunks
-    fn merge_small_chunks(&self, chunks: Vec<CodeChunk>) -> Vec<CodeChunk> {
+    fn merge_small_chunks(&self, chunks: Vec<CodeChunk>, config: &ChunkerConfig) -> Vec<CodeChunk> {
         if chunks.is_empty() {
             return chunks;
         }
@@ -784,9 +898,8 @@ impl AstChunker {
                 let combined_lines = curr_lines + chunk_lines;
 
                 // Merge if both are small and same type or adjacent
-                if combined_lines <= self.config.max_chunk_lines
-                    && (curr_lines < self.config.min_chunk_lines
-                        || chunk_lines < self.config.min_chunk_lines)
+                if combined_lines <= config.max_chunk_lines
+                    && (curr_lines < config.min_chunk_lines || chunk_lines < config.min_chunk_lines)
                     && chunk.start_line <= curr.end_line + 2
                 {
                     // Merge chunks
@@ -1150,6 +1263,124 @@ fn d() {}
         assert_eq!(format!("{}", ChunkType::Module), "module");
     }
 
+    #[test]
+    fn test_sliding_window_strategy_ignores_ast() {
+        let code = r#"
+fn foo() {
+    println!("foo");
+}
+
+fn bar() {
+    println!("bar");
+}
+"#;
+        let config = ChunkerConfig {
+            strategy: ChunkingStrategy::SlidingWindow,
+            max_chunk_lines: 4,
+            ..Default::default()
+        };
+        let chunker = AstChunker::with_config(config);
+        let chunks = chunker.chunk_file(code, "test.rs");
+
+        // Windows are cut by line count, not symbols
+        assert!(chunks.len() > 1, "Should produce multiple windows");
+        assert!(chunks.iter().all(|c| c.id.ends_with(":lines")));
+        assert!(chunks.iter().all(|c| c.symbol_context.is_none()));
+    }
+
+    #[test]
+    fn test_language_overrides_applied() {
+        let mut language_overrides = HashMap::new();
+        language_overrides.insert(
+            "python".to_string(),
+            ChunkerOverrides {
+                strategy: Some(ChunkingStrategy::SlidingWindow),
+                ..Default::default()
+            },
+        );
+        let config = ChunkerConfig {
+            language_overrides,
+            ..Default::default()
+        };
+        let chunker = AstChunker::with_config(config);
+
+        // Python uses the sliding-window override, Rust keeps AST boundaries
+        let py_chunks = chunker.chunk_file("def hello():\n    pass\n", "test.py");
+        assert!(py_chunks.iter().all(|c| c.id.ends_with(":lines")));
+
+        let rs_chunks = chunker.chunk_file("fn hello() {\n    let x = 1;\n}\n", "test.rs");
+        assert!(rs_chunks.iter().any(|c| c.symbol_context.is_some()));
+    }
+
+    #[test]
+    fn test_for_language_inherits_unset_fields() {
+        let mut language_overrides = HashMap::new();
+        language_overrides.insert(
+            "go".to_string(),
+            ChunkerOverrides {
+                max_chunk_lines: Some(40),
+                ..Default::default()
+            },
+        );
+        let config = ChunkerConfig {
+            max_chunk_lines: 100,
+            overlap_lines: 7,
+            language_overrides,
+            ..Default::default()
+        };
+
+        let go_config = config.for_language("go");
+        assert_eq!(go_config.max_chunk_lines, 40);
+        assert_eq!(go_config.overlap_lines, 7); // inherited
+
+        let rust_config = config.for_language("rust");
+        assert_eq!(rust_config.max_chunk_lines, 100);
+    }
+
+    #[test]
+    fn test_include_imports_disabled() {
+        let code = r#"
+use std::collections::HashMap;
+
+fn main() {
+    let map = HashMap::new();
+}
+"#;
+        let config = ChunkerConfig {
+            include_imports: false,
+            ..Default::default()
+        };
+        let chunker = AstChunker::with_config(config);
+        let chunks = chunker.chunk_file(code, "test.rs");
+
+        let fn_chunk = chunks
+            .iter()
+            .find(|c| c.content.contains("fn main"))
+            .unwrap();
+        assert!(fn_chunk.imports.is_empty(), "Imports should be skipped");
+    }
+
+    #[test]
+    fn test_chunker_config_from_yaml() {
+        let yaml = r#"
+strategy: sliding-window
+max_chunk_lines: 60
+language_overrides:
+  python:
+    strategy: ast-boundary
+    include_imports: false
+"#;
+        let config: ChunkerConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.strategy, ChunkingStrategy::SlidingWindow);
+        assert_eq!(config.max_chunk_lines, 60);
+        assert_eq!(config.min_chunk_lines, 5); // default preserved
+
+        let py = config.for_language("python");
+        assert_eq!(py.strategy, ChunkingStrategy::AstBoundary);
+        assert!(!py.include_imports);
+        assert_eq!(py.max_chunk_lines, 60); // inherited from base
+    }
+
     #[test]
     fn test_detect_language() {
         let chunker = AstChunker::new();
@@ -4,7 +4,7 @@
 /// Must complete in <1ms for responsive tool list queries.
 use crate::config::editor::get_editor_preset_or_full;
 use crate::config::preset::Preset;
-use crate::config::schema::ToolConfig;
+use crate::config::schema::{ClientMapping, ToolConfig};
 use crate::index::EngineOptions;
 use crate::tool_metadata::{FeatureFlag, PerformanceImpact, ToolMetadata, TOOL_METADATA};
 use std::collections::HashSet;
@@ -21,6 +21,9 @@ pub struct ToolFilter {
     config: ToolConfig,
     enabled_flags: HashSet<FeatureFlag>,
     preset: Preset,
+    /// Explicit tool whitelist from a matching client mapping; when set it
+    /// takes the place of a preset whitelist
+    client_tools: Option<HashSet<String>>,
 }
 
 impl ToolFilter {
@@ -32,13 +35,27 @@ impl ToolFilter {
     ) -> Self {
         let enabled_flags = Self::convert_engine_options(engine_options);
 
-        // Determine preset with priority: config.preset > client_info > Full
+        // Determine preset with priority:
+        // config.preset > custom client mapping > built-in editor table > Full
+        let mut client_tools: Option<HashSet<String>> = None;
         let preset = if let Some(ref preset_str) = config.preset {
             // Config preset has highest priority
             Preset::parse(preset_str).unwrap_or(Preset::Full)
         } else if let Some(ref client) = client_info {
-            // Fall back to client-based preset
-            get_editor_preset_or_full(&client.name)
+            match Self::find_client_mapping(&config, &client.name) {
+                Some(mapping) if !mapping.tools.is_empty() => {
+                    client_tools = Some(mapping.tools.iter().cloned().collect());
+                    // The explicit list governs; Full keeps preset checks open
+                    Preset::Full
+                }
+                Some(mapping) => mapping
+                    .preset
+                    .as_deref()
+                    .and_then(Preset::parse)
+                    .unwrap_or_else(|| get_editor_preset_or_full(&client.name)),
+                // Fall back to the built-in editor table
+                None => get_editor_preset_or_full(&client.name),
+            }
         } else {
             // Default to full preset
             Preset::Full
@@ -48,9 +65,25 @@ impl ToolFilter {
             config,
             enabled_flags,
             preset,
+            client_tools,
         }
     }
 
+    /// Find the first custom client mapping whose pattern matches the
+    /// client name (case-insensitive regex; invalid patterns are skipped)
+    fn find_client_mapping<'a>(
+        config: &'a ToolConfig,
+        client_name: &str,
+    ) -> Option<&'a ClientMapping> {
+        config.client_mappings.iter().find(|mapping| {
+            regex::RegexBuilder::new(&mapping.pattern)
+                .case_insensitive(true)
+                .build()
+                .map(|re| re.is_match(client_name))
+                .unwrap_or(false)
+        })
+    }
+
     /// Convert EngineOptions to a set of FeatureFlags
     pub fn convert_engine_options(options: &EngineOptions) -> HashSet<FeatureFlag> {
         let mut flags = HashSet::new();
@@ -73,6 +106,9 @@ impl ToolFilter {
         if options.neural_config.enabled {
             flags.insert(FeatureFlag::Neural);
         }
+        if options.write_enabled {
+            flags.insert(FeatureFlag::Write);
+        }
         // Remote flag would be set via engine options if there's a remote field
         // For now, we'll check the neural_config backend or other indicators
         // This can be extended as needed
@@ -105,13 +141,20 @@ impl ToolFilter {
             // If explicitly enabled via override, still need to check required flags
         }
 
-        // 2. Check if preset explicitly disables this tool
+        // 2. An explicit client tool list acts as a whitelist
+        if let Some(ref allowed) = self.client_tools {
+            if !allowed.contains(tool_name) {
+                return false; // Not in the client's explicit tool list
+            }
+        }
+
+        // 3. Check if preset explicitly disables this tool
         let disabled_by_preset = self.preset.get_disabled_tools();
         if disabled_by_preset.contains(tool_name) {
             return false; // Disabled by preset
         }
 
-        // 3. Check if preset has an enabled whitelist
+        // 4. Check if preset has an enabled whitelist
         let enabled_by_preset = self.preset.get_enabled_tools();
         if !enabled_by_preset.is_empty() {
             // Preset has a whitelist (not Full preset)
@@ -121,7 +164,7 @@ impl ToolFilter {
         }
         // If preset is Full (empty whitelist), all tools are allowed
 
-        // 4. Check if tool's category is enabled
+        // 5. Check if tool's category is enabled
         let category_name = format!("{:?}", metadata.category);
         if let Some(category_config) = self.config.tools.categories.get(&category_name) {
             if !category_config.enabled {
@@ -129,7 +172,7 @@ impl ToolFilter {
             }
         }
 
-        // 5. Check required feature flags
+        // 6. Check required feature flags
         if !metadata.required_flags.is_empty() {
             // Tool requires specific flags - must have ALL of them
             for required_flag in &metadata.required_flags {
@@ -139,7 +182,7 @@ impl ToolFilter {
             }
         }
 
-        // 6. All checks passed
+        // 7. All checks passed
         true
     }
 
@@ -189,6 +232,7 @@ mod tests {
             call_graph_enabled: true,
             persist_enabled: true,
             watch_enabled: true,
+            write_enabled: true,
             lsp_config: crate::lsp::LspConfig {
                 enabled: true,
                 ..Default::default()
@@ -202,13 +246,14 @@ mod tests {
 
         let flags = ToolFilter::convert_engine_options(&options);
 
-        assert_eq!(flags.len(), 6);
+        assert_eq!(flags.len(), 7);
         assert!(flags.contains(&FeatureFlag::Git));
         assert!(flags.contains(&FeatureFlag::CallGraph));
         assert!(flags.contains(&FeatureFlag::Persist));
         assert!(flags.contains(&FeatureFlag::Watch));
         assert!(flags.contains(&FeatureFlag::Lsp));
         assert!(flags.contains(&FeatureFlag::Neural));
+        assert!(flags.contains(&FeatureFlag::Write));
     }
 
     #[test]
@@ -302,6 +347,73 @@ mod tests {
         assert!(!filter.is_tool_enabled("list_repos", meta));
     }
 
+    #[test]
+    fn test_client_mapping_preset() {
+        use crate::config::schema::ClientMapping;
+
+        let mut config = ToolConfig::default();
+        config.client_mappings.push(ClientMapping {
+            pattern: "windsurf".to_string(),
+            preset: Some("minimal".to_string()),
+            tools: vec![],
+        });
+
+        let options = EngineOptions::default();
+        let client = ClientInfo {
+            name: "Windsurf 1.2".to_string(),
+            version: None,
+        };
+        let filter = ToolFilter::new(config, &options, Some(client));
+
+        assert_eq!(filter.preset, Preset::Minimal);
+    }
+
+    #[test]
+    fn test_client_mapping_explicit_tools() {
+        use crate::config::schema::ClientMapping;
+
+        let mut config = ToolConfig::default();
+        config.client_mappings.push(ClientMapping {
+            pattern: "^nvim-".to_string(),
+            preset: None,
+            tools: vec!["list_repos".to_string(), "search_code".to_string()],
+        });
+
+        let options = EngineOptions::default();
+        let client = ClientInfo {
+            name: "nvim-mcp-client".to_string(),
+            version: None,
+        };
+        let filter = ToolFilter::new(config, &options, Some(client));
+
+        let enabled = filter.get_enabled_tools();
+        assert_eq!(enabled.len(), 2);
+        assert!(enabled.contains(&"list_repos"));
+        assert!(enabled.contains(&"search_code"));
+    }
+
+    #[test]
+    fn test_client_mapping_unmatched_falls_back() {
+        use crate::config::schema::ClientMapping;
+
+        let mut config = ToolConfig::default();
+        config.client_mappings.push(ClientMapping {
+            pattern: "windsurf".to_string(),
+            preset: Some("minimal".to_string()),
+            tools: vec![],
+        });
+
+        let options = EngineOptions::default();
+        let client = ClientInfo {
+            name: "zed".to_string(),
+            version: None,
+        };
+        let filter = ToolFilter::new(config, &options, Some(client));
+
+        // Falls back to the built-in editor table (zed -> Minimal)
+        assert_eq!(filter.preset, Preset::Minimal);
+    }
+
     #[test]
     fn test_apply_performance_budget() {
         let mut config = ToolConfig::default();
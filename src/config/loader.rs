@@ -54,7 +54,7 @@ tools:
       description: "Graph visualization"
   overrides: {}
 performance:
-  max_tool_count: 76
+  max_tool_count: 108
   startup_latency_ms: 10
   filtering_latency_ms: 1
 "#;
@@ -110,12 +110,34 @@ impl ConfigLoader {
             config = Self::merge_configs(config, project_config);
         }
 
+        // Apply the profile selected via NARSIL_PROFILE, if any
+        if let Ok(profile_name) = std::env::var("NARSIL_PROFILE") {
+            config = Self::apply_profile(config, &profile_name)?;
+        }
+
         // Apply environment variable overrides
         Self::apply_env_overrides(&mut config)?;
 
         Ok(config)
     }
 
+    /// Overlay a named profile onto the resolved configuration
+    fn apply_profile(mut config: ToolConfig, name: &str) -> Result<ToolConfig> {
+        let profile = config.profiles.remove(name).with_context(|| {
+            format!(
+                "Profile '{}' not found in configuration (available: {:?})",
+                name,
+                {
+                    let mut names: Vec<&str> = config.profiles.keys().map(String::as_str).collect();
+                    names.sort_unstable();
+                    names
+                }
+            )
+        })?;
+
+        Ok(Self::merge_configs(config, profile.into_overlay()))
+    }
+
     /// Get the default user config path for the current platform
     ///
     /// Returns the platform-specific configuration directory:
@@ -227,7 +249,7 @@ impl ConfigLoader {
         }
 
         // Merge performance config (overlay takes precedence)
-        if overlay.performance.max_tool_count != 76 {
+        if overlay.performance.max_tool_count != 108 {
             base.performance.max_tool_count = overlay.performance.max_tool_count;
         }
         if overlay.performance.startup_latency_ms != 10 {
@@ -242,6 +264,22 @@ impl ConfigLoader {
             base.feature_requirements.insert(name, req);
         }
 
+        // Architecture rules replace wholesale when the overlay declares any
+        // (project configs define their own layering, they don't extend ours)
+        if !overlay.architecture.layers.is_empty() || !overlay.architecture.rules.is_empty() {
+            base.architecture = overlay.architecture;
+        }
+
+        // Merge profiles (a project config can add or replace profiles)
+        for (name, profile) in overlay.profiles {
+            base.profiles.insert(name, profile);
+        }
+
+        // Client mappings replace wholesale when the overlay declares any
+        if !overlay.client_mappings.is_empty() {
+            base.client_mappings = overlay.client_mappings;
+        }
+
         base
     }
 
@@ -368,6 +406,39 @@ mod tests {
         assert_eq!(repo_cat.description.as_ref().unwrap(), "Overlay");
     }
 
+    #[test]
+    fn test_apply_profile() {
+        use crate::config::schema::ProfileConfig;
+
+        let mut config = ToolConfig {
+            preset: Some("balanced".to_string()),
+            ..Default::default()
+        };
+        config.profiles.insert(
+            "ci".to_string(),
+            ProfileConfig {
+                preset: Some("security-focused".to_string()),
+                performance: Some(crate::config::schema::PerformanceConfig {
+                    max_tool_count: 30,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        );
+
+        let applied = ConfigLoader::apply_profile(config, "ci").unwrap();
+        assert_eq!(applied.preset.as_deref(), Some("security-focused"));
+        assert_eq!(applied.performance.max_tool_count, 30);
+        // Unset knobs keep their base values
+        assert_eq!(applied.performance.startup_latency_ms, 10);
+    }
+
+    #[test]
+    fn test_apply_unknown_profile_errors() {
+        let config = ToolConfig::default();
+        assert!(ConfigLoader::apply_profile(config, "nope").is_err());
+    }
+
     #[test]
     fn test_env_var_override() {
         use std::env;
@@ -35,7 +35,10 @@ pub use validation::validate_config;
 // Suppress unused warnings for nested types that are part of ToolConfig's structure.
 // The binary deserializes these from YAML but never constructs them directly.
 #[allow(unused_imports)]
-pub use schema::{CategoryConfig, PerformanceConfig, ToolConfig, ToolOverride, ToolsConfig};
+pub use schema::{
+    ArchitectureConfig, ArchitectureRule, CategoryConfig, ClientMapping, PerformanceConfig,
+    ProfileConfig, ToolConfig, ToolOverride, ToolsConfig,
+};
 
 // Note: Preset is an internal implementation detail of the filter module and
 // is not re-exported. External code should use preset strings in YAML configs.
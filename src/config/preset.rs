@@ -236,6 +236,7 @@ impl Preset {
             "infer_types",
             "check_type_errors",
             "get_typed_taint_flow",
+            "find_null_risks",
         ]
         .iter()
         .copied()
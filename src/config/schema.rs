@@ -37,6 +37,32 @@ pub struct ToolConfig {
     /// Feature flag requirements (optional)
     #[serde(default)]
     pub feature_requirements: HashMap<String, serde_json::Value>,
+
+    /// Global ignore patterns (gitignore syntax) applied by the indexer and
+    /// watcher in every repo, in addition to per-repo `.narsilignore` files
+    #[serde(default)]
+    pub ignore: Vec<String>,
+
+    /// Chunking strategy used when splitting files for embedding and
+    /// hybrid search, with optional per-language overrides
+    #[serde(default)]
+    pub chunking: crate::chunking::ChunkerConfig,
+
+    /// Layering constraints evaluated by the check_architecture tool
+    #[serde(default)]
+    pub architecture: ArchitectureConfig,
+
+    /// Named environment profiles (e.g. "ci", "dev") selected via the
+    /// NARSIL_PROFILE environment variable; the selected profile is
+    /// overlaid on the resolved configuration
+    #[serde(default)]
+    pub profiles: HashMap<String, ProfileConfig>,
+
+    /// Custom client-name mappings consulted before the built-in editor
+    /// table, so unlisted clients (Cursor forks, neovim plugins, ...) get
+    /// an appropriate tool set; first matching entry wins
+    #[serde(default)]
+    pub client_mappings: Vec<ClientMapping>,
 }
 
 impl Default for ToolConfig {
@@ -48,10 +74,101 @@ impl Default for ToolConfig {
             tools: ToolsConfig::default(),
             performance: PerformanceConfig::default(),
             feature_requirements: HashMap::new(),
+            ignore: Vec::new(),
+            chunking: crate::chunking::ChunkerConfig::default(),
+            architecture: ArchitectureConfig::default(),
+            profiles: HashMap::new(),
+            client_mappings: Vec::new(),
         }
     }
 }
 
+/// A client-name → preset (or explicit tool list) mapping
+///
+/// The pattern is a regex matched case-insensitively against the MCP
+/// clientInfo name; a plain name like `cursor` works as a substring match.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientMapping {
+    /// Regex matched case-insensitively against the client name
+    pub pattern: String,
+
+    /// Preset to apply (minimal, balanced, full, security-focused)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preset: Option<String>,
+
+    /// Explicit tool whitelist applied instead of a preset
+    #[serde(default)]
+    pub tools: Vec<String>,
+}
+
+/// A named environment profile: partial overrides applied when selected
+///
+/// Profiles let one config file serve multiple contexts (CI, local dev)
+/// by overriding the preset, tool toggles, feature requirements, and
+/// performance knobs without duplicating the rest of the configuration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProfileConfig {
+    /// Preset override (minimal, balanced, full, security-focused)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preset: Option<String>,
+
+    /// Category toggles and per-tool overrides
+    #[serde(default)]
+    pub tools: ToolsConfig,
+
+    /// Performance budget overrides; unset knobs keep the base values
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub performance: Option<PerformanceConfig>,
+
+    /// Feature flag requirement overrides
+    #[serde(default)]
+    pub feature_requirements: HashMap<String, serde_json::Value>,
+}
+
+impl ProfileConfig {
+    /// Convert the profile into a partial `ToolConfig` overlay so it can
+    /// be applied with the same merge rules as user and project configs
+    pub fn into_overlay(self) -> ToolConfig {
+        ToolConfig {
+            preset: self.preset,
+            tools: self.tools,
+            performance: self.performance.unwrap_or_default(),
+            feature_requirements: self.feature_requirements,
+            ..Default::default()
+        }
+    }
+}
+
+/// Architecture layering rules
+///
+/// Layers map names to glob patterns over repo-relative paths; rules forbid
+/// imports from one layer into others (e.g. handlers must not import
+/// persistence directly).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ArchitectureConfig {
+    /// Layer definitions: layer name -> glob patterns for its files
+    #[serde(default)]
+    pub layers: HashMap<String, Vec<String>>,
+
+    /// Layering constraints between the declared layers
+    #[serde(default)]
+    pub rules: Vec<ArchitectureRule>,
+}
+
+/// A single layering constraint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchitectureRule {
+    /// Layer whose files the rule restricts
+    pub from: String,
+
+    /// Layers that files in `from` must not import
+    pub deny: Vec<String>,
+
+    /// Optional rationale included in violation reports
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
 /// Tools configuration (categories and overrides)
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ToolsConfig {
@@ -129,7 +246,7 @@ pub struct PerformanceConfig {
 impl Default for PerformanceConfig {
     fn default() -> Self {
         Self {
-            max_tool_count: 76,
+            max_tool_count: 108,
             startup_latency_ms: 10,
             filtering_latency_ms: 1,
         }
@@ -137,7 +254,7 @@ impl Default for PerformanceConfig {
 }
 
 fn default_max_tool_count() -> usize {
-    76
+    108
 }
 
 fn default_startup_latency() -> u64 {
@@ -183,7 +300,7 @@ mod tests {
     #[test]
     fn test_default_performance_config() {
         let perf = PerformanceConfig::default();
-        assert_eq!(perf.max_tool_count, 76);
+        assert_eq!(perf.max_tool_count, 108);
         assert_eq!(perf.startup_latency_ms, 10);
         assert_eq!(perf.filtering_latency_ms, 1);
     }
@@ -224,6 +341,50 @@ preset: "full"
         assert!(config.tools.overrides.is_empty());
     }
 
+    #[test]
+    fn test_profiles_parse() {
+        let yaml = r#"
+version: "1.0"
+preset: "balanced"
+profiles:
+  ci:
+    preset: "security-focused"
+    performance:
+      max_tool_count: 30
+  dev:
+    preset: "full"
+"#;
+        let config: ToolConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.profiles.len(), 2);
+
+        let ci = config.profiles.get("ci").unwrap();
+        assert_eq!(ci.preset.as_deref(), Some("security-focused"));
+        assert_eq!(ci.performance.as_ref().unwrap().max_tool_count, 30);
+
+        let dev = config.profiles.get("dev").unwrap();
+        assert_eq!(dev.preset.as_deref(), Some("full"));
+        assert!(dev.performance.is_none());
+    }
+
+    #[test]
+    fn test_client_mappings_parse() {
+        let yaml = r#"
+version: "1.0"
+client_mappings:
+  - pattern: "cursor|windsurf"
+    preset: "balanced"
+  - pattern: "^nvim-"
+    tools: ["list_repos", "search_code"]
+"#;
+        let config: ToolConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.client_mappings.len(), 2);
+        assert_eq!(
+            config.client_mappings[0].preset.as_deref(),
+            Some("balanced")
+        );
+        assert_eq!(config.client_mappings[1].tools.len(), 2);
+    }
+
     #[test]
     fn test_minimal_preset_config() {
         // Even more minimal - just preset
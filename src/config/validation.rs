@@ -145,6 +145,11 @@ mod tests {
             },
             performance: PerformanceConfig::default(),
             feature_requirements: HashMap::new(),
+            ignore: Vec::new(),
+            chunking: Default::default(),
+            architecture: Default::default(),
+            profiles: Default::default(),
+            client_mappings: Default::default(),
         };
 
         assert!(validate_config(&config).is_ok());
@@ -162,6 +167,11 @@ mod tests {
             },
             performance: PerformanceConfig::default(),
             feature_requirements: HashMap::new(),
+            ignore: Vec::new(),
+            chunking: Default::default(),
+            architecture: Default::default(),
+            profiles: Default::default(),
+            client_mappings: Default::default(),
         };
 
         assert!(validate_config(&config).is_err());
@@ -183,6 +193,11 @@ mod tests {
                 filtering_latency_ms: 1,
             },
             feature_requirements: HashMap::new(),
+            ignore: Vec::new(),
+            chunking: Default::default(),
+            architecture: Default::default(),
+            profiles: Default::default(),
+            client_mappings: Default::default(),
         };
 
         assert!(validate_config(&config).is_err());
@@ -211,6 +226,11 @@ mod tests {
             },
             performance: PerformanceConfig::default(),
             feature_requirements: HashMap::new(),
+            ignore: Vec::new(),
+            chunking: Default::default(),
+            architecture: Default::default(),
+            profiles: Default::default(),
+            client_mappings: Default::default(),
         };
 
         // Should succeed but print warning
@@ -242,6 +262,11 @@ mod tests {
             },
             performance: PerformanceConfig::default(),
             feature_requirements: HashMap::new(),
+            ignore: Vec::new(),
+            chunking: Default::default(),
+            architecture: Default::default(),
+            profiles: Default::default(),
+            client_mappings: Default::default(),
         };
 
         // Should succeed but print warning
@@ -0,0 +1,280 @@
+//! Coverage report ingestion - LCOV and Cobertura XML
+//!
+//! Parses coverage files produced by common tooling (lcov, grcov, tarpaulin,
+//! coverage.py, Jest, JaCoCo-style Cobertura exporters) into per-file line
+//! hit counts, so coverage percentages can be attached to symbols in the
+//! index and uncovered functions can be queried.
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Line hit counts for a single source file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileCoverage {
+    /// Path as recorded in the coverage report
+    pub path: String,
+    /// Line number -> hit count (only instrumented lines appear)
+    pub line_hits: HashMap<usize, u64>,
+}
+
+impl FileCoverage {
+    /// Coverage over a line range: `(covered, instrumented)` counts.
+    /// Returns `None` when no line in the range was instrumented.
+    pub fn range_coverage(&self, start: usize, end: usize) -> Option<(usize, usize)> {
+        let mut covered = 0;
+        let mut instrumented = 0;
+
+        for (&line, &hits) in &self.line_hits {
+            if line >= start && line <= end {
+                instrumented += 1;
+                if hits > 0 {
+                    covered += 1;
+                }
+            }
+        }
+
+        if instrumented == 0 {
+            None
+        } else {
+            Some((covered, instrumented))
+        }
+    }
+}
+
+/// Parsed coverage data for a repository
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CoverageData {
+    /// Report path -> per-file coverage
+    pub files: HashMap<String, FileCoverage>,
+}
+
+impl CoverageData {
+    /// Parse a coverage report, auto-detecting the format: XML documents are
+    /// treated as Cobertura, anything with LCOV records as LCOV.
+    pub fn parse(content: &str) -> Result<Self> {
+        let trimmed = content.trim_start();
+        if trimmed.starts_with('<') {
+            Ok(Self::parse_cobertura(content))
+        } else if content.contains("SF:") || content.contains("end_of_record") {
+            Ok(Self::parse_lcov(content))
+        } else {
+            Err(anyhow!(
+                "Unrecognized coverage format: expected LCOV (SF:/DA: records) or Cobertura XML"
+            ))
+        }
+    }
+
+    /// Parse LCOV tracefile format (`SF:`, `DA:line,count`, `end_of_record`)
+    pub fn parse_lcov(content: &str) -> Self {
+        let mut data = CoverageData::default();
+        let mut current: Option<FileCoverage> = None;
+
+        for line in content.lines() {
+            let line = line.trim();
+
+            if let Some(path) = line.strip_prefix("SF:") {
+                current = Some(FileCoverage {
+                    path: path.trim().to_string(),
+                    line_hits: HashMap::new(),
+                });
+            } else if let Some(record) = line.strip_prefix("DA:") {
+                if let Some(file) = current.as_mut() {
+                    let mut parts = record.split(',');
+                    let line_no = parts.next().and_then(|s| s.trim().parse::<usize>().ok());
+                    let hits = parts.next().and_then(|s| s.trim().parse::<u64>().ok());
+                    if let (Some(line_no), Some(hits)) = (line_no, hits) {
+                        *file.line_hits.entry(line_no).or_insert(0) += hits;
+                    }
+                }
+            } else if line == "end_of_record" {
+                if let Some(file) = current.take() {
+                    data.files.insert(file.path.clone(), file);
+                }
+            }
+        }
+
+        // Tolerate a missing trailing end_of_record
+        if let Some(file) = current.take() {
+            data.files.insert(file.path.clone(), file);
+        }
+
+        data
+    }
+
+    /// Parse Cobertura XML (`<class filename="...">` with `<line number=".."
+    /// hits=".."/>` elements). Uses lightweight attribute scanning rather
+    /// than a full XML parser - enough for the machine-generated reports
+    /// coverage tools emit.
+    pub fn parse_cobertura(content: &str) -> Self {
+        let mut data = CoverageData::default();
+        let mut current_path: Option<String> = None;
+
+        for raw in content.split('<') {
+            let tag = raw.trim_start();
+
+            if tag.starts_with("class ") || tag.starts_with("class\t") {
+                if let Some(path) = extract_xml_attr(tag, "filename") {
+                    current_path = Some(path);
+                }
+            } else if tag.starts_with("line ") || tag.starts_with("line\t") {
+                let path = match current_path.as_ref() {
+                    Some(p) => p,
+                    None => continue,
+                };
+                let line_no = extract_xml_attr(tag, "number").and_then(|v| v.parse::<usize>().ok());
+                let hits = extract_xml_attr(tag, "hits").and_then(|v| v.parse::<u64>().ok());
+
+                if let (Some(line_no), Some(hits)) = (line_no, hits) {
+                    let file = data
+                        .files
+                        .entry(path.clone())
+                        .or_insert_with(|| FileCoverage {
+                            path: path.clone(),
+                            line_hits: HashMap::new(),
+                        });
+                    *file.line_hits.entry(line_no).or_insert(0) += hits;
+                }
+            }
+        }
+
+        data
+    }
+
+    /// Look up coverage for a source file, tolerating path prefix differences
+    /// between the coverage report and the index (absolute vs repo-relative)
+    pub fn file_coverage(&self, path: &str) -> Option<&FileCoverage> {
+        if let Some(file) = self.files.get(path) {
+            return Some(file);
+        }
+
+        self.files
+            .values()
+            .find(|f| f.path.ends_with(path) || path.ends_with(&f.path))
+    }
+
+    /// Overall `(covered, instrumented)` line counts across all files
+    pub fn totals(&self) -> (usize, usize) {
+        let mut covered = 0;
+        let mut instrumented = 0;
+
+        for file in self.files.values() {
+            instrumented += file.line_hits.len();
+            covered += file.line_hits.values().filter(|&&h| h > 0).count();
+        }
+
+        (covered, instrumented)
+    }
+}
+
+/// Extract an attribute value from a raw XML tag body
+fn extract_xml_attr(tag: &str, attr: &str) -> Option<String> {
+    let needle = format!("{}=\"", attr);
+    let start = tag.find(&needle)? + needle.len();
+    let end = tag[start..].find('"')?;
+    Some(tag[start..start + end].to_string())
+}
+
+/// Format a coverage ratio as a percentage string
+pub fn percent(covered: usize, instrumented: usize) -> f64 {
+    if instrumented == 0 {
+        0.0
+    } else {
+        covered as f64 * 100.0 / instrumented as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LCOV_SAMPLE: &str = "\
+TN:
+SF:src/lib.rs
+FN:3,covered_fn
+DA:3,5
+DA:4,5
+DA:8,0
+DA:9,0
+end_of_record
+SF:src/other.rs
+DA:1,1
+end_of_record
+";
+
+    const COBERTURA_SAMPLE: &str = r#"<?xml version="1.0"?>
+<coverage line-rate="0.5">
+  <packages>
+    <package name="pkg">
+      <classes>
+        <class name="lib" filename="src/lib.rs">
+          <lines>
+            <line number="3" hits="5"/>
+            <line number="4" hits="5"/>
+            <line number="8" hits="0"/>
+          </lines>
+        </class>
+      </classes>
+    </package>
+  </packages>
+</coverage>
+"#;
+
+    #[test]
+    fn test_parse_lcov() {
+        let data = CoverageData::parse_lcov(LCOV_SAMPLE);
+        assert_eq!(data.files.len(), 2);
+
+        let lib = &data.files["src/lib.rs"];
+        assert_eq!(lib.line_hits.len(), 4);
+        assert_eq!(lib.line_hits[&3], 5);
+        assert_eq!(lib.line_hits[&8], 0);
+    }
+
+    #[test]
+    fn test_parse_cobertura() {
+        let data = CoverageData::parse_cobertura(COBERTURA_SAMPLE);
+        assert_eq!(data.files.len(), 1);
+
+        let lib = &data.files["src/lib.rs"];
+        assert_eq!(lib.line_hits.len(), 3);
+        assert_eq!(lib.line_hits[&3], 5);
+        assert_eq!(lib.line_hits[&8], 0);
+    }
+
+    #[test]
+    fn test_parse_auto_detect() {
+        assert!(CoverageData::parse(LCOV_SAMPLE).is_ok());
+        assert!(CoverageData::parse(COBERTURA_SAMPLE).is_ok());
+        assert!(CoverageData::parse("not a coverage report").is_err());
+    }
+
+    #[test]
+    fn test_range_coverage() {
+        let data = CoverageData::parse_lcov(LCOV_SAMPLE);
+        let lib = data.file_coverage("src/lib.rs").unwrap();
+
+        // Lines 3-4 covered, 8-9 not
+        assert_eq!(lib.range_coverage(3, 4), Some((2, 2)));
+        assert_eq!(lib.range_coverage(8, 9), Some((0, 2)));
+        assert_eq!(lib.range_coverage(3, 9), Some((2, 4)));
+        // No instrumented lines in range
+        assert_eq!(lib.range_coverage(100, 200), None);
+    }
+
+    #[test]
+    fn test_file_coverage_suffix_match() {
+        let data = CoverageData::parse_lcov("SF:/abs/path/to/src/lib.rs\nDA:1,1\nend_of_record\n");
+        assert!(data.file_coverage("src/lib.rs").is_some());
+        assert!(data.file_coverage("src/missing.rs").is_none());
+    }
+
+    #[test]
+    fn test_totals_and_percent() {
+        let data = CoverageData::parse_lcov(LCOV_SAMPLE);
+        let (covered, instrumented) = data.totals();
+        assert_eq!((covered, instrumented), (3, 5));
+        assert!((percent(covered, instrumented) - 60.0).abs() < 1e-9);
+        assert_eq!(percent(0, 0), 0.0);
+    }
+}
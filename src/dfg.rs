@@ -253,6 +253,47 @@ impl DataFlowAnalysis {
 
         md
     }
+
+    /// Export to DOT format for visualization
+    ///
+    /// Definitions become nodes and def-use chains become edges, so the
+    /// output can be rendered with Graphviz to inspect how values flow
+    /// through the function.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph DFG {\n");
+        dot.push_str("  rankdir=TB;\n");
+        dot.push_str("  node [shape=box, fontname=\"monospace\"];\n\n");
+
+        // Add definition nodes
+        for (i, chain) in self.def_use_chains.iter().enumerate() {
+            let def = &chain.definition;
+            let color = if chain.is_used { "black" } else { "red" };
+            dot.push_str(&format!(
+                "  def{} [label=\"{}\\n(line {}, block {})\", color={}];\n",
+                i, def.variable, def.line, def.block, color
+            ));
+        }
+        dot.push('\n');
+
+        // Add use nodes and def->use edges
+        for (i, chain) in self.def_use_chains.iter().enumerate() {
+            for (j, use_) in chain.uses.iter().enumerate() {
+                dot.push_str(&format!(
+                    "  use{}_{} [label=\"{:?}\\n(line {})\", shape=ellipse];\n",
+                    i, j, use_.kind, use_.line
+                ));
+                dot.push_str(&format!("  def{} -> use{}_{};\n", i, i, j));
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Export to JSON format for external tooling
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
 }
 
 /// Data flow graph analyzer
@@ -393,6 +434,11 @@ impl<'a> DfgAnalyzer<'a> {
                         let uses = self.extract_uses_from_text(&stmt.text, *block_id, stmt.line);
                         self.uses.extend(uses);
                     }
+                    crate::cfg::StatementKind::Await => {
+                        // Awaited expressions read their operands like any other expression
+                        let uses = self.extract_uses_from_text(&stmt.text, *block_id, stmt.line);
+                        self.uses.extend(uses);
+                    }
                     _ => {}
                 }
             }
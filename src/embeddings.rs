@@ -244,6 +244,36 @@ impl VectorStore {
             .and_then(|&idx| self.documents.get(idx))
     }
 
+    /// Re-point documents from one file path to another after a rename,
+    /// reusing the stored embeddings instead of re-embedding. Document ids
+    /// are conventionally prefixed with the file path, so matching prefixes
+    /// are rewritten too. Returns the number of documents moved.
+    pub fn rename_file(&mut self, old_path: &str, new_path: &str) -> usize {
+        let mut id_updates: Vec<(String, String)> = Vec::new();
+        let mut moved = 0;
+
+        for doc in &mut self.documents {
+            if doc.file_path != old_path {
+                continue;
+            }
+            doc.file_path = new_path.to_string();
+            if let Some(rest) = doc.id.strip_prefix(old_path) {
+                let new_id = format!("{}{}", new_path, rest);
+                id_updates.push((doc.id.clone(), new_id.clone()));
+                doc.id = new_id;
+            }
+            moved += 1;
+        }
+
+        for (old_id, new_id) in id_updates {
+            if let Some(idx) = self.id_to_idx.remove(&old_id) {
+                self.id_to_idx.insert(new_id, idx);
+            }
+        }
+
+        moved
+    }
+
     /// Get number of documents
     pub fn len(&self) -> usize {
         self.documents.len()
@@ -295,6 +325,10 @@ impl ConcurrentVectorStore {
         self.inner.read().get(id).cloned()
     }
 
+    pub fn rename_file(&self, old_path: &str, new_path: &str) -> usize {
+        self.inner.write().rename_file(old_path, new_path)
+    }
+
     pub fn len(&self) -> usize {
         self.inner.read().len()
     }
@@ -376,6 +410,12 @@ impl EmbeddingEngine {
         (embedding_stats, doc_count)
     }
 
+    /// Carry indexed snippets over to a new file path after a rename,
+    /// without re-embedding. Returns the number of documents moved.
+    pub fn rename_file(&self, old_path: &str, new_path: &str) -> usize {
+        self.store.rename_file(old_path, new_path)
+    }
+
     /// Clear all data
     pub fn clear(&self) {
         self.store.clear();
@@ -469,6 +509,42 @@ mod tests {
         assert!(results[0].similarity > results[1].similarity);
     }
 
+    #[test]
+    fn test_vector_store_rename_file() {
+        let mut store = VectorStore::new();
+
+        store.add(EmbeddedDocument {
+            id: "src/old.rs::hello".to_string(),
+            file_path: "src/old.rs".to_string(),
+            content: "fn hello()".to_string(),
+            start_line: 1,
+            end_line: 5,
+            embedding: vec![1.0, 0.0, 0.0],
+        });
+        store.add(EmbeddedDocument {
+            id: "src/other.rs::goodbye".to_string(),
+            file_path: "src/other.rs".to_string(),
+            content: "fn goodbye()".to_string(),
+            start_line: 1,
+            end_line: 5,
+            embedding: vec![0.0, 1.0, 0.0],
+        });
+
+        let moved = store.rename_file("src/old.rs", "src/new.rs");
+        assert_eq!(moved, 1);
+
+        // The document is reachable under its rewritten id with the new path
+        let doc = store.get("src/new.rs::hello").expect("renamed doc");
+        assert_eq!(doc.file_path, "src/new.rs");
+        assert!(store.get("src/old.rs::hello").is_none());
+
+        // The embedding itself was carried over, not recomputed
+        assert_eq!(doc.embedding, vec![1.0, 0.0, 0.0]);
+
+        // Unrelated documents are untouched
+        assert!(store.get("src/other.rs::goodbye").is_some());
+    }
+
     #[test]
     fn test_embedding_engine() {
         let engine = EmbeddingEngine::new(100);
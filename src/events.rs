@@ -0,0 +1,123 @@
+//! Engine event bus for live index updates
+//!
+//! A broadcast channel carrying index lifecycle events (reindexes, watch-mode
+//! file changes, periodic metrics snapshots) so consumers like the HTTP
+//! server's WebSocket endpoint can push updates instead of polling.
+
+// Allow dead code - the bus is only consumed when the HTTP server is running
+#![allow(dead_code)]
+
+use serde::Serialize;
+use tokio::sync::broadcast;
+
+/// An event emitted by the engine as the index changes
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EngineEvent {
+    /// A repository finished (re)indexing
+    Reindexed {
+        /// Repository name
+        repo: String,
+        /// Number of files indexed
+        files: usize,
+    },
+    /// A file changed and was re-indexed (watch mode or write tools)
+    FileChanged {
+        /// File path relative to its repository root
+        path: String,
+        /// "created", "modified" or "deleted"
+        change_type: String,
+    },
+    /// Periodic metrics snapshot for dashboards
+    MetricsTick {
+        /// Output of the metrics JSON report
+        metrics: serde_json::Value,
+    },
+}
+
+/// Fan-out bus for [`EngineEvent`]s
+///
+/// Cheap to clone; publishing with no subscribers is a no-op, so the engine
+/// can emit events unconditionally.
+#[derive(Clone)]
+pub struct EventBus {
+    sender: broadcast::Sender<EngineEvent>,
+}
+
+impl EventBus {
+    /// Create a bus with a bounded backlog; slow subscribers lag rather
+    /// than block publishers
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(256);
+        Self { sender }
+    }
+
+    /// Publish an event to all current subscribers
+    pub fn publish(&self, event: EngineEvent) {
+        // send only fails when there are no receivers, which is fine
+        let _ = self.sender.send(event);
+    }
+
+    /// Subscribe to events published after this call
+    pub fn subscribe(&self) -> broadcast::Receiver<EngineEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Number of live subscribers
+    pub fn subscriber_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_publish_subscribe_roundtrip() {
+        let bus = EventBus::new();
+        let mut rx = bus.subscribe();
+
+        bus.publish(EngineEvent::Reindexed {
+            repo: "myrepo".to_string(),
+            files: 42,
+        });
+
+        match rx.recv().await.unwrap() {
+            EngineEvent::Reindexed { repo, files } => {
+                assert_eq!(repo, "myrepo");
+                assert_eq!(files, 42);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_publish_without_subscribers_is_noop() {
+        let bus = EventBus::new();
+        assert_eq!(bus.subscriber_count(), 0);
+        // Must not panic or block
+        bus.publish(EngineEvent::FileChanged {
+            path: "src/main.rs".to_string(),
+            change_type: "modified".to_string(),
+        });
+    }
+
+    #[test]
+    fn test_event_serialization() {
+        let event = EngineEvent::FileChanged {
+            path: "src/lib.rs".to_string(),
+            change_type: "modified".to_string(),
+        };
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["type"], "file_changed");
+        assert_eq!(json["path"], "src/lib.rs");
+        assert_eq!(json["change_type"], "modified");
+    }
+}
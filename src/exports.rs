@@ -0,0 +1,543 @@
+//! Language-aware export analysis
+//!
+//! Determines the real public surface of a module rather than its raw
+//! top-level definitions: Python `__all__` lists and `__init__.py`
+//! re-exports, and JS/TS `export` statements including `export ... from`
+//! barrel files, default exports, and CommonJS `module.exports`.
+//! Languages without an export syntax fall back to the symbol table.
+
+use std::collections::HashMap;
+
+/// How a name is exposed from a module
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportKind {
+    /// Defined in this file and exported
+    Named,
+    /// The module's default export (JS/TS)
+    Default,
+    /// Re-exported from another module
+    ReExport,
+    /// A wildcard re-export (`export * from`, `export * as ns from`)
+    Namespace,
+}
+
+impl std::fmt::Display for ExportKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExportKind::Named => write!(f, "named"),
+            ExportKind::Default => write!(f, "default"),
+            ExportKind::ReExport => write!(f, "re-export"),
+            ExportKind::Namespace => write!(f, "namespace"),
+        }
+    }
+}
+
+/// One entry in a module's export map
+#[derive(Debug, Clone)]
+pub struct ExportEntry {
+    /// The name as seen by importers (the alias, for renamed exports)
+    pub name: String,
+    pub kind: ExportKind,
+    /// 1-based line of the export or definition
+    pub line: usize,
+    /// Module the name is re-exported from, if any
+    pub source: Option<String>,
+}
+
+/// Extract the export map for a file, or `None` when the language has no
+/// export analysis (callers fall back to the symbol table)
+pub fn extract_exports(path: &str, content: &str) -> Option<Vec<ExportEntry>> {
+    let file_name = path.rsplit('/').next().unwrap_or(path);
+    match path.rsplit('.').next()? {
+        "py" | "pyi" => Some(python_exports(content, file_name == "__init__.py")),
+        "js" | "jsx" | "mjs" | "ts" | "tsx" => Some(js_exports(content)),
+        _ => None,
+    }
+}
+
+/// Python export analysis
+///
+/// When `__all__` is declared it is authoritative: every listed name is
+/// public, marked as a re-export when it arrives via an import. Without
+/// `__all__`, top-level definitions not starting with `_` are public, and
+/// `__init__.py` files additionally re-export their imports (the barrel
+/// convention).
+fn python_exports(content: &str, is_init: bool) -> Vec<ExportEntry> {
+    let lines: Vec<&str> = content.lines().collect();
+    let imports = python_imports(&lines);
+
+    if let Some((all_names, all_line)) = python_all(&lines) {
+        return all_names
+            .into_iter()
+            .map(|name| {
+                let source = imports.get(&name).cloned();
+                let line = python_definition_line(&lines, &name).unwrap_or(all_line);
+                ExportEntry {
+                    kind: if source.is_some() {
+                        ExportKind::ReExport
+                    } else {
+                        ExportKind::Named
+                    },
+                    name,
+                    line,
+                    source,
+                }
+            })
+            .collect();
+    }
+
+    let mut entries = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        // Only top-level (unindented) definitions are module attributes
+        if line.starts_with(' ') || line.starts_with('\t') {
+            continue;
+        }
+        if let Some(name) = python_definition_name(line) {
+            if !name.starts_with('_') {
+                entries.push(ExportEntry {
+                    name,
+                    kind: ExportKind::Named,
+                    line: i + 1,
+                    source: None,
+                });
+            }
+        }
+    }
+
+    if is_init {
+        let mut import_lines: Vec<(String, String, usize)> = Vec::new();
+        for (i, line) in lines.iter().enumerate() {
+            for (name, module) in python_import_names(line) {
+                if !name.starts_with('_') {
+                    import_lines.push((name, module, i + 1));
+                }
+            }
+        }
+        for (name, module, line) in import_lines {
+            entries.push(ExportEntry {
+                name,
+                kind: ExportKind::ReExport,
+                line,
+                source: Some(module),
+            });
+        }
+    }
+
+    entries
+}
+
+/// Map of imported name -> source module, for classifying `__all__` entries
+fn python_imports(lines: &[&str]) -> HashMap<String, String> {
+    let mut imports = HashMap::new();
+    for line in lines {
+        for (name, module) in python_import_names(line) {
+            imports.insert(name, module);
+        }
+    }
+    imports
+}
+
+/// Names bound by a single import line, with the module they come from
+fn python_import_names(line: &str) -> Vec<(String, String)> {
+    let trimmed = line.trim();
+    let mut names = Vec::new();
+
+    if let Some(rest) = trimmed.strip_prefix("from ") {
+        if let Some((module, items)) = rest.split_once(" import ") {
+            let module = module.trim().to_string();
+            for item in items.trim_end_matches('\\').split(',') {
+                let item = item.trim().trim_matches(|c| c == '(' || c == ')');
+                if item.is_empty() || item == "*" {
+                    continue;
+                }
+                // `X as Y` binds Y
+                let bound = item.rsplit(" as ").next().unwrap_or(item).trim();
+                names.push((bound.to_string(), module.clone()));
+            }
+        }
+    } else if let Some(rest) = trimmed.strip_prefix("import ") {
+        for item in rest.split(',') {
+            let item = item.trim();
+            if item.is_empty() {
+                continue;
+            }
+            let bound = item.rsplit(" as ").next().unwrap_or(item).trim();
+            // `import a.b` binds the top-level package `a`
+            let bound = bound.split('.').next().unwrap_or(bound);
+            names.push((
+                bound.to_string(),
+                item.split(" as ").next().unwrap_or(item).trim().to_string(),
+            ));
+        }
+    }
+
+    names
+}
+
+/// Parse an `__all__` list, which may span several lines. Returns the
+/// quoted names and the 1-based line where `__all__` starts.
+fn python_all(lines: &[&str]) -> Option<(Vec<String>, usize)> {
+    let start = lines.iter().position(|l| {
+        let t = l.trim_start();
+        t.starts_with("__all__") && t[7..].trim_start().starts_with(['=', '+'])
+    })?;
+
+    // Accumulate until the bracket closes
+    let mut buffer = String::new();
+    let mut depth = 0i32;
+    for line in &lines[start..] {
+        buffer.push_str(line);
+        buffer.push('\n');
+        depth += line.matches(['[', '(']).count() as i32;
+        depth -= line.matches([']', ')']).count() as i32;
+        if depth <= 0 && (line.contains(']') || line.contains(')')) {
+            break;
+        }
+    }
+
+    let mut names = Vec::new();
+    let mut rest = buffer.as_str();
+    while let Some(open) = rest.find(['"', '\'']) {
+        let quote = rest.as_bytes()[open] as char;
+        let Some(close) = rest[open + 1..].find(quote) else {
+            break;
+        };
+        names.push(rest[open + 1..open + 1 + close].to_string());
+        rest = &rest[open + close + 2..];
+    }
+
+    Some((names, start + 1))
+}
+
+/// Name bound by a top-level Python definition or assignment line
+fn python_definition_name(line: &str) -> Option<String> {
+    let line = line.strip_prefix("async ").unwrap_or(line);
+    if let Some(rest) = line
+        .strip_prefix("def ")
+        .or_else(|| line.strip_prefix("class "))
+    {
+        let name: String = rest
+            .chars()
+            .take_while(|c| c.is_alphanumeric() || *c == '_')
+            .collect();
+        return (!name.is_empty()).then_some(name);
+    }
+    // Simple module-level assignment: NAME = value
+    let (lhs, _) = line.split_once('=')?;
+    let lhs = lhs.trim_end().trim_end_matches(':');
+    let name = lhs.split(':').next().unwrap_or(lhs).trim();
+    if !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return Some(name.to_string());
+    }
+    None
+}
+
+/// Line where a name listed in `__all__` is defined in this file
+fn python_definition_line(lines: &[&str], name: &str) -> Option<usize> {
+    lines
+        .iter()
+        .position(|line| {
+            !line.starts_with(' ')
+                && !line.starts_with('\t')
+                && python_definition_name(line).as_deref() == Some(name)
+        })
+        .map(|i| i + 1)
+}
+
+/// JS/TS export analysis: ES module `export` forms plus CommonJS
+/// `module.exports` / `exports.X` assignments
+fn js_exports(content: &str) -> Vec<ExportEntry> {
+    let mut entries = Vec::new();
+    let lines: Vec<&str> = content.lines().collect();
+
+    let mut i = 0;
+    while i < lines.len() {
+        let line_no = i + 1;
+        let trimmed = lines[i].trim();
+
+        if let Some(rest) = trimmed.strip_prefix("export ") {
+            let rest = rest.trim_start();
+
+            if let Some(default_rest) = rest.strip_prefix("default ") {
+                entries.push(ExportEntry {
+                    name: js_declaration_name(default_rest)
+                        .unwrap_or_else(|| "default".to_string()),
+                    kind: ExportKind::Default,
+                    line: line_no,
+                    source: None,
+                });
+            } else if let Some(ns_rest) = rest.strip_prefix("* as ") {
+                let name: String = ns_rest
+                    .chars()
+                    .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '$')
+                    .collect();
+                entries.push(ExportEntry {
+                    name,
+                    kind: ExportKind::Namespace,
+                    line: line_no,
+                    source: js_from_source(trimmed),
+                });
+            } else if rest.starts_with('*') {
+                entries.push(ExportEntry {
+                    name: "*".to_string(),
+                    kind: ExportKind::Namespace,
+                    line: line_no,
+                    source: js_from_source(trimmed),
+                });
+            } else if rest.starts_with('{') {
+                // Gather a possibly multi-line export clause
+                let mut clause = String::from(trimmed);
+                while !clause.contains('}') && i + 1 < lines.len() {
+                    i += 1;
+                    clause.push(' ');
+                    clause.push_str(lines[i].trim());
+                }
+                let source = js_from_source(&clause);
+                let open = clause.find('{').map(|p| p + 1).unwrap_or(0);
+                let close = clause.find('}').unwrap_or(clause.len());
+                for item in clause[open..close].split(',') {
+                    let item = item.trim();
+                    if item.is_empty() {
+                        continue;
+                    }
+                    let exported = item.rsplit(" as ").next().unwrap_or(item).trim();
+                    entries.push(ExportEntry {
+                        name: exported.to_string(),
+                        kind: if exported == "default" {
+                            ExportKind::Default
+                        } else if source.is_some() {
+                            ExportKind::ReExport
+                        } else {
+                            ExportKind::Named
+                        },
+                        line: line_no,
+                        source: source.clone(),
+                    });
+                }
+            } else if let Some(name) = js_declaration_name(rest) {
+                entries.push(ExportEntry {
+                    name,
+                    kind: ExportKind::Named,
+                    line: line_no,
+                    source: None,
+                });
+            }
+        } else if let Some(rest) = trimmed.strip_prefix("module.exports") {
+            let rest = rest.trim_start();
+            if let Some(prop) = rest.strip_prefix('.') {
+                // module.exports.X = ...
+                let name: String = prop
+                    .chars()
+                    .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '$')
+                    .collect();
+                if !name.is_empty() {
+                    entries.push(ExportEntry {
+                        name,
+                        kind: ExportKind::Named,
+                        line: line_no,
+                        source: None,
+                    });
+                }
+            } else if rest.starts_with('=') {
+                // module.exports = { A, B } exports each property; anything
+                // else is the default export surface
+                let mut clause = String::from(trimmed);
+                while !clause.contains('}') && clause.contains('{') && i + 1 < lines.len() {
+                    i += 1;
+                    clause.push(' ');
+                    clause.push_str(lines[i].trim());
+                }
+                if let (Some(open), Some(close)) = (clause.find('{'), clause.find('}')) {
+                    for item in clause[open + 1..close].split(',') {
+                        let name = item.split(':').next().unwrap_or("").trim();
+                        if !name.is_empty()
+                            && name
+                                .chars()
+                                .all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+                        {
+                            entries.push(ExportEntry {
+                                name: name.to_string(),
+                                kind: ExportKind::Named,
+                                line: line_no,
+                                source: None,
+                            });
+                        }
+                    }
+                } else {
+                    entries.push(ExportEntry {
+                        name: "default".to_string(),
+                        kind: ExportKind::Default,
+                        line: line_no,
+                        source: None,
+                    });
+                }
+            }
+        } else if let Some(rest) = trimmed.strip_prefix("exports.") {
+            let name: String = rest
+                .chars()
+                .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '$')
+                .collect();
+            if !name.is_empty() && rest[name.len()..].trim_start().starts_with('=') {
+                entries.push(ExportEntry {
+                    name,
+                    kind: ExportKind::Named,
+                    line: line_no,
+                    source: None,
+                });
+            }
+        }
+
+        i += 1;
+    }
+
+    entries
+}
+
+/// The module path of an `export ... from '...'` clause, if present
+fn js_from_source(clause: &str) -> Option<String> {
+    let from = clause.rfind(" from ")?;
+    let rest = clause[from + 6..].trim();
+    let quote = rest.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    let rest = &rest[1..];
+    let end = rest.find(quote)?;
+    Some(rest[..end].to_string())
+}
+
+/// Name introduced by a JS/TS declaration (`const X`, `function f`, ...)
+fn js_declaration_name(decl: &str) -> Option<String> {
+    let decl = decl
+        .trim_start_matches("abstract ")
+        .trim_start_matches("async ");
+    let rest = [
+        "const ",
+        "let ",
+        "var ",
+        "function ",
+        "class ",
+        "interface ",
+        "type ",
+        "enum ",
+        "namespace ",
+    ]
+    .iter()
+    .find_map(|kw| decl.strip_prefix(kw))?;
+
+    let rest = rest.trim_start_matches('*').trim_start();
+    let name: String = rest
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '$')
+        .collect();
+    (!name.is_empty()).then_some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(entries: &[ExportEntry]) -> Vec<&str> {
+        entries.iter().map(|e| e.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_python_all_is_authoritative() {
+        let content = r#"
+from .loader import ConfigLoader
+import os
+
+__all__ = ["ConfigLoader", "parse"]
+
+def parse(text):
+    pass
+
+def _private():
+    pass
+
+def unlisted():
+    pass
+"#;
+        let exports = python_exports(content, false);
+        assert_eq!(names(&exports), vec!["ConfigLoader", "parse"]);
+
+        let loader = &exports[0];
+        assert_eq!(loader.kind, ExportKind::ReExport);
+        assert_eq!(loader.source.as_deref(), Some(".loader"));
+
+        let parse = &exports[1];
+        assert_eq!(parse.kind, ExportKind::Named);
+        assert_eq!(parse.line, 7);
+    }
+
+    #[test]
+    fn test_python_multiline_all() {
+        let content = "__all__ = [\n    'alpha',\n    \"beta\",\n]\n\ndef alpha():\n    pass\n";
+        let exports = python_exports(content, false);
+        assert_eq!(names(&exports), vec!["alpha", "beta"]);
+    }
+
+    #[test]
+    fn test_python_without_all_skips_private() {
+        let content = "def public():\n    pass\n\ndef _hidden():\n    pass\n\nVERSION = '1.0'\n";
+        let exports = python_exports(content, false);
+        assert_eq!(names(&exports), vec!["public", "VERSION"]);
+    }
+
+    #[test]
+    fn test_python_init_barrel_reexports() {
+        let content = "from .engine import Engine\nfrom .config import load as load_config\n";
+        let exports = python_exports(content, true);
+        assert_eq!(names(&exports), vec!["Engine", "load_config"]);
+        assert!(exports.iter().all(|e| e.kind == ExportKind::ReExport));
+        assert_eq!(exports[1].source.as_deref(), Some(".config"));
+    }
+
+    #[test]
+    fn test_js_named_and_default_exports() {
+        let content = r#"
+export const VERSION = "1.0";
+export default class App {}
+export async function run() {}
+"#;
+        let exports = js_exports(content);
+        assert_eq!(names(&exports), vec!["VERSION", "App", "run"]);
+        assert_eq!(exports[1].kind, ExportKind::Default);
+        assert_eq!(exports[2].kind, ExportKind::Named);
+    }
+
+    #[test]
+    fn test_js_barrel_reexports() {
+        let content = r#"
+export { Engine, start as boot } from './engine';
+export * from './utils';
+export * as helpers from './helpers';
+export { local };
+"#;
+        let exports = js_exports(content);
+        assert_eq!(
+            names(&exports),
+            vec!["Engine", "boot", "*", "helpers", "local"]
+        );
+
+        assert_eq!(exports[0].kind, ExportKind::ReExport);
+        assert_eq!(exports[0].source.as_deref(), Some("./engine"));
+        assert_eq!(exports[1].name, "boot"); // importers see the alias
+        assert_eq!(exports[2].kind, ExportKind::Namespace);
+        assert_eq!(exports[3].kind, ExportKind::Namespace);
+        assert_eq!(exports[4].kind, ExportKind::Named);
+        assert_eq!(exports[4].source, None);
+    }
+
+    #[test]
+    fn test_js_commonjs_exports() {
+        let content =
+            "module.exports = { run, parse };\nexports.extra = 1;\nmodule.exports.more = 2;\n";
+        let exports = js_exports(content);
+        assert_eq!(names(&exports), vec!["run", "parse", "extra", "more"]);
+    }
+
+    #[test]
+    fn test_extract_exports_language_dispatch() {
+        assert!(extract_exports("api.py", "def f():\n    pass\n").is_some());
+        assert!(extract_exports("index.ts", "export const x = 1;\n").is_some());
+        assert!(extract_exports("main.rs", "pub fn f() {}\n").is_none());
+    }
+}
@@ -332,6 +332,742 @@ pub fn select_best_excerpt(excerpts: &[Excerpt], max_count: usize) -> Vec<&Excer
     sorted
 }
 
+/// An HTTP route extracted from framework registration code
+#[derive(Debug, Clone)]
+pub struct Route {
+    /// HTTP method (GET, POST, ...), or `*` when the framework registers all
+    pub method: String,
+
+    /// URL path as written in the source (e.g. `/users/:id`)
+    pub path: String,
+
+    /// Handler symbol the route dispatches to
+    pub handler: String,
+
+    /// Framework the route was recognized from
+    pub framework: &'static str,
+
+    /// Middleware attached to the route (per-route or app-wide in the file)
+    pub middleware: Vec<String>,
+
+    /// Line where the route is registered (1-indexed)
+    pub line: usize,
+}
+
+/// Extract HTTP routes from a source file
+///
+/// Recognizes the registration idioms of the popular frameworks: axum and
+/// actix-web (Rust), Express (JavaScript/TypeScript), Flask and FastAPI
+/// (Python), and Spring (Java/Kotlin). Pattern-based like the rest of this
+/// module, so unusual registration styles may be missed.
+pub fn extract_routes(source: &str, file_path: &str) -> Vec<Route> {
+    let ext = file_path.rsplit('.').next().unwrap_or("");
+    match ext {
+        "rs" => extract_rust_routes(source),
+        "js" | "ts" | "jsx" | "tsx" | "mjs" | "cjs" => extract_express_routes(source),
+        "py" => extract_python_routes(source),
+        "java" | "kt" => extract_spring_routes(source),
+        _ => Vec::new(),
+    }
+}
+
+const HTTP_METHODS: &[&str] = &["get", "post", "put", "delete", "patch", "head", "options"];
+
+/// Split an argument list on top-level commas (ignores commas inside
+/// nested parens/brackets and string literals)
+fn split_args(args: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut in_str: Option<char> = None;
+    let mut current = String::new();
+
+    for c in args.chars() {
+        match in_str {
+            Some(q) => {
+                current.push(c);
+                if c == q {
+                    in_str = None;
+                }
+            }
+            None => match c {
+                '\'' | '"' | '`' => {
+                    in_str = Some(c);
+                    current.push(c);
+                }
+                '(' | '[' | '{' => {
+                    depth += 1;
+                    current.push(c);
+                }
+                ')' | ']' | '}' => {
+                    depth -= 1;
+                    current.push(c);
+                }
+                ',' if depth == 0 => {
+                    parts.push(current.trim().to_string());
+                    current.clear();
+                }
+                _ => current.push(c),
+            },
+        }
+    }
+    if !current.trim().is_empty() {
+        parts.push(current.trim().to_string());
+    }
+    parts
+}
+
+/// Pull the first quoted string out of a snippet
+fn first_quoted(s: &str) -> Option<String> {
+    for quote in ['"', '\''] {
+        if let Some(start) = s.find(quote) {
+            if let Some(len) = s[start + 1..].find(quote) {
+                return Some(s[start + 1..start + 1 + len].to_string());
+            }
+        }
+    }
+    None
+}
+
+/// Extract the balanced argument list after `start` (index of the opening paren)
+fn balanced_args(s: &str, start: usize) -> Option<&str> {
+    let bytes = s.as_bytes();
+    if bytes.get(start) != Some(&b'(') {
+        return None;
+    }
+    let mut depth = 0;
+    for (i, &b) in bytes.iter().enumerate().skip(start) {
+        match b {
+            b'(' => depth += 1,
+            b')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&s[start + 1..i]);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Find the name of the next function definition at or after `from_line`
+fn next_fn_name(lines: &[&str], from_line: usize, keywords: &[&str]) -> Option<String> {
+    for line in lines.iter().skip(from_line) {
+        let trimmed = line.trim_start();
+        for kw in keywords {
+            if let Some(rest) = trimmed.strip_prefix(kw) {
+                let name: String = rest
+                    .chars()
+                    .take_while(|c| c.is_alphanumeric() || *c == '_')
+                    .collect();
+                if !name.is_empty() {
+                    return Some(name);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// axum `.route("/p", get(handler))` chains and actix `#[get("/p")]` macros
+fn extract_rust_routes(source: &str) -> Vec<Route> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut routes = Vec::new();
+
+    // App-wide middleware: axum `.layer(X)` and actix `.wrap(X)`
+    let mut middleware = Vec::new();
+    for line in &lines {
+        let trimmed = line.trim();
+        for prefix in [".layer(", ".route_layer(", ".wrap("] {
+            if let Some(pos) = trimmed.find(prefix) {
+                if let Some(args) = balanced_args(trimmed, pos + prefix.len() - 1) {
+                    let name: String = args
+                        .trim()
+                        .chars()
+                        .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == ':')
+                        .collect();
+                    if !name.is_empty() && !middleware.contains(&name) {
+                        middleware.push(name);
+                    }
+                }
+            }
+        }
+    }
+
+    for (i, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+
+        // axum: .route("/path", get(handler).post(other))
+        if let Some(pos) = trimmed.find(".route(") {
+            if let Some(args) = balanced_args(trimmed, pos + ".route(".len() - 1) {
+                let parts = split_args(args);
+                if parts.len() >= 2 {
+                    if let Some(path) = first_quoted(&parts[0]) {
+                        // The second argument may chain several methods
+                        for method in HTTP_METHODS {
+                            let needle = format!("{}(", method);
+                            let mut search = parts[1].as_str();
+                            while let Some(mpos) = search.find(&needle) {
+                                // Require a method-call boundary, not e.g. `forget(`
+                                let boundary = mpos == 0
+                                    || !search[..mpos]
+                                        .ends_with(|c: char| c.is_alphanumeric() || c == '_');
+                                if boundary {
+                                    if let Some(handler) =
+                                        balanced_args(search, mpos + needle.len() - 1)
+                                    {
+                                        routes.push(Route {
+                                            method: method.to_uppercase(),
+                                            path: path.clone(),
+                                            handler: handler.trim().to_string(),
+                                            framework: "axum",
+                                            middleware: middleware.clone(),
+                                            line: i + 1,
+                                        });
+                                    }
+                                }
+                                search = &search[mpos + needle.len()..];
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // actix-web: #[get("/path")] above an async fn
+        if let Some(rest) = trimmed.strip_prefix("#[") {
+            for method in HTTP_METHODS {
+                if let Some(args) = rest
+                    .strip_prefix(method)
+                    .and_then(|r| r.strip_prefix('('))
+                    .and_then(|r| r.strip_suffix(")]"))
+                {
+                    if let Some(path) = first_quoted(args) {
+                        let handler = next_fn_name(&lines, i + 1, &["pub async fn ", "async fn "])
+                            .unwrap_or_default();
+                        routes.push(Route {
+                            method: method.to_uppercase(),
+                            path,
+                            handler,
+                            framework: "actix-web",
+                            middleware: middleware.clone(),
+                            line: i + 1,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    routes
+}
+
+/// Express `app.get('/path', mw1, mw2, handler)` registrations
+fn extract_express_routes(source: &str) -> Vec<Route> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut routes = Vec::new();
+
+    // App-wide middleware: app.use(cors()) — but app.use('/prefix', router)
+    // is mounting, not middleware
+    let mut middleware = Vec::new();
+    for line in &lines {
+        let trimmed = line.trim();
+        if let Some(pos) = trimmed.find(".use(") {
+            if let Some(args) = balanced_args(trimmed, pos + ".use(".len() - 1) {
+                let parts = split_args(args);
+                if parts.len() == 1 && !parts[0].starts_with(['"', '\'', '`']) {
+                    let name: String = parts[0]
+                        .chars()
+                        .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '.')
+                        .collect();
+                    if !name.is_empty() && !middleware.contains(&name) {
+                        middleware.push(name);
+                    }
+                }
+            }
+        }
+    }
+
+    for (i, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        for method in HTTP_METHODS.iter().chain(["all"].iter()) {
+            let needle = format!(".{}(", method);
+            let Some(pos) = trimmed.find(&needle) else {
+                continue;
+            };
+            // Only app/router receivers, not arbitrary objects
+            let receiver: String = trimmed[..pos]
+                .chars()
+                .rev()
+                .take_while(|c| c.is_alphanumeric() || *c == '_')
+                .collect();
+            let receiver: String = receiver.chars().rev().collect();
+            if !(receiver.to_lowercase().contains("app")
+                || receiver.to_lowercase().contains("router"))
+            {
+                continue;
+            }
+            let Some(args) = balanced_args(trimmed, pos + needle.len() - 1) else {
+                continue;
+            };
+            let parts = split_args(args);
+            if parts.len() < 2 {
+                continue;
+            }
+            let Some(path) = first_quoted(&parts[0]) else {
+                continue;
+            };
+            let handler = parts.last().unwrap().clone();
+            let route_mw: Vec<String> = parts[1..parts.len() - 1].to_vec();
+            let mut all_mw = middleware.clone();
+            all_mw.extend(route_mw);
+            routes.push(Route {
+                method: if *method == "all" {
+                    "*".to_string()
+                } else {
+                    method.to_uppercase()
+                },
+                path,
+                handler,
+                framework: "express",
+                middleware: all_mw,
+                line: i + 1,
+            });
+        }
+    }
+
+    routes
+}
+
+/// Flask `@app.route(...)` and FastAPI `@app.get(...)` decorators
+fn extract_python_routes(source: &str) -> Vec<Route> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut routes = Vec::new();
+
+    for (i, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        let Some(rest) = trimmed.strip_prefix('@') else {
+            continue;
+        };
+
+        // Flask: @app.route('/path', methods=['GET', 'POST'])
+        if let Some(pos) = rest.find(".route(") {
+            let Some(args) = balanced_args(rest, pos + ".route(".len() - 1) else {
+                continue;
+            };
+            let Some(path) = first_quoted(args) else {
+                continue;
+            };
+            let handler = next_fn_name(&lines, i + 1, &["async def ", "def "]).unwrap_or_default();
+            let methods: Vec<String> = if let Some(mpos) = args.find("methods") {
+                let spec = &args[mpos..];
+                HTTP_METHODS
+                    .iter()
+                    .filter(|m| spec.to_uppercase().contains(&m.to_uppercase()))
+                    .map(|m| m.to_uppercase())
+                    .collect()
+            } else {
+                vec!["GET".to_string()]
+            };
+            for method in methods {
+                routes.push(Route {
+                    method,
+                    path: path.clone(),
+                    handler: handler.clone(),
+                    framework: "flask",
+                    middleware: Vec::new(),
+                    line: i + 1,
+                });
+            }
+            continue;
+        }
+
+        // FastAPI: @app.get('/path', dependencies=[Depends(auth)])
+        for method in HTTP_METHODS {
+            let needle = format!(".{}(", method);
+            let Some(pos) = rest.find(&needle) else {
+                continue;
+            };
+            let Some(args) = balanced_args(rest, pos + needle.len() - 1) else {
+                continue;
+            };
+            let Some(path) = first_quoted(args) else {
+                continue;
+            };
+            let handler = next_fn_name(&lines, i + 1, &["async def ", "def "]).unwrap_or_default();
+            // dependencies=[Depends(x), Depends(y)] act as middleware
+            let mut middleware = Vec::new();
+            let mut search = args;
+            while let Some(dpos) = search.find("Depends(") {
+                if let Some(dep) = balanced_args(search, dpos + "Depends(".len() - 1) {
+                    middleware.push(dep.trim().to_string());
+                }
+                search = &search[dpos + "Depends(".len()..];
+            }
+            routes.push(Route {
+                method: method.to_uppercase(),
+                path,
+                handler,
+                framework: "fastapi",
+                middleware,
+                line: i + 1,
+            });
+        }
+    }
+
+    routes
+}
+
+/// Spring `@GetMapping`/`@RequestMapping` annotations (Java/Kotlin)
+fn extract_spring_routes(source: &str) -> Vec<Route> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut routes = Vec::new();
+
+    // Class-level @RequestMapping("/api") prefixes every method mapping
+    let mut base_path = String::new();
+    for (i, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("@RequestMapping")
+            && lines
+                .iter()
+                .skip(i + 1)
+                .find(|l| !l.trim().starts_with('@') && !l.trim().is_empty())
+                .is_some_and(|l| l.contains("class "))
+        {
+            if let Some(p) = first_quoted(trimmed) {
+                base_path = p;
+            }
+        }
+    }
+
+    let mappings = [
+        ("@GetMapping", "GET"),
+        ("@PostMapping", "POST"),
+        ("@PutMapping", "PUT"),
+        ("@DeleteMapping", "DELETE"),
+        ("@PatchMapping", "PATCH"),
+    ];
+
+    for (i, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        for (annotation, method) in &mappings {
+            if !trimmed.starts_with(annotation) {
+                continue;
+            }
+            let path = first_quoted(trimmed).unwrap_or_default();
+            let handler = find_java_method_name(&lines, i + 1).unwrap_or_default();
+            routes.push(Route {
+                method: method.to_string(),
+                path: format!("{}{}", base_path, path),
+                handler,
+                framework: "spring",
+                middleware: Vec::new(),
+                line: i + 1,
+            });
+        }
+
+        // Method-level @RequestMapping(value = "/x", method = RequestMethod.GET)
+        if trimmed.starts_with("@RequestMapping") && trimmed.contains("RequestMethod.") {
+            let path = first_quoted(trimmed).unwrap_or_default();
+            let method = HTTP_METHODS
+                .iter()
+                .find(|m| trimmed.contains(&format!("RequestMethod.{}", m.to_uppercase())))
+                .map(|m| m.to_uppercase())
+                .unwrap_or_else(|| "*".to_string());
+            let handler = find_java_method_name(&lines, i + 1).unwrap_or_default();
+            routes.push(Route {
+                method,
+                path: format!("{}{}", base_path, path),
+                handler,
+                framework: "spring",
+                middleware: Vec::new(),
+                line: i + 1,
+            });
+        }
+    }
+
+    routes
+}
+
+/// Find the next Java/Kotlin method name after annotation lines
+fn find_java_method_name(lines: &[&str], from_line: usize) -> Option<String> {
+    for line in lines.iter().skip(from_line) {
+        let trimmed = line.trim();
+        if trimmed.starts_with('@') || trimmed.is_empty() {
+            continue;
+        }
+        // `public ResponseEntity<User> getUser(...)` or `fun getUser(...)`
+        let before_paren = trimmed.split('(').next()?;
+        let name = before_paren.split_whitespace().last()?;
+        if name.chars().all(|c| c.is_alphanumeric() || c == '_') && !name.is_empty() {
+            return Some(name.to_string());
+        }
+        return None;
+    }
+    None
+}
+
+/// A configuration knob read from the environment or a config object
+#[derive(Debug, Clone)]
+pub struct ConfigUsage {
+    /// The key being read (env var name or config key)
+    pub key: String,
+
+    /// Where the value comes from: `env` or `config`
+    pub kind: &'static str,
+
+    /// Default value when the read provides one (as written in the source)
+    pub default: Option<String>,
+
+    /// Line of the read (1-indexed)
+    pub line: usize,
+}
+
+/// Extract environment-variable and config-key reads from a source file
+///
+/// Recognizes `env::var` (Rust), `os.environ`/`os.getenv` (Python),
+/// `process.env` (JavaScript/TypeScript), `os.Getenv` (Go),
+/// `System.getenv` (Java/Kotlin), and `config.get`/`settings.get` style
+/// lookups in any language.
+pub fn extract_config_usage(source: &str) -> Vec<ConfigUsage> {
+    let mut usages = Vec::new();
+
+    for (i, line) in source.lines().enumerate() {
+        let line_no = i + 1;
+
+        // Rust: env::var("KEY") / env::var_os("KEY"), default via .unwrap_or*
+        for needle in ["env::var(", "env::var_os("] {
+            for (pos, args) in call_sites(line, needle) {
+                if let Some(key) = first_quoted(args) {
+                    let after = &line[pos..];
+                    let default = ["unwrap_or(", "unwrap_or_else(", "unwrap_or_default("]
+                        .iter()
+                        .find_map(|m| {
+                            let mpos = after.find(m)?;
+                            if m.ends_with("default(") {
+                                return Some("(type default)".to_string());
+                            }
+                            // `"8080".to_string()` reads better as just `8080`
+                            balanced_args(after, mpos + m.len() - 1)
+                                .map(|a| first_quoted(a).unwrap_or_else(|| a.trim().to_string()))
+                        });
+                    usages.push(ConfigUsage {
+                        key,
+                        kind: "env",
+                        default,
+                        line: line_no,
+                    });
+                }
+            }
+        }
+
+        // Python: os.environ.get("KEY", default) / os.getenv("KEY", default)
+        for needle in ["os.environ.get(", "os.getenv("] {
+            for (_, args) in call_sites(line, needle) {
+                let parts = split_args(args);
+                if let Some(key) = parts.first().and_then(|p| first_quoted(p)) {
+                    usages.push(ConfigUsage {
+                        key,
+                        kind: "env",
+                        default: parts
+                            .get(1)
+                            .map(|d| d.trim_matches(['"', '\'']).to_string()),
+                        line: line_no,
+                    });
+                }
+            }
+        }
+        // Python: os.environ["KEY"] (no default; raises when missing)
+        let mut search = line;
+        while let Some(pos) = search.find("os.environ[") {
+            let rest = &search[pos + "os.environ[".len()..];
+            if let Some(key) = first_quoted(rest) {
+                usages.push(ConfigUsage {
+                    key,
+                    kind: "env",
+                    default: None,
+                    line: line_no,
+                });
+            }
+            search = &search[pos + "os.environ[".len()..];
+        }
+
+        // JavaScript: process.env.KEY (|| or ?? default), process.env["KEY"]
+        let mut search = line;
+        while let Some(pos) = search.find("process.env") {
+            let rest = &search[pos + "process.env".len()..];
+            let key = if let Some(stripped) = rest.strip_prefix('.') {
+                let name: String = stripped
+                    .chars()
+                    .take_while(|c| c.is_alphanumeric() || *c == '_')
+                    .collect();
+                (!name.is_empty()).then_some(name)
+            } else if rest.starts_with('[') {
+                first_quoted(rest)
+            } else {
+                None
+            };
+            if let Some(key) = key {
+                let default = ["||", "??"].iter().find_map(|op| {
+                    let opos = rest.find(op)?;
+                    let value = rest[opos + op.len()..]
+                        .trim()
+                        .trim_end_matches([';', ',', ')']);
+                    Some(value.trim_matches(['"', '\'', '`']).to_string())
+                });
+                usages.push(ConfigUsage {
+                    key,
+                    kind: "env",
+                    default,
+                    line: line_no,
+                });
+            }
+            search = &search[pos + "process.env".len()..];
+        }
+
+        // Go and Java
+        for needle in ["os.Getenv(", "System.getenv("] {
+            for (_, args) in call_sites(line, needle) {
+                if let Some(key) = first_quoted(args) {
+                    usages.push(ConfigUsage {
+                        key,
+                        kind: "env",
+                        default: None,
+                        line: line_no,
+                    });
+                }
+            }
+        }
+
+        // Generic config-object lookups: config.get("key", default)
+        for needle in ["config.get(", "settings.get(", "conf.get("] {
+            for (_, args) in call_sites(line, needle) {
+                let parts = split_args(args);
+                if let Some(key) = parts.first().and_then(|p| first_quoted(p)) {
+                    usages.push(ConfigUsage {
+                        key,
+                        kind: "config",
+                        default: parts
+                            .get(1)
+                            .map(|d| d.trim_matches(['"', '\'']).to_string()),
+                        line: line_no,
+                    });
+                }
+            }
+        }
+    }
+
+    usages
+}
+
+/// A feature-flag check found in source code
+#[derive(Debug, Clone)]
+pub struct FeatureFlag {
+    /// Flag name as written in the check
+    pub name: String,
+
+    /// Where the check comes from: `launchdarkly`, `unleash`, `cfg`, or `env`
+    pub sdk: &'static str,
+
+    /// Line of the check (1-indexed)
+    pub line: usize,
+}
+
+/// Extract feature-flag checks from a source file
+///
+/// Recognizes LaunchDarkly `variation` calls, Unleash `isEnabled` calls,
+/// Rust `cfg!(feature = ...)` / `#[cfg(feature = ...)]`, and environment
+/// reads whose key looks like a flag (FEATURE/FLAG/ENABLE/TOGGLE).
+pub fn extract_feature_flags(source: &str) -> Vec<FeatureFlag> {
+    let mut flags = Vec::new();
+
+    for (i, line) in source.lines().enumerate() {
+        let line_no = i + 1;
+
+        // Rust: cfg!(feature = "x") and #[cfg(feature = "x")]
+        let mut search = line;
+        while let Some(pos) = search.find("feature = \"") {
+            let rest = &search[pos + "feature = \"".len()..];
+            if let Some(end) = rest.find('"') {
+                flags.push(FeatureFlag {
+                    name: rest[..end].to_string(),
+                    sdk: "cfg",
+                    line: line_no,
+                });
+            }
+            search = &search[pos + "feature = \"".len()..];
+        }
+
+        // LaunchDarkly: client.variation("flag-key", user, default)
+        for needle in [
+            ".variation(",
+            ".boolVariation(",
+            ".bool_variation(",
+            ".stringVariation(",
+            ".string_variation(",
+            ".intVariation(",
+            ".int_variation(",
+        ] {
+            for (_, args) in call_sites(line, needle) {
+                if let Some(name) = first_quoted(args) {
+                    flags.push(FeatureFlag {
+                        name,
+                        sdk: "launchdarkly",
+                        line: line_no,
+                    });
+                }
+            }
+        }
+
+        // Unleash: unleash.isEnabled("flag") / is_enabled("flag")
+        for needle in [".isEnabled(", ".is_enabled("] {
+            for (_, args) in call_sites(line, needle) {
+                if let Some(name) = first_quoted(args) {
+                    flags.push(FeatureFlag {
+                        name,
+                        sdk: "unleash",
+                        line: line_no,
+                    });
+                }
+            }
+        }
+    }
+
+    // Env reads whose key looks like a flag toggle
+    for usage in extract_config_usage(source) {
+        if usage.kind != "env" {
+            continue;
+        }
+        let upper = usage.key.to_uppercase();
+        if ["FEATURE", "FLAG", "ENABLE", "TOGGLE"]
+            .iter()
+            .any(|kw| upper.contains(kw))
+        {
+            flags.push(FeatureFlag {
+                name: usage.key,
+                sdk: "env",
+                line: usage.line,
+            });
+        }
+    }
+
+    flags
+}
+
+/// All call sites of `needle` in a line as (offset, balanced args) pairs
+fn call_sites<'a>(line: &'a str, needle: &str) -> Vec<(usize, &'a str)> {
+    let mut sites = Vec::new();
+    let mut offset = 0;
+    while let Some(pos) = line[offset..].find(needle) {
+        let abs = offset + pos;
+        if let Some(args) = balanced_args(line, abs + needle.len() - 1) {
+            sites.push((abs, args));
+        }
+        offset = abs + needle.len();
+    }
+    sites
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -366,4 +1102,164 @@ fn main() {
         assert_eq!(merged[0].0, 0);
         assert_eq!(merged[0].1, 10);
     }
+
+    #[test]
+    fn test_extract_axum_routes() {
+        let source = r#"
+let app = Router::new()
+    .route("/users", get(list_users).post(create_user))
+    .route("/users/:id", get(get_user))
+    .layer(TraceLayer::new_for_http());
+"#;
+        let routes = extract_routes(source, "src/main.rs");
+        assert_eq!(routes.len(), 3);
+        assert_eq!(routes[0].framework, "axum");
+        assert!(routes
+            .iter()
+            .any(|r| r.method == "POST" && r.path == "/users" && r.handler == "create_user"));
+        assert!(routes[0]
+            .middleware
+            .iter()
+            .any(|m| m.contains("TraceLayer")));
+    }
+
+    #[test]
+    fn test_extract_actix_routes() {
+        let source = r#"
+#[get("/health")]
+async fn health() -> impl Responder {
+    HttpResponse::Ok()
+}
+"#;
+        let routes = extract_routes(source, "src/handlers.rs");
+        assert_eq!(routes.len(), 1);
+        assert_eq!(routes[0].method, "GET");
+        assert_eq!(routes[0].path, "/health");
+        assert_eq!(routes[0].handler, "health");
+        assert_eq!(routes[0].framework, "actix-web");
+    }
+
+    #[test]
+    fn test_extract_express_routes() {
+        let source = r#"
+app.use(cors());
+app.get('/users/:id', authenticate, getUser);
+router.post('/users', createUser);
+"#;
+        let routes = extract_routes(source, "server.js");
+        assert_eq!(routes.len(), 2);
+        assert_eq!(routes[0].method, "GET");
+        assert_eq!(routes[0].path, "/users/:id");
+        assert_eq!(routes[0].handler, "getUser");
+        assert!(routes[0].middleware.contains(&"cors".to_string()));
+        assert!(routes[0].middleware.contains(&"authenticate".to_string()));
+    }
+
+    #[test]
+    fn test_extract_flask_and_fastapi_routes() {
+        let source = r#"
+@app.route('/items', methods=['GET', 'POST'])
+def list_items():
+    pass
+
+@app.get("/users/{id}", dependencies=[Depends(verify_token)])
+async def get_user(id: int):
+    pass
+"#;
+        let routes = extract_routes(source, "app.py");
+        assert_eq!(routes.len(), 3);
+        assert!(routes
+            .iter()
+            .any(|r| r.framework == "flask" && r.method == "POST" && r.handler == "list_items"));
+        let fastapi: Vec<_> = routes.iter().filter(|r| r.framework == "fastapi").collect();
+        assert_eq!(fastapi.len(), 1);
+        assert_eq!(fastapi[0].handler, "get_user");
+        assert_eq!(fastapi[0].middleware, vec!["verify_token".to_string()]);
+    }
+
+    #[test]
+    fn test_extract_config_usage_rust() {
+        let source = r#"
+let port = env::var("PORT").unwrap_or("8080".to_string());
+let home = std::env::var("HOME")?;
+"#;
+        let usages = extract_config_usage(source);
+        assert_eq!(usages.len(), 2);
+        assert_eq!(usages[0].key, "PORT");
+        assert_eq!(usages[0].kind, "env");
+        assert_eq!(usages[0].default.as_deref(), Some("8080"));
+        assert_eq!(usages[1].key, "HOME");
+        assert!(usages[1].default.is_none());
+    }
+
+    #[test]
+    fn test_extract_config_usage_python_and_js() {
+        let source = r#"
+debug = os.environ.get("DEBUG", "false")
+secret = os.environ["SECRET_KEY"]
+const host = process.env.DB_HOST || 'localhost';
+timeout = config.get("timeout", 30)
+"#;
+        let usages = extract_config_usage(source);
+        assert!(usages
+            .iter()
+            .any(|u| u.key == "DEBUG" && u.default.as_deref() == Some("false")));
+        assert!(usages
+            .iter()
+            .any(|u| u.key == "SECRET_KEY" && u.default.is_none()));
+        assert!(usages
+            .iter()
+            .any(|u| u.key == "DB_HOST" && u.default.as_deref() == Some("localhost")));
+        assert!(usages
+            .iter()
+            .any(|u| u.key == "timeout" && u.kind == "config"));
+    }
+
+    #[test]
+    fn test_extract_feature_flags() {
+        let source = r#"
+if cfg!(feature = "telemetry") {
+}
+let show = client.bool_variation("new-dashboard", &user, false);
+if unleash.isEnabled("beta-search") {
+}
+let dark = env::var("ENABLE_DARK_MODE").is_ok();
+let path = env::var("HOME");
+"#;
+        let flags = extract_feature_flags(source);
+        assert!(flags
+            .iter()
+            .any(|f| f.name == "telemetry" && f.sdk == "cfg"));
+        assert!(flags
+            .iter()
+            .any(|f| f.name == "new-dashboard" && f.sdk == "launchdarkly"));
+        assert!(flags
+            .iter()
+            .any(|f| f.name == "beta-search" && f.sdk == "unleash"));
+        assert!(flags
+            .iter()
+            .any(|f| f.name == "ENABLE_DARK_MODE" && f.sdk == "env"));
+        // Plain env reads are not flags
+        assert!(!flags.iter().any(|f| f.name == "HOME"));
+    }
+
+    #[test]
+    fn test_extract_spring_routes() {
+        let source = r#"
+@RestController
+@RequestMapping("/api")
+public class UserController {
+    @GetMapping("/users/{id}")
+    public ResponseEntity<User> getUser(@PathVariable Long id) {
+        return null;
+    }
+}
+"#;
+        let routes = extract_routes(source, "UserController.java");
+        assert_eq!(routes.len(), 1);
+        assert_eq!(routes[0].method, "GET");
+        assert_eq!(routes[0].path, "/api/users/{id}");
+        assert_eq!(routes[0].handler, "getUser");
+        assert_eq!(routes[0].framework, "spring");
+    }
 }
@@ -196,6 +196,27 @@ impl GitRepo {
         self.parse_log_output(&String::from_utf8_lossy(&output.stdout))
     }
 
+    /// Get the most recent commits on the current branch
+    pub fn recent_commits(&self, max_commits: usize) -> Result<Vec<FileCommit>> {
+        let output = Command::new("git")
+            .args([
+                "log",
+                "--format=%H|%h|%an|%ae|%at|%s",
+                "--numstat",
+                &format!("-{}", max_commits),
+            ])
+            .current_dir(&self.root)
+            .output()
+            .context("Failed to run git log")?;
+
+        if !output.status.success() {
+            let err = String::from_utf8_lossy(&output.stderr);
+            return Err(anyhow!("git log failed: {}", err));
+        }
+
+        self.parse_log_output(&String::from_utf8_lossy(&output.stdout))
+    }
+
     fn parse_log_output(&self, output: &str) -> Result<Vec<FileCommit>> {
         let mut commits = Vec::new();
         let mut current: Option<FileCommit> = None;
@@ -374,7 +395,7 @@ impl GitRepo {
         }
 
         // Sort by commit count descending
-        contributors.sort_by(|a, b| b.1.cmp(&a.1));
+        contributors.sort_by_key(|c| std::cmp::Reverse(c.1));
 
         Ok(contributors)
     }
@@ -471,6 +492,124 @@ impl GitRepo {
         Ok(files)
     }
 
+    /// Resolve HEAD to a commit hash
+    pub fn head_commit(&self) -> Result<String> {
+        let output = Command::new("git")
+            .args(["rev-parse", "HEAD"])
+            .current_dir(&self.root)
+            .output()
+            .context("Failed to run git rev-parse")?;
+
+        if !output.status.success() {
+            return Err(anyhow!("Failed to resolve HEAD in {:?}", self.root));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    /// List files that differ between two commits as (status, path) pairs
+    /// using git's one-letter status codes (A/M/D). Renames and copies are
+    /// reported as a removal of the old path plus an addition of the new one.
+    pub fn changed_files_between(&self, old: &str, new: &str) -> Result<Vec<(char, String)>> {
+        Self::validate_input(old, "commit")?;
+        Self::validate_input(new, "commit")?;
+
+        let output = Command::new("git")
+            .args(["diff", "--name-status", old, new])
+            .current_dir(&self.root)
+            .output()
+            .context("Failed to run git diff")?;
+
+        if !output.status.success() {
+            return Err(anyhow!(
+                "git diff failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        let mut changes = Vec::new();
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            let mut parts = line.split('\t');
+            let (status, path) = match (parts.next(), parts.next()) {
+                (Some(s), Some(p)) if !s.is_empty() => (s, p),
+                _ => continue,
+            };
+            match status.chars().next().unwrap_or('M') {
+                code @ ('R' | 'C') => {
+                    // Rxx/Cxx lines carry old and new paths
+                    if let Some(new_path) = parts.next() {
+                        if code == 'R' {
+                            changes.push(('D', path.to_string()));
+                        }
+                        changes.push(('A', new_path.to_string()));
+                    }
+                }
+                code => changes.push((code, path.to_string())),
+            }
+        }
+
+        Ok(changes)
+    }
+
+    /// Get the changed line ranges per file between two refs (or between a
+    /// ref and the working tree when `new` is `None`).
+    ///
+    /// Returns a map of repo-relative path to `(start, end)` line ranges in
+    /// the new version of the file. Pure deletions are reported as a
+    /// single-line range at the deletion point so the enclosing function
+    /// still counts as changed.
+    pub fn changed_lines_between(
+        &self,
+        old: &str,
+        new: Option<&str>,
+    ) -> Result<HashMap<String, Vec<(usize, usize)>>> {
+        Self::validate_input(old, "commit")?;
+        if let Some(new) = new {
+            Self::validate_input(new, "commit")?;
+        }
+
+        let mut args = vec!["diff", "--unified=0", old];
+        if let Some(new) = new {
+            args.push(new);
+        }
+
+        let output = Command::new("git")
+            .args(&args)
+            .current_dir(&self.root)
+            .output()
+            .context("Failed to run git diff")?;
+
+        if !output.status.success() {
+            return Err(anyhow!(
+                "git diff failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        Ok(parse_diff_line_ranges(&String::from_utf8_lossy(
+            &output.stdout,
+        )))
+    }
+
+    /// Get a file's contents as of a specific commit/ref
+    pub fn file_at_ref(&self, reference: &str, file_path: &str) -> Result<String> {
+        Self::validate_input(reference, "reference")?;
+        Self::validate_input(file_path, "file_path")?;
+
+        let output = Command::new("git")
+            .args(["show", &format!("{}:{}", reference, file_path)])
+            .current_dir(&self.root)
+            .output()
+            .context("Failed to run git show")?;
+
+        if !output.status.success() {
+            let err = String::from_utf8_lossy(&output.stderr);
+            return Err(anyhow!("git show failed: {}", err));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+
     /// Check if git is available on the system
     #[allow(dead_code)]
     pub fn check_git_available() -> Result<()> {
@@ -530,7 +669,7 @@ impl GitRepo {
     }
 }
 
-fn chrono_lite_format(timestamp: i64) -> String {
+pub(crate) fn chrono_lite_format(timestamp: i64) -> String {
     use std::time::{Duration, UNIX_EPOCH};
 
     let dt = UNIX_EPOCH + Duration::from_secs(timestamp as u64);
@@ -562,6 +701,197 @@ fn truncate(s: &str, max: usize) -> String {
     }
 }
 
+/// A single CODEOWNERS rule: a path pattern and the owners it assigns
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OwnerRule {
+    /// The pattern as written in the CODEOWNERS file
+    pub pattern: String,
+    /// Owner handles (@user, @org/team, or email addresses)
+    pub owners: Vec<String>,
+}
+
+/// Parse a CODEOWNERS file into its rules, in file order
+pub fn parse_codeowners(content: &str) -> Vec<OwnerRule> {
+    let mut rules = Vec::new();
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut parts = line.split_whitespace();
+        let pattern = match parts.next() {
+            Some(p) => p.to_string(),
+            None => continue,
+        };
+        let owners: Vec<String> = parts
+            .take_while(|p| !p.starts_with('#'))
+            .map(|p| p.to_string())
+            .collect();
+
+        rules.push(OwnerRule { pattern, owners });
+    }
+
+    rules
+}
+
+/// Find the CODEOWNERS rule governing a path. As on GitHub, the *last*
+/// matching rule wins.
+pub fn match_owners<'a>(rules: &'a [OwnerRule], path: &str) -> Option<&'a OwnerRule> {
+    rules
+        .iter()
+        .rev()
+        .find(|rule| codeowners_pattern_matches(&rule.pattern, path))
+}
+
+/// gitignore-style pattern matching for CODEOWNERS rules (simplified):
+/// a leading `/` anchors to the repo root, a trailing `/` matches everything
+/// under the directory, patterns without a slash match in any directory.
+fn codeowners_pattern_matches(pattern: &str, path: &str) -> bool {
+    let path = path.trim_start_matches('/');
+    let anchored = pattern.starts_with('/');
+    let mut pat = pattern.trim_start_matches('/').to_string();
+
+    // `docs/` means everything under docs
+    if pat.ends_with('/') {
+        pat.push_str("**");
+    }
+
+    // A bare-name pattern (no slash) matches at any depth
+    let candidates: Vec<String> = if !anchored && !pat.contains('/') {
+        vec![pat.clone(), format!("**/{}", pat)]
+    } else {
+        vec![pat.clone()]
+    };
+
+    let options = glob::MatchOptions {
+        case_sensitive: true,
+        require_literal_separator: true,
+        require_literal_leading_dot: false,
+    };
+
+    for candidate in candidates {
+        // Try the pattern itself and as a directory prefix
+        for full in [candidate.clone(), format!("{}/**", candidate)] {
+            if let Ok(compiled) = glob::Pattern::new(&full) {
+                if compiled.matches_with(path, options) {
+                    return true;
+                }
+            }
+        }
+    }
+
+    false
+}
+
+/// Parse unified diff output into per-file changed line ranges (new side)
+fn parse_diff_line_ranges(diff: &str) -> HashMap<String, Vec<(usize, usize)>> {
+    let mut ranges: HashMap<String, Vec<(usize, usize)>> = HashMap::new();
+    let mut current_file: Option<String> = None;
+
+    for line in diff.lines() {
+        if let Some(path) = line.strip_prefix("+++ b/") {
+            current_file = Some(path.to_string());
+        } else if line.starts_with("+++ ") {
+            // /dev/null for deleted files
+            current_file = None;
+        } else if let Some(hunk) = line.strip_prefix("@@ ") {
+            let file = match current_file.as_ref() {
+                Some(f) => f,
+                None => continue,
+            };
+
+            // Hunk header: "-old_start,old_count +new_start,new_count @@"
+            let new_part = match hunk.split_whitespace().find(|p| p.starts_with('+')) {
+                Some(p) => &p[1..],
+                None => continue,
+            };
+
+            let (start_str, count_str) = match new_part.split_once(',') {
+                Some((s, c)) => (s, c),
+                None => (new_part, "1"),
+            };
+
+            let start: usize = match start_str.parse() {
+                Ok(s) => s,
+                Err(_) => continue,
+            };
+            let count: usize = count_str.parse().unwrap_or(1);
+
+            // Pure deletions have count 0; keep a single-line marker so the
+            // enclosing function still registers as changed
+            let start = start.max(1);
+            let end = start + count.saturating_sub(1);
+            ranges.entry(file.clone()).or_default().push((start, end));
+        }
+    }
+
+    ranges
+}
+
+/// Check a commit subject against conventional-commit rules.
+///
+/// Returns `None` when the subject is compliant, or a short human-readable
+/// violation description otherwise. `allowed_types` is the set of accepted
+/// commit types (e.g. `feat`, `fix`), compared case-sensitively.
+pub fn check_commit_subject(subject: &str, allowed_types: &[String]) -> Option<String> {
+    // Merge commits and reverts produced by git itself are exempt
+    if subject.starts_with("Merge ") || subject.starts_with("Revert \"") {
+        return None;
+    }
+
+    let colon = match subject.find(':') {
+        Some(pos) => pos,
+        None => return Some("missing `type:` prefix".to_string()),
+    };
+
+    let mut prefix = &subject[..colon];
+    let description = subject[colon + 1..].trim();
+
+    // Optional breaking-change marker: `feat(api)!: ...`
+    if let Some(stripped) = prefix.strip_suffix('!') {
+        prefix = stripped;
+    }
+
+    // Optional scope: `fix(parser): ...`
+    let commit_type = if let Some(open) = prefix.find('(') {
+        if !prefix.ends_with(')') {
+            return Some("malformed scope: missing closing `)`".to_string());
+        }
+        let scope = &prefix[open + 1..prefix.len() - 1];
+        if scope.is_empty() {
+            return Some("empty scope".to_string());
+        }
+        &prefix[..open]
+    } else {
+        prefix
+    };
+
+    if commit_type.is_empty() || commit_type.contains(' ') {
+        return Some("missing `type:` prefix".to_string());
+    }
+
+    if !allowed_types.iter().any(|t| t == commit_type) {
+        return Some(format!("unknown type `{}`", commit_type));
+    }
+
+    if description.is_empty() {
+        return Some("empty description".to_string());
+    }
+
+    if subject.len() > 72 {
+        return Some(format!("subject exceeds 72 characters ({})", subject.len()));
+    }
+
+    None
+}
+
+/// Default commit types accepted by `check_commit_subject`
+pub const DEFAULT_COMMIT_TYPES: &[&str] = &[
+    "feat", "fix", "docs", "style", "refactor", "perf", "test", "build", "ci", "chore", "revert",
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -583,6 +913,41 @@ mod tests {
         assert_eq!(truncate("hello world this is long", 10), "hello w...");
     }
 
+    #[test]
+    fn test_check_commit_subject() {
+        let types: Vec<String> = DEFAULT_COMMIT_TYPES.iter().map(|t| t.to_string()).collect();
+
+        assert_eq!(check_commit_subject("feat: add parser", &types), None);
+        assert_eq!(check_commit_subject("fix(lexer): handle EOF", &types), None);
+        assert_eq!(
+            check_commit_subject("feat(api)!: drop v1 routes", &types),
+            None
+        );
+        // Merge/revert commits produced by git itself are exempt
+        assert_eq!(check_commit_subject("Merge branch 'main'", &types), None);
+
+        assert_eq!(
+            check_commit_subject("add parser", &types),
+            Some("missing `type:` prefix".to_string())
+        );
+        assert_eq!(
+            check_commit_subject("feature: add parser", &types),
+            Some("unknown type `feature`".to_string())
+        );
+        assert_eq!(
+            check_commit_subject("fix(): handle EOF", &types),
+            Some("empty scope".to_string())
+        );
+        assert_eq!(
+            check_commit_subject("fix:", &types),
+            Some("empty description".to_string())
+        );
+        let long = format!("feat: {}", "x".repeat(80));
+        assert!(check_commit_subject(&long, &types)
+            .unwrap()
+            .contains("exceeds 72"));
+    }
+
     #[test]
     fn test_git_argument_injection_blocked_file_path() {
         // Test that file_path starting with '-' is rejected
@@ -652,4 +1017,93 @@ mod tests {
         let result = GitRepo::validate_input("function\0name", "function_name");
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_parse_diff_line_ranges() {
+        let diff = "\
+diff --git a/src/lib.rs b/src/lib.rs
+index 123..456 100644
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -10,2 +10,3 @@ fn foo() {
+-old line
++new line
++another line
+@@ -40,0 +42,5 @@ fn bar() {
++added block
+diff --git a/src/gone.rs b/src/gone.rs
+--- a/src/gone.rs
++++ /dev/null
+@@ -1,10 +0,0 @@
+-removed
+";
+
+        let ranges = parse_diff_line_ranges(diff);
+        assert_eq!(ranges.len(), 1);
+        let lib = &ranges["src/lib.rs"];
+        assert_eq!(lib, &vec![(10, 12), (42, 46)]);
+    }
+
+    #[test]
+    fn test_parse_diff_line_ranges_pure_deletion() {
+        let diff = "\
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -20,3 +19,0 @@ fn foo() {
+-a
+-b
+-c
+";
+
+        let ranges = parse_diff_line_ranges(diff);
+        // Deletion keeps a single-line marker at the deletion point
+        assert_eq!(ranges["src/lib.rs"], vec![(19, 19)]);
+    }
+
+    #[test]
+    fn test_parse_codeowners() {
+        let content = "\
+# Comment line
+*       @org/default-team
+*.rs    @alice @bob
+/docs/  @docs-team
+src/parsers/*.rs @parser-team # trailing comment
+";
+        let rules = parse_codeowners(content);
+        assert_eq!(rules.len(), 4);
+        assert_eq!(rules[0].pattern, "*");
+        assert_eq!(rules[0].owners, vec!["@org/default-team"]);
+        assert_eq!(rules[1].owners, vec!["@alice", "@bob"]);
+        assert_eq!(rules[3].pattern, "src/parsers/*.rs");
+        assert_eq!(rules[3].owners, vec!["@parser-team"]);
+    }
+
+    #[test]
+    fn test_match_owners_last_rule_wins() {
+        let rules = parse_codeowners("* @default\n*.rs @rustaceans\n/docs/ @docs-team\n");
+
+        let m = match_owners(&rules, "src/main.rs").unwrap();
+        assert_eq!(m.owners, vec!["@rustaceans"]);
+
+        let m = match_owners(&rules, "docs/guide.md").unwrap();
+        assert_eq!(m.owners, vec!["@docs-team"]);
+
+        let m = match_owners(&rules, "README.md").unwrap();
+        assert_eq!(m.owners, vec!["@default"]);
+    }
+
+    #[test]
+    fn test_match_owners_anchoring() {
+        let rules = parse_codeowners("/build/ @infra\nconfig.yaml @ops\n");
+
+        // Anchored pattern only matches at the root
+        assert!(match_owners(&rules, "build/out.bin").is_some());
+        assert!(match_owners(&rules, "nested/build/out.bin").is_none());
+
+        // Bare filename matches at any depth
+        assert_eq!(
+            match_owners(&rules, "deep/dir/config.yaml").unwrap().owners,
+            vec!["@ops"]
+        );
+    }
 }
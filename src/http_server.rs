@@ -9,7 +9,10 @@
 
 use anyhow::Result;
 use axum::{
-    extract::{Query, State},
+    extract::{
+        ws::{Message, WebSocket},
+        Query, State, WebSocketUpgrade,
+    },
     http::StatusCode,
     response::IntoResponse,
     routing::{get, post},
@@ -116,7 +119,11 @@ impl HttpServer {
             .route("/health", get(health_check))
             .route("/tools", get(list_tools))
             .route("/tools/call", post(call_tool))
-            .route("/graph", get(get_graph));
+            .route("/graph", get(get_graph))
+            .route("/graph/call", get(get_call_graph))
+            .route("/graph/import", get(get_import_graph))
+            .route("/graph/cfg", get(get_cfg_graph))
+            .route("/ws/events", get(ws_events));
 
         // Add embedded frontend routes when feature is enabled
         #[cfg(feature = "frontend")]
@@ -253,6 +260,161 @@ fn default_cluster() -> String {
     "none".to_string()
 }
 
+/// Query parameters for the dedicated graph endpoints
+#[derive(Debug, Deserialize)]
+pub struct GraphViewQuery {
+    /// Repository name
+    #[serde(default)]
+    repo: String,
+    /// Root function/module for a focused view
+    root: Option<String>,
+    /// Maximum traversal depth
+    #[serde(default = "default_depth")]
+    depth: usize,
+    /// Direction (callers, callees, both)
+    #[serde(default = "default_direction")]
+    direction: String,
+    /// Include complexity metrics on nodes
+    #[serde(default = "default_true")]
+    include_metrics: bool,
+    /// Include security overlay
+    #[serde(default)]
+    include_security: bool,
+    /// Cluster nodes by file
+    #[serde(default = "default_cluster")]
+    cluster_by: String,
+}
+
+/// Serve the call graph as nodes/edges JSON for D3/Cytoscape
+async fn get_call_graph(
+    State(state): State<AppState>,
+    Query(query): Query<GraphViewQuery>,
+) -> impl IntoResponse {
+    dispatch_graph_view(&state, "call", &query).await
+}
+
+/// Serve the import graph as nodes/edges JSON for D3/Cytoscape
+async fn get_import_graph(
+    State(state): State<AppState>,
+    Query(query): Query<GraphViewQuery>,
+) -> impl IntoResponse {
+    dispatch_graph_view(&state, "import", &query).await
+}
+
+/// Serve a function's control flow graph as nodes/edges JSON
+///
+/// Requires `root` naming the function to analyze.
+async fn get_cfg_graph(
+    State(state): State<AppState>,
+    Query(query): Query<GraphViewQuery>,
+) -> impl IntoResponse {
+    if query.root.is_none() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(json!({
+                "success": false,
+                "error": "cfg view requires a `root` function name",
+            })),
+        );
+    }
+    dispatch_graph_view(&state, "flow", &query).await
+}
+
+/// Run the get_code_graph tool with a fixed view and return the raw
+/// nodes/edges JSON
+async fn dispatch_graph_view(
+    state: &AppState,
+    view: &str,
+    query: &GraphViewQuery,
+) -> (StatusCode, Json<Value>) {
+    let args = json!({
+        "repo": query.repo,
+        "view": view,
+        "root": query.root,
+        "depth": query.depth,
+        "direction": query.direction,
+        "include_metrics": query.include_metrics,
+        "include_security": query.include_security,
+        "cluster_by": query.cluster_by,
+    });
+
+    match state
+        .tool_registry
+        .dispatch("get_code_graph", &state.engine, args)
+        .await
+    {
+        Ok(output) => match serde_json::from_str::<Value>(&output) {
+            Ok(graph) => (StatusCode::OK, Json(graph)),
+            Err(e) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({
+                    "success": false,
+                    "error": format!("Graph output was not valid JSON: {}", e),
+                })),
+            ),
+        },
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({
+                "success": false,
+                "error": e.to_string(),
+            })),
+        ),
+    }
+}
+
+/// Interval between metrics snapshots pushed over the event socket
+const METRICS_TICK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Upgrade to a WebSocket streaming engine events
+///
+/// Pushes reindex events, watch-mode file changes, and periodic metrics
+/// ticks as JSON, so the frontend can live-update without polling.
+async fn ws_events(State(state): State<AppState>, ws: WebSocketUpgrade) -> impl IntoResponse {
+    ws.on_upgrade(move |socket| handle_ws_events(socket, state))
+}
+
+/// Forward engine events and metrics ticks over one WebSocket connection
+async fn handle_ws_events(mut socket: WebSocket, state: AppState) {
+    let mut events = state.engine.subscribe_events();
+    let mut metrics_tick = tokio::time::interval(METRICS_TICK_INTERVAL);
+
+    loop {
+        tokio::select! {
+            event = events.recv() => match event {
+                Ok(event) => {
+                    let Ok(json) = serde_json::to_string(&event) else {
+                        continue;
+                    };
+                    if socket.send(Message::Text(json)).await.is_err() {
+                        break;
+                    }
+                }
+                // Slow client missed some events - keep streaming the rest
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+            },
+            _ = metrics_tick.tick() => {
+                let event = crate::events::EngineEvent::MetricsTick {
+                    metrics: state.engine.metrics.report_json(),
+                };
+                let Ok(json) = serde_json::to_string(&event) else {
+                    continue;
+                };
+                if socket.send(Message::Text(json)).await.is_err() {
+                    break;
+                }
+            },
+            // Drain client messages so close frames are noticed
+            msg = socket.recv() => match msg {
+                Some(Ok(Message::Close(_))) | None => break,
+                Some(Ok(_)) => {}
+                Some(Err(_)) => break,
+            },
+        }
+    }
+}
+
 // ============================================================================
 // Embedded Frontend Handlers (only when frontend feature is enabled)
 // ============================================================================
@@ -449,6 +611,20 @@ mod tests {
         }
     }
 
+    /// Test graph view query default deserialization
+    #[test]
+    fn test_graph_view_query_defaults() {
+        let query: GraphViewQuery = serde_json::from_str(r#"{"repo": "test"}"#).unwrap();
+
+        assert_eq!(query.repo, "test");
+        assert!(query.root.is_none());
+        assert_eq!(query.depth, 3);
+        assert_eq!(query.direction, "both");
+        assert!(query.include_metrics);
+        assert!(!query.include_security);
+        assert_eq!(query.cluster_by, "none");
+    }
+
     /// Test graph query default deserialization
     #[test]
     fn test_graph_query_defaults() {
@@ -1,17 +1,60 @@
-//! Hybrid search combining BM25 keyword search with TF-IDF semantic similarity.
+//! Hybrid search combining BM25 keyword search with TF-IDF semantic similarity,
+//! and optionally neural embedding results when a neural engine is attached.
 //!
 //! Uses Reciprocal Rank Fusion (RRF) to combine results from multiple search methods.
 
 use crate::chunking::{ChunkType, CodeChunk};
 use crate::embeddings::{EmbeddingEngine, SimilarityResult};
-use crate::search::{ConcurrentSearchIndex, DocType, SearchDocument, SearchResult};
+use crate::git::ChangeFrequency;
+use crate::neural::{NeuralEngine, NeuralSearchResult};
+use crate::search::{
+    highlight_spans, ConcurrentSearchIndex, DocType, HighlightSpan, SearchDocument, SearchResult,
+};
+use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// Strategy for fusing the ranked lists from each search channel.
+///
+/// RRF is rank-based and robust to score scale differences, but it can
+/// dilute a clearly dominant channel; the score-based strategies keep
+/// more of the original score signal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FusionStrategy {
+    /// Reciprocal Rank Fusion: 1 / (k + rank), weighted per channel
+    #[default]
+    Rrf,
+    /// Weighted linear combination of min-max normalized scores
+    WeightedSum,
+    /// CombSUM: sum of min-max normalized scores, ignoring channel weights
+    CombSum,
+    /// CombMNZ: CombSUM multiplied by the number of channels that matched
+    CombMnz,
+}
+
+impl std::str::FromStr for FusionStrategy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rrf" => Ok(Self::Rrf),
+            "weighted" | "weighted-sum" => Ok(Self::WeightedSum),
+            "combsum" => Ok(Self::CombSum),
+            "combmnz" => Ok(Self::CombMnz),
+            other => anyhow::bail!(
+                "Unknown fusion strategy '{}'. Valid: rrf, weighted, combsum, combmnz",
+                other
+            ),
+        }
+    }
+}
+
 /// Configuration for hybrid search
 #[derive(Debug, Clone)]
 pub struct HybridSearchConfig {
+    /// How to fuse the ranked lists from each channel
+    pub fusion: FusionStrategy,
     /// RRF k parameter (typically 60)
     /// Higher values reduce the impact of high rankings
     pub rrf_k: f64,
@@ -19,27 +62,54 @@ pub struct HybridSearchConfig {
     pub bm25_weight: f64,
     /// Weight for TF-IDF/semantic results (0.0 to 1.0)
     pub tfidf_weight: f64,
+    /// Weight for neural embedding results (0.0 to 1.0), used when a
+    /// neural engine is attached
+    pub neural_weight: f64,
+    /// Weight for fuzzy symbol-name matches (0.0 to 1.0)
+    pub fuzzy_weight: f64,
     /// Boost factor for exact name matches
     pub exact_match_boost: f64,
     /// Boost factor for function/method matches
     pub function_boost: f64,
     /// Number of candidates to fetch from each index before fusion
     pub candidate_multiplier: usize,
+    /// Number of top fused results to re-score with the attached reranker
+    /// (0 disables the reranking stage even when a reranker is set)
+    pub rerank_top_k: usize,
+    /// Multiplier for git-derived recency weights: fused scores are scaled
+    /// by `1.0 + recency_boost * weight`. Only applies when per-file
+    /// weights have been loaded via `set_file_recency`; 0.0 disables
+    pub recency_boost: f64,
 }
 
 impl Default for HybridSearchConfig {
     fn default() -> Self {
         Self {
+            fusion: FusionStrategy::default(),
             rrf_k: 60.0,
             bm25_weight: 1.0,
             tfidf_weight: 1.0,
+            neural_weight: 1.0,
+            fuzzy_weight: 1.0,
             exact_match_boost: 2.0,
             function_boost: 1.5,
             candidate_multiplier: 3,
+            rerank_top_k: 20,
+            recency_boost: 0.25,
         }
     }
 }
 
+/// Trait for rerankers that re-score fused results against the query.
+///
+/// Cross-encoders see the query and document together, so they rank
+/// natural-language queries far more precisely than the bag-of-words
+/// scores used during fusion. Implementations return one relevance
+/// score per document, in input order (higher is more relevant).
+pub trait Reranker: Send + Sync {
+    fn rerank(&self, query: &str, documents: &[String]) -> anyhow::Result<Vec<f32>>;
+}
+
 /// A result from hybrid search with combined scoring
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HybridResult {
@@ -55,18 +125,44 @@ pub struct HybridResult {
     pub end_line: usize,
     /// Combined RRF score
     pub score: f64,
+    /// Cross-encoder relevance score, when a reranker re-scored this result
+    #[serde(default)]
+    pub rerank_score: Option<f64>,
     /// BM25 rank (if found)
     pub bm25_rank: Option<usize>,
     /// TF-IDF rank (if found)
     pub tfidf_rank: Option<usize>,
+    /// Neural embedding rank (if found)
+    #[serde(default)]
+    pub neural_rank: Option<usize>,
+    /// Fuzzy symbol-name rank (if found)
+    #[serde(default)]
+    pub fuzzy_rank: Option<usize>,
     /// Terms that matched
     pub matched_terms: Vec<String>,
+    /// Byte offsets of matched terms within `content`, for highlighting
+    #[serde(default)]
+    pub highlights: Vec<HighlightSpan>,
     /// Symbol context if available
     pub symbol_name: Option<String>,
     /// Type of result
     pub result_type: String,
 }
 
+/// Per-channel ranks (BM25, TF-IDF, neural, fuzzy) for a fused document
+type ChannelRanks = (Option<usize>, Option<usize>, Option<usize>, Option<usize>);
+
+/// Min-max normalize scores to [0, 1]. A channel where all scores are
+/// equal normalizes to 1.0 so it still contributes.
+fn min_max_normalize(scores: &[f64]) -> Vec<f64> {
+    let min = scores.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = scores.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    if !min.is_finite() || !max.is_finite() || (max - min).abs() < f64::EPSILON {
+        return vec![1.0; scores.len()];
+    }
+    scores.iter().map(|s| (s - min) / (max - min)).collect()
+}
+
 /// Document info for merging results
 #[derive(Debug, Clone)]
 struct DocumentInfo {
@@ -80,12 +176,153 @@ struct DocumentInfo {
     result_type: String,
 }
 
+/// A symbol name registered for fuzzy matching
+#[derive(Debug, Clone)]
+struct FuzzySymbolEntry {
+    id: String,
+    name: String,
+    file_path: String,
+    start_line: usize,
+    end_line: usize,
+}
+
+/// Index of symbol names for fzf-style fuzzy matching.
+///
+/// Lets abbreviated queries like "hsrch eng" find `HybridSearchEngine`:
+/// every query token must match as a subsequence of the symbol name,
+/// with bonuses for camelCase/snake_case word boundaries and consecutive
+/// matches.
+pub struct FuzzySymbolIndex {
+    entries: RwLock<Vec<FuzzySymbolEntry>>,
+}
+
+impl FuzzySymbolIndex {
+    pub fn new() -> Self {
+        Self {
+            entries: RwLock::new(Vec::new()),
+        }
+    }
+
+    fn add(&self, entry: FuzzySymbolEntry) {
+        self.entries.write().push(entry);
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.read().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&self) {
+        self.entries.write().clear();
+    }
+
+    /// Return the best-matching symbols for `query`, sorted by score
+    fn search(&self, query: &str, limit: usize) -> Vec<(FuzzySymbolEntry, f64)> {
+        let entries = self.entries.read();
+        let mut matches: Vec<(FuzzySymbolEntry, f64)> = entries
+            .iter()
+            .filter_map(|entry| fuzzy_match(query, &entry.name).map(|score| (entry.clone(), score)))
+            .collect();
+        matches.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        matches.truncate(limit);
+        matches
+    }
+}
+
+impl Default for FuzzySymbolIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Fuzzy-match `query` against a symbol name. Every whitespace-separated
+/// query token must match as a case-insensitive subsequence; the score is
+/// the sum of the per-token scores (higher is better). Returns None when
+/// any token fails to match.
+pub fn fuzzy_match(query: &str, name: &str) -> Option<f64> {
+    let mut total = 0.0;
+    let mut matched_any = false;
+    for token in query.split_whitespace() {
+        total += fuzzy_match_token(token, name)?;
+        matched_any = true;
+    }
+    matched_any.then_some(total)
+}
+
+/// Match a single token as a subsequence of `name`, scoring word-boundary
+/// hits (start, after `_`/`-`/`:`/`.`, or a lowercase-to-uppercase
+/// camelCase transition) and consecutive runs higher than scattered hits
+fn fuzzy_match_token(token: &str, name: &str) -> Option<f64> {
+    const BOUNDARY_BONUS: f64 = 2.0;
+    const CONSECUTIVE_BONUS: f64 = 1.5;
+    const MATCH_SCORE: f64 = 1.0;
+    const GAP_PENALTY: f64 = 0.05;
+
+    let name_chars: Vec<char> = name.chars().collect();
+    let mut score = 0.0;
+    let mut pos = 0;
+    let mut last_match: Option<usize> = None;
+
+    for token_char in token.chars() {
+        let token_lower = token_char.to_ascii_lowercase();
+        let mut found = None;
+        while pos < name_chars.len() {
+            if name_chars[pos].to_ascii_lowercase() == token_lower {
+                found = Some(pos);
+                break;
+            }
+            pos += 1;
+        }
+        let at = found?;
+
+        score += MATCH_SCORE;
+        if is_word_boundary(&name_chars, at) {
+            score += BOUNDARY_BONUS;
+        }
+        if last_match == Some(at.wrapping_sub(1)) && at > 0 {
+            score += CONSECUTIVE_BONUS;
+        }
+        if let Some(last) = last_match {
+            score -= GAP_PENALTY * (at - last - 1) as f64;
+        }
+
+        last_match = Some(at);
+        pos = at + 1;
+    }
+
+    Some(score)
+}
+
+/// Whether position `at` starts a word within a symbol name
+fn is_word_boundary(chars: &[char], at: usize) -> bool {
+    if at == 0 {
+        return true;
+    }
+    let prev = chars[at - 1];
+    if matches!(prev, '_' | '-' | ':' | '.') {
+        return true;
+    }
+    // camelCase transition
+    chars[at].is_uppercase() && prev.is_lowercase()
+}
+
 /// Hybrid search engine combining BM25 and TF-IDF
 pub struct HybridSearchEngine {
     /// BM25 keyword search index
     bm25_index: Arc<ConcurrentSearchIndex>,
     /// TF-IDF embedding engine
     tfidf_engine: Arc<EmbeddingEngine>,
+    /// Optional neural embedding engine, fused as a third ranked list
+    neural_engine: Option<Arc<NeuralEngine>>,
+    /// Fuzzy symbol-name index, fused as another ranked list
+    fuzzy_index: FuzzySymbolIndex,
+    /// Optional cross-encoder reranking stage applied after fusion
+    reranker: Option<Arc<dyn Reranker>>,
+    /// Per-file recency weights (0.0 to 1.0) from git history
+    file_recency: RwLock<HashMap<String, f64>>,
     /// Configuration
     config: HybridSearchConfig,
 }
@@ -96,6 +333,10 @@ impl HybridSearchEngine {
         Self {
             bm25_index,
             tfidf_engine,
+            neural_engine: None,
+            fuzzy_index: FuzzySymbolIndex::new(),
+            reranker: None,
+            file_recency: RwLock::new(HashMap::new()),
             config: HybridSearchConfig::default(),
         }
     }
@@ -109,23 +350,118 @@ impl HybridSearchEngine {
         Self {
             bm25_index,
             tfidf_engine,
+            neural_engine: None,
+            fuzzy_index: FuzzySymbolIndex::new(),
+            reranker: None,
+            file_recency: RwLock::new(HashMap::new()),
             config,
         }
     }
 
+    /// Attach a neural embedding engine whose results are fused as a
+    /// third ranked list, weighted by `neural_weight` in the config
+    pub fn with_neural(mut self, neural_engine: Arc<NeuralEngine>) -> Self {
+        self.neural_engine = Some(neural_engine);
+        self
+    }
+
+    /// Attach a reranker that re-scores the top fused results
+    /// (`rerank_top_k` in the config controls how many)
+    pub fn with_reranker(mut self, reranker: Arc<dyn Reranker>) -> Self {
+        self.reranker = Some(reranker);
+        self
+    }
+
+    /// Supply per-file recency weights (0.0 to 1.0) derived from git
+    /// history; see [`recency_weights`]. Fused scores for matching files
+    /// are scaled by `1.0 + recency_boost * weight`
+    pub fn set_file_recency(&self, weights: HashMap<String, f64>) {
+        *self.file_recency.write() = weights;
+    }
+
     /// Perform hybrid search combining BM25 and TF-IDF results
     /// Searches are run in parallel for better performance
     pub fn search(&self, query: &str, limit: usize) -> Vec<HybridResult> {
         let candidate_limit = limit * self.config.candidate_multiplier;
 
-        // Run BM25 and TF-IDF searches in parallel using rayon::join
-        let (bm25_results, tfidf_results) = rayon::join(
+        // Run BM25, TF-IDF and neural searches in parallel using rayon::join
+        let (bm25_results, (tfidf_results, neural_results)) = rayon::join(
             || self.bm25_index.search(query, candidate_limit),
-            || self.tfidf_engine.find_similar_code(query, candidate_limit),
+            || {
+                rayon::join(
+                    || self.tfidf_engine.find_similar_code(query, candidate_limit),
+                    || self.search_neural(query, candidate_limit),
+                )
+            },
+        );
+        let fuzzy_results = self.fuzzy_index.search(query, candidate_limit);
+
+        // Combine using the configured fusion strategy
+        let fused = self.fuse_results(
+            bm25_results,
+            tfidf_results,
+            neural_results,
+            fuzzy_results,
+            query,
+            limit,
         );
 
-        // Combine using RRF
-        self.reciprocal_rank_fusion(bm25_results, tfidf_results, query, limit)
+        // Optional cross-encoder reranking of the top fused results
+        self.apply_reranker(query, fused)
+    }
+
+    /// Re-score the top `rerank_top_k` fused results with the attached
+    /// reranker and re-order them by cross-encoder relevance. Results
+    /// beyond the top-K keep their fused order, and a reranker failure
+    /// degrades gracefully to the fused ranking.
+    fn apply_reranker(&self, query: &str, mut results: Vec<HybridResult>) -> Vec<HybridResult> {
+        let Some(ref reranker) = self.reranker else {
+            return results;
+        };
+        let top_k = self.config.rerank_top_k.min(results.len());
+        if top_k == 0 {
+            return results;
+        }
+
+        let documents: Vec<String> = results[..top_k].iter().map(|r| r.content.clone()).collect();
+        match reranker.rerank(query, &documents) {
+            Ok(scores) if scores.len() == top_k => {
+                for (result, &score) in results[..top_k].iter_mut().zip(scores.iter()) {
+                    result.rerank_score = Some(score as f64);
+                }
+                results[..top_k].sort_by(|a, b| {
+                    b.rerank_score
+                        .partial_cmp(&a.rerank_score)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                });
+            }
+            Ok(scores) => {
+                tracing::warn!(
+                    "Reranker returned {} scores for {} documents, keeping fused order",
+                    scores.len(),
+                    top_k
+                );
+            }
+            Err(e) => {
+                tracing::warn!("Reranking failed, keeping fused order: {}", e);
+            }
+        }
+        results
+    }
+
+    /// Query the attached neural engine, degrading to no results if it
+    /// is missing or fails (e.g. the embedding API is unreachable)
+    fn search_neural(&self, query: &str, limit: usize) -> Vec<NeuralSearchResult> {
+        let Some(ref neural) = self.neural_engine else {
+            return Vec::new();
+        };
+        match neural.search(query, limit) {
+            Ok(results) => results,
+            Err(e) => {
+                tracing::warn!("Neural search failed during fusion: {}", e);
+                Vec::new()
+            }
+        }
     }
 
     /// Perform BM25-only search
@@ -142,8 +478,12 @@ impl HybridSearchEngine {
                 start_line: r.document.start_line,
                 end_line: r.document.end_line,
                 score: r.score,
+                rerank_score: None,
                 bm25_rank: Some(rank),
                 tfidf_rank: None,
+                neural_rank: None,
+                fuzzy_rank: None,
+                highlights: r.highlights,
                 matched_terms: r.matched_terms,
                 symbol_name: None,
                 result_type: format!("{:?}", r.document.doc_type),
@@ -165,8 +505,12 @@ impl HybridSearchEngine {
                 start_line: r.document.start_line,
                 end_line: r.document.end_line,
                 score: r.similarity as f64,
+                rerank_score: None,
                 bm25_rank: None,
                 tfidf_rank: Some(rank),
+                neural_rank: None,
+                fuzzy_rank: None,
+                highlights: Vec::new(),
                 matched_terms: Vec::new(),
                 symbol_name: None,
                 result_type: "embedding".to_string(),
@@ -174,25 +518,45 @@ impl HybridSearchEngine {
             .collect()
     }
 
-    /// Reciprocal Rank Fusion of BM25 and TF-IDF results
-    fn reciprocal_rank_fusion(
+    /// Fuse the BM25, TF-IDF and neural ranked lists into one list
+    /// using the configured `FusionStrategy`
+    fn fuse_results(
         &self,
         bm25_results: Vec<SearchResult>,
         tfidf_results: Vec<SimilarityResult>,
+        neural_results: Vec<NeuralSearchResult>,
+        fuzzy_results: Vec<(FuzzySymbolEntry, f64)>,
         query: &str,
         limit: usize,
     ) -> Vec<HybridResult> {
         let mut scores: HashMap<String, f64> = HashMap::new();
-        let mut ranks: HashMap<String, (Option<usize>, Option<usize>)> = HashMap::new();
+        let mut ranks: HashMap<String, ChannelRanks> = HashMap::new();
         let mut doc_info: HashMap<String, DocumentInfo> = HashMap::new();
 
-        let k = self.config.rrf_k;
         let query_lower = query.to_lowercase();
 
+        // Normalized raw scores per channel, for the score-based strategies
+        let bm25_norms =
+            min_max_normalize(&bm25_results.iter().map(|r| r.score).collect::<Vec<_>>());
+        let tfidf_norms = min_max_normalize(
+            &tfidf_results
+                .iter()
+                .map(|r| r.similarity as f64)
+                .collect::<Vec<_>>(),
+        );
+        let neural_norms = min_max_normalize(
+            &neural_results
+                .iter()
+                .map(|r| r.similarity as f64)
+                .collect::<Vec<_>>(),
+        );
+        let fuzzy_norms =
+            min_max_normalize(&fuzzy_results.iter().map(|(_, s)| *s).collect::<Vec<_>>());
+
         // Process BM25 results
         for (rank, result) in bm25_results.iter().enumerate() {
             let id = &result.document.id;
-            let rrf_score = self.config.bm25_weight / (k + rank as f64 + 1.0);
+            let fused_score = self.channel_score(self.config.bm25_weight, rank, bm25_norms[rank]);
 
             // Apply boosts
             let mut boost = 1.0;
@@ -206,8 +570,11 @@ impl HybridSearchEngine {
                 boost *= self.config.function_boost;
             }
 
-            *scores.entry(id.clone()).or_default() += rrf_score * boost;
-            ranks.entry(id.clone()).or_insert((None, None)).0 = Some(rank);
+            *scores.entry(id.clone()).or_default() += fused_score * boost;
+            ranks
+                .entry(id.clone())
+                .or_insert((None, None, None, None))
+                .0 = Some(rank);
 
             doc_info.entry(id.clone()).or_insert_with(|| DocumentInfo {
                 id: id.clone(),
@@ -224,7 +591,7 @@ impl HybridSearchEngine {
         // Process TF-IDF results
         for (rank, result) in tfidf_results.iter().enumerate() {
             let id = &result.document.id;
-            let rrf_score = self.config.tfidf_weight / (k + rank as f64 + 1.0);
+            let fused_score = self.channel_score(self.config.tfidf_weight, rank, tfidf_norms[rank]);
 
             // Apply boosts
             let mut boost = 1.0;
@@ -232,8 +599,11 @@ impl HybridSearchEngine {
                 boost *= self.config.exact_match_boost;
             }
 
-            *scores.entry(id.clone()).or_default() += rrf_score * boost;
-            ranks.entry(id.clone()).or_insert((None, None)).1 = Some(rank);
+            *scores.entry(id.clone()).or_default() += fused_score * boost;
+            ranks
+                .entry(id.clone())
+                .or_insert((None, None, None, None))
+                .1 = Some(rank);
 
             doc_info.entry(id.clone()).or_insert_with(|| DocumentInfo {
                 id: id.clone(),
@@ -247,6 +617,97 @@ impl HybridSearchEngine {
             });
         }
 
+        // Process neural results
+        for (rank, result) in neural_results.iter().enumerate() {
+            let id = &result.document.id;
+            let fused_score =
+                self.channel_score(self.config.neural_weight, rank, neural_norms[rank]);
+
+            // Apply boosts
+            let mut boost = 1.0;
+            if id.to_lowercase().contains(&query_lower) {
+                boost *= self.config.exact_match_boost;
+            }
+
+            *scores.entry(id.clone()).or_default() += fused_score * boost;
+            ranks
+                .entry(id.clone())
+                .or_insert((None, None, None, None))
+                .2 = Some(rank);
+
+            doc_info.entry(id.clone()).or_insert_with(|| DocumentInfo {
+                id: id.clone(),
+                file_path: result.document.file_path.clone(),
+                content: result.document.content.clone(),
+                start_line: result.document.start_line,
+                end_line: result.document.end_line,
+                matched_terms: Vec::new(),
+                symbol_name: result.document.symbol_name.clone(),
+                result_type: "neural".to_string(),
+            });
+        }
+
+        // Process fuzzy symbol-name results
+        for (rank, (entry, _)) in fuzzy_results.iter().enumerate() {
+            let id = &entry.id;
+            let fused_score = self.channel_score(self.config.fuzzy_weight, rank, fuzzy_norms[rank]);
+
+            *scores.entry(id.clone()).or_default() += fused_score;
+            ranks
+                .entry(id.clone())
+                .or_insert((None, None, None, None))
+                .3 = Some(rank);
+
+            doc_info
+                .entry(id.clone())
+                .and_modify(|info| {
+                    // Another channel saw this doc first; keep its snippet but
+                    // attach the matched symbol name if it lacks one
+                    if info.symbol_name.is_none() {
+                        info.symbol_name = Some(entry.name.clone());
+                    }
+                })
+                .or_insert_with(|| DocumentInfo {
+                    id: id.clone(),
+                    file_path: entry.file_path.clone(),
+                    content: entry.name.clone(),
+                    start_line: entry.start_line,
+                    end_line: entry.end_line,
+                    matched_terms: Vec::new(),
+                    symbol_name: Some(entry.name.clone()),
+                    result_type: "symbol".to_string(),
+                });
+        }
+
+        // CombMNZ rewards documents found by several channels
+        if self.config.fusion == FusionStrategy::CombMnz {
+            for (id, score) in scores.iter_mut() {
+                let (bm25, tfidf, neural, fuzzy) =
+                    ranks.get(id).copied().unwrap_or((None, None, None, None));
+                let match_count = [bm25, tfidf, neural, fuzzy]
+                    .iter()
+                    .filter(|r| r.is_some())
+                    .count();
+                *score *= match_count as f64;
+            }
+        }
+
+        // Boost recently and frequently changed files when git-derived
+        // weights have been loaded
+        if self.config.recency_boost > 0.0 {
+            let recency = self.file_recency.read();
+            if !recency.is_empty() {
+                for (id, score) in scores.iter_mut() {
+                    if let Some(weight) = doc_info
+                        .get(id)
+                        .and_then(|info| recency.get(&info.file_path))
+                    {
+                        *score *= 1.0 + self.config.recency_boost * weight.clamp(0.0, 1.0);
+                    }
+                }
+            }
+        }
+
         // Sort by combined score
         let mut combined: Vec<_> = scores.into_iter().collect();
         combined.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
@@ -257,7 +718,8 @@ impl HybridSearchEngine {
             .take(limit)
             .filter_map(|(id, score)| {
                 let info = doc_info.get(&id)?;
-                let (bm25_rank, tfidf_rank) = ranks.get(&id).copied().unwrap_or((None, None));
+                let (bm25_rank, tfidf_rank, neural_rank, fuzzy_rank) =
+                    ranks.get(&id).copied().unwrap_or((None, None, None, None));
 
                 Some(HybridResult {
                     id: info.id.clone(),
@@ -266,8 +728,12 @@ impl HybridSearchEngine {
                     start_line: info.start_line,
                     end_line: info.end_line,
                     score,
+                    rerank_score: None,
                     bm25_rank,
                     tfidf_rank,
+                    neural_rank,
+                    fuzzy_rank,
+                    highlights: highlight_spans(&info.content, &info.matched_terms),
                     matched_terms: info.matched_terms.clone(),
                     symbol_name: info.symbol_name.clone(),
                     result_type: info.result_type.clone(),
@@ -276,6 +742,15 @@ impl HybridSearchEngine {
             .collect()
     }
 
+    /// Score contribution of one ranked entry under the configured strategy
+    fn channel_score(&self, weight: f64, rank: usize, normalized_score: f64) -> f64 {
+        match self.config.fusion {
+            FusionStrategy::Rrf => weight / (self.config.rrf_k + rank as f64 + 1.0),
+            FusionStrategy::WeightedSum => weight * normalized_score,
+            FusionStrategy::CombSum | FusionStrategy::CombMnz => normalized_score,
+        }
+    }
+
     /// Index a code chunk for both BM25 and TF-IDF search
     pub fn index_chunk(&self, chunk: &CodeChunk) {
         // Index in BM25
@@ -305,6 +780,17 @@ impl HybridSearchEngine {
 
         self.bm25_index.inner.write().add_document(search_doc);
 
+        // Register the symbol name for fuzzy matching
+        if let Some(name) = chunk_symbol_name(chunk) {
+            self.fuzzy_index.add(FuzzySymbolEntry {
+                id: chunk.id.clone(),
+                name,
+                file_path: chunk.file_path.clone(),
+                start_line: chunk.start_line,
+                end_line: chunk.end_line,
+            });
+        }
+
         // Index in TF-IDF
         self.tfidf_engine.index_snippet(
             chunk.id.clone(),
@@ -319,6 +805,8 @@ impl HybridSearchEngine {
     pub fn clear(&self) {
         self.bm25_index.clear();
         self.tfidf_engine.clear();
+        self.fuzzy_index.clear();
+        self.file_recency.write().clear();
     }
 
     /// Get statistics about the hybrid index
@@ -331,10 +819,61 @@ impl HybridSearchEngine {
             bm25_terms: bm25_stats.total_terms,
             tfidf_documents: doc_count,
             tfidf_vocab_size: tfidf_stats.vocab_size,
+            fuzzy_symbols: self.fuzzy_index.len(),
         }
     }
 }
 
+/// Half-life used when decaying git modification times into recency weights
+const RECENCY_HALF_LIFE_DAYS: f64 = 30.0;
+
+/// Turn git change-frequency data into per-file recency weights (0.0 to 1.0)
+///
+/// Combines an exponential decay of the last modification time with a
+/// log-scaled commit count, so files that are both fresh and frequently
+/// touched score highest. `change_frequency` is already windowed and does
+/// not always populate `last_modified`; files without a timestamp count
+/// as fresh since they were touched inside the window.
+pub fn recency_weights(frequencies: &[ChangeFrequency], now: i64) -> HashMap<String, f64> {
+    let max_commits = frequencies
+        .iter()
+        .map(|f| f.total_commits)
+        .max()
+        .unwrap_or(0);
+
+    frequencies
+        .iter()
+        .map(|f| {
+            let freshness = if f.last_modified > 0 {
+                let age_days = (now - f.last_modified).max(0) as f64 / 86_400.0;
+                0.5f64.powf(age_days / RECENCY_HALF_LIFE_DAYS)
+            } else {
+                1.0
+            };
+            let frequency = if max_commits > 0 {
+                (1.0 + f.total_commits as f64).ln() / (1.0 + max_commits as f64).ln()
+            } else {
+                0.0
+            };
+            (f.file_path.clone(), (freshness + frequency) / 2.0)
+        })
+        .collect()
+}
+
+/// Best available symbol name for a chunk: the parsed symbol context if
+/// present, otherwise the name segment of the chunk id (skipping the
+/// synthetic "toplevel"/"lines" chunks)
+fn chunk_symbol_name(chunk: &CodeChunk) -> Option<String> {
+    if let Some(ref context) = chunk.symbol_context {
+        return Some(context.name.clone());
+    }
+    let name = chunk.id.rsplit(':').next()?;
+    if name.is_empty() || name == "toplevel" || name == "lines" {
+        return None;
+    }
+    Some(name.to_string())
+}
+
 /// Statistics about the hybrid search index
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HybridSearchStats {
@@ -342,6 +881,8 @@ pub struct HybridSearchStats {
     pub bm25_terms: usize,
     pub tfidf_documents: usize,
     pub tfidf_vocab_size: usize,
+    #[serde(default)]
+    pub fuzzy_symbols: usize,
 }
 
 /// Builder for hybrid search configuration
@@ -356,6 +897,11 @@ impl HybridSearchConfigBuilder {
         }
     }
 
+    pub fn fusion(mut self, strategy: FusionStrategy) -> Self {
+        self.config.fusion = strategy;
+        self
+    }
+
     pub fn rrf_k(mut self, k: f64) -> Self {
         self.config.rrf_k = k;
         self
@@ -371,6 +917,16 @@ impl HybridSearchConfigBuilder {
         self
     }
 
+    pub fn neural_weight(mut self, weight: f64) -> Self {
+        self.config.neural_weight = weight;
+        self
+    }
+
+    pub fn fuzzy_weight(mut self, weight: f64) -> Self {
+        self.config.fuzzy_weight = weight;
+        self
+    }
+
     pub fn exact_match_boost(mut self, boost: f64) -> Self {
         self.config.exact_match_boost = boost;
         self
@@ -386,6 +942,16 @@ impl HybridSearchConfigBuilder {
         self
     }
 
+    pub fn rerank_top_k(mut self, top_k: usize) -> Self {
+        self.config.rerank_top_k = top_k;
+        self
+    }
+
+    pub fn recency_boost(mut self, boost: f64) -> Self {
+        self.config.recency_boost = boost;
+        self
+    }
+
     pub fn build(self) -> HybridSearchConfig {
         self.config
     }
@@ -397,6 +963,107 @@ impl Default for HybridSearchConfigBuilder {
     }
 }
 
+/// API-based cross-encoder reranker (Voyage, Cohere-compatible endpoints)
+#[cfg(feature = "native")]
+pub struct ApiReranker {
+    client: reqwest::blocking::Client,
+    endpoint: String,
+    model: String,
+    api_key: Option<String>,
+}
+
+#[cfg(feature = "native")]
+impl ApiReranker {
+    /// Create a Voyage AI reranker
+    pub fn voyage(api_key: &str) -> Self {
+        Self::custom(
+            "https://api.voyageai.com/v1/rerank",
+            "rerank-2",
+            Some(api_key),
+        )
+    }
+
+    /// Create a reranker against a custom rerank endpoint
+    pub fn custom(endpoint: &str, model: &str, api_key: Option<&str>) -> Self {
+        Self {
+            client: reqwest::blocking::Client::builder()
+                .timeout(std::time::Duration::from_secs(60))
+                .connect_timeout(std::time::Duration::from_secs(30))
+                .build()
+                .expect("Failed to create HTTP client"),
+            endpoint: endpoint.to_string(),
+            model: model.to_string(),
+            api_key: api_key.map(|s| s.to_string()),
+        }
+    }
+}
+
+#[cfg(feature = "native")]
+impl Reranker for ApiReranker {
+    fn rerank(&self, query: &str, documents: &[String]) -> anyhow::Result<Vec<f32>> {
+        use anyhow::Context;
+
+        #[derive(Serialize)]
+        struct Request<'a> {
+            model: &'a str,
+            query: &'a str,
+            documents: &'a [String],
+        }
+
+        #[derive(Deserialize)]
+        struct Response {
+            data: Vec<RerankResult>,
+        }
+
+        #[derive(Deserialize)]
+        struct RerankResult {
+            index: usize,
+            relevance_score: f32,
+        }
+
+        if documents.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut request = self
+            .client
+            .post(&self.endpoint)
+            .header("Content-Type", "application/json")
+            .json(&Request {
+                model: &self.model,
+                query,
+                documents,
+            });
+        if let Some(key) = &self.api_key {
+            request = request.header("Authorization", format!("Bearer {}", key));
+        }
+
+        let resp = request.send().context("Failed to send rerank request")?;
+        let status = resp.status();
+        let text = resp.text().context("Failed to read rerank response")?;
+        if !status.is_success() {
+            anyhow::bail!(
+                "Rerank API error ({}): {}",
+                status,
+                &text[..text.len().min(500)]
+            );
+        }
+
+        let response: Response =
+            serde_json::from_str(&text).context("Failed to parse rerank response")?;
+
+        // The API returns results sorted by relevance; map back to input order
+        let mut scores = vec![0.0f32; documents.len()];
+        for result in response.data {
+            if result.index >= documents.len() {
+                anyhow::bail!("Rerank response index {} out of range", result.index);
+            }
+            scores[result.index] = result.relevance_score;
+        }
+        Ok(scores)
+    }
+}
+
 /// Convenience function to create a hybrid search engine with default config
 pub fn create_hybrid_engine(
     bm25_index: Arc<ConcurrentSearchIndex>,
@@ -581,20 +1248,286 @@ mod tests {
     #[test]
     fn test_config_builder() {
         let config = HybridSearchConfigBuilder::new()
+            .fusion(FusionStrategy::WeightedSum)
             .rrf_k(80.0)
             .bm25_weight(0.8)
             .tfidf_weight(0.6)
+            .neural_weight(0.4)
+            .fuzzy_weight(0.3)
             .exact_match_boost(3.0)
             .function_boost(2.0)
             .candidate_multiplier(5)
+            .rerank_top_k(15)
+            .recency_boost(0.5)
             .build();
 
+        assert_eq!(config.fusion, FusionStrategy::WeightedSum);
         assert_eq!(config.rrf_k, 80.0);
         assert_eq!(config.bm25_weight, 0.8);
         assert_eq!(config.tfidf_weight, 0.6);
+        assert_eq!(config.neural_weight, 0.4);
+        assert_eq!(config.fuzzy_weight, 0.3);
         assert_eq!(config.exact_match_boost, 3.0);
         assert_eq!(config.function_boost, 2.0);
         assert_eq!(config.candidate_multiplier, 5);
+        assert_eq!(config.rerank_top_k, 15);
+        assert_eq!(config.recency_boost, 0.5);
+    }
+
+    #[test]
+    fn test_recency_weights_favor_fresh_frequent_files() {
+        let now = 1_700_000_000;
+        let frequencies = vec![
+            ChangeFrequency {
+                file_path: "hot.rs".to_string(),
+                total_commits: 20,
+                total_lines_changed: 0,
+                unique_authors: 3,
+                last_modified: now - 86_400, // one day old
+                churn_score: 1.0,
+            },
+            ChangeFrequency {
+                file_path: "cold.rs".to_string(),
+                total_commits: 1,
+                total_lines_changed: 0,
+                unique_authors: 1,
+                last_modified: now - 300 * 86_400, // ~10 half-lives old
+                churn_score: 0.1,
+            },
+        ];
+
+        let weights = recency_weights(&frequencies, now);
+        assert!(weights["hot.rs"] > weights["cold.rs"]);
+        assert!(weights["hot.rs"] <= 1.0);
+        assert!(weights["cold.rs"] >= 0.0);
+    }
+
+    #[test]
+    fn test_recency_boost_reorders_equal_matches() {
+        let engine = create_test_engine();
+
+        // Two identical chunks in different files tie on every channel
+        for file in ["old.rs", "fresh.rs"] {
+            let chunk = CodeChunk {
+                id: format!("{}:0:process", file),
+                content: "fn process_data() { transform(); }".to_string(),
+                file_path: file.to_string(),
+                start_line: 1,
+                end_line: 1,
+                language: "rust".to_string(),
+                symbol_context: None,
+                chunk_type: ChunkType::Function,
+                doc_comment: None,
+                imports: Vec::new(),
+            };
+            engine.index_chunk(&chunk);
+        }
+
+        let mut weights = HashMap::new();
+        weights.insert("fresh.rs".to_string(), 1.0);
+        engine.set_file_recency(weights);
+
+        let results = engine.search("process data", 10);
+        assert!(results.len() >= 2);
+        assert_eq!(results[0].file_path, "fresh.rs");
+    }
+
+    /// Scores documents by how many query words they contain, so tests
+    /// can exercise the reranking stage without a model
+    struct WordOverlapReranker;
+
+    impl Reranker for WordOverlapReranker {
+        fn rerank(&self, query: &str, documents: &[String]) -> anyhow::Result<Vec<f32>> {
+            Ok(documents
+                .iter()
+                .map(|doc| {
+                    query
+                        .split_whitespace()
+                        .filter(|word| doc.contains(word))
+                        .count() as f32
+                })
+                .collect())
+        }
+    }
+
+    /// A reranker that always fails, for testing graceful degradation
+    struct FailingReranker;
+
+    impl Reranker for FailingReranker {
+        fn rerank(&self, _query: &str, _documents: &[String]) -> anyhow::Result<Vec<f32>> {
+            anyhow::bail!("model unavailable")
+        }
+    }
+
+    fn index_numbered_chunks(engine: &HybridSearchEngine) {
+        for i in 0..3 {
+            let chunk = CodeChunk {
+                id: format!("test.rs:{}:func{}", i, i),
+                content: format!("fn function_{}() {{ compute(); }}", i),
+                file_path: "test.rs".to_string(),
+                start_line: i + 1,
+                end_line: i + 1,
+                language: "rust".to_string(),
+                symbol_context: None,
+                chunk_type: ChunkType::Function,
+                doc_comment: None,
+                imports: Vec::new(),
+            };
+            engine.index_chunk(&chunk);
+        }
+    }
+
+    #[test]
+    fn test_fuzzy_match_abbreviations() {
+        // Abbreviated multi-token query hits camelCase boundaries
+        assert!(fuzzy_match("hsrch eng", "HybridSearchEngine").is_some());
+        assert!(fuzzy_match("fzy idx", "FuzzySymbolIndex").is_some());
+        assert!(fuzzy_match("zzz", "HybridSearchEngine").is_none());
+
+        // Word-boundary matches outrank scattered subsequence matches
+        let boundary = fuzzy_match("fb", "foo_bar").unwrap();
+        let scattered = fuzzy_match("fb", "ffffb").unwrap();
+        assert!(boundary > scattered);
+    }
+
+    #[test]
+    fn test_fuzzy_symbol_channel_finds_abbreviated_names() {
+        let engine = create_test_engine();
+
+        let chunk = CodeChunk {
+            id: "src/engine.rs:0:HybridSearchEngine".to_string(),
+            content: "pub struct HybridSearchEngine { config: Config }".to_string(),
+            file_path: "src/engine.rs".to_string(),
+            start_line: 10,
+            end_line: 20,
+            language: "rust".to_string(),
+            symbol_context: None,
+            chunk_type: ChunkType::Class,
+            doc_comment: None,
+            imports: Vec::new(),
+        };
+        engine.index_chunk(&chunk);
+        assert_eq!(engine.stats().fuzzy_symbols, 1);
+
+        // Neither BM25 nor TF-IDF can match this abbreviation
+        let results = engine.search("hsrch eng", 5);
+        assert!(!results.is_empty());
+        assert_eq!(
+            results[0].symbol_name.as_deref(),
+            Some("HybridSearchEngine")
+        );
+        assert!(results[0].fuzzy_rank.is_some());
+    }
+
+    #[test]
+    fn test_min_max_normalize() {
+        let normalized = min_max_normalize(&[2.0, 4.0, 6.0]);
+        assert_eq!(normalized, vec![0.0, 0.5, 1.0]);
+
+        // Uniform scores normalize to 1.0, empty input stays empty
+        assert_eq!(min_max_normalize(&[3.0, 3.0]), vec![1.0, 1.0]);
+        assert!(min_max_normalize(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_fusion_strategy_from_str() {
+        assert_eq!(
+            "rrf".parse::<FusionStrategy>().unwrap(),
+            FusionStrategy::Rrf
+        );
+        assert_eq!(
+            "weighted".parse::<FusionStrategy>().unwrap(),
+            FusionStrategy::WeightedSum
+        );
+        assert_eq!(
+            "combsum".parse::<FusionStrategy>().unwrap(),
+            FusionStrategy::CombSum
+        );
+        assert_eq!(
+            "combmnz".parse::<FusionStrategy>().unwrap(),
+            FusionStrategy::CombMnz
+        );
+        assert!("borda".parse::<FusionStrategy>().is_err());
+    }
+
+    #[test]
+    fn test_all_fusion_strategies_rank_results() {
+        for strategy in [
+            FusionStrategy::Rrf,
+            FusionStrategy::WeightedSum,
+            FusionStrategy::CombSum,
+            FusionStrategy::CombMnz,
+        ] {
+            let bm25_index = Arc::new(ConcurrentSearchIndex::new());
+            let tfidf_engine = Arc::new(EmbeddingEngine::new(100));
+            let config = HybridSearchConfigBuilder::new().fusion(strategy).build();
+            let engine = HybridSearchEngine::with_config(bm25_index, tfidf_engine, config);
+
+            index_numbered_chunks(&engine);
+
+            let results = engine.search("compute function", 3);
+            assert!(
+                !results.is_empty(),
+                "{:?} fusion should return results",
+                strategy
+            );
+            for i in 1..results.len() {
+                assert!(
+                    results[i - 1].score >= results[i].score,
+                    "{:?} results should be sorted by score",
+                    strategy
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_reranker_reorders_top_results() {
+        let bm25_index = Arc::new(ConcurrentSearchIndex::new());
+        let tfidf_engine = Arc::new(EmbeddingEngine::new(100));
+        let engine = HybridSearchEngine::new(bm25_index, tfidf_engine)
+            .with_reranker(Arc::new(WordOverlapReranker));
+
+        index_numbered_chunks(&engine);
+
+        // "function_2" only appears in one chunk; the word-overlap reranker
+        // should promote it to the top regardless of fused order
+        let results = engine.search("compute function_2", 3);
+        assert!(!results.is_empty());
+        assert!(results[0].content.contains("function_2"));
+        assert!(results[0].rerank_score.is_some());
+    }
+
+    #[test]
+    fn test_reranker_disabled_by_top_k_zero() {
+        let bm25_index = Arc::new(ConcurrentSearchIndex::new());
+        let tfidf_engine = Arc::new(EmbeddingEngine::new(100));
+        let config = HybridSearchConfigBuilder::new().rerank_top_k(0).build();
+        let engine = HybridSearchEngine::with_config(bm25_index, tfidf_engine, config)
+            .with_reranker(Arc::new(WordOverlapReranker));
+
+        index_numbered_chunks(&engine);
+
+        let results = engine.search("compute", 3);
+        assert!(!results.is_empty());
+        assert!(results.iter().all(|r| r.rerank_score.is_none()));
+    }
+
+    #[test]
+    fn test_reranker_failure_keeps_fused_order() {
+        let bm25_index = Arc::new(ConcurrentSearchIndex::new());
+        let tfidf_engine = Arc::new(EmbeddingEngine::new(100));
+        let engine = HybridSearchEngine::new(bm25_index, tfidf_engine)
+            .with_reranker(Arc::new(FailingReranker));
+
+        index_numbered_chunks(&engine);
+
+        let results = engine.search("compute", 3);
+        assert!(!results.is_empty());
+        assert!(results.iter().all(|r| r.rerank_score.is_none()));
+        for i in 1..results.len() {
+            assert!(results[i - 1].score >= results[i].score);
+        }
     }
 
     #[test]
@@ -664,12 +1597,17 @@ mod tests {
         let tfidf_engine = Arc::new(EmbeddingEngine::new(100));
 
         let config = HybridSearchConfig {
+            fusion: FusionStrategy::CombSum,
             rrf_k: 30.0,
             bm25_weight: 0.7,
             tfidf_weight: 0.3,
+            neural_weight: 0.5,
+            fuzzy_weight: 0.4,
             exact_match_boost: 1.5,
             function_boost: 1.2,
             candidate_multiplier: 2,
+            rerank_top_k: 10,
+            recency_boost: 0.0,
         };
 
         let engine = HybridSearchEngine::with_config(bm25_index, tfidf_engine, config);
@@ -836,6 +836,50 @@ impl ImportGraph {
         rec_stack.remove(node);
     }
 
+    /// Compute a minimal set of edges whose removal breaks every given cycle
+    ///
+    /// Greedy hitting set: repeatedly cut the edge shared by the most
+    /// still-unbroken cycles. Greedy is not guaranteed optimal in general,
+    /// but edges shared across cycles are exactly the ones worth cutting,
+    /// so in practice the result is minimal or very close.
+    pub fn minimal_break_edges(&self, cycles: &[Vec<PathBuf>]) -> Vec<(PathBuf, PathBuf)> {
+        // Each cycle as its edge set (consecutive pairs, wrapping around)
+        let mut cycle_edges: Vec<Vec<(PathBuf, PathBuf)>> = cycles
+            .iter()
+            .filter(|c| !c.is_empty())
+            .map(|cycle| {
+                (0..cycle.len())
+                    .map(|i| (cycle[i].clone(), cycle[(i + 1) % cycle.len()].clone()))
+                    .collect()
+            })
+            .collect();
+
+        let mut cut_set = Vec::new();
+        while !cycle_edges.is_empty() {
+            // Count how many unbroken cycles each edge appears in
+            let mut counts: HashMap<&(PathBuf, PathBuf), usize> = HashMap::new();
+            for edges in &cycle_edges {
+                for edge in edges {
+                    *counts.entry(edge).or_insert(0) += 1;
+                }
+            }
+
+            // Pick the most shared edge (ties broken by path for determinism)
+            let best = counts
+                .into_iter()
+                .max_by(|(a, ca), (b, cb)| ca.cmp(cb).then_with(|| b.cmp(a)))
+                .map(|(edge, _)| edge.clone());
+            let Some(best) = best else {
+                break;
+            };
+
+            cycle_edges.retain(|edges| !edges.contains(&best));
+            cut_set.push(best);
+        }
+
+        cut_set
+    }
+
     /// Calculate depth of a file in import hierarchy
     pub fn depth(&self, file: &Path) -> usize {
         let mut visited = HashSet::new();
@@ -1246,6 +1290,75 @@ mod tests {
         assert!(!cycles.is_empty());
     }
 
+    #[test]
+    fn test_minimal_break_edges_single_cycle() {
+        let mut graph = ImportGraph::new();
+
+        graph.add_edge(
+            PathBuf::from("a.ts"),
+            PathBuf::from("b.ts"),
+            "./b".to_string(),
+        );
+        graph.add_edge(
+            PathBuf::from("b.ts"),
+            PathBuf::from("a.ts"),
+            "./a".to_string(),
+        );
+
+        let cycles = graph.find_cycles();
+        let cuts = graph.minimal_break_edges(&cycles);
+        // One cut is enough to break a 2-cycle
+        assert_eq!(cuts.len(), 1);
+    }
+
+    #[test]
+    fn test_minimal_break_edges_prefers_shared_edge() {
+        let mut graph = ImportGraph::new();
+
+        // Two cycles sharing the edge b -> a:
+        //   a -> b -> a  and  a -> c -> b -> a
+        graph.add_edge(
+            PathBuf::from("a.ts"),
+            PathBuf::from("b.ts"),
+            "./b".to_string(),
+        );
+        graph.add_edge(
+            PathBuf::from("b.ts"),
+            PathBuf::from("a.ts"),
+            "./a".to_string(),
+        );
+        graph.add_edge(
+            PathBuf::from("a.ts"),
+            PathBuf::from("c.ts"),
+            "./c".to_string(),
+        );
+        graph.add_edge(
+            PathBuf::from("c.ts"),
+            PathBuf::from("b.ts"),
+            "./b".to_string(),
+        );
+
+        let cycles = vec![
+            vec![PathBuf::from("a.ts"), PathBuf::from("b.ts")],
+            vec![
+                PathBuf::from("a.ts"),
+                PathBuf::from("c.ts"),
+                PathBuf::from("b.ts"),
+            ],
+        ];
+
+        let cuts = graph.minimal_break_edges(&cycles);
+        // The shared edge b -> a breaks both cycles at once
+        assert_eq!(cuts.len(), 1);
+        assert_eq!(cuts[0], (PathBuf::from("b.ts"), PathBuf::from("a.ts")));
+    }
+
+    #[test]
+    fn test_minimal_break_edges_no_cycles() {
+        let graph = ImportGraph::new();
+        assert!(graph.minimal_break_edges(&[]).is_empty());
+    }
+
     #[test]
     fn test_import_graph_depth() {
         let mut graph = ImportGraph::new();
@@ -9,12 +9,12 @@ use anyhow::{anyhow, Context, Result};
 use dashmap::DashMap;
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::SystemTime;
-use tracing::{info, warn};
+use tracing::{debug, info, warn};
 
 use crate::callgraph::CallGraph;
 use crate::cfg;
@@ -27,6 +27,7 @@ use crate::neural::{NeuralConfig, NeuralEngine};
 use crate::parser::LanguageParser;
 use crate::persist::{IndexStore, PersistedIndex};
 use crate::remote::RemoteRepoManager;
+use crate::repo::SymlinkPolicy;
 use crate::search::ConcurrentSearchIndex;
 use crate::streaming::StreamingConfig;
 use crate::symbols::{Symbol, SymbolKind};
@@ -50,6 +51,13 @@ pub struct LanguageStats {
     pub byte_count: usize,
 }
 
+/// Files excluded from the index by content heuristics (per repo)
+#[derive(Debug, Clone, Copy, Default)]
+struct SkippedFileStats {
+    binary: usize,
+    minified: usize,
+}
+
 /// A code excerpt with context
 #[derive(Debug, Clone, Serialize)]
 pub struct CodeExcerpt {
@@ -61,6 +69,60 @@ pub struct CodeExcerpt {
     pub relevance_score: f32,
 }
 
+/// Common path filters shared by the search tools
+///
+/// Built from the `exclude_tests`/`only_tests`/`exclude_generated`/
+/// `path_glob` tool arguments; all filters default to off.
+#[derive(Debug, Clone, Default)]
+pub struct SearchFilters {
+    /// Skip test files (language-aware detection)
+    pub exclude_tests: bool,
+    /// Only include test files
+    pub only_tests: bool,
+    /// Skip generated files (protobuf output, minified bundles, lockfiles)
+    pub exclude_generated: bool,
+    /// Only include paths matching this glob
+    pub path_glob: Option<glob::Pattern>,
+}
+
+impl SearchFilters {
+    /// Build filters from raw tool arguments; an invalid glob is ignored
+    pub fn from_args(
+        exclude_tests: Option<bool>,
+        only_tests: Option<bool>,
+        exclude_generated: Option<bool>,
+        path_glob: Option<&str>,
+    ) -> Self {
+        Self {
+            exclude_tests: exclude_tests.unwrap_or(false),
+            only_tests: only_tests.unwrap_or(false),
+            exclude_generated: exclude_generated.unwrap_or(false),
+            path_glob: path_glob.and_then(|p| glob::Pattern::new(p).ok()),
+        }
+    }
+
+    /// Check a repo-relative path against all active filters
+    pub fn matches(&self, path: &str) -> bool {
+        use crate::security_rules::{is_generated_file, is_test_file};
+
+        if self.exclude_tests && is_test_file(path) {
+            return false;
+        }
+        if self.only_tests && !is_test_file(path) {
+            return false;
+        }
+        if self.exclude_generated && is_generated_file(path) {
+            return false;
+        }
+        if let Some(ref glob) = self.path_glob {
+            if !glob.matches(path) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
 /// Options for configuring the CodeIntelEngine
 #[derive(Debug, Clone, Default)]
 pub struct EngineOptions {
@@ -72,12 +134,39 @@ pub struct EngineOptions {
     pub persist_enabled: bool,
     /// Enable file watching for incremental updates
     pub watch_enabled: bool,
+    /// Enable write tools (apply_patch). Disabled by default.
+    pub write_enabled: bool,
+    /// Defer repository indexing until a tool first touches the repo
+    /// (remaining repos fill in as a background sweep)
+    pub lazy_enabled: bool,
+    /// Global ignore patterns (gitignore syntax) applied in every repo,
+    /// in addition to per-repo `.narsilignore` files
+    pub global_ignores: Vec<String>,
+    /// Maximum bytes of a file parsed for symbols; larger files are parsed
+    /// up to this limit but remain fully text-searchable (0 = unlimited)
+    pub max_parse_bytes: usize,
+    /// How symbolic links are treated during indexing: skipped entirely,
+    /// always followed, or followed only when the target stays in the repo
+    pub symlink_policy: SymlinkPolicy,
     /// Streaming configuration
     pub streaming_config: StreamingConfig,
     /// LSP configuration
     pub lsp_config: LspConfig,
     /// Neural embedding configuration
     pub neural_config: NeuralConfig,
+    /// Chunking configuration used by the search and embedding tools
+    pub chunker_config: crate::chunking::ChunkerConfig,
+    /// Architecture layering rules from config, checked by check_architecture
+    pub architecture: crate::config::ArchitectureConfig,
+}
+
+/// Structured results of a CI security scan (the `scan` subcommand)
+#[derive(Debug)]
+pub struct CiScanReport {
+    /// Security rule findings, sorted by severity (highest first)
+    pub security: Vec<crate::security_rules::SecurityFinding>,
+    /// Dependencies with known vulnerabilities
+    pub dependencies: Vec<crate::supply_chain::DependencyVuln>,
 }
 
 /// The main code intelligence engine
@@ -91,14 +180,26 @@ pub struct CodeIntelEngine {
     repos: DashMap<String, RepoMetadata>,
     /// Symbol index: repo -> symbols
     symbols: DashMap<String, Vec<Symbol>>,
+    /// Repos deferred by lazy mode, indexed on first tool touch
+    pending_lazy: DashMap<String, PathBuf>,
+    /// Files skipped by binary/minified heuristics, per repo
+    skipped_files: DashMap<String, SkippedFileStats>,
+    /// Relative paths of files whose symbol parse was truncated by
+    /// `max_parse_bytes`, per repo
+    truncated_files: DashMap<String, HashSet<String>>,
     /// File content cache (path -> content)
     file_cache: DashMap<PathBuf, Arc<String>>,
     /// Language parser
     parser: Arc<LanguageParser>,
     /// Git repository handles (when git is enabled)
     git_repos: DashMap<String, GitRepo>,
+    /// Last seen HEAD commit per repo, used by watch mode to diff-and-refresh
+    /// after branch switches
+    git_heads: DashMap<String, String>,
     /// Call graphs per repository (when call_graph is enabled)
     call_graphs: DashMap<String, CallGraph>,
+    /// Ingested coverage reports per repository (via load_coverage)
+    coverage: DashMap<String, crate::coverage::CoverageData>,
     /// Semantic search index
     search_index: Arc<ConcurrentSearchIndex>,
     /// Embedding engine for semantic similarity (TF-IDF)
@@ -117,6 +218,8 @@ pub struct CodeIntelEngine {
     remote_manager: Option<Arc<tokio::sync::Mutex<RemoteRepoManager>>>,
     /// Cached security rules engine (avoids reloading rules on each scan)
     security_engine: Arc<crate::security_rules::SecurityRulesEngine>,
+    /// Event bus for live index updates (WebSocket push, etc.)
+    events: crate::events::EventBus,
     /// Tracks whether background initialization has completed
     initialization_complete: AtomicBool,
     /// Number of repositories that have been fully indexed
@@ -174,7 +277,13 @@ impl CodeIntelEngine {
         };
         // Initialize neural engine if enabled
         let neural_engine = if options.neural_config.enabled {
-            match NeuralEngine::new(options.neural_config.clone()) {
+            let mut neural_config = options.neural_config.clone();
+            // Default the embedding cache next to the persisted indices so
+            // restarts and re-indexing reuse embeddings for unchanged code
+            if neural_config.cache_dir.is_none() {
+                neural_config.cache_dir = Some(expanded_index.to_string_lossy().into_owned());
+            }
+            match NeuralEngine::new(neural_config) {
                 Ok(engine) => {
                     info!(
                         "Neural embedding engine initialized (backend={}, model={:?})",
@@ -204,10 +313,15 @@ impl CodeIntelEngine {
             repo_paths: expanded_repos.clone(),
             repos: DashMap::new(),
             symbols: DashMap::new(),
+            pending_lazy: DashMap::new(),
+            skipped_files: DashMap::new(),
+            truncated_files: DashMap::new(),
             file_cache: DashMap::new(),
             parser: Arc::new(LanguageParser::new()?),
             git_repos: DashMap::new(),
+            git_heads: DashMap::new(),
             call_graphs: DashMap::new(),
+            coverage: DashMap::new(),
             search_index: Arc::new(ConcurrentSearchIndex::new()),
             embedding_engine: Arc::new(EmbeddingEngine::new(1000)), // 1000-dim TF-IDF vectors
             neural_engine,
@@ -217,6 +331,7 @@ impl CodeIntelEngine {
             lsp_manager,
             remote_manager: None,
             security_engine,
+            events: crate::events::EventBus::new(),
             initialization_complete: AtomicBool::new(false),
             indexed_repos_count: AtomicUsize::new(0),
             total_repos_count: AtomicUsize::new(total_repos),
@@ -352,6 +467,15 @@ impl CodeIntelEngine {
             }
 
             if repo_path.exists() {
+                if self.options.lazy_enabled {
+                    info!(
+                        "Lazy mode: deferring index of {:?} until first use",
+                        repo_path
+                    );
+                    self.pending_lazy.insert(repo_name, repo_path.clone());
+                    continue;
+                }
+
                 info!("Indexing repository: {:?}", repo_path);
                 if let Err(e) = self.index_repo(repo_path).await {
                     warn!("Failed to index {:?}: {}", repo_path, e);
@@ -386,9 +510,35 @@ impl CodeIntelEngine {
             }
         }
 
+        // In lazy mode the server is ready as soon as repos are registered;
+        // indexing happens on first touch and via the sweep below
         self.initialization_complete.store(true, Ordering::Release);
         info!("Background initialization complete");
 
+        // Lazy mode background sweep: fill in deferred repos after startup,
+        // yielding between repos so first-touch requests can jump the queue
+        if self.options.lazy_enabled {
+            let pending: Vec<String> = self.pending_lazy.iter().map(|e| e.key().clone()).collect();
+            for repo_name in pending {
+                if let Err(e) = self.ensure_repo_indexed(&repo_name).await {
+                    warn!("Background lazy indexing of {} failed: {}", repo_name, e);
+                }
+                tokio::task::yield_now().await;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Index a lazily-deferred repository on first use. No-op when the repo
+    /// is already indexed (or was never deferred), so it is cheap to call on
+    /// every tool dispatch.
+    pub async fn ensure_repo_indexed(&self, repo: &str) -> Result<()> {
+        if let Some((repo_name, repo_path)) = self.pending_lazy.remove(repo) {
+            info!("Lazy-indexing repository {} on first use", repo_name);
+            self.index_repo(&repo_path).await?;
+            self.indexed_repos_count.fetch_add(1, Ordering::Release);
+        }
         Ok(())
     }
 
@@ -442,6 +592,39 @@ impl CodeIntelEngine {
         Ok(())
     }
 
+    /// Number of files parsed per batch during initial indexing. Bounds the
+    /// transient memory (file contents + syntax trees) held at once, so huge
+    /// monorepos index without OOM while still saturating the rayon pool.
+    const INDEX_BATCH_SIZE: usize = 256;
+
+    /// Build a matcher from the repo's root `.narsilignore` file plus the
+    /// global ignore list from config. Returns `None` when neither has any
+    /// patterns, so callers can skip matching entirely.
+    fn narsil_ignore_matcher(&self, repo_root: &Path) -> Option<ignore::gitignore::Gitignore> {
+        let ignore_file = repo_root.join(".narsilignore");
+        if !ignore_file.exists() && self.options.global_ignores.is_empty() {
+            return None;
+        }
+
+        let mut builder = ignore::gitignore::GitignoreBuilder::new(repo_root);
+        if ignore_file.exists() {
+            builder.add(&ignore_file);
+        }
+        for pattern in &self.options.global_ignores {
+            if let Err(e) = builder.add_line(None, pattern) {
+                warn!("Invalid global ignore pattern {:?}: {}", pattern, e);
+            }
+        }
+
+        match builder.build() {
+            Ok(matcher) => Some(matcher),
+            Err(e) => {
+                warn!("Failed to build ignore matcher: {}", e);
+                None
+            }
+        }
+    }
+
     async fn index_repo(&self, path: &Path) -> Result<()> {
         let start_time = std::time::Instant::now();
         let repo_name = path
@@ -451,101 +634,240 @@ impl CodeIntelEngine {
             .to_string();
 
         let mut languages: HashMap<String, LanguageStats> = HashMap::new();
-        let mut symbols_vec: Vec<Symbol> = Vec::new();
         let mut neural_docs: Vec<crate::neural::NeuralDocument> = Vec::new();
         let mut file_count = 0;
         let mut total_lines = 0;
+        let mut symbol_count = 0;
 
-        // Use ignore crate to respect .gitignore
-        let walker = ignore::WalkBuilder::new(path)
+        // Use ignore crate to respect .gitignore and per-repo .narsilignore
+        let follow_links = self.options.symlink_policy != SymlinkPolicy::Skip;
+        let mut walk_builder = ignore::WalkBuilder::new(path);
+        walk_builder
             .hidden(true)
             .git_ignore(true)
             .git_global(true)
             .git_exclude(true)
-            .build();
+            .follow_links(follow_links)
+            .add_custom_ignore_filename(".narsilignore");
+
+        // follow-within-repo: prune symlinks whose target escapes the repo
+        // root before the walker descends into them
+        if self.options.symlink_policy == SymlinkPolicy::FollowWithinRepo {
+            let repo_root = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+            walk_builder.filter_entry(move |entry| {
+                !entry.path_is_symlink()
+                    || entry
+                        .path()
+                        .canonicalize()
+                        .map(|target| target.starts_with(&repo_root))
+                        .unwrap_or(false)
+            });
+        }
 
-        let files: Vec<PathBuf> = walker
+        let mut files: Vec<PathBuf> = walk_builder
+            .build()
             .filter_map(|e| e.ok())
             .filter(|e| e.file_type().map(|ft| ft.is_file()).unwrap_or(false))
             .map(|e| e.path().to_path_buf())
             .collect();
 
-        // Parse files in parallel
+        // When links are followed, the same file can be reachable through
+        // both its real path and a symlink (the walker already refuses to
+        // descend into directory cycles); keep one path per canonical file
+        if follow_links {
+            let mut seen: HashSet<PathBuf> = HashSet::new();
+            files.retain(|f| match f.canonicalize() {
+                Ok(canonical) => seen.insert(canonical),
+                Err(_) => true,
+            });
+        }
+
+        // Drop files excluded by the global ignore list from config
+        if let Some(matcher) = self.narsil_ignore_matcher(path) {
+            files.retain(|f| !matcher.matched_path_or_any_parents(f, false).is_ignore());
+        }
+
+        // Index the most relevant files first (git working-tree changes,
+        // then most recently modified) so queries issued while indexing is
+        // still in flight get useful results within the first few batches
+        prioritize_index_order(path, &mut files);
+
+        // Start with a clean, visible symbol list for this repo; each batch
+        // appends to it as soon as it is parsed
+        self.symbols.insert(repo_name.clone(), Vec::new());
+
+        // Parse and flush in bounded batches: only one batch's contents and
+        // syntax trees are held transiently, so 500k-file monorepos index
+        // without the parse results for the whole tree sitting in memory.
         let metrics = Arc::clone(&self.metrics);
-        let parsed_results: Vec<_> = files
-            .par_iter()
-            .filter_map(|file_path| {
-                let parse_start = std::time::Instant::now();
-                let content = std::fs::read_to_string(file_path).ok()?;
-                let parsed = self.parser.parse_file(file_path, &content).ok()?;
-                metrics.record_file_parse(parse_start.elapsed());
-                Some((file_path.clone(), content, parsed))
-            })
-            .collect();
 
-        // Collect parsed trees for call graph construction
-        let mut trees_for_callgraph: Vec<(String, String, tree_sitter::Tree)> = Vec::new();
+        // Files that produced trees; re-parsed per batch for the call
+        // graph's second pass instead of keeping every tree alive
+        let mut callgraph_files: Vec<(PathBuf, String)> = Vec::new();
+
+        let binary_skipped = AtomicUsize::new(0);
+        let minified_skipped = AtomicUsize::new(0);
+        let mut truncated_paths: HashSet<String> = HashSet::new();
+
+        for file_batch in files.chunks(Self::INDEX_BATCH_SIZE) {
+            let parsed_results: Vec<_> = file_batch
+                .par_iter()
+                .filter_map(|file_path| {
+                    let parse_start = std::time::Instant::now();
+                    let content = std::fs::read_to_string(file_path).ok()?;
+                    if let Some(kind) = detect_unindexable(file_path, &content) {
+                        debug!("Skipping {} file: {:?}", kind, file_path);
+                        match kind {
+                            "binary" => binary_skipped.fetch_add(1, Ordering::Relaxed),
+                            _ => minified_skipped.fetch_add(1, Ordering::Relaxed),
+                        };
+                        return None;
+                    }
+                    // Documentation files have no tree-sitter grammar; give
+                    // them an empty parse so they still reach the file cache
+                    // and the heading-aware search index below
+                    let path_str = file_path.to_string_lossy();
+                    if crate::search::is_documentation_file(&path_str) {
+                        let parsed = crate::parser::ParsedFile {
+                            path: path_str.to_string(),
+                            language: get_language_id(&path_str).to_string(),
+                            symbols: Vec::new(),
+                            tree: None,
+                        };
+                        return Some((file_path.clone(), content, parsed, false));
+                    }
+                    // Huge files: parse only the head for symbols; the full
+                    // content still goes into the text-search index below
+                    let to_parse = truncate_for_parse(&content, self.options.max_parse_bytes);
+                    let truncated = to_parse.len() < content.len();
+                    let parsed = self.parser.parse_file(file_path, to_parse).ok()?;
+                    metrics.record_file_parse(parse_start.elapsed());
+                    Some((file_path.clone(), content, parsed, truncated))
+                })
+                .collect();
+
+            // Trees from this batch only (dropped after the batch flush)
+            let mut trees_for_callgraph: Vec<(String, String, tree_sitter::Tree)> = Vec::new();
+            let mut batch_symbols: Vec<Symbol> = Vec::new();
+
+            for (file_path, content, parsed, truncated) in parsed_results {
+                file_count += 1;
+                let lines = content.lines().count();
+                total_lines += lines;
+
+                // Update language stats
+                let lang_stats = languages.entry(parsed.language.clone()).or_default();
+                lang_stats.file_count += 1;
+                lang_stats.line_count += lines;
+                lang_stats.byte_count += content.len();
+
+                // Collect symbols with file path and index for embeddings
+                let relative_path = file_path
+                    .strip_prefix(path)
+                    .unwrap_or(&file_path)
+                    .to_string_lossy()
+                    .to_string();
 
-        for (file_path, content, parsed) in parsed_results {
-            file_count += 1;
-            let lines = content.lines().count();
-            total_lines += lines;
-
-            // Update language stats
-            let lang_stats = languages.entry(parsed.language.clone()).or_default();
-            lang_stats.file_count += 1;
-            lang_stats.line_count += lines;
-            lang_stats.byte_count += content.len();
-
-            // Collect symbols with file path and index for embeddings
-            let relative_path = file_path
-                .strip_prefix(path)
-                .unwrap_or(&file_path)
-                .to_string_lossy()
-                .to_string();
+                if truncated {
+                    truncated_paths.insert(relative_path.clone());
+                }
 
-            for mut symbol in parsed.symbols {
-                symbol.file_path = relative_path.clone();
+                for mut symbol in parsed.symbols {
+                    symbol.file_path = relative_path.clone();
+
+                    // Index symbol into embedding engine for similarity search
+                    if let Some(ref sig) = symbol.signature {
+                        let symbol_id = format!("{}::{}", relative_path, symbol.name);
+                        self.embedding_engine.index_snippet(
+                            symbol_id.clone(),
+                            relative_path.clone(),
+                            sig.clone(),
+                            symbol.start_line,
+                            symbol.end_line,
+                        );
 
-                // Index symbol into embedding engine for similarity search
-                if let Some(ref sig) = symbol.signature {
-                    let symbol_id = format!("{}::{}", relative_path, symbol.name);
-                    self.embedding_engine.index_snippet(
-                        symbol_id.clone(),
-                        relative_path.clone(),
-                        sig.clone(),
-                        symbol.start_line,
-                        symbol.end_line,
-                    );
+                        // Collect for neural batch indexing if enabled
+                        if self.neural_engine.is_some() {
+                            neural_docs.push(crate::neural::NeuralDocument {
+                                id: symbol_id,
+                                file_path: relative_path.clone(),
+                                content: sig.clone(),
+                                start_line: symbol.start_line,
+                                end_line: symbol.end_line,
+                                symbol_name: Some(symbol.name.clone()),
+                            });
+                        }
+                    }
 
-                    // Collect for neural batch indexing if enabled
-                    if self.neural_engine.is_some() {
-                        neural_docs.push(crate::neural::NeuralDocument {
-                            id: symbol_id,
-                            file_path: relative_path.clone(),
-                            content: sig.clone(),
-                            start_line: symbol.start_line,
-                            end_line: symbol.end_line,
-                            symbol_name: Some(symbol.name.clone()),
-                        });
+                    batch_symbols.push(symbol);
+                }
+
+                // Cache file content
+                self.file_cache
+                    .insert(file_path.clone(), Arc::new(content.clone()));
+
+                // Index file for semantic search; documentation files are
+                // split into heading-delimited sections instead
+                if crate::search::is_documentation_file(&relative_path) {
+                    self.search_index
+                        .index_documentation(&relative_path, &content);
+                } else {
+                    self.search_index.index_file(&relative_path, &content);
+                }
+
+                // Collect tree for call graph if enabled and tree exists
+                if self.options.call_graph_enabled {
+                    if let Some(tree) = parsed.tree {
+                        callgraph_files.push((file_path, relative_path.clone()));
+                        trees_for_callgraph.push((relative_path, content, tree));
                     }
                 }
+            }
 
-                symbols_vec.push(symbol);
+            // Flush this batch's symbols immediately so tools see them
+            // before the rest of the repo finishes indexing
+            symbol_count += batch_symbols.len();
+            if let Some(mut entry) = self.symbols.get_mut(&repo_name) {
+                entry.extend(batch_symbols);
             }
 
-            // Cache file content
-            self.file_cache
-                .insert(file_path.clone(), Arc::new(content.clone()));
+            // Register this batch's function definitions now; call edges are
+            // resolved in a second pass once every definition is known
+            if !trees_for_callgraph.is_empty() {
+                if let Some(call_graph) = self.call_graphs.get(&repo_name) {
+                    if let Err(e) = call_graph.add_definitions(&trees_for_callgraph) {
+                        warn!("Failed to extract call graph functions: {}", e);
+                    }
+                }
+            }
+        }
 
-            // Index file for semantic search
-            self.search_index.index_file(&relative_path, &content);
+        let skipped = SkippedFileStats {
+            binary: binary_skipped.load(Ordering::Relaxed),
+            minified: minified_skipped.load(Ordering::Relaxed),
+        };
+        if skipped.binary + skipped.minified > 0 {
+            info!(
+                "Skipped {} binary and {} minified file(s) in {}",
+                skipped.binary, skipped.minified, repo_name
+            );
+        }
+        self.skipped_files.insert(repo_name.clone(), skipped);
+        if !truncated_paths.is_empty() {
+            info!(
+                "Truncated symbol parse for {} large file(s) in {} (limit: {} bytes)",
+                truncated_paths.len(),
+                repo_name,
+                self.options.max_parse_bytes
+            );
+        }
+        self.truncated_files
+            .insert(repo_name.clone(), truncated_paths);
 
-            // Collect tree for call graph if enabled and tree exists
-            if self.options.call_graph_enabled {
-                if let Some(tree) = parsed.tree {
-                    trees_for_callgraph.push((relative_path, content, tree));
-                }
+        // Record HEAD so watch mode can diff against it on branch switches
+        if self.options.watch_enabled {
+            if let Ok(head) = GitRepo::new(path).and_then(|git| git.head_commit()) {
+                self.git_heads.insert(repo_name.clone(), head);
             }
         }
 
@@ -560,9 +882,7 @@ impl CodeIntelEngine {
 
         info!(
             "Indexed {} files, {} symbols in {}",
-            file_count,
-            symbols_vec.len(),
-            repo_name
+            file_count, symbol_count, repo_name
         );
 
         // Batch index neural embeddings if enabled
@@ -585,23 +905,43 @@ impl CodeIntelEngine {
         // Record indexing metrics
         let elapsed = start_time.elapsed();
         self.metrics
-            .record_repo_index(repo_name.clone(), elapsed, file_count, symbols_vec.len());
+            .record_repo_index(repo_name.clone(), elapsed, file_count, symbol_count);
+
+        // Notify live subscribers (e.g. WebSocket clients)
+        self.events.publish(crate::events::EngineEvent::Reindexed {
+            repo: repo_name.clone(),
+            files: file_count,
+        });
 
         self.repos.insert(repo_name.clone(), metadata);
-        self.symbols.insert(repo_name.clone(), symbols_vec);
 
-        // Build call graph if enabled
-        if self.options.call_graph_enabled && !trees_for_callgraph.is_empty() {
+        // Second call graph pass: record call edges now that every function
+        // definition is registered. Files are re-parsed per batch (contents
+        // come from the cache) rather than keeping all trees in memory.
+        if self.options.call_graph_enabled && !callgraph_files.is_empty() {
             if let Some(call_graph) = self.call_graphs.get(&repo_name) {
-                if let Err(e) = call_graph.build_from_files(&trees_for_callgraph) {
-                    warn!("Failed to build call graph for {}: {}", repo_name, e);
-                } else {
-                    info!(
-                        "Built call graph for {} with {} files",
-                        repo_name,
-                        trees_for_callgraph.len()
-                    );
+                for file_batch in callgraph_files.chunks(Self::INDEX_BATCH_SIZE) {
+                    let batch_trees: Vec<(String, String, tree_sitter::Tree)> = file_batch
+                        .par_iter()
+                        .filter_map(|(file_path, relative_path)| {
+                            let content = self.file_cache.get(file_path).map(|c| Arc::clone(&c))?;
+                            let to_parse =
+                                truncate_for_parse(&content, self.options.max_parse_bytes);
+                            let parsed = self.parser.parse_file(file_path, to_parse).ok()?;
+                            let tree = parsed.tree?;
+                            Some((relative_path.clone(), content.as_ref().clone(), tree))
+                        })
+                        .collect();
+
+                    if let Err(e) = call_graph.add_calls(&batch_trees) {
+                        warn!("Failed to build call graph for {}: {}", repo_name, e);
+                    }
                 }
+                info!(
+                    "Built call graph for {} with {} files",
+                    repo_name,
+                    callgraph_files.len()
+                );
             }
         }
 
@@ -730,7 +1070,7 @@ impl CodeIntelEngine {
             output.push_str("- **Languages**:\n");
 
             let mut langs: Vec<_> = repo.languages.iter().collect();
-            langs.sort_by(|a, b| b.1.line_count.cmp(&a.1.line_count));
+            langs.sort_by_key(|l| std::cmp::Reverse(l.1.line_count));
 
             for (lang, stats) in langs {
                 output.push_str(&format!(
@@ -852,7 +1192,14 @@ impl CodeIntelEngine {
                 }
                 // Name pattern filter
                 if let Some(pat) = pattern {
-                    if !s.name.to_lowercase().contains(&pat.to_lowercase()) {
+                    let pat_lower = pat.to_lowercase();
+                    let name_matches = s.name.to_lowercase().contains(&pat_lower);
+                    // Qualified paths disambiguate common names like `new`
+                    let qualified_matches = s
+                        .qualified_name
+                        .as_ref()
+                        .is_some_and(|q| q.to_lowercase().contains(&pat_lower));
+                    if !name_matches && !qualified_matches {
                         return false;
                     }
                 }
@@ -881,7 +1228,7 @@ impl CodeIntelEngine {
             for sym in syms {
                 output.push_str(&format!(
                     "- **{}** (`{}:{}`) {}\n",
-                    sym.name,
+                    sym.qualified_name.as_deref().unwrap_or(&sym.name),
                     sym.file_path,
                     sym.start_line,
                     sym.signature.as_deref().unwrap_or("")
@@ -890,6 +1237,16 @@ impl CodeIntelEngine {
             output.push('\n');
         }
 
+        if let Some(truncated) = self.truncated_files.get(repo) {
+            if filtered.iter().any(|s| truncated.contains(&s.file_path)) {
+                output.push_str(
+                    "> Note: some matching files exceeded the parse limit \
+                     (--max-parse-bytes); symbols past the truncation point \
+                     are not indexed.\n",
+                );
+            }
+        }
+
         Ok(output)
     }
 
@@ -931,7 +1288,23 @@ impl CodeIntelEngine {
             "**Lines**: {}-{}\n",
             symbol.start_line, symbol.end_line
         ));
-        output.push_str(&format!("**Kind**: {:?}\n\n", symbol.kind));
+        output.push_str(&format!("**Kind**: {:?}\n", symbol.kind));
+
+        // Coverage annotation (when a report has been ingested)
+        if let Some(coverage) = self.coverage.get(repo) {
+            if let Some((covered, instrumented)) = coverage
+                .file_coverage(&symbol.file_path)
+                .and_then(|f| f.range_coverage(symbol.start_line, symbol.end_line))
+            {
+                output.push_str(&format!(
+                    "**Coverage**: {:.1}% ({}/{} instrumented lines)\n",
+                    crate::coverage::percent(covered, instrumented),
+                    covered,
+                    instrumented
+                ));
+            }
+        }
+        output.push('\n');
 
         output.push_str("```");
         output.push_str(get_language_id(&symbol.file_path));
@@ -970,12 +1343,9 @@ impl CodeIntelEngine {
         query: &str,
         file_pattern: Option<&str>,
         max_results: usize,
-        exclude_tests: Option<bool>,
+        filters: SearchFilters,
     ) -> Result<String> {
-        use crate::security_rules::is_test_file;
-
         let query_lower = query.to_lowercase();
-        let exclude_tests = exclude_tests.unwrap_or(false); // Default false for search
         let mut results: Vec<CodeExcerpt> = Vec::new();
 
         let repos_to_search: Vec<String> = match repo {
@@ -1005,8 +1375,8 @@ impl CodeIntelEngine {
                     .unwrap_or(file_path)
                     .to_string_lossy();
 
-                // Skip test files if exclude_tests is enabled
-                if exclude_tests && is_test_file(&rel_path) {
+                // Apply the common path filters
+                if !filters.matches(&rel_path) {
                     continue;
                 }
 
@@ -1441,7 +1811,7 @@ impl CodeIntelEngine {
             }
 
             // Save the index
-            store.save(&persisted)?;
+            store.save(&mut persisted)?;
             saved_count += 1;
             info!(
                 "Saved index for {} ({} files)",
@@ -1588,6 +1958,33 @@ impl CodeIntelEngine {
                 None => continue,
             };
 
+            // A .git/HEAD write signals a branch switch (or reset/pull): one
+            // targeted diff replaces the flood of per-file watcher events,
+            // which backends are prone to dropping
+            if change.path.ends_with(".git/HEAD") {
+                let repo_name = repo_path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or("unknown")
+                    .to_string();
+                match self.refresh_after_head_change(&repo_name, repo_path).await {
+                    Ok(n) => count += n,
+                    Err(e) => warn!("Branch-switch refresh failed for {}: {}", repo_name, e),
+                }
+                continue;
+            }
+
+            // Respect .narsilignore and the global ignore list for watch
+            // events (the initial-index walker already filters these)
+            if let Some(matcher) = self.narsil_ignore_matcher(repo_path) {
+                if matcher
+                    .matched_path_or_any_parents(&change.path, false)
+                    .is_ignore()
+                {
+                    continue;
+                }
+            }
+
             let repo_name = repo_path
                 .file_name()
                 .and_then(|n| n.to_str())
@@ -1596,36 +1993,45 @@ impl CodeIntelEngine {
 
             match change.change_type {
                 ChangeType::Created | ChangeType::Modified => {
-                    // Re-index the changed file
-                    if let Ok(content) = std::fs::read_to_string(&change.path) {
-                        if let Ok(parsed) = self.parser.parse_file(&change.path, &content) {
-                            let rel_path = change
-                                .path
-                                .strip_prefix(repo_path)
-                                .unwrap_or(&change.path)
-                                .to_string_lossy()
-                                .to_string();
-
-                            // Update symbols for this file
-                            if let Some(mut symbols) = self.symbols.get_mut(&repo_name) {
-                                // Remove old symbols from this file
-                                symbols.retain(|s| s.file_path != rel_path);
-
-                                // Add new symbols
-                                for mut symbol in parsed.symbols {
-                                    symbol.file_path = rel_path.clone();
-                                    symbols.push(symbol);
-                                }
-                            }
-
-                            // Update file cache
-                            self.file_cache
-                                .insert(change.path.clone(), Arc::new(content.clone()));
-
-                            // Update search index
-                            self.search_index.index_file(&rel_path, &content);
-
-                            info!("Re-indexed file: {}", rel_path);
+                    let event = match change.change_type {
+                        ChangeType::Created => "created",
+                        _ => "modified",
+                    };
+                    if self
+                        .reindex_changed_file(&repo_name, repo_path, &change.path, event)
+                        .await
+                    {
+                        count += 1;
+                    }
+                }
+                ChangeType::Renamed { ref from } => {
+                    // Carry symbols and embeddings over under the new path
+                    // (handles whole-directory moves too) instead of
+                    // re-parsing and re-embedding the moved files
+                    let moved = self.carry_renamed_path(&repo_name, repo_path, from, &change.path);
+                    if moved > 0 {
+                        let rel_to = change
+                            .path
+                            .strip_prefix(repo_path)
+                            .unwrap_or(&change.path)
+                            .to_string_lossy()
+                            .to_string();
+                        self.events
+                            .publish(crate::events::EngineEvent::FileChanged {
+                                path: rel_to.clone(),
+                                change_type: "renamed".to_string(),
+                            });
+                        info!(
+                            "Carried {} indexed file(s) across rename to {}",
+                            moved, rel_to
+                        );
+                        count += 1;
+                    } else if change.path.is_file() {
+                        // The old path was never indexed; index fresh
+                        if self
+                            .reindex_changed_file(&repo_name, repo_path, &change.path, "created")
+                            .await
+                        {
                             count += 1;
                         }
                     }
@@ -1646,6 +2052,12 @@ impl CodeIntelEngine {
                     // Remove from file cache
                     self.file_cache.remove(&change.path);
 
+                    self.events
+                        .publish(crate::events::EngineEvent::FileChanged {
+                            path: rel_path.clone(),
+                            change_type: "deleted".to_string(),
+                        });
+
                     info!("Removed file from index: {}", rel_path);
                     count += 1;
                 }
@@ -1660,6 +2072,208 @@ impl CodeIntelEngine {
         Ok(count)
     }
 
+    /// Re-parse a single changed file and replace its index entries,
+    /// publishing the given event type. Returns true if the file was indexed.
+    async fn reindex_changed_file(
+        &self,
+        repo_name: &str,
+        repo_path: &Path,
+        file_path: &Path,
+        event: &str,
+    ) -> bool {
+        let content = match std::fs::read_to_string(file_path) {
+            Ok(c) => c,
+            Err(_) => return false,
+        };
+        if let Some(kind) = detect_unindexable(file_path, &content) {
+            debug!("Skipping {} file: {:?}", kind, file_path);
+            if let Some(mut skipped) = self.skipped_files.get_mut(repo_name) {
+                match kind {
+                    "binary" => skipped.binary += 1,
+                    _ => skipped.minified += 1,
+                }
+            }
+            return false;
+        }
+        let to_parse = truncate_for_parse(&content, self.options.max_parse_bytes);
+        let parsed = match self.parser.parse_file(file_path, to_parse) {
+            Ok(p) => p,
+            Err(_) => return false,
+        };
+
+        let rel_path = file_path
+            .strip_prefix(repo_path)
+            .unwrap_or(file_path)
+            .to_string_lossy()
+            .to_string();
+
+        let mut new_symbols = parsed.symbols;
+        for symbol in &mut new_symbols {
+            symbol.file_path = rel_path.clone();
+        }
+
+        // Refine symbol kinds with LSP semantic tokens
+        // when a language server is running
+        if let Some(ref lsp) = self.lsp_manager {
+            if lsp.is_enabled() {
+                let language = get_language_from_path(&rel_path);
+                if let Ok(Some(tokens)) = lsp.get_semantic_tokens(&language, file_path).await {
+                    let refined = crate::lsp::refine_symbol_kinds(&mut new_symbols, &tokens);
+                    if refined > 0 {
+                        debug!(
+                            "Refined {} symbol kind(s) in {} via semantic tokens",
+                            refined, rel_path
+                        );
+                    }
+                }
+            }
+        }
+
+        // Update symbols for this file
+        if let Some(mut symbols) = self.symbols.get_mut(repo_name) {
+            // Remove old symbols from this file
+            symbols.retain(|s| s.file_path != rel_path);
+            symbols.extend(new_symbols);
+        }
+
+        // Update file cache
+        self.file_cache
+            .insert(file_path.to_path_buf(), Arc::new(content.clone()));
+
+        // Update search index
+        self.search_index.index_file(&rel_path, &content);
+
+        self.events
+            .publish(crate::events::EngineEvent::FileChanged {
+                path: rel_path.clone(),
+                change_type: event.to_string(),
+            });
+
+        info!("Re-indexed file: {}", rel_path);
+        true
+    }
+
+    /// Re-path index entries for a renamed file or directory, carrying over
+    /// symbols, cached content and embeddings instead of re-parsing and
+    /// re-embedding. Returns the number of indexed files moved.
+    fn carry_renamed_path(
+        &self,
+        repo_name: &str,
+        repo_path: &Path,
+        from: &Path,
+        to: &Path,
+    ) -> usize {
+        let rel_from = from
+            .strip_prefix(repo_path)
+            .unwrap_or(from)
+            .to_string_lossy()
+            .to_string();
+        let rel_to = to
+            .strip_prefix(repo_path)
+            .unwrap_or(to)
+            .to_string_lossy()
+            .to_string();
+        let dir_prefix = format!("{}/", rel_from);
+
+        // Re-path symbols in place; a directory move re-paths every file below
+        let mut moved: Vec<(String, String)> = Vec::new();
+        if let Some(mut symbols) = self.symbols.get_mut(repo_name) {
+            for symbol in symbols.iter_mut() {
+                let new_rel = if symbol.file_path == rel_from {
+                    rel_to.clone()
+                } else if let Some(rest) = symbol.file_path.strip_prefix(&dir_prefix) {
+                    format!("{}/{}", rel_to, rest)
+                } else {
+                    continue;
+                };
+                moved.push((symbol.file_path.clone(), new_rel.clone()));
+                symbol.file_path = new_rel;
+            }
+        }
+        moved.sort();
+        moved.dedup();
+
+        for (old_rel, new_rel) in &moved {
+            // Carry embeddings over under the new path
+            self.embedding_engine.rename_file(old_rel, new_rel);
+            if let Some(ref neural) = self.neural_engine {
+                neural.rename_file(old_rel, new_rel);
+            }
+
+            // Move the cached content and re-point the text-search entry
+            let old_abs = repo_path.join(old_rel);
+            if let Some((_, content)) = self.file_cache.remove(&old_abs) {
+                self.search_index.index_file(new_rel, &content);
+                self.file_cache.insert(repo_path.join(new_rel), content);
+            }
+
+            // Keep the truncation report pointing at the new path
+            if let Some(mut truncated) = self.truncated_files.get_mut(repo_name) {
+                if truncated.remove(old_rel) {
+                    truncated.insert(new_rel.clone());
+                }
+            }
+        }
+
+        moved.len()
+    }
+
+    /// Diff the previously seen HEAD against the current one and re-index
+    /// exactly the files that changed between them. Returns the number of
+    /// files refreshed.
+    async fn refresh_after_head_change(&self, repo_name: &str, repo_path: &Path) -> Result<usize> {
+        let git = GitRepo::new(repo_path)?;
+        let new_head = git.head_commit()?;
+        let old_head = match self
+            .git_heads
+            .insert(repo_name.to_string(), new_head.clone())
+        {
+            Some(h) if h != new_head => h,
+            // First sighting, or HEAD rewritten to the same commit
+            _ => return Ok(0),
+        };
+
+        let changed = git.changed_files_between(&old_head, &new_head)?;
+        info!(
+            "HEAD moved in {} ({} -> {}): refreshing {} changed file(s)",
+            repo_name,
+            &old_head[..old_head.len().min(8)],
+            &new_head[..new_head.len().min(8)],
+            changed.len()
+        );
+
+        let matcher = self.narsil_ignore_matcher(repo_path);
+        let mut count = 0;
+        for (status, rel_path) in changed {
+            let abs_path = repo_path.join(&rel_path);
+            if let Some(ref m) = matcher {
+                if m.matched_path_or_any_parents(&abs_path, false).is_ignore() {
+                    continue;
+                }
+            }
+
+            if status == 'D' || !abs_path.exists() {
+                if let Some(mut symbols) = self.symbols.get_mut(repo_name) {
+                    symbols.retain(|s| s.file_path != rel_path);
+                }
+                self.file_cache.remove(&abs_path);
+                self.events
+                    .publish(crate::events::EngineEvent::FileChanged {
+                        path: rel_path.clone(),
+                        change_type: "deleted".to_string(),
+                    });
+                count += 1;
+            } else if self
+                .reindex_changed_file(repo_name, repo_path, &abs_path, "modified")
+                .await
+            {
+                count += 1;
+            }
+        }
+
+        Ok(count)
+    }
+
     // === Git Integration Methods ===
 
     /// Get git blame for a file
@@ -1838,35 +2452,131 @@ impl CodeIntelEngine {
         Ok(output)
     }
 
-    /// Get code hotspots (complex + frequently changed)
-    pub async fn get_hotspots(
+    /// Check recent commit messages against conventional-commit rules
+    pub async fn check_commit_conventions(
         &self,
         repo: &str,
-        days: u32,
-        _min_complexity: Option<usize>,
+        max_commits: usize,
+        types: Option<&str>,
     ) -> Result<String> {
         let git_repo = self
             .git_repos
             .get(repo)
             .ok_or_else(|| anyhow!("Git not available for {}. Enable with --git flag.", repo))?;
 
-        let freq = git_repo.change_frequency(days)?;
+        let commits = git_repo.recent_commits(max_commits)?;
+
+        let allowed_types: Vec<String> = match types {
+            Some(list) => list
+                .split(',')
+                .map(|t| t.trim().to_string())
+                .filter(|t| !t.is_empty())
+                .collect(),
+            None => crate::git::DEFAULT_COMMIT_TYPES
+                .iter()
+                .map(|t| t.to_string())
+                .collect(),
+        };
+
+        // Per-author (total commits, violations); violations keep commit context
+        let mut author_stats: HashMap<String, (usize, usize)> = HashMap::new();
+        let mut violations: Vec<(String, String, String, String)> = Vec::new();
+
+        for commit in &commits {
+            let entry = author_stats.entry(commit.author.clone()).or_insert((0, 0));
+            entry.0 += 1;
+            if let Some(reason) = crate::git::check_commit_subject(&commit.subject, &allowed_types)
+            {
+                entry.1 += 1;
+                violations.push((
+                    commit.short_hash.clone(),
+                    commit.author.clone(),
+                    commit.subject.clone(),
+                    reason,
+                ));
+            }
+        }
 
         let mut output = String::new();
-        output.push_str(&format!("# Code Hotspots (last {} days)\n\n", days));
-        output.push_str("Files with high change frequency (potential maintenance burden):\n\n");
+        output.push_str(&format!("# Commit Convention Check: {}\n\n", repo));
+        output.push_str(&format!(
+            "Checked {} commits against types: {}\n\n",
+            commits.len(),
+            allowed_types.join(", ")
+        ));
 
-        output.push_str("| File | Commits | Authors | Churn Score |\n");
-        output.push_str("|------|---------|---------|-------------|\n");
+        if commits.is_empty() {
+            output.push_str("*No commits found*\n");
+            return Ok(output);
+        }
 
-        for item in freq.iter().take(20) {
+        let compliant = commits.len() - violations.len();
+        output.push_str(&format!(
+            "**Compliance**: {}/{} commits ({:.0}%)\n\n",
+            compliant,
+            commits.len(),
+            compliant as f64 / commits.len() as f64 * 100.0
+        ));
+
+        output.push_str("## By Author\n\n");
+        output.push_str("| Author | Commits | Violations | Compliance |\n");
+        output.push_str("|--------|---------|------------|------------|\n");
+        let mut authors: Vec<_> = author_stats.iter().collect();
+        authors.sort_by(|a, b| b.1 .1.cmp(&a.1 .1).then_with(|| a.0.cmp(b.0)));
+        for (author, (total, bad)) in authors {
             output.push_str(&format!(
-                "| `{}` | {} | {} | {:.2} |\n",
-                item.file_path, item.total_commits, item.unique_authors, item.churn_score
+                "| {} | {} | {} | {:.0}% |\n",
+                author,
+                total,
+                bad,
+                (total - bad) as f64 / *total as f64 * 100.0
             ));
         }
 
-        Ok(output)
+        if violations.is_empty() {
+            output.push_str("\n*All commits follow the convention*\n");
+        } else {
+            output.push_str("\n## Violations\n\n");
+            for (hash, author, subject, reason) in &violations {
+                output.push_str(&format!(
+                    "- `{}` {} — \"{}\" ({})\n",
+                    hash, author, subject, reason
+                ));
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// Get code hotspots (complex + frequently changed)
+    pub async fn get_hotspots(
+        &self,
+        repo: &str,
+        days: u32,
+        _min_complexity: Option<usize>,
+    ) -> Result<String> {
+        let git_repo = self
+            .git_repos
+            .get(repo)
+            .ok_or_else(|| anyhow!("Git not available for {}. Enable with --git flag.", repo))?;
+
+        let freq = git_repo.change_frequency(days)?;
+
+        let mut output = String::new();
+        output.push_str(&format!("# Code Hotspots (last {} days)\n\n", days));
+        output.push_str("Files with high change frequency (potential maintenance burden):\n\n");
+
+        output.push_str("| File | Commits | Authors | Churn Score |\n");
+        output.push_str("|------|---------|---------|-------------|\n");
+
+        for item in freq.iter().take(20) {
+            output.push_str(&format!(
+                "| `{}` | {} | {} | {:.2} |\n",
+                item.file_path, item.total_commits, item.unique_authors, item.churn_score
+            ));
+        }
+
+        Ok(output)
     }
 
     /// Get contributors to a file or repository
@@ -1918,6 +2628,95 @@ impl CodeIntelEngine {
         Ok(output)
     }
 
+    /// Determine who owns a file or symbol, combining CODEOWNERS rules
+    /// with git contributor statistics
+    pub async fn get_owners(
+        &self,
+        repo: &str,
+        path: Option<&str>,
+        symbol: Option<&str>,
+    ) -> Result<String> {
+        let repo_path = self.get_repo_path(repo)?;
+
+        // Resolve the target file: explicit path, or the file defining a symbol
+        let file = match (path, symbol) {
+            (Some(p), _) => {
+                validate_path(&repo_path, p)?;
+                p.to_string()
+            }
+            (None, Some(name)) => {
+                let symbols = self
+                    .symbols
+                    .get(repo)
+                    .ok_or_else(|| self.repo_not_found_error(repo))?;
+                let sym = symbols
+                    .iter()
+                    .find(|s| s.name == name || s.qualified_name.as_deref() == Some(name))
+                    .ok_or_else(|| {
+                        anyhow!("Symbol '{}' not found in repository '{}'", name, repo)
+                    })?;
+                sym.file_path.clone()
+            }
+            (None, None) => {
+                return Err(anyhow!("Either 'path' or 'symbol' must be provided"));
+            }
+        };
+
+        let mut output = String::new();
+        output.push_str(&format!("# Ownership: `{}`\n\n", file));
+
+        // CODEOWNERS rules (checked in the locations GitHub recognizes)
+        let codeowners_locations = ["CODEOWNERS", ".github/CODEOWNERS", "docs/CODEOWNERS"];
+        let mut codeowners_found = false;
+        for location in &codeowners_locations {
+            let candidate = repo_path.join(location);
+            if !candidate.is_file() {
+                continue;
+            }
+            codeowners_found = true;
+            let content =
+                std::fs::read_to_string(&candidate).context("Failed to read CODEOWNERS")?;
+            let rules = crate::git::parse_codeowners(&content);
+            output.push_str(&format!("## CODEOWNERS (`{}`)\n\n", location));
+            match crate::git::match_owners(&rules, &file) {
+                Some(rule) => {
+                    output.push_str(&format!(
+                        "**Owners**: {}\n**Matched rule**: `{}`\n\n",
+                        rule.owners.join(", "),
+                        rule.pattern
+                    ));
+                }
+                None => {
+                    output.push_str("*No CODEOWNERS rule matches this file.*\n\n");
+                }
+            }
+            break;
+        }
+        if !codeowners_found {
+            output.push_str("*No CODEOWNERS file found in this repository.*\n\n");
+        }
+
+        // Contributor statistics (best-effort: works only with --git)
+        match self.git_repos.get(repo) {
+            Some(git_repo) => {
+                output.push_str("## Top Contributors (git history)\n\n");
+                let contributors = git_repo.file_contributors(&file)?;
+                if contributors.is_empty() {
+                    output.push_str("*No commit history found for this file.*\n");
+                } else {
+                    for (name, count) in contributors.iter().take(5) {
+                        output.push_str(&format!("- {} ({} commits)\n", name, count));
+                    }
+                }
+            }
+            None => {
+                output.push_str("*Contributor statistics unavailable (enable with --git flag).*\n");
+            }
+        }
+
+        Ok(output)
+    }
+
     // === Repository Discovery ===
 
     /// Discover repositories in a directory
@@ -2093,6 +2892,23 @@ impl CodeIntelEngine {
                     "- Symbols: {}\n",
                     self.symbols.get(&meta.name).map(|s| s.len()).unwrap_or(0)
                 ));
+                if let Some(skipped) = self.skipped_files.get(&meta.name) {
+                    if skipped.binary + skipped.minified > 0 {
+                        output.push_str(&format!(
+                            "- Skipped: {} binary, {} minified\n",
+                            skipped.binary, skipped.minified
+                        ));
+                    }
+                }
+                if let Some(truncated) = self.truncated_files.get(&meta.name) {
+                    if !truncated.is_empty() {
+                        output.push_str(&format!(
+                            "- Truncated: {} large file(s), symbols parsed up to {} bytes\n",
+                            truncated.len(),
+                            self.options.max_parse_bytes
+                        ));
+                    }
+                }
                 output.push_str(&format!(
                     "- Git: {}\n\n",
                     if self.git_repos.contains_key(&meta.name) {
@@ -2116,12 +2932,8 @@ impl CodeIntelEngine {
         query: &str,
         max_results: usize,
         _doc_type: Option<&str>,
-        exclude_tests: Option<bool>,
+        filters: SearchFilters,
     ) -> Result<String> {
-        use crate::security_rules::is_test_file;
-
-        let exclude_tests = exclude_tests.unwrap_or(false); // Default false for search
-
         // Validate repo if specified
         let repo_name = if let Some(r) = repo {
             if !r.is_empty() {
@@ -2139,7 +2951,7 @@ impl CodeIntelEngine {
             .search_index
             .search(query, max_results * 2) // Get more results to filter
             .into_iter()
-            .filter(|r| !exclude_tests || !is_test_file(&r.document.file_path))
+            .filter(|r| filters.matches(&r.document.file_path))
             .take(max_results)
             .collect();
 
@@ -2574,596 +3386,3430 @@ impl CodeIntelEngine {
         Ok(output)
     }
 
-    // === Excerpt Extraction ===
-
-    /// Get an intelligent code excerpt with context
-    pub async fn get_excerpt(
+    /// Export the call graph in a machine-readable format (GraphML,
+    /// Cytoscape JSON, or DOT) for external tools like Gephi or dashboards
+    pub async fn export_call_graph(
         &self,
         repo: &str,
-        path: &str,
-        match_lines: &[usize],
-        config: crate::extract::ExcerptConfig,
+        format: &str,
+        file: Option<&str>,
     ) -> Result<String> {
-        let repo_path = self.get_repo_path(repo)?;
-        let file_path = validate_path(&repo_path, path)?;
+        let call_graph = self.call_graphs.get(repo).ok_or_else(|| {
+            anyhow!(
+                "Call graph not available for {}. Enable with --call-graph flag.",
+                repo
+            )
+        })?;
 
-        let content = std::fs::read_to_string(&file_path).context("Failed to read file")?;
+        match format.to_lowercase().as_str() {
+            "graphml" => Ok(call_graph.to_graphml(file)),
+            "cytoscape" | "cytoscape-json" => Ok(call_graph.to_cytoscape_json(file)),
+            "dot" | "graphviz" => Ok(call_graph.to_dot(file)),
+            other => Err(anyhow!(
+                "Unknown export format '{}'. Supported: graphml, cytoscape, dot",
+                other
+            )),
+        }
+    }
 
-        let excerpts = crate::extract::extract_excerpts(&content, match_lines, &config);
-        let best = crate::extract::select_best_excerpt(&excerpts, 3);
+    /// Rank the most structurally important functions by PageRank over the
+    /// call graph - a good starting point for exploring an unfamiliar codebase
+    pub async fn get_key_functions(&self, repo: &str, limit: usize) -> Result<String> {
+        let call_graph = self.call_graphs.get(repo).ok_or_else(|| {
+            anyhow!(
+                "Call graph not available for {}. Enable with --call-graph flag.",
+                repo
+            )
+        })?;
+
+        let scored = call_graph.compute_pagerank(0.85, 30);
 
         let mut output = String::new();
-        output.push_str(&format!("# Code Excerpt: `{}`\n\n", path));
-        output.push_str(&format!(
-            "Extracted {} excerpt(s) from {} match line(s)\n\n",
-            best.len(),
-            match_lines.len()
-        ));
+        output.push_str(&format!("# Key Functions in {}\n\n", repo));
 
-        for (i, excerpt) in best.iter().enumerate() {
-            output.push_str(&format!("## Excerpt {}\n", i + 1));
-            output.push_str(&format!(
-                "Lines {}-{} | Relevance: {:.2}\n\n",
-                excerpt.start_line, excerpt.end_line, excerpt.relevance
-            ));
-            output.push_str("```");
-            output.push_str(get_language_id(path));
-            output.push('\n');
-            output.push_str(&excerpt.content);
-            output.push_str("\n```\n\n");
+        if scored.is_empty() {
+            output.push_str("*Call graph is empty.*\n");
+            return Ok(output);
         }
 
-        if best.is_empty() {
-            output.push_str("*No excerpts could be extracted.*\n");
+        output.push_str(
+            "Ranked by PageRank centrality over the call graph: functions reached \
+             from many (themselves important) call sites score highest.\n\n",
+        );
+        output.push_str("| Rank | Function | Score | Location | Callers | Callees | CC |\n");
+        output.push_str("|------|----------|-------|----------|---------|---------|----|\n");
+
+        for (rank, (name, score)) in scored.iter().take(limit).enumerate() {
+            if let Some(node) = call_graph.get_node(name) {
+                output.push_str(&format!(
+                    "| {} | `{}` | {:.4} | `{}:{}` | {} | {} | {} |\n",
+                    rank + 1,
+                    name,
+                    score,
+                    node.file_path,
+                    node.line,
+                    node.called_by.len(),
+                    node.calls.len(),
+                    node.metrics.cyclomatic
+                ));
+            }
         }
 
+        output.push_str(&format!(
+            "\n**Total functions ranked**: {} (showing top {})\n",
+            scored.len(),
+            limit.min(scored.len())
+        ));
+
         Ok(output)
     }
 
-    // === Performance Metrics Methods ===
+    /// List mutually recursive function groups (SCCs of the call graph)
+    /// with their members and entry edges, for stack-depth and termination
+    /// reviews
+    pub async fn find_recursion_cycles(&self, repo: &str) -> Result<String> {
+        let call_graph = self.call_graphs.get(repo).ok_or_else(|| {
+            anyhow!(
+                "Call graph not available for {}. Enable with --call-graph flag.",
+                repo
+            )
+        })?;
 
-    /// Get performance metrics report
-    pub async fn get_metrics(&self, format: &str) -> Result<String> {
-        if format == "json" {
-            Ok(self.metrics.report_json().to_string())
-        } else {
-            Ok(self.metrics.report())
-        }
-    }
+        let cycles = call_graph.find_recursion_cycles();
 
-    // === LSP Integration Methods ===
+        let mut output = String::new();
+        output.push_str(&format!("# Recursion Cycles in {}\n\n", repo));
 
-    /// Get hover information from LSP (type info, documentation, etc.)
-    pub async fn get_hover_info(
-        &self,
-        repo: &str,
-        path: &str,
-        line: usize,
-        character: usize,
-    ) -> Result<String> {
-        let repo_path = self.get_repo_path(repo)?;
-        let file_path = validate_path(&repo_path, path)?;
+        if cycles.is_empty() {
+            output.push_str("*No recursive cycles found.*\n");
+            return Ok(output);
+        }
 
-        // Detect language from file extension
-        let language = get_language_from_path(path);
+        output.push_str(&format!(
+            "Found {} recursive group(s). Mutually recursive functions need a \
+             termination argument and bounded stack depth.\n\n",
+            cycles.len()
+        ));
 
-        let mut output = String::new();
-        output.push_str(&format!("# Hover Info: `{}`\n\n", path));
-        output.push_str(&format!("**Position**: {}:{}\n\n", line, character));
+        for (i, members) in cycles.iter().enumerate() {
+            let member_set: std::collections::HashSet<&str> =
+                members.iter().map(|m| m.as_str()).collect();
 
-        // Try LSP first if available
-        if let Some(ref lsp) = self.lsp_manager {
-            match lsp
-                .get_hover(&language, &file_path, line as u32, character as u32)
-                .await
-            {
-                Ok(Some(hover)) => {
-                    output.push_str("## LSP Hover Information (LSP enhanced)\n\n");
-                    output.push_str(&crate::lsp::hover_to_markdown(&hover));
-                    output.push_str("\n\n");
-                    return Ok(output);
-                }
-                Ok(None) => {
-                    output.push_str("*No hover information available from LSP*\n\n");
-                }
-                Err(e) => {
-                    output.push_str(&format!("*LSP error: {}*\n\n", e));
-                }
+            if members.len() == 1 {
+                output.push_str(&format!("## Cycle {} (self-recursive)\n\n", i + 1));
+            } else {
+                output.push_str(&format!(
+                    "## Cycle {} ({} functions)\n\n",
+                    i + 1,
+                    members.len()
+                ));
             }
-        }
 
-        // Fallback to tree-sitter symbols
-        output.push_str("## Symbol Information (tree-sitter)\n\n");
-        let symbols = self
-            .symbols
-            .get(repo)
-            .ok_or_else(|| self.repo_not_found_error(repo))?;
+            output.push_str("**Members:**\n");
+            for member in members {
+                if let Some(node) = call_graph.get_node(member) {
+                    output.push_str(&format!(
+                        "- `{}` at `{}:{}`\n",
+                        member, node.file_path, node.line
+                    ));
+                } else {
+                    output.push_str(&format!("- `{}`\n", member));
+                }
+            }
 
-        // Find symbol at this location
-        for symbol in symbols.iter() {
-            if symbol.file_path == path && line >= symbol.start_line && line <= symbol.end_line {
-                output.push_str(&format!("**Symbol**: {}\n", symbol.name));
-                output.push_str(&format!("**Kind**: {:?}\n", symbol.kind));
-                if let Some(sig) = &symbol.signature {
-                    output.push_str(&format!("**Signature**: `{}`\n", sig));
+            // Entry edges: calls into the cycle from functions outside it
+            let mut entries = Vec::new();
+            for member in members {
+                if let Some(node) = call_graph.get_node(member) {
+                    for caller in &node.called_by {
+                        if !member_set.contains(caller.target.as_str()) {
+                            entries.push(format!(
+                                "- `{}` -> `{}` at `{}:{}`\n",
+                                caller.target, member, caller.file_path, caller.line
+                            ));
+                        }
+                    }
                 }
-                if let Some(doc) = &symbol.doc_comment {
-                    output.push_str(&format!("\n{}\n", doc));
+            }
+            entries.sort();
+            entries.dedup();
+
+            output.push_str("\n**Entry edges (calls into the cycle from outside):**\n");
+            if entries.is_empty() {
+                output.push_str("*None - the cycle is unreachable or an entry point itself*\n");
+            } else {
+                for entry in entries {
+                    output.push_str(&entry);
                 }
-                break;
             }
+            output.push('\n');
         }
 
         Ok(output)
     }
 
-    /// Get type information for a symbol (requires LSP)
-    pub async fn get_type_info(
+    /// Find the test files/functions likely affected by a diff or ref range,
+    /// by mapping changed lines to functions and walking the call graph
+    /// backwards to tests - for CI test selection
+    pub async fn get_impacted_tests(
         &self,
         repo: &str,
-        path: &str,
-        line: usize,
-        character: usize,
+        base: &str,
+        head: Option<&str>,
+        max_depth: usize,
     ) -> Result<String> {
-        let repo_path = self.get_repo_path(repo)?;
-        let file_path = validate_path(&repo_path, path)?;
-        let language = get_language_from_path(path);
+        use crate::security_rules::is_test_file;
+        use std::collections::{BTreeMap, BTreeSet, VecDeque};
 
-        let mut output = String::new();
-        output.push_str(&format!("# Type Information: `{}`\n\n", path));
-        output.push_str(&format!("**Position**: {}:{}\n\n", line, character));
+        let git_repo = self
+            .git_repos
+            .get(repo)
+            .ok_or_else(|| anyhow!("Git not available for {}. Enable with --git flag.", repo))?;
+        let call_graph = self.call_graphs.get(repo).ok_or_else(|| {
+            anyhow!(
+                "Call graph not available for {}. Enable with --call-graph flag.",
+                repo
+            )
+        })?;
 
-        if let Some(ref lsp) = self.lsp_manager {
-            match lsp
-                .get_hover(&language, &file_path, line as u32, character as u32)
-                .await
-            {
-                Ok(Some(hover)) => {
-                    output.push_str("## Type Information (LSP enhanced)\n\n");
-                    output.push_str(&crate::lsp::hover_to_markdown(&hover));
-                    return Ok(output);
-                }
-                Ok(None) => {
-                    output.push_str("*No type information available from LSP*\n");
-                }
-                Err(e) => {
-                    output.push_str(&format!("*LSP error: {}*\n", e));
-                }
-            }
-        } else {
-            output.push_str("*LSP not enabled. Use --lsp flag to enable type information.*\n");
-        }
+        let changed = git_repo.changed_lines_between(base, head)?;
 
-        Ok(output)
-    }
+        let range_desc = match head {
+            Some(head) => format!("{}..{}", base, head),
+            None => format!("{} vs working tree", base),
+        };
 
-    // === Go to Definition (LSP) ===
+        let mut output = String::new();
+        output.push_str(&format!(
+            "# Impacted Tests in {} ({})\n\n",
+            repo, range_desc
+        ));
 
-    /// Get definition location using LSP
-    pub async fn go_to_definition(
-        &self,
-        repo: &str,
-        path: &str,
-        line: usize,
-        character: usize,
-    ) -> Result<String> {
-        let repo_path = self.get_repo_path(repo)?;
-        let file_path = validate_path(&repo_path, path)?;
-        let language = get_language_from_path(path);
+        if changed.is_empty() {
+            output.push_str("*No changes found in the given range.*\n");
+            return Ok(output);
+        }
 
-        let mut output = String::new();
-        output.push_str(&format!("# Go to Definition: `{}`\n\n", path));
-        output.push_str(&format!("**Position**: {}:{}\n\n", line, character));
+        // Changed test files should rerun entirely regardless of the graph
+        let changed_test_files: BTreeSet<&str> = changed
+            .keys()
+            .filter(|path| is_test_file(path))
+            .map(|path| path.as_str())
+            .collect();
 
-        if let Some(ref lsp) = self.lsp_manager {
-            match lsp
-                .get_definition(&language, &file_path, line as u32, character as u32)
-                .await
-            {
-                Ok(Some(locations)) => {
-                    if locations.is_empty() {
-                        output.push_str("*No definition found*\n");
-                    } else {
-                        output.push_str(&format!("Found {} definition(s):\n\n", locations.len()));
-                        for loc in locations {
-                            if let Ok(def_path) = loc.uri.to_file_path() {
-                                let rel_path = def_path
-                                    .strip_prefix(&repo_path)
-                                    .unwrap_or(&def_path)
-                                    .to_string_lossy();
-                                output.push_str(&format!(
-                                    "- `{}:{}:{}`\n",
-                                    rel_path,
-                                    loc.range.start.line + 1,
-                                    loc.range.start.character
-                                ));
-                            } else {
-                                output.push_str(&format!(
-                                    "- `{}:{}:{}`\n",
-                                    loc.uri,
-                                    loc.range.start.line + 1,
-                                    loc.range.start.character
-                                ));
-                            }
-                        }
-                    }
-                    return Ok(output);
-                }
-                Ok(None) => {
-                    output.push_str("*No definition found from LSP*\n");
-                }
-                Err(e) => {
-                    output.push_str(&format!("*LSP error: {}*\n", e));
+        // Seed: functions whose span overlaps a changed line range
+        let mut seeds: Vec<String> = Vec::new();
+        for node in call_graph.iter_nodes() {
+            let ranges = changed
+                .iter()
+                .find(|(path, _)| node.file_path.ends_with(path.as_str()))
+                .map(|(_, ranges)| ranges);
+            if let Some(ranges) = ranges {
+                let start = node.line;
+                let end = node.line + node.metrics.loc.saturating_sub(1);
+                if ranges.iter().any(|&(s, e)| s <= end && start <= e) {
+                    seeds.push(node.key().clone());
                 }
             }
-        } else {
-            output.push_str("*LSP not enabled. Use --lsp flag to enable go-to-definition.*\n");
         }
 
-        // Fallback: try to find in our symbol index
-        output.push_str("\n## Symbol Index Fallback\n\n");
+        // Walk callers backwards from changed functions, collecting tests
+        let mut visited: HashSet<String> = seeds.iter().cloned().collect();
+        let mut queue: VecDeque<(String, usize)> =
+            seeds.iter().map(|name| (name.clone(), 0)).collect();
+        let mut impacted: BTreeMap<String, BTreeSet<(usize, String)>> = BTreeMap::new();
 
-        // Read the file to find what symbol is at this position
-        let content = std::fs::read_to_string(&file_path)?;
-        let lines: Vec<&str> = content.lines().collect();
+        while let Some((name, depth)) = queue.pop_front() {
+            if let Some(node) = call_graph.get_node(&name) {
+                if is_test_file(&node.file_path) || name.starts_with("test_") {
+                    impacted
+                        .entry(node.file_path.clone())
+                        .or_default()
+                        .insert((node.line, name.clone()));
+                }
 
-        if line > 0 && line <= lines.len() {
-            let source_line = lines[line - 1];
-            // Try to find a symbol at or near the character position
-            if let Some(symbols) = self.symbols.get(repo) {
-                for symbol in symbols.iter() {
-                    if source_line.contains(&symbol.name) {
-                        output.push_str(&format!(
-                            "Possible match: **{}** at `{}:{}` ({:?})\n",
-                            symbol.name, symbol.file_path, symbol.start_line, symbol.kind
-                        ));
+                if depth < max_depth {
+                    for caller in &node.called_by {
+                        if !visited.contains(&caller.target) {
+                            visited.insert(caller.target.clone());
+                            queue.push_back((caller.target.clone(), depth + 1));
+                        }
                     }
                 }
             }
         }
 
-        Ok(output)
-    }
-
-    // === Remote Repository Methods ===
+        output.push_str(&format!(
+            "Changed files: {} | Changed functions: {}\n\n",
+            changed.len(),
+            seeds.len()
+        ));
 
-    /// Initialize the remote repository manager
-    pub fn init_remote_manager(&mut self) -> Result<()> {
-        if self.remote_manager.is_none() {
-            let manager = RemoteRepoManager::new()?;
-            self.remote_manager = Some(Arc::new(tokio::sync::Mutex::new(manager)));
-            info!("Remote repository manager initialized");
+        if !changed_test_files.is_empty() {
+            output.push_str("## Changed Test Files (run entirely)\n\n");
+            for path in &changed_test_files {
+                output.push_str(&format!("- `{}`\n", path));
+            }
+            output.push('\n');
         }
-        Ok(())
-    }
 
-    /// Add a remote GitHub repository for indexing
-    pub async fn add_remote_repo(
-        &self,
-        url: &str,
-        sparse_paths: Option<&[String]>,
-    ) -> Result<String> {
-        // Initialize manager if needed
-        let manager = match &self.remote_manager {
-            Some(m) => m.clone(),
-            None => {
-                return Err(anyhow!(
-                    "Remote repository support not initialized. Use init_remote_manager() first."
-                ));
+        if impacted.is_empty() {
+            output.push_str("*No tests reach the changed functions through the call graph.*\n");
+            if changed_test_files.is_empty() {
+                output.push_str(
+                    "\nNote: tests invoking changed code only through dynamic dispatch or \
+                     fixtures may not be detected - consider a full run for risky changes.\n",
+                );
             }
-        };
+            return Ok(output);
+        }
 
-        let remote = crate::remote::RemoteRepo::from_url(url)?;
+        output.push_str("## Impacted Tests\n\n");
+        let mut total = 0;
+        for (file, tests) in &impacted {
+            output.push_str(&format!("### `{}`\n\n", file));
+            for (line, name) in tests {
+                output.push_str(&format!("- `{}` (line {})\n", name, line));
+                total += 1;
+            }
+            output.push('\n');
+        }
 
-        let mut output = String::new();
         output.push_str(&format!(
-            "# Adding Remote Repository: {}\n\n",
-            remote.identifier()
+            "**Total**: {} test function(s) across {} file(s)\n",
+            total,
+            impacted.len()
         ));
-        output.push_str(&format!("**URL**: {}\n", remote.url));
-        if let Some(branch) = &remote.branch {
-            output.push_str(&format!("**Branch**: {}\n", branch));
-        }
-        output.push('\n');
 
-        let local_path = {
-            let mut mgr = manager.lock().await;
-            if let Some(paths) = sparse_paths {
-                let path_refs: Vec<&str> = paths.iter().map(|s| s.as_str()).collect();
-                output.push_str(&format!(
-                    "Performing sparse checkout of {} paths...\n\n",
-                    paths.len()
-                ));
-                mgr.sparse_checkout(&remote, &path_refs).await?
-            } else {
-                output.push_str("Cloning repository...\n\n");
-                mgr.clone_repo(&remote).await?
-            }
-        };
+        Ok(output)
+    }
 
-        output.push_str(&format!("**Local Path**: `{}`\n\n", local_path.display()));
-        output.push_str("Repository cloned successfully. You can now index it with `reindex`.\n");
+    // === Coverage ===
 
-        // Note: Full indexing would require adding this path to repo_paths and calling index_repo
-        // For now we just clone and return the path
+    /// Ingest an LCOV or Cobertura coverage report so coverage percentages
+    /// can be attached to symbols and uncovered functions queried
+    pub async fn load_coverage(&self, repo: &str, path: &str) -> Result<String> {
+        let repo_path = self.get_repo_path(repo)?;
+        let file_path = validate_path(&repo_path, path)?;
+
+        let content =
+            std::fs::read_to_string(&file_path).context("Failed to read coverage file")?;
+        let data = crate::coverage::CoverageData::parse(&content)?;
+
+        let (covered, instrumented) = data.totals();
+        let file_count = data.files.len();
+        self.coverage.insert(repo.to_string(), data);
+
+        let mut output = String::new();
+        output.push_str(&format!("# Coverage Loaded for {}\n\n", repo));
+        output.push_str(&format!("**Report**: `{}`\n", path));
+        output.push_str(&format!("**Files**: {}\n", file_count));
+        output.push_str(&format!(
+            "**Line coverage**: {:.1}% ({}/{} instrumented lines)\n\n",
+            crate::coverage::percent(covered, instrumented),
+            covered,
+            instrumented
+        ));
+        output.push_str(
+            "Coverage annotations are now available in `get_symbol_definition` and \
+             `find_uncovered_functions`.\n",
+        );
 
         Ok(output)
     }
 
-    /// List files in a remote GitHub repository via API
-    pub async fn list_remote_files(&self, url: &str, path: Option<&str>) -> Result<String> {
-        let manager = match &self.remote_manager {
-            Some(m) => m.clone(),
-            None => {
-                // Try to create a temporary manager for API-only operation
-                let mgr = RemoteRepoManager::new()?;
-                let remote = crate::remote::RemoteRepo::from_url(url)?;
-                let files = mgr.list_files(&remote, path).await?;
+    /// List functions whose line coverage falls below a threshold, worst
+    /// first - requires a previously loaded coverage report
+    pub async fn find_uncovered_functions(
+        &self,
+        repo: &str,
+        threshold: f64,
+        limit: usize,
+    ) -> Result<String> {
+        let coverage = self.coverage.get(repo).ok_or_else(|| {
+            anyhow!(
+                "No coverage data loaded for {}. Ingest a report with load_coverage first.",
+                repo
+            )
+        })?;
+        let symbols = self
+            .symbols
+            .get(repo)
+            .ok_or_else(|| self.repo_not_found_error(repo))?;
 
-                let mut output = String::new();
-                output.push_str(&format!("# Files in {}\n\n", remote.identifier()));
-                if let Some(p) = path {
-                    output.push_str(&format!("**Path**: `{}`\n\n", p));
-                }
-                output.push_str(&format!("Found {} files:\n\n", files.len()));
-                for file in files {
-                    output.push_str(&format!("- `{}`\n", file));
-                }
-                return Ok(output);
+        let mut results: Vec<(f64, usize, usize, &Symbol)> = Vec::new();
+        for symbol in symbols.iter() {
+            if !matches!(
+                symbol.kind,
+                SymbolKind::Function | SymbolKind::Method | SymbolKind::Constructor
+            ) {
+                continue;
             }
-        };
 
-        let remote = crate::remote::RemoteRepo::from_url(url)?;
+            if let Some(file) = coverage.file_coverage(&symbol.file_path) {
+                if let Some((covered, instrumented)) =
+                    file.range_coverage(symbol.start_line, symbol.end_line)
+                {
+                    let pct = crate::coverage::percent(covered, instrumented);
+                    if pct < threshold {
+                        results.push((pct, covered, instrumented, symbol));
+                    }
+                }
+            }
+        }
 
-        let files = {
-            let mgr = manager.lock().await;
-            mgr.list_files(&remote, path).await?
-        };
+        results.sort_by(|a, b| {
+            a.0.partial_cmp(&b.0)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.3.name.cmp(&b.3.name))
+        });
 
         let mut output = String::new();
-        output.push_str(&format!("# Files in {}\n\n", remote.identifier()));
-        if let Some(p) = path {
-            output.push_str(&format!("**Path**: `{}`\n\n", p));
+        output.push_str(&format!(
+            "# Uncovered Functions in {} (< {:.0}% line coverage)\n\n",
+            repo, threshold
+        ));
+
+        if results.is_empty() {
+            output.push_str("*No functions below the coverage threshold.*\n");
+            return Ok(output);
         }
-        output.push_str(&format!("Found {} files:\n\n", files.len()));
-        for file in &files {
-            output.push_str(&format!("- `{}`\n", file));
+
+        output.push_str(&format!(
+            "Found {} function(s), worst first\n\n",
+            results.len()
+        ));
+        output.push_str("| Function | Coverage | Lines | Location |\n");
+        output.push_str("|----------|----------|-------|----------|\n");
+
+        for (pct, covered, instrumented, symbol) in results.iter().take(limit) {
+            output.push_str(&format!(
+                "| `{}` | {:.1}% | {}/{} | `{}:{}` |\n",
+                symbol.name, pct, covered, instrumented, symbol.file_path, symbol.start_line
+            ));
         }
 
-        if files.is_empty() {
-            output.push_str("*No files found (directory may be empty or not exist)*\n");
+        if results.len() > limit {
+            output.push_str(&format!(
+                "\n*... and {} more (raise `limit` to see them)*\n",
+                results.len() - limit
+            ));
         }
 
         Ok(output)
     }
 
-    /// Fetch a specific file from a remote GitHub repository
-    pub async fn get_remote_file(&self, url: &str, path: &str) -> Result<String> {
-        let manager = match &self.remote_manager {
-            Some(m) => m.clone(),
-            None => {
-                // Try to create a temporary manager for API-only operation
-                let mgr = RemoteRepoManager::new()?;
-                let remote = crate::remote::RemoteRepo::from_url(url)?;
-                let content = mgr.get_file(&remote, path).await?;
+    /// Inventory TODO/FIXME/HACK comments with author and age from blame
+    pub async fn find_todos(
+        &self,
+        repo: &str,
+        marker: Option<&str>,
+        limit: usize,
+    ) -> Result<String> {
+        let repo_path = self.get_repo_path(repo)?;
 
-                let mut output = String::new();
-                output.push_str(&format!("# {} from {}\n\n", path, remote.identifier()));
-                output.push_str("```");
-                output.push_str(get_language_id(path));
-                output.push('\n');
-                output.push_str(&content);
-                output.push_str("\n```\n");
-                return Ok(output);
+        const MARKERS: [&str; 4] = ["TODO", "FIXME", "HACK", "XXX"];
+        let wanted: Vec<&str> = match marker {
+            Some(m) => {
+                let m = m.to_uppercase();
+                MARKERS.iter().copied().filter(|k| *k == m).collect()
             }
+            None => MARKERS.to_vec(),
         };
+        if wanted.is_empty() {
+            return Err(anyhow!(
+                "Unknown marker '{}'. Expected one of: {}",
+                marker.unwrap_or(""),
+                MARKERS.join(", ")
+            ));
+        }
+
+        // Collect (file, line, marker, text) from the indexed file cache
+        let mut todos: Vec<(String, usize, &str, String)> = Vec::new();
+        for entry in self.file_cache.iter() {
+            let file_path = entry.key();
+            if !file_path.starts_with(&repo_path) {
+                continue;
+            }
+            let rel_path = file_path
+                .strip_prefix(&repo_path)
+                .unwrap_or(file_path)
+                .to_string_lossy()
+                .to_string();
+
+            for (line_num, line) in entry.value().lines().enumerate() {
+                for m in &wanted {
+                    if let Some(pos) = line.find(m) {
+                        // Require a word boundary so e.g. "HACKER" doesn't match
+                        let after = line[pos + m.len()..].chars().next();
+                        if after.is_some_and(|c| c.is_alphanumeric()) {
+                            continue;
+                        }
+                        let text = line[pos..].trim().chars().take(100).collect::<String>();
+                        todos.push((rel_path.clone(), line_num + 1, m, text));
+                        break;
+                    }
+                }
+            }
+        }
+
+        // Attribute each comment via blame (best-effort: works only with --git)
+        let git_repo = self.git_repos.get(repo);
+        let mut blamed: HashMap<String, HashMap<usize, (String, i64)>> = HashMap::new();
+        if let Some(ref git_repo) = git_repo {
+            let files: HashSet<&String> = todos.iter().map(|(f, _, _, _)| f).collect();
+            for file in files {
+                if let Ok(blame) = git_repo.blame(file) {
+                    let by_line = blame
+                        .into_iter()
+                        .map(|b| (b.line_number, (b.author, b.timestamp)))
+                        .collect();
+                    blamed.insert(file.clone(), by_line);
+                }
+            }
+        }
+
+        // Oldest first; unattributed entries last
+        let mut entries: Vec<_> = todos
+            .into_iter()
+            .map(|(file, line, m, text)| {
+                let attribution = blamed.get(&file).and_then(|b| b.get(&line)).cloned();
+                (attribution, file, line, m, text)
+            })
+            .collect();
+        entries.sort_by(|a, b| match (&a.0, &b.0) {
+            (Some((_, ta)), Some((_, tb))) => ta.cmp(tb),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        });
+
+        let mut output = String::new();
+        output.push_str(&format!("# TODO Inventory: {}\n\n", repo));
+        if entries.is_empty() {
+            output.push_str("No TODO/FIXME/HACK comments found.\n");
+            return Ok(output);
+        }
+
+        output.push_str(&format!("{} comment(s), oldest first\n\n", entries.len()));
+        if git_repo.is_none() {
+            output.push_str("*Author/age unavailable (enable with --git flag).*\n\n");
+        }
+
+        output.push_str("| Marker | Location | Age | Author | Comment |\n");
+        output.push_str("|--------|----------|-----|--------|----------|\n");
+        for (attribution, file, line, m, text) in entries.iter().take(limit) {
+            let (age, author) = match attribution {
+                Some((author, timestamp)) => {
+                    (crate::git::chrono_lite_format(*timestamp), author.clone())
+                }
+                None => ("-".to_string(), "-".to_string()),
+            };
+            output.push_str(&format!(
+                "| {} | `{}:{}` | {} | {} | {} |\n",
+                m,
+                file,
+                line,
+                age,
+                author,
+                text.replace('|', "\\|")
+            ));
+        }
+        if entries.len() > limit {
+            output.push_str(&format!(
+                "\n*... and {} more (raise `limit` to see them)*\n",
+                entries.len() - limit
+            ));
+        }
+
+        Ok(output)
+    }
+
+    // === Excerpt Extraction ===
+
+    /// Get an intelligent code excerpt with context
+    pub async fn get_excerpt(
+        &self,
+        repo: &str,
+        path: &str,
+        match_lines: &[usize],
+        config: crate::extract::ExcerptConfig,
+    ) -> Result<String> {
+        let repo_path = self.get_repo_path(repo)?;
+        let file_path = validate_path(&repo_path, path)?;
+
+        let content = std::fs::read_to_string(&file_path).context("Failed to read file")?;
+
+        let excerpts = crate::extract::extract_excerpts(&content, match_lines, &config);
+        let best = crate::extract::select_best_excerpt(&excerpts, 3);
+
+        let mut output = String::new();
+        output.push_str(&format!("# Code Excerpt: `{}`\n\n", path));
+        output.push_str(&format!(
+            "Extracted {} excerpt(s) from {} match line(s)\n\n",
+            best.len(),
+            match_lines.len()
+        ));
+
+        for (i, excerpt) in best.iter().enumerate() {
+            output.push_str(&format!("## Excerpt {}\n", i + 1));
+            output.push_str(&format!(
+                "Lines {}-{} | Relevance: {:.2}\n\n",
+                excerpt.start_line, excerpt.end_line, excerpt.relevance
+            ));
+            output.push_str("```");
+            output.push_str(get_language_id(path));
+            output.push('\n');
+            output.push_str(&excerpt.content);
+            output.push_str("\n```\n\n");
+        }
+
+        if best.is_empty() {
+            output.push_str("*No excerpts could be extracted.*\n");
+        }
+
+        Ok(output)
+    }
+
+    // === Performance Metrics Methods ===
+
+    /// Get performance metrics report
+    pub async fn get_metrics(&self, format: &str) -> Result<String> {
+        self.refresh_index_size_metrics();
+        if format == "json" {
+            Ok(self.metrics.report_json().to_string())
+        } else {
+            Ok(self.metrics.report())
+        }
+    }
+
+    /// Snapshot index data structure sizes into the metrics gauges
+    fn refresh_index_size_metrics(&self) {
+        let symbol_count = self.symbols.iter().map(|entry| entry.value().len()).sum();
+        let file_cache_bytes = self
+            .file_cache
+            .iter()
+            .map(|entry| entry.value().len())
+            .sum();
+        let bm25_postings_bytes = self.search_index.postings_bytes();
+        // TF-IDF vectors are f32 per dimension per document
+        let (embedding_stats, doc_count) = self.embedding_engine.stats();
+        let embedding_bytes = doc_count * embedding_stats.dimension * std::mem::size_of::<f32>();
+        let call_graph_edges = self
+            .call_graphs
+            .iter()
+            .map(|entry| entry.value().edge_count())
+            .sum();
+
+        self.metrics
+            .set_index_sizes(crate::metrics::IndexSizeStats {
+                symbol_count,
+                file_cache_bytes,
+                bm25_postings_bytes,
+                embedding_bytes,
+                call_graph_edges,
+            });
+    }
+
+    /// Fetch a file from the content cache, recording the hit rate
+    fn cached_file_content(&self, path: &Path) -> Option<Arc<String>> {
+        let cached = self.file_cache.get(path).map(|entry| entry.value().clone());
+        self.metrics
+            .record_cache_access("file_cache", cached.is_some());
+        cached
+    }
+
+    /// Subscribe to live engine events (reindexes, file changes)
+    pub fn subscribe_events(&self) -> tokio::sync::broadcast::Receiver<crate::events::EngineEvent> {
+        self.events.subscribe()
+    }
+
+    // === LSP Integration Methods ===
+
+    /// Get hover information from LSP (type info, documentation, etc.)
+    pub async fn get_hover_info(
+        &self,
+        repo: &str,
+        path: &str,
+        line: usize,
+        character: usize,
+    ) -> Result<String> {
+        let repo_path = self.get_repo_path(repo)?;
+        let file_path = validate_path(&repo_path, path)?;
+
+        // Detect language from file extension
+        let language = get_language_from_path(path);
+
+        let mut output = String::new();
+        output.push_str(&format!("# Hover Info: `{}`\n\n", path));
+        output.push_str(&format!("**Position**: {}:{}\n\n", line, character));
+
+        // Try LSP first if available
+        if let Some(ref lsp) = self.lsp_manager {
+            match lsp
+                .get_hover(&language, &file_path, line as u32, character as u32)
+                .await
+            {
+                Ok(Some(hover)) => {
+                    output.push_str("## LSP Hover Information (LSP enhanced)\n\n");
+                    output.push_str(&crate::lsp::hover_to_markdown(&hover));
+                    output.push_str("\n\n");
+                    return Ok(output);
+                }
+                Ok(None) => {
+                    output.push_str("*No hover information available from LSP*\n\n");
+                }
+                Err(e) => {
+                    output.push_str(&format!("*LSP error: {}*\n\n", e));
+                }
+            }
+        }
+
+        // Fallback to tree-sitter symbols
+        output.push_str("## Symbol Information (tree-sitter)\n\n");
+        let symbols = self
+            .symbols
+            .get(repo)
+            .ok_or_else(|| self.repo_not_found_error(repo))?;
+
+        // Find symbol at this location
+        for symbol in symbols.iter() {
+            if symbol.file_path == path && line >= symbol.start_line && line <= symbol.end_line {
+                output.push_str(&format!("**Symbol**: {}\n", symbol.name));
+                output.push_str(&format!("**Kind**: {:?}\n", symbol.kind));
+                if let Some(sig) = &symbol.signature {
+                    output.push_str(&format!("**Signature**: `{}`\n", sig));
+                }
+                if let Some(doc) = &symbol.doc_comment {
+                    output.push_str(&format!("\n{}\n", doc));
+                }
+                break;
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// Get type information for a symbol (requires LSP)
+    pub async fn get_type_info(
+        &self,
+        repo: &str,
+        path: &str,
+        line: usize,
+        character: usize,
+    ) -> Result<String> {
+        let repo_path = self.get_repo_path(repo)?;
+        let file_path = validate_path(&repo_path, path)?;
+        let language = get_language_from_path(path);
+
+        let mut output = String::new();
+        output.push_str(&format!("# Type Information: `{}`\n\n", path));
+        output.push_str(&format!("**Position**: {}:{}\n\n", line, character));
+
+        if let Some(ref lsp) = self.lsp_manager {
+            match lsp
+                .get_hover(&language, &file_path, line as u32, character as u32)
+                .await
+            {
+                Ok(Some(hover)) => {
+                    output.push_str("## Type Information (LSP enhanced)\n\n");
+                    output.push_str(&crate::lsp::hover_to_markdown(&hover));
+                    return Ok(output);
+                }
+                Ok(None) => {
+                    output.push_str("*No type information available from LSP*\n");
+                }
+                Err(e) => {
+                    output.push_str(&format!("*LSP error: {}*\n", e));
+                }
+            }
+        } else {
+            output.push_str("*LSP not enabled. Use --lsp flag to enable type information.*\n");
+        }
+
+        Ok(output)
+    }
+
+    // === Go to Definition (LSP) ===
+
+    /// Get definition location using LSP
+    pub async fn go_to_definition(
+        &self,
+        repo: &str,
+        path: &str,
+        line: usize,
+        character: usize,
+    ) -> Result<String> {
+        let repo_path = self.get_repo_path(repo)?;
+        let file_path = validate_path(&repo_path, path)?;
+        let language = get_language_from_path(path);
+
+        let mut output = String::new();
+        output.push_str(&format!("# Go to Definition: `{}`\n\n", path));
+        output.push_str(&format!("**Position**: {}:{}\n\n", line, character));
+
+        if let Some(ref lsp) = self.lsp_manager {
+            match lsp
+                .get_definition(&language, &file_path, line as u32, character as u32)
+                .await
+            {
+                Ok(Some(locations)) => {
+                    if locations.is_empty() {
+                        output.push_str("*No definition found*\n");
+                    } else {
+                        output.push_str(&format!("Found {} definition(s):\n\n", locations.len()));
+                        for loc in locations {
+                            if let Ok(def_path) = loc.uri.to_file_path() {
+                                let rel_path = def_path
+                                    .strip_prefix(&repo_path)
+                                    .unwrap_or(&def_path)
+                                    .to_string_lossy();
+                                output.push_str(&format!(
+                                    "- `{}:{}:{}`\n",
+                                    rel_path,
+                                    loc.range.start.line + 1,
+                                    loc.range.start.character
+                                ));
+                            } else {
+                                output.push_str(&format!(
+                                    "- `{}:{}:{}`\n",
+                                    loc.uri,
+                                    loc.range.start.line + 1,
+                                    loc.range.start.character
+                                ));
+                            }
+                        }
+                    }
+                    return Ok(output);
+                }
+                Ok(None) => {
+                    output.push_str("*No definition found from LSP*\n");
+                }
+                Err(e) => {
+                    output.push_str(&format!("*LSP error: {}*\n", e));
+                }
+            }
+        } else {
+            output.push_str("*LSP not enabled. Use --lsp flag to enable go-to-definition.*\n");
+        }
+
+        // Fallback: try to find in our symbol index
+        output.push_str("\n## Symbol Index Fallback\n\n");
+
+        // Read the file to find what symbol is at this position
+        let content = std::fs::read_to_string(&file_path)?;
+        let lines: Vec<&str> = content.lines().collect();
+
+        if line > 0 && line <= lines.len() {
+            let source_line = lines[line - 1];
+            // Try to find a symbol at or near the character position
+            if let Some(symbols) = self.symbols.get(repo) {
+                for symbol in symbols.iter() {
+                    if source_line.contains(&symbol.name) {
+                        output.push_str(&format!(
+                            "Possible match: **{}** at `{}:{}` ({:?})\n",
+                            symbol.name, symbol.file_path, symbol.start_line, symbol.kind
+                        ));
+                    }
+                }
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// Get diagnostics pushed by language servers (requires LSP)
+    ///
+    /// Language servers publish diagnostics for documents that have been
+    /// opened, so when a path is given the file is opened first and the
+    /// server is given a short window to respond.
+    pub async fn get_diagnostics(
+        &self,
+        repo: &str,
+        path: Option<&str>,
+        min_severity: Option<&str>,
+    ) -> Result<String> {
+        let repo_path = self.get_repo_path(repo)?;
+
+        let mut output = String::new();
+        output.push_str(&format!("# Diagnostics: {}\n\n", repo));
+
+        let lsp = match &self.lsp_manager {
+            Some(lsp) => lsp,
+            None => {
+                output.push_str("*LSP not enabled. Use --lsp flag to enable diagnostics.*\n");
+                return Ok(output);
+            }
+        };
+
+        // Opening the document prompts the server to publish diagnostics for it
+        if let Some(path) = path {
+            let file_path = validate_path(&repo_path, path)?;
+            let language = get_language_from_path(path);
+            let content = match self.cached_file_content(&file_path) {
+                Some(cached) => cached.to_string(),
+                None => std::fs::read_to_string(&file_path)?,
+            };
+            if let Err(e) = lsp.did_open(&language, &file_path, &content).await {
+                debug!("Failed to open {} with LSP: {}", path, e);
+            } else {
+                // Give the server a moment to analyze and publish
+                tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+            }
+        }
+
+        // Map a severity name to its LSP rank (lower is more severe)
+        let threshold = match min_severity.map(|s| s.to_lowercase()).as_deref() {
+            Some("error") => 1,
+            Some("warning") => 2,
+            Some("information") | Some("info") => 3,
+            _ => 4,
+        };
+
+        let severity_rank = |d: &lsp_types::Diagnostic| match d.severity {
+            Some(lsp_types::DiagnosticSeverity::ERROR) => 1,
+            Some(lsp_types::DiagnosticSeverity::INFORMATION) => 3,
+            Some(lsp_types::DiagnosticSeverity::HINT) => 4,
+            // Treat missing severity as a warning
+            _ => 2,
+        };
+
+        let severity_label = |rank: u8| match rank {
+            1 => "error",
+            2 => "warning",
+            3 => "info",
+            _ => "hint",
+        };
+
+        let mut files: Vec<(PathBuf, Vec<lsp_types::Diagnostic>)> = lsp
+            .get_diagnostics(path)
+            .into_iter()
+            .filter(|(file, _)| file.starts_with(&repo_path))
+            .map(|(file, diags)| {
+                let diags: Vec<_> = diags
+                    .into_iter()
+                    .filter(|d| severity_rank(d) <= threshold)
+                    .collect();
+                (file, diags)
+            })
+            .filter(|(_, diags)| !diags.is_empty())
+            .collect();
+        files.sort_by(|a, b| a.0.cmp(&b.0));
+
+        if files.is_empty() {
+            output.push_str("No diagnostics reported.\n");
+            return Ok(output);
+        }
+
+        let total: usize = files.iter().map(|(_, d)| d.len()).sum();
+        output.push_str(&format!(
+            "Found {} diagnostic(s) in {} file(s):\n\n",
+            total,
+            files.len()
+        ));
+
+        for (file, mut diags) in files {
+            let rel_path = file
+                .strip_prefix(&repo_path)
+                .unwrap_or(&file)
+                .display()
+                .to_string();
+            output.push_str(&format!("## `{}`\n\n", rel_path));
+
+            diags.sort_by_key(|d| (d.range.start.line, d.range.start.character));
+            for diag in diags {
+                let rank = severity_rank(&diag);
+                let source = diag
+                    .source
+                    .as_deref()
+                    .map(|s| format!(" [{}]", s))
+                    .unwrap_or_default();
+                output.push_str(&format!(
+                    "- **{}:{}** ({}){}: {}\n",
+                    diag.range.start.line + 1,
+                    diag.range.start.character,
+                    severity_label(rank),
+                    source,
+                    diag.message.lines().next().unwrap_or("")
+                ));
+            }
+            output.push('\n');
+        }
+
+        Ok(output)
+    }
+
+    /// Rename a symbol across the workspace using LSP (requires LSP)
+    ///
+    /// The server's `WorkspaceEdit` is rendered as a reviewable unified
+    /// diff; with `apply` set (and writes enabled) the edits are written
+    /// and the touched files re-indexed.
+    pub async fn rename_symbol(
+        &self,
+        repo: &str,
+        path: &str,
+        line: usize,
+        character: usize,
+        new_name: &str,
+        apply: bool,
+    ) -> Result<String> {
+        if apply && !self.options.write_enabled {
+            return Err(anyhow!(
+                "rename_symbol apply is disabled. Start the server with --allow-writes to enable write tools."
+            ));
+        }
+        if new_name.is_empty() {
+            return Err(anyhow!("new_name must not be empty"));
+        }
+
+        let repo_path = self.get_repo_path(repo)?;
+        let file_path = validate_path(&repo_path, path)?;
+        let language = get_language_from_path(path);
+
+        let mut output = String::new();
+        output.push_str(&format!("# Rename Symbol: `{}`\n\n", path));
+        output.push_str(&format!(
+            "**Position**: {}:{} -> `{}`\n\n",
+            line, character, new_name
+        ));
+
+        let lsp = match &self.lsp_manager {
+            Some(lsp) => lsp,
+            None => {
+                output.push_str("*LSP not enabled. Use --lsp flag to enable rename.*\n");
+                return Ok(output);
+            }
+        };
+
+        let edit = match lsp
+            .rename(
+                &language,
+                &file_path,
+                line as u32,
+                character as u32,
+                new_name,
+            )
+            .await
+        {
+            Ok(Some(edit)) => edit,
+            Ok(None) => {
+                output.push_str("*No rename available from LSP at this position*\n");
+                return Ok(output);
+            }
+            Err(e) => {
+                output.push_str(&format!("*LSP error: {}*\n", e));
+                return Ok(output);
+            }
+        };
+
+        let changes = crate::lsp::workspace_edit_changes(&edit);
+        if changes.is_empty() {
+            output.push_str("*Language server returned an empty edit*\n");
+            return Ok(output);
+        }
+
+        // Compute new content per file; refuse edits that escape the repo
+        let mut planned: Vec<(PathBuf, String, String, usize)> = Vec::new();
+        let mut total_edits = 0;
+        for (file, edits) in &changes {
+            if !file.starts_with(&repo_path) {
+                output.push_str(&format!(
+                    "*Skipping edit outside repository: {}*\n\n",
+                    file.display()
+                ));
+                continue;
+            }
+            let old_content = match self.cached_file_content(file) {
+                Some(cached) => cached.to_string(),
+                None => std::fs::read_to_string(file)?,
+            };
+            let new_content = crate::lsp::apply_text_edits(&old_content, edits);
+            if new_content == old_content {
+                continue;
+            }
+            total_edits += edits.len();
+            planned.push((file.clone(), old_content, new_content, edits.len()));
+        }
+
+        if planned.is_empty() {
+            output.push_str("*Rename produced no changes*\n");
+            return Ok(output);
+        }
+
+        output.push_str(&format!(
+            "Found {} edit(s) across {} file(s):\n\n",
+            total_edits,
+            planned.len()
+        ));
+
+        for (file, old_content, new_content, edit_count) in &planned {
+            let rel_path = file
+                .strip_prefix(&repo_path)
+                .unwrap_or(file)
+                .display()
+                .to_string();
+            output.push_str(&format!("## `{}` ({} edit(s))\n\n", rel_path, edit_count));
+            let diff =
+                crate::security_rules::unified_diff_files(&rel_path, old_content, new_content);
+            output.push_str("```diff\n");
+            output.push_str(&diff);
+            output.push_str("```\n\n");
+        }
+
+        if !apply {
+            output.push_str("**Mode**: preview — pass `apply: true` to write these edits\n");
+            return Ok(output);
+        }
+
+        // Write the edits and re-index the touched files
+        let mut file_changes = Vec::new();
+        for (file, _, new_content, _) in &planned {
+            std::fs::write(file, new_content)
+                .map_err(|e| anyhow!("Failed to write {}: {}", file.display(), e))?;
+            file_changes.push(crate::persist::FileChange {
+                path: file.clone(),
+                change_type: crate::persist::ChangeType::Modified,
+            });
+        }
+        self.process_file_changes(&file_changes).await?;
+
+        output.push_str(&format!(
+            "**Mode**: applied — {} file(s) written and re-indexed\n",
+            planned.len()
+        ));
+        Ok(output)
+    }
+
+    /// Report the health of configured language servers
+    pub async fn get_lsp_status(&self) -> Result<String> {
+        let mut output = String::new();
+        output.push_str("# LSP Status\n\n");
+
+        let lsp = match &self.lsp_manager {
+            Some(lsp) => lsp,
+            None => {
+                output.push_str("*LSP not enabled. Use --lsp flag to enable LSP integration.*\n");
+                return Ok(output);
+            }
+        };
+
+        let statuses = lsp.status().await;
+        if statuses.is_empty() {
+            output.push_str("No languages have LSP enabled.\n");
+            return Ok(output);
+        }
+
+        output.push_str("| Language | Server | Status | Restarts |\n");
+        output.push_str("|----------|--------|--------|----------|\n");
+        for status in &statuses {
+            let state = if status.running {
+                "running"
+            } else if status.last_error.is_some() {
+                "failed"
+            } else {
+                "not started"
+            };
+            output.push_str(&format!(
+                "| {} | `{}` | {} | {} |\n",
+                status.language, status.command, state, status.restarts
+            ));
+        }
+
+        // Surface errors (including install hints) below the table
+        let errors: Vec<&crate::lsp::LspServerStatus> =
+            statuses.iter().filter(|s| s.last_error.is_some()).collect();
+        if !errors.is_empty() {
+            output.push_str("\n## Errors\n\n");
+            for status in errors {
+                output.push_str(&format!(
+                    "- **{}**: {}\n",
+                    status.language,
+                    status.last_error.as_deref().unwrap_or("")
+                ));
+                if let Some(hint) = crate::lsp::install_hint(&status.language) {
+                    output.push_str(&format!("  - Install: `{}`\n", hint));
+                }
+            }
+        }
+
+        Ok(output)
+    }
+
+    // === Remote Repository Methods ===
+
+    /// Initialize the remote repository manager with a persistent clone
+    /// cache under the index path, so restarts reuse existing clones
+    pub fn init_remote_manager(&mut self) -> Result<()> {
+        if self.remote_manager.is_none() {
+            let manager = RemoteRepoManager::with_cache_dir(self.index_path.join("remote-repos"))?;
+            self.remote_manager = Some(Arc::new(tokio::sync::Mutex::new(manager)));
+            info!("Remote repository manager initialized");
+        }
+        Ok(())
+    }
+
+    /// Add a remote GitHub repository for indexing
+    pub async fn add_remote_repo(
+        &self,
+        url: &str,
+        sparse_paths: Option<&[String]>,
+        tarball: bool,
+    ) -> Result<String> {
+        // Initialize manager if needed
+        let manager = match &self.remote_manager {
+            Some(m) => m.clone(),
+            None => {
+                return Err(anyhow!(
+                    "Remote repository support not initialized. Use init_remote_manager() first."
+                ));
+            }
+        };
+
+        // Single-file and gist URLs become virtual one-file repos instead
+        // of clones
+        if let Some(source) = crate::remote::parse_snippet_url(url) {
+            return self.add_remote_snippet(&manager, &source).await;
+        }
+
+        let remote = crate::remote::RemoteRepo::from_url(url)?;
+
+        let mut output = String::new();
+        output.push_str(&format!(
+            "# Adding Remote Repository: {}\n\n",
+            remote.identifier()
+        ));
+        output.push_str(&format!("**URL**: {}\n", remote.url));
+        if let Some(branch) = &remote.branch {
+            output.push_str(&format!("**Branch**: {}\n", branch));
+        }
+        output.push('\n');
+
+        let local_path = {
+            let mut mgr = manager.lock().await;
+            if tarball {
+                output.push_str("Downloading tarball snapshot...\n\n");
+                mgr.download_tarball(&remote).await?
+            } else if let Some(paths) = sparse_paths {
+                let path_refs: Vec<&str> = paths.iter().map(|s| s.as_str()).collect();
+                output.push_str(&format!(
+                    "Performing sparse checkout of {} paths...\n\n",
+                    paths.len()
+                ));
+                mgr.sparse_checkout(&remote, &path_refs).await?
+            } else {
+                output.push_str("Cloning repository...\n\n");
+                mgr.clone_repo(&remote).await?
+            }
+        };
+
+        output.push_str(&format!("**Local Path**: `{}`\n\n", local_path.display()));
+        if tarball {
+            output
+                .push_str("Tarball extracted successfully. You can now index it with `reindex`.\n");
+        } else {
+            output
+                .push_str("Repository cloned successfully. You can now index it with `reindex`.\n");
+        }
+
+        // Note: Full indexing would require adding this path to repo_paths and calling index_repo
+        // For now we just clone and return the path
+
+        Ok(output)
+    }
+
+    /// Fetch a single remote file or gist and index it as a virtual
+    /// one-file repository, so snippets shared in issues can be analyzed
+    /// without cloning anything
+    async fn add_remote_snippet(
+        &self,
+        manager: &Arc<tokio::sync::Mutex<RemoteRepoManager>>,
+        source: &crate::remote::SnippetSource,
+    ) -> Result<String> {
+        let files = {
+            let mgr = manager.lock().await;
+            mgr.fetch_snippet(source).await?
+        };
+
+        let repo_name = source.repo_name();
+        let snippet_dir = self.index_path.join("remote-snippets").join(&repo_name);
+        // Replace any previous copy of the same snippet
+        if snippet_dir.exists() {
+            std::fs::remove_dir_all(&snippet_dir)
+                .context("Failed to clear previous snippet directory")?;
+        }
+        std::fs::create_dir_all(&snippet_dir).context("Failed to create snippet directory")?;
+
+        let mut output = String::new();
+        output.push_str(&format!("# Indexed Remote Snippet: {}\n\n", repo_name));
+        output.push_str(&format!("**Files**: {}\n\n", files.len()));
+        for (name, content) in &files {
+            std::fs::write(snippet_dir.join(name), content)
+                .context(format!("Failed to write snippet file: {}", name))?;
+            output.push_str(&format!(
+                "- `{}` ({} lines)\n",
+                name,
+                content.lines().count()
+            ));
+        }
+
+        self.index_repo(&snippet_dir).await?;
+
+        output.push_str(&format!(
+            "\nIndexed as repository `{}`. Symbol and search tools can now query it.\n",
+            repo_name
+        ));
+
+        Ok(output)
+    }
+
+    /// List files in a remote GitHub repository via API
+    pub async fn list_remote_files(&self, url: &str, path: Option<&str>) -> Result<String> {
+        let manager = match &self.remote_manager {
+            Some(m) => m.clone(),
+            None => {
+                // Try to create a temporary manager for API-only operation
+                let mgr = RemoteRepoManager::new()?;
+                let remote = crate::remote::RemoteRepo::from_url(url)?;
+                let files = mgr.list_files(&remote, path).await?;
+
+                let mut output = String::new();
+                output.push_str(&format!("# Files in {}\n\n", remote.identifier()));
+                if let Some(p) = path {
+                    output.push_str(&format!("**Path**: `{}`\n\n", p));
+                }
+                output.push_str(&format!("Found {} files:\n\n", files.len()));
+                for file in files {
+                    output.push_str(&format!("- `{}`\n", file));
+                }
+                return Ok(output);
+            }
+        };
+
+        let remote = crate::remote::RemoteRepo::from_url(url)?;
+
+        let files = {
+            let mgr = manager.lock().await;
+            mgr.list_files(&remote, path).await?
+        };
+
+        let mut output = String::new();
+        output.push_str(&format!("# Files in {}\n\n", remote.identifier()));
+        if let Some(p) = path {
+            output.push_str(&format!("**Path**: `{}`\n\n", p));
+        }
+        output.push_str(&format!("Found {} files:\n\n", files.len()));
+        for file in &files {
+            output.push_str(&format!("- `{}`\n", file));
+        }
+
+        if files.is_empty() {
+            output.push_str("*No files found (directory may be empty or not exist)*\n");
+        }
+
+        Ok(output)
+    }
+
+    /// Fetch a specific file from a remote GitHub repository
+    pub async fn get_remote_file(&self, url: &str, path: &str) -> Result<String> {
+        let manager = match &self.remote_manager {
+            Some(m) => m.clone(),
+            None => {
+                // Try to create a temporary manager for API-only operation
+                let mgr = RemoteRepoManager::new()?;
+                let remote = crate::remote::RemoteRepo::from_url(url)?;
+                let content = mgr.get_file(&remote, path).await?;
+
+                let mut output = String::new();
+                output.push_str(&format!("# {} from {}\n\n", path, remote.identifier()));
+                output.push_str("```");
+                output.push_str(get_language_id(path));
+                output.push('\n');
+                output.push_str(&content);
+                output.push_str("\n```\n");
+                return Ok(output);
+            }
+        };
+
+        let remote = crate::remote::RemoteRepo::from_url(url)?;
+
+        let content = {
+            let mgr = manager.lock().await;
+            mgr.get_file(&remote, path).await?
+        };
+
+        let mut output = String::new();
+        output.push_str(&format!("# {} from {}\n\n", path, remote.identifier()));
+
+        let lines: Vec<&str> = content.lines().collect();
+        output.push_str(&format!("**Lines**: {}\n\n", lines.len()));
+
+        output.push_str("```");
+        output.push_str(get_language_id(path));
+        output.push('\n');
+        output.push_str(&content);
+        output.push_str("\n```\n");
+
+        Ok(output)
+    }
+
+    /// Show clone-cache statistics and remaining GitHub API quota
+    pub async fn get_remote_stats(&self) -> Result<String> {
+        let manager = match &self.remote_manager {
+            Some(m) => m.clone(),
+            None => {
+                return Err(anyhow!(
+                    "Remote repository support not initialized. Use init_remote_manager() first."
+                ));
+            }
+        };
+
+        let (stats, quota) = {
+            let mgr = manager.lock().await;
+            let stats = mgr.get_stats();
+            // Before any API call has populated the snapshot, fall back to
+            // the /rate_limit endpoint (which does not count against quota)
+            let quota = match stats.rate_limit {
+                Some(rl) => Some(rl),
+                None => mgr.fetch_rate_limit().await.ok(),
+            };
+            (stats, quota)
+        };
+
+        let mut output = String::new();
+        output.push_str("# Remote Repository Stats\n\n");
+        output.push_str(&format!(
+            "**Cloned repositories**: {}\n",
+            stats.cloned_count
+        ));
+        output.push_str(&format!(
+            "**Total size**: {}\n",
+            format_size(stats.total_size_bytes)
+        ));
+        output.push_str(&format!(
+            "**Clone directory**: `{}`\n\n",
+            stats.clone_dir.display()
+        ));
+
+        output.push_str("## GitHub API Quota\n\n");
+        match quota {
+            Some(rl) => {
+                output.push_str(&format!(
+                    "**Remaining**: {} of {} requests\n",
+                    rl.remaining, rl.limit
+                ));
+                output.push_str(&format!("**Resets at**: unix timestamp {}\n", rl.reset));
+                if rl.limit <= 60 {
+                    output.push_str(
+                        "\n*Unauthenticated - set GITHUB_TOKEN to raise the limit to 5000/hour.*\n",
+                    );
+                }
+            }
+            None => {
+                output.push_str("*Quota unknown - could not reach the GitHub API.*\n");
+            }
+        }
+
+        Ok(output)
+    }
+
+    // ==================== Control Flow Graph (CFG) Tools ====================
+
+    /// Get control flow graph for a specific function
+    pub async fn get_control_flow(&self, repo: &str, path: &str, function: &str) -> Result<String> {
+        let repo_meta = self
+            .repos
+            .get(repo)
+            .ok_or_else(|| anyhow!("Repository '{}' not found", repo))?;
+
+        let full_path = validate_path(&repo_meta.path, path)?;
+        let content = std::fs::read_to_string(&full_path).context("Failed to read file")?;
+
+        // Parse the file
+        let parsed = self.parser.parse_file(&full_path, &content)?;
+
+        // Get the tree (required for CFG analysis)
+        let tree = parsed
+            .tree
+            .as_ref()
+            .ok_or_else(|| anyhow!("Failed to parse file"))?;
+
+        // Build CFGs for all functions
+        let cfgs = cfg::analyze_function(tree, &content, path)?;
+
+        // Find the requested function
+        let cfg = cfgs
+            .iter()
+            .find(|c| c.function_name == function)
+            .ok_or_else(|| anyhow!("Function '{}' not found in {}", function, path))?;
+
+        Ok(cfg.to_markdown())
+    }
+
+    /// Enumerate bounded entry-to-exit paths through a function's CFG,
+    /// listing the branch conditions taken along each path
+    pub async fn get_execution_paths(
+        &self,
+        repo: &str,
+        path: &str,
+        function: &str,
+        max_paths: usize,
+    ) -> Result<String> {
+        let repo_meta = self
+            .repos
+            .get(repo)
+            .ok_or_else(|| anyhow!("Repository '{}' not found", repo))?;
+
+        let full_path = validate_path(&repo_meta.path, path)?;
+        let content = std::fs::read_to_string(&full_path).context("Failed to read file")?;
+
+        let parsed = self.parser.parse_file(&full_path, &content)?;
+        let tree = parsed
+            .tree
+            .as_ref()
+            .ok_or_else(|| anyhow!("Failed to parse file"))?;
+        let cfgs = cfg::analyze_function(tree, &content, path)?;
+
+        let cfg = cfgs
+            .iter()
+            .find(|c| c.function_name == function)
+            .ok_or_else(|| anyhow!("Function '{}' not found in {}", function, path))?;
+
+        let paths = cfg.enumerate_paths(max_paths);
+
+        let mut output = String::new();
+        output.push_str(&format!("# Execution Paths: `{}`\n\n", function));
+        output.push_str(&format!("**File**: `{}`\n\n", path));
+        output.push_str(&format!(
+            "**Paths found**: {} (bounded at {})\n\n",
+            paths.len(),
+            max_paths
+        ));
+
+        for (i, exec_path) in paths.iter().enumerate() {
+            output.push_str(&format!("## Path {}\n\n", i + 1));
+
+            let blocks: Vec<String> = exec_path
+                .blocks
+                .iter()
+                .map(|id| {
+                    cfg.blocks
+                        .get(id)
+                        .map(|b| format!("{} ({})", id, b.label))
+                        .unwrap_or_else(|| id.to_string())
+                })
+                .collect();
+            output.push_str(&format!("**Blocks**: {}\n\n", blocks.join(" → ")));
+
+            if exec_path.conditions.is_empty() {
+                output.push_str("*No branch conditions (straight-line path)*\n\n");
+            } else {
+                output.push_str("**Conditions**:\n");
+                for cond in &exec_path.conditions {
+                    output.push_str(&format!(
+                        "- Line {}: `{}` is **{}**\n",
+                        cond.line, cond.condition, cond.taken
+                    ));
+                }
+                output.push('\n');
+            }
+        }
+
+        if paths.is_empty() {
+            output.push_str("*No entry-to-exit paths found.*\n");
+        }
+
+        Ok(output)
+    }
+
+    /// Find dead (unreachable) code blocks in a file or function
+    pub async fn find_dead_code(
+        &self,
+        repo: &str,
+        path: &str,
+        function: Option<&str>,
+        exclude_tests: Option<bool>,
+    ) -> Result<String> {
+        use crate::security_rules::is_test_file;
+
+        let exclude_tests = exclude_tests.unwrap_or(true);
+        if exclude_tests && is_test_file(path) {
+            return Ok(format!("# Dead Code Analysis: `{}`\n\nSkipped: test file (use exclude_tests=false to include)", path));
+        }
+
+        let repo_meta = self
+            .repos
+            .get(repo)
+            .ok_or_else(|| anyhow!("Repository '{}' not found", repo))?;
+
+        let full_path = validate_path(&repo_meta.path, path)?;
+        let content = std::fs::read_to_string(&full_path).context("Failed to read file")?;
+
+        let parsed = self.parser.parse_file(&full_path, &content)?;
+        let tree = parsed
+            .tree
+            .as_ref()
+            .ok_or_else(|| anyhow!("Failed to parse file"))?;
+        let cfgs = cfg::analyze_function(tree, &content, path)?;
+
+        let mut output = String::new();
+        output.push_str(&format!("# Dead Code Analysis: `{}`\n\n", path));
+
+        let mut total_unreachable = 0;
+
+        for cfg in &cfgs {
+            // Filter by function if specified
+            if let Some(func_name) = function {
+                if cfg.function_name != func_name {
+                    continue;
+                }
+            }
+
+            if !cfg.unreachable_blocks.is_empty() {
+                output.push_str(&format!("## Function: `{}`\n\n", cfg.function_name));
+                output.push_str("### Unreachable Code Blocks\n\n");
+
+                for &block_id in &cfg.unreachable_blocks {
+                    if let Some(block) = cfg.blocks.get(&block_id) {
+                        output.push_str(&format!(
+                            "- **Block {}** (`{}`): lines {}-{}\n",
+                            block_id, block.label, block.start_line, block.end_line
+                        ));
+                        total_unreachable += 1;
+                    }
+                }
+                output.push('\n');
+            }
+        }
+
+        if total_unreachable == 0 {
+            output.push_str("✅ No unreachable code detected.\n");
+        } else {
+            output.push_str(&format!(
+                "\n**Total**: {} unreachable block(s) found.\n",
+                total_unreachable
+            ));
+        }
+
+        Ok(output)
+    }
+
+    // ==================== Data Flow Graph (DFG) Tools ====================
+
+    /// Get data flow analysis for a specific function
+    pub async fn get_data_flow(
+        &self,
+        repo: &str,
+        path: &str,
+        function: &str,
+        format: Option<&str>,
+    ) -> Result<String> {
+        let repo_meta = self
+            .repos
+            .get(repo)
+            .ok_or_else(|| anyhow!("Repository '{}' not found", repo))?;
+
+        let full_path = validate_path(&repo_meta.path, path)?;
+        let content = std::fs::read_to_string(&full_path).context("Failed to read file")?;
+
+        let parsed = self.parser.parse_file(&full_path, &content)?;
+        let tree = parsed
+            .tree
+            .as_ref()
+            .ok_or_else(|| anyhow!("Failed to parse file"))?;
+        let analyses = dfg::analyze_file(tree, &content, path)?;
+
+        // Find the requested function
+        let analysis = analyses
+            .iter()
+            .find(|a| a.function_name == function)
+            .ok_or_else(|| anyhow!("Function '{}' not found in {}", function, path))?;
+
+        match format.unwrap_or("markdown") {
+            "dot" => Ok(analysis.to_dot()),
+            "json" => analysis.to_json(),
+            _ => Ok(analysis.to_markdown()),
+        }
+    }
+
+    /// Get reaching definitions analysis for a function
+    pub async fn get_reaching_definitions(
+        &self,
+        repo: &str,
+        path: &str,
+        function: &str,
+    ) -> Result<String> {
+        let repo_meta = self
+            .repos
+            .get(repo)
+            .ok_or_else(|| anyhow!("Repository '{}' not found", repo))?;
+
+        let full_path = validate_path(&repo_meta.path, path)?;
+        let content = std::fs::read_to_string(&full_path).context("Failed to read file")?;
+
+        let parsed = self.parser.parse_file(&full_path, &content)?;
+        let tree = parsed
+            .tree
+            .as_ref()
+            .ok_or_else(|| anyhow!("Failed to parse file"))?;
+        let cfgs = cfg::analyze_function(tree, &content, path)?;
+
+        let cfg = cfgs
+            .iter()
+            .find(|c| c.function_name == function)
+            .ok_or_else(|| anyhow!("Function '{}' not found in {}", function, path))?;
+
+        let mut analyzer = dfg::DfgAnalyzer::new(cfg, &content);
+        let analysis = analyzer.analyze();
+
+        let mut output = String::new();
+        output.push_str(&format!("# Reaching Definitions: `{}`\n\n", function));
+        output.push_str(&format!("**File**: `{}`\n\n", path));
+
+        output.push_str("## Def-Use Chains\n\n");
+        for chain in &analysis.def_use_chains {
+            output.push_str(&format!(
+                "### `{}` (line {})\n\n",
+                chain.definition.variable, chain.definition.line
+            ));
+
+            if chain.uses.is_empty() {
+                output.push_str("*No uses found (dead store)*\n\n");
+            } else {
+                output.push_str("**Reaches**:\n");
+                for use_ in &chain.uses {
+                    output.push_str(&format!("- Line {}: {:?}\n", use_.line, use_.kind));
+                }
+                output.push('\n');
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// Explain a function by composing the existing analyses into one report:
+    /// signature and doc comment, control-flow shape, data-flow highlights,
+    /// direct callers, and recent git history for the symbol
+    pub async fn explain_function(&self, repo: &str, path: &str, function: &str) -> Result<String> {
+        let repo_meta = self
+            .repos
+            .get(repo)
+            .ok_or_else(|| anyhow!("Repository '{}' not found", repo))?;
+
+        let full_path = validate_path(&repo_meta.path, path)?;
+        let content = std::fs::read_to_string(&full_path).context("Failed to read file")?;
+
+        let mut output = String::new();
+        output.push_str(&format!("# Function: `{}`\n\n", function));
+        output.push_str(&format!("**File**: `{}`\n\n", path));
+
+        // Signature and documentation from the symbol index
+        let symbol = self.symbols.get(repo).and_then(|symbols| {
+            symbols
+                .iter()
+                .find(|s| s.name == function && s.file_path == path)
+                .cloned()
+        });
+
+        if let Some(ref sym) = symbol {
+            output.push_str(&format!(
+                "**Lines**: {}-{} ({} lines)\n\n",
+                sym.start_line,
+                sym.end_line,
+                sym.line_count()
+            ));
+            if let Some(ref sig) = sym.signature {
+                output.push_str(&format!("**Signature**: `{}`\n\n", sig));
+            }
+            if let Some(ref doc) = sym.doc_comment {
+                output.push_str("## Documentation\n\n");
+                output.push_str(doc);
+                output.push_str("\n\n");
+            }
+        }
+
+        // Control-flow shape: branches, loops, and error paths
+        let parsed = self.parser.parse_file(&full_path, &content)?;
+        if let Some(tree) = parsed.tree.as_ref() {
+            match cfg::analyze_function(tree, &content, path) {
+                Ok(cfgs) => {
+                    if let Some(func_cfg) = cfgs.iter().find(|c| c.function_name == function) {
+                        let branches = func_cfg
+                            .edges
+                            .iter()
+                            .filter(|e| e.kind == cfg::EdgeKind::TrueBranch)
+                            .count();
+                        let exceptions = func_cfg
+                            .edges
+                            .iter()
+                            .filter(|e| e.kind == cfg::EdgeKind::Exception)
+                            .count();
+                        let loops = func_cfg.find_loops().len();
+
+                        output.push_str("## Control Flow\n\n");
+                        output
+                            .push_str(&format!("- **Basic blocks**: {}\n", func_cfg.blocks.len()));
+                        output.push_str(&format!("- **Branches**: {}\n", branches));
+                        output.push_str(&format!("- **Loops**: {}\n", loops));
+                        if exceptions > 0 {
+                            output.push_str(&format!("- **Error paths**: {}\n", exceptions));
+                        }
+                        output.push('\n');
+                    } else if symbol.is_none() {
+                        return Err(anyhow!("Function '{}' not found in {}", function, path));
+                    }
+                }
+                Err(e) => {
+                    output.push_str(&format!("## Control Flow\n\n*Not available: {}*\n\n", e));
+                }
+            }
+
+            // Data-flow highlights: only report what stands out
+            if let Ok(analyses) = dfg::analyze_file(tree, &content, path) {
+                if let Some(analysis) = analyses.iter().find(|a| a.function_name == function) {
+                    output.push_str("## Data Flow\n\n");
+                    output.push_str(&format!(
+                        "- **Definitions**: {}, **Uses**: {}\n",
+                        analysis.definitions.len(),
+                        analysis.uses.len()
+                    ));
+                    if !analysis.dead_stores.is_empty() {
+                        output.push_str(&format!(
+                            "- **Dead stores**: {}\n",
+                            analysis.dead_stores.len()
+                        ));
+                    }
+                    if !analysis.uninitialized_uses.is_empty() {
+                        output.push_str(&format!(
+                            "- **Potentially uninitialized uses**: {}\n",
+                            analysis.uninitialized_uses.len()
+                        ));
+                    }
+                    if !analysis.use_after_move.is_empty() {
+                        output.push_str(&format!(
+                            "- **Possible use-after-move**: {}\n",
+                            analysis.use_after_move.len()
+                        ));
+                    }
+                    output.push('\n');
+                }
+            }
+        }
+
+        // Direct callers, when the call graph is enabled
+        if let Some(call_graph) = self.call_graphs.get(repo) {
+            let callers = call_graph.get_callers(function);
+            output.push_str("## Callers\n\n");
+            if callers.is_empty() {
+                output.push_str("*No direct callers found.*\n\n");
+            } else {
+                for caller in callers.iter().take(10) {
+                    output.push_str(&format!(
+                        "- `{}` at `{}:{}`\n",
+                        caller.target, caller.file_path, caller.line
+                    ));
+                }
+                if callers.len() > 10 {
+                    output.push_str(&format!("- ... and {} more\n", callers.len() - 10));
+                }
+                output.push('\n');
+            }
+        } else {
+            output.push_str("## Callers\n\n*Call graph not enabled (--call-graph).*\n\n");
+        }
+
+        // Recent commits touching this symbol, when git is enabled
+        if let Some(git_repo) = self.git_repos.get(repo) {
+            output.push_str("## Recent History\n\n");
+            match git_repo.symbol_history(path, function, 5) {
+                Ok(commits) if !commits.is_empty() => {
+                    for commit in &commits {
+                        let date = crate::git::chrono_lite_format(commit.timestamp);
+                        output.push_str(&format!(
+                            "- `{}` {} — {} ({})\n",
+                            commit.short_hash, date, commit.subject, commit.author
+                        ));
+                    }
+                    output.push('\n');
+                }
+                Ok(_) => output.push_str("*No commits found for this symbol.*\n\n"),
+                Err(e) => output.push_str(&format!("*History not available: {}*\n\n", e)),
+            }
+        } else {
+            output.push_str("## Recent History\n\n*Git integration not enabled (--git).*\n\n");
+        }
+
+        Ok(output)
+    }
+
+    /// Extract HTTP routes registered by popular web frameworks
+    pub async fn get_routes(&self, repo: Option<&str>, framework: Option<&str>) -> Result<String> {
+        // (rel_path, route) pairs grouped per file for stable output
+        let mut found: Vec<(String, crate::extract::Route)> = Vec::new();
+
+        for repo_entry in self.repos.iter() {
+            let repo_name = repo_entry.key();
+            let repo_meta = repo_entry.value();
+
+            if let Some(target_repo) = repo {
+                if repo_name != target_repo && !repo_meta.path.ends_with(target_repo) {
+                    continue;
+                }
+            }
+
+            let repo_path = &repo_meta.path;
+
+            for file_entry in self.file_cache.iter() {
+                let file_path = file_entry.key();
+                if !file_path.starts_with(repo_path) {
+                    continue;
+                }
+                let rel_path = file_path
+                    .strip_prefix(repo_path)
+                    .unwrap_or(file_path)
+                    .to_string_lossy()
+                    .to_string();
+
+                for route in crate::extract::extract_routes(file_entry.value(), &rel_path) {
+                    if let Some(fw) = framework {
+                        if !route.framework.eq_ignore_ascii_case(fw) {
+                            continue;
+                        }
+                    }
+                    found.push((rel_path.clone(), route));
+                }
+            }
+        }
+
+        found.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.line.cmp(&b.1.line)));
+
+        let mut output = String::new();
+        output.push_str("# HTTP Routes\n\n");
+        if let Some(fw) = framework {
+            output.push_str(&format!("**Framework filter**: {}\n", fw));
+        }
+        output.push_str(&format!("**Routes found**: {}\n\n", found.len()));
+
+        if found.is_empty() {
+            output.push_str("No routes found. Supported frameworks: axum, actix-web, express, flask, fastapi, spring.\n");
+            return Ok(output);
+        }
+
+        let mut current_file = "";
+        for (rel_path, route) in &found {
+            if rel_path != current_file {
+                if !current_file.is_empty() {
+                    output.push('\n');
+                }
+                output.push_str(&format!("## {}\n\n", rel_path));
+                output.push_str("| Method | Path | Handler | Framework | Middleware |\n");
+                output.push_str("|--------|------|---------|-----------|------------|\n");
+                current_file = rel_path;
+            }
+            output.push_str(&format!(
+                "| {} | `{}` | `{}` | {} | {} |\n",
+                route.method,
+                route.path,
+                if route.handler.is_empty() {
+                    "?"
+                } else {
+                    &route.handler
+                },
+                route.framework,
+                if route.middleware.is_empty() {
+                    "-".to_string()
+                } else {
+                    route.middleware.join(", ")
+                }
+            ));
+        }
+
+        Ok(output)
+    }
+
+    /// Report routes with no recognizable auth protection, cross-referenced
+    /// with taint sinks reachable from their handlers — the unauthenticated
+    /// attack surface of the indexed repos
+    pub async fn find_unprotected_routes(&self, repo: Option<&str>) -> Result<String> {
+        /// Names that indicate auth middleware, guards, or decorators
+        const AUTH_KEYWORDS: &[&str] = &[
+            "auth",
+            "jwt",
+            "login",
+            "session",
+            "token",
+            "guard",
+            "permission",
+            "secure",
+            "protect",
+            "oauth",
+            "apikey",
+            "api_key",
+            "acl",
+            "role",
+            "verify",
+        ];
+        /// Auth decorators/annotations that appear next to the route, not in
+        /// its middleware list (e.g. Flask `@login_required`, Spring
+        /// `@PreAuthorize`)
+        const AUTH_DECORATORS: &[&str] = &[
+            "@login_required",
+            "@jwt_required",
+            "@permission_required",
+            "@requires_auth",
+            "@PreAuthorize",
+            "@Secured",
+            "@RolesAllowed",
+        ];
+
+        let is_auth_name = |name: &str| {
+            let lower = name.to_lowercase();
+            AUTH_KEYWORDS.iter().any(|kw| lower.contains(kw))
+        };
+
+        let mut unprotected: Vec<(String, crate::extract::Route, Vec<String>)> = Vec::new();
+        let mut total_routes = 0usize;
+
+        for repo_entry in self.repos.iter() {
+            let repo_name = repo_entry.key();
+            let repo_meta = repo_entry.value();
+
+            if let Some(target_repo) = repo {
+                if repo_name != target_repo && !repo_meta.path.ends_with(target_repo) {
+                    continue;
+                }
+            }
+
+            let repo_path = &repo_meta.path;
+            let custom_taint = crate::security_config::CustomTaintConfig::load_from_repo(repo_path);
+
+            for file_entry in self.file_cache.iter() {
+                let file_path = file_entry.key();
+                if !file_path.starts_with(repo_path) {
+                    continue;
+                }
+                let rel_path = file_path
+                    .strip_prefix(repo_path)
+                    .unwrap_or(file_path)
+                    .to_string_lossy()
+                    .to_string();
+
+                let content = file_entry.value();
+                let routes = crate::extract::extract_routes(content, &rel_path);
+                if routes.is_empty() {
+                    continue;
+                }
+                total_routes += routes.len();
+
+                let lines: Vec<&str> = content.lines().collect();
+                // Taint sinks for the whole file, resolved lazily on first use
+                let mut file_sinks: Option<Vec<crate::taint::TaintSink>> = None;
+
+                for route in routes {
+                    // Protected via middleware name?
+                    if route.middleware.iter().any(|m| is_auth_name(m)) {
+                        continue;
+                    }
+                    // Protected via an adjacent decorator/annotation stack?
+                    let start = route.line.saturating_sub(4);
+                    let end = (route.line + 3).min(lines.len());
+                    let vicinity = lines[start..end].join("\n");
+                    if AUTH_DECORATORS.iter().any(|d| vicinity.contains(d)) {
+                        continue;
+                    }
+
+                    // Cross-reference taint sinks within the handler's span
+                    let sinks = file_sinks.get_or_insert_with(|| {
+                        crate::taint::analyze_code_with_config(
+                            content,
+                            &file_path.to_string_lossy(),
+                            custom_taint.as_ref(),
+                        )
+                        .sinks
+                    });
+
+                    let handler_span = self.symbols.get(repo_name).and_then(|symbols| {
+                        symbols
+                            .iter()
+                            .find(|s| s.name == route.handler && s.file_path == rel_path)
+                            .map(|s| (s.start_line, s.end_line))
+                    });
+
+                    let reachable: Vec<String> = sinks
+                        .iter()
+                        .filter(|sink| match handler_span {
+                            Some((start, end)) => sink.line >= start && sink.line <= end,
+                            // Without a resolved handler span, report sinks
+                            // anywhere in the file as potentially reachable
+                            None => true,
+                        })
+                        .map(|sink| format!("{} (line {})", sink.kind.display_name(), sink.line))
+                        .collect();
+
+                    unprotected.push((rel_path.clone(), route, reachable));
+                }
+            }
+        }
+
+        unprotected.sort_by(|a, b| {
+            // Routes that reach sinks first, then by location
+            a.2.is_empty()
+                .cmp(&b.2.is_empty())
+                .then(a.0.cmp(&b.0))
+                .then(a.1.line.cmp(&b.1.line))
+        });
+
+        let mut output = String::new();
+        output.push_str("# Unauthenticated Attack Surface\n\n");
+        output.push_str(&format!("**Routes analyzed**: {}\n", total_routes));
+        output.push_str(&format!(
+            "**Routes without recognizable auth**: {}\n",
+            unprotected.len()
+        ));
+        let with_sinks = unprotected.iter().filter(|(_, _, s)| !s.is_empty()).count();
+        output.push_str(&format!(
+            "**Of those, reaching taint sinks**: {}\n\n",
+            with_sinks
+        ));
+
+        if total_routes == 0 {
+            output.push_str("No routes found. Supported frameworks: axum, actix-web, express, flask, fastapi, spring.\n");
+            return Ok(output);
+        }
+        if unprotected.is_empty() {
+            output.push_str(
+                "All detected routes carry recognizable auth middleware or decorators.\n",
+            );
+            return Ok(output);
+        }
+
+        for (rel_path, route, sinks) in &unprotected {
+            let marker = if sinks.is_empty() { "⚠️" } else { "🔴" };
+            output.push_str(&format!(
+                "## {} {} `{}` — `{}`\n\n",
+                marker, route.method, route.path, rel_path
+            ));
+            output.push_str(&format!(
+                "- **Handler**: `{}` (line {})\n",
+                if route.handler.is_empty() {
+                    "?"
+                } else {
+                    &route.handler
+                },
+                route.line
+            ));
+            output.push_str(&format!("- **Framework**: {}\n", route.framework));
+            if !route.middleware.is_empty() {
+                output.push_str(&format!(
+                    "- **Middleware (non-auth)**: {}\n",
+                    route.middleware.join(", ")
+                ));
+            }
+            if !sinks.is_empty() {
+                output.push_str(&format!("- **Reachable sinks**: {}\n", sinks.join("; ")));
+            }
+            output.push('\n');
+        }
+
+        output.push_str(
+            "---\n*Auth detection is name-based; verify findings before acting. \
+            Routes marked 🔴 reach dangerous sinks without recognizable auth.*\n",
+        );
+
+        Ok(output)
+    }
+
+    /// Inventory environment-variable and config-key reads across the
+    /// indexed repos: each knob with its defaults and usage sites
+    pub async fn find_config_usage(&self, repo: Option<&str>) -> Result<String> {
+        // (kind, key) -> (defaults seen, usage sites as "file:line")
+        type KnobMap =
+            std::collections::BTreeMap<(&'static str, String), (Vec<String>, Vec<String>)>;
+        let mut knobs = KnobMap::new();
+
+        for repo_entry in self.repos.iter() {
+            let repo_name = repo_entry.key();
+            let repo_meta = repo_entry.value();
+
+            if let Some(target_repo) = repo {
+                if repo_name != target_repo && !repo_meta.path.ends_with(target_repo) {
+                    continue;
+                }
+            }
+
+            let repo_path = &repo_meta.path;
+
+            for file_entry in self.file_cache.iter() {
+                let file_path = file_entry.key();
+                if !file_path.starts_with(repo_path) {
+                    continue;
+                }
+                let rel_path = file_path
+                    .strip_prefix(repo_path)
+                    .unwrap_or(file_path)
+                    .to_string_lossy()
+                    .to_string();
+
+                for usage in crate::extract::extract_config_usage(file_entry.value()) {
+                    let entry = knobs
+                        .entry((usage.kind, usage.key))
+                        .or_insert_with(|| (Vec::new(), Vec::new()));
+                    if let Some(default) = usage.default {
+                        if !entry.0.contains(&default) {
+                            entry.0.push(default);
+                        }
+                    }
+                    entry.1.push(format!("{}:{}", rel_path, usage.line));
+                }
+            }
+        }
+
+        let mut output = String::new();
+        output.push_str("# Configuration Usage Inventory\n\n");
+        output.push_str(&format!("**Knobs found**: {}\n\n", knobs.len()));
+
+        if knobs.is_empty() {
+            output.push_str(
+                "No environment-variable or config-key reads found. Recognized patterns: \
+                 env::var, os.environ, os.getenv, process.env, os.Getenv, System.getenv, \
+                 config.get.\n",
+            );
+            return Ok(output);
+        }
+
+        // Environment variables first, then config keys
+        for (section, kind) in [
+            ("## Environment Variables", "env"),
+            ("## Config Keys", "config"),
+        ] {
+            let group: Vec<_> = knobs.iter().filter(|((k, _), _)| *k == kind).collect();
+            if group.is_empty() {
+                continue;
+            }
+            output.push_str(&format!("{}\n\n", section));
+            output.push_str("| Key | Default | Reads | Usage Sites |\n");
+            output.push_str("|-----|---------|-------|-------------|\n");
+            for ((_, key), (defaults, sites)) in group {
+                let default = if defaults.is_empty() {
+                    "-".to_string()
+                } else {
+                    defaults
+                        .iter()
+                        .map(|d| format!("`{}`", d))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                };
+                let shown: Vec<String> = sites.iter().take(5).map(|s| format!("`{}`", s)).collect();
+                let site_list = if sites.len() > 5 {
+                    format!("{} (+{} more)", shown.join(", "), sites.len() - 5)
+                } else {
+                    shown.join(", ")
+                };
+                output.push_str(&format!(
+                    "| `{}` | {} | {} | {} |\n",
+                    key,
+                    default,
+                    sites.len(),
+                    site_list
+                ));
+            }
+            output.push('\n');
+        }
+
+        Ok(output)
+    }
+
+    /// List feature-flag checks across the indexed repos, flagging flags
+    /// that never appear in any config file — likely dead toggles
+    pub async fn find_feature_flags(&self, repo: Option<&str>) -> Result<String> {
+        // flag name -> (sdks seen, usage sites as "file:line")
+        let mut flags: std::collections::BTreeMap<String, (Vec<&'static str>, Vec<String>)> =
+            std::collections::BTreeMap::new();
+        // Concatenated config-file contents per repo, for the toggle check
+        let mut config_haystack = String::new();
+
+        for repo_entry in self.repos.iter() {
+            let repo_name = repo_entry.key();
+            let repo_meta = repo_entry.value();
+
+            if let Some(target_repo) = repo {
+                if repo_name != target_repo && !repo_meta.path.ends_with(target_repo) {
+                    continue;
+                }
+            }
+
+            let repo_path = &repo_meta.path;
+
+            for file_entry in self.file_cache.iter() {
+                let file_path = file_entry.key();
+                if !file_path.starts_with(repo_path) {
+                    continue;
+                }
+                let rel_path = file_path
+                    .strip_prefix(repo_path)
+                    .unwrap_or(file_path)
+                    .to_string_lossy()
+                    .to_string();
+
+                for flag in crate::extract::extract_feature_flags(file_entry.value()) {
+                    let entry = flags.entry(flag.name).or_default();
+                    if !entry.0.contains(&flag.sdk) {
+                        entry.0.push(flag.sdk);
+                    }
+                    entry.1.push(format!("{}:{}", rel_path, flag.line));
+                }
+            }
+
+            // Config files are not in the file cache (only parsed source is),
+            // so walk the repo for them, honoring ignore files like indexing
+            for entry in ignore::WalkBuilder::new(repo_path)
+                .build()
+                .filter_map(|e| e.ok())
+            {
+                let path = entry.path();
+                if !path.is_file() {
+                    continue;
+                }
+                let name = path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                let ext = path
+                    .extension()
+                    .map(|e| e.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                let is_config = matches!(
+                    ext.as_str(),
+                    "yaml" | "yml" | "toml" | "json" | "ini" | "properties"
+                ) || name.starts_with(".env");
+                if !is_config {
+                    continue;
+                }
+                // Skip anything suspiciously large (lockfiles, bundles)
+                if entry
+                    .metadata()
+                    .map(|m| m.len() > 512 * 1024)
+                    .unwrap_or(true)
+                {
+                    continue;
+                }
+                if let Ok(content) = std::fs::read_to_string(path) {
+                    config_haystack.push_str(&content);
+                    config_haystack.push('\n');
+                }
+            }
+        }
+
+        let mut output = String::new();
+        output.push_str("# Feature Flags\n\n");
+        output.push_str(&format!("**Flags found**: {}\n\n", flags.len()));
+
+        if flags.is_empty() {
+            output.push_str(
+                "No feature-flag checks found. Recognized: LaunchDarkly variation calls, \
+                 Unleash isEnabled, cfg!(feature = ...), and FEATURE/FLAG/ENABLE env reads.\n",
+            );
+            return Ok(output);
+        }
+
+        output.push_str("| Flag | SDK | Checks | Call Sites | In Config |\n");
+        output.push_str("|------|-----|--------|------------|----------|\n");
+        let mut dead: Vec<&str> = Vec::new();
+        for (name, (sdks, sites)) in &flags {
+            let toggled = config_haystack.contains(name.as_str());
+            if !toggled {
+                dead.push(name);
+            }
+            let shown: Vec<String> = sites.iter().take(3).map(|s| format!("`{}`", s)).collect();
+            let site_list = if sites.len() > 3 {
+                format!("{} (+{} more)", shown.join(", "), sites.len() - 3)
+            } else {
+                shown.join(", ")
+            };
+            output.push_str(&format!(
+                "| `{}` | {} | {} | {} | {} |\n",
+                name,
+                sdks.join(", "),
+                sites.len(),
+                site_list,
+                if toggled { "yes" } else { "**no**" }
+            ));
+        }
+        output.push('\n');
+
+        if !dead.is_empty() {
+            output.push_str("## Apparently Dead Flags\n\n");
+            output.push_str(
+                "Checked in code but never mentioned in any config file — \
+                 candidates for removal:\n\n",
+            );
+            for name in &dead {
+                output.push_str(&format!("- `{}`\n", name));
+            }
+            output.push('\n');
+        }
+
+        Ok(output)
+    }
+
+    /// Audit error handling: panicking calls in non-test Rust, bare excepts
+    /// in Python, and swallowed promise rejections in JavaScript, each rated
+    /// by where it sits (request handlers are highest risk)
+    pub async fn audit_error_handling(&self, repo: Option<&str>) -> Result<String> {
+        use crate::security_rules::is_test_file;
+
+        // (risk 0=High 1=Medium 2=Low, file, line, pattern, enclosing fn, snippet)
+        let mut findings: Vec<(u8, String, usize, String, String, String)> = Vec::new();
+
+        for repo_entry in self.repos.iter() {
+            let repo_name = repo_entry.key();
+            let repo_meta = repo_entry.value();
+
+            if let Some(target_repo) = repo {
+                if repo_name != target_repo && !repo_meta.path.ends_with(target_repo) {
+                    continue;
+                }
+            }
+
+            let repo_path = &repo_meta.path;
+
+            for file_entry in self.file_cache.iter() {
+                let file_path = file_entry.key();
+                if !file_path.starts_with(repo_path) {
+                    continue;
+                }
+                let path_str = file_path.to_string_lossy();
+                if is_test_file(&path_str) {
+                    continue;
+                }
+                let ext = file_path
+                    .extension()
+                    .map(|e| e.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                let rel_path = file_path
+                    .strip_prefix(repo_path)
+                    .unwrap_or(file_path)
+                    .to_string_lossy()
+                    .to_string();
+
+                let content = file_entry.value();
+                let lines: Vec<&str> = content.lines().collect();
+
+                // Route handlers in this file are the highest-risk locations
+                let handler_names: HashSet<String> =
+                    crate::extract::extract_routes(content, &rel_path)
+                        .into_iter()
+                        .map(|r| r.handler)
+                        .collect();
+
+                // In Rust files, ignore everything from an inline test module on
+                let cutoff = if ext == "rs" {
+                    lines
+                        .iter()
+                        .position(|l| l.trim_start().starts_with("#[cfg(test)]"))
+                        .unwrap_or(lines.len())
+                } else {
+                    lines.len()
+                };
+
+                for (i, line) in lines.iter().enumerate().take(cutoff) {
+                    let line_no = i + 1;
+                    let trimmed = line.trim();
+                    // Comment and attribute lines can't register real findings
+                    if trimmed.starts_with("//") || trimmed.starts_with('#') {
+                        continue;
+                    }
+
+                    let pattern: Option<&str> = match ext.as_str() {
+                        "rs" => {
+                            if trimmed.contains(".unwrap()") {
+                                Some(".unwrap()")
+                            } else if trimmed.contains(".expect(") {
+                                Some(".expect(...)")
+                            } else if trimmed.contains("panic!(") {
+                                Some("panic!")
+                            } else {
+                                None
+                            }
+                        }
+                        "py" => {
+                            if trimmed == "except:" || trimmed.starts_with("except:") {
+                                Some("bare except:")
+                            } else {
+                                None
+                            }
+                        }
+                        "js" | "jsx" | "ts" | "tsx" | "mjs" | "cjs" => {
+                            let compact: String =
+                                trimmed.chars().filter(|c| !c.is_whitespace()).collect();
+                            if compact.contains(".catch(()=>{})")
+                                || compact.contains(".catch(function(){})")
+                                || compact.contains(".catch(_=>{})")
+                            {
+                                Some("swallowed rejection")
+                            } else if compact.contains("catch(") && compact.ends_with("){}") {
+                                Some("empty catch block")
+                            } else {
+                                None
+                            }
+                        }
+                        _ => None,
+                    };
+
+                    let Some(pattern) = pattern else {
+                        continue;
+                    };
+
+                    // Enclosing function and its risk
+                    let enclosing = self.symbols.get(repo_name).and_then(|symbols| {
+                        symbols
+                            .iter()
+                            .filter(|s| {
+                                s.file_path == rel_path
+                                    && s.start_line <= line_no
+                                    && s.end_line >= line_no
+                            })
+                            .min_by_key(|s| s.end_line - s.start_line)
+                            .cloned()
+                    });
+
+                    let (risk, fn_name) = match &enclosing {
+                        Some(sym) if handler_names.contains(&sym.name) => (0u8, sym.name.clone()),
+                        Some(sym) => {
+                            let public = sym
+                                .signature
+                                .as_deref()
+                                .map(|s| s.starts_with("pub ") || s.contains("export "))
+                                .unwrap_or(false);
+                            (if public { 1 } else { 2 }, sym.name.clone())
+                        }
+                        None => (2, "-".to_string()),
+                    };
+
+                    // A couple of lines either side for context
+                    let start = i.saturating_sub(1);
+                    let end = (i + 2).min(lines.len());
+                    let snippet = lines[start..end].join("\n");
+
+                    findings.push((
+                        risk,
+                        rel_path.clone(),
+                        line_no,
+                        pattern.to_string(),
+                        fn_name,
+                        snippet,
+                    ));
+                }
+            }
+        }
+
+        findings.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.cmp(&b.1)).then(a.2.cmp(&b.2)));
+
+        let mut output = String::new();
+        output.push_str("# Error Handling Audit\n\n");
+        output.push_str(&format!("**Findings**: {}\n\n", findings.len()));
+
+        if findings.is_empty() {
+            output.push_str("No risky error handling found in non-test code.\n");
+            return Ok(output);
+        }
+
+        let risk_names = ["High (request handler)", "Medium (public API)", "Low"];
+        let mut current_risk = u8::MAX;
+        for (risk, rel_path, line_no, pattern, fn_name, snippet) in &findings {
+            if *risk != current_risk {
+                output.push_str(&format!("## {}\n\n", risk_names[*risk as usize]));
+                current_risk = *risk;
+            }
+            output.push_str(&format!("### {} — `{}:{}`\n\n", pattern, rel_path, line_no));
+            if fn_name != "-" {
+                output.push_str(&format!("In `{}`:\n\n", fn_name));
+            }
+            output.push_str(&format!("```\n{}\n```\n\n", snippet));
+        }
+
+        Ok(output)
+    }
+
+    /// Inventory every `unsafe` block, fn, impl, and trait in the indexed
+    /// Rust code, noting the enclosing function, span size, and whether a
+    /// `// SAFETY:` comment justifies it — aggregated per file
+    pub async fn find_unsafe(&self, repo: Option<&str>) -> Result<String> {
+        // (file, line, kind, enclosing fn, LOC, has SAFETY comment)
+        let mut findings: Vec<(String, usize, &'static str, String, usize, bool)> = Vec::new();
+
+        for repo_entry in self.repos.iter() {
+            let repo_name = repo_entry.key();
+            let repo_meta = repo_entry.value();
+
+            if let Some(target_repo) = repo {
+                if repo_name != target_repo && !repo_meta.path.ends_with(target_repo) {
+                    continue;
+                }
+            }
+
+            let repo_path = &repo_meta.path;
+
+            for file_entry in self.file_cache.iter() {
+                let file_path = file_entry.key();
+                if !file_path.starts_with(repo_path)
+                    || file_path.extension().and_then(|e| e.to_str()) != Some("rs")
+                {
+                    continue;
+                }
+                let rel_path = file_path
+                    .strip_prefix(repo_path)
+                    .unwrap_or(file_path)
+                    .to_string_lossy()
+                    .to_string();
+
+                let lines: Vec<&str> = file_entry.value().lines().collect();
+
+                for (i, line) in lines.iter().enumerate() {
+                    let trimmed = line.trim_start();
+                    if trimmed.starts_with("//") {
+                        continue;
+                    }
+                    let Some(pos) = line.find("unsafe") else {
+                        continue;
+                    };
+                    // Keyword boundary: not part of a longer identifier
+                    let before_ok = pos == 0
+                        || !line[..pos].ends_with(|c: char| c.is_alphanumeric() || c == '_');
+                    let after = &line[pos + "unsafe".len()..];
+                    let after_ok = !after.starts_with(|c: char| c.is_alphanumeric() || c == '_');
+                    if !before_ok || !after_ok {
+                        continue;
+                    }
+
+                    let rest = after.trim_start();
+                    let kind = if rest.starts_with("fn ") {
+                        "unsafe fn"
+                    } else if rest.starts_with("impl") {
+                        "unsafe impl"
+                    } else if rest.starts_with("trait ") {
+                        "unsafe trait"
+                    } else if rest.starts_with('{') || rest.is_empty() {
+                        "unsafe block"
+                    } else {
+                        continue;
+                    };
 
-        let remote = crate::remote::RemoteRepo::from_url(url)?;
+                    // Span: brace-match from the unsafe keyword onward
+                    let loc = {
+                        let mut depth = 0i32;
+                        let mut seen_open = false;
+                        let mut end_line = i;
+                        'outer: for (j, l) in lines.iter().enumerate().skip(i) {
+                            let scan = if j == i { &line[pos..] } else { l };
+                            for c in scan.chars() {
+                                match c {
+                                    '{' => {
+                                        depth += 1;
+                                        seen_open = true;
+                                    }
+                                    '}' => depth -= 1,
+                                    _ => {}
+                                }
+                                if seen_open && depth == 0 {
+                                    end_line = j;
+                                    break 'outer;
+                                }
+                            }
+                            end_line = j;
+                        }
+                        end_line - i + 1
+                    };
 
-        let content = {
-            let mgr = manager.lock().await;
-            mgr.get_file(&remote, path).await?
-        };
+                    // SAFETY comment on the same line or just above
+                    let has_safety = line.to_uppercase().contains("SAFETY")
+                        || lines[i.saturating_sub(3)..i].iter().any(|l| {
+                            let t = l.trim_start();
+                            (t.starts_with("//") || t.starts_with("/*"))
+                                && t.to_uppercase().contains("SAFETY")
+                        });
+
+                    let enclosing = self
+                        .symbols
+                        .get(repo_name)
+                        .and_then(|symbols| {
+                            symbols
+                                .iter()
+                                .filter(|s| {
+                                    s.file_path == rel_path
+                                        && s.start_line <= i + 1
+                                        && s.end_line > i
+                                })
+                                .min_by_key(|s| s.end_line - s.start_line)
+                                .map(|s| s.name.clone())
+                        })
+                        .unwrap_or_else(|| "-".to_string());
+
+                    findings.push((rel_path.clone(), i + 1, kind, enclosing, loc, has_safety));
+                }
+            }
+        }
+
+        findings.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.cmp(&b.1)));
 
         let mut output = String::new();
-        output.push_str(&format!("# {} from {}\n\n", path, remote.identifier()));
+        output.push_str("# Unsafe Code Inventory\n\n");
+        let undocumented = findings.iter().filter(|f| !f.5).count();
+        output.push_str(&format!("**Unsafe sites**: {}\n", findings.len()));
+        output.push_str(&format!(
+            "**Missing SAFETY comments**: {}\n\n",
+            undocumented
+        ));
 
-        let lines: Vec<&str> = content.lines().collect();
-        output.push_str(&format!("**Lines**: {}\n\n", lines.len()));
+        if findings.is_empty() {
+            output.push_str("No unsafe code found.\n");
+            return Ok(output);
+        }
 
-        output.push_str("```");
-        output.push_str(get_language_id(path));
+        // Per-file summary, then detail rows
+        output.push_str("## By File\n\n");
+        output.push_str("| File | Sites | Undocumented |\n");
+        output.push_str("|------|-------|---------------|\n");
+        let mut by_file: Vec<(&str, usize, usize)> = Vec::new();
+        for (file, _, _, _, _, safety) in &findings {
+            match by_file.last_mut() {
+                Some((f, sites, undoc)) if *f == file.as_str() => {
+                    *sites += 1;
+                    if !safety {
+                        *undoc += 1;
+                    }
+                }
+                _ => by_file.push((file, 1, usize::from(!safety))),
+            }
+        }
+        for (file, sites, undoc) in &by_file {
+            output.push_str(&format!("| `{}` | {} | {} |\n", file, sites, undoc));
+        }
         output.push('\n');
-        output.push_str(&content);
-        output.push_str("\n```\n");
+
+        output.push_str("## Sites\n\n");
+        output.push_str("| Location | Kind | Enclosing Fn | LOC | SAFETY |\n");
+        output.push_str("|----------|------|--------------|-----|--------|\n");
+        for (file, line, kind, enclosing, loc, safety) in &findings {
+            output.push_str(&format!(
+                "| `{}:{}` | {} | `{}` | {} | {} |\n",
+                file,
+                line,
+                kind,
+                enclosing,
+                loc,
+                if *safety { "yes" } else { "**missing**" }
+            ));
+        }
 
         Ok(output)
     }
 
-    // ==================== Control Flow Graph (CFG) Tools ====================
+    /// Catalog concurrency primitives and flag risky lock usage: locks held
+    /// across an `.await` and lock pairs acquired in inconsistent orders
+    /// (expanded one call-graph level deep)
+    pub async fn audit_concurrency(&self, repo: Option<&str>) -> Result<String> {
+        // Primitive declarations: (primitive, "file:line")
+        let mut inventory: Vec<(&'static str, String)> = Vec::new();
+        // Possible std locks held across await: (file, line, lock expr, fn)
+        let mut across_await: Vec<(String, usize, String, String)> = Vec::new();
+        // Per function: ordered lock acquisitions and outgoing calls by line
+        let mut fn_locks: HashMap<String, Vec<(usize, String)>> = HashMap::new();
+
+        const PRIMITIVES: &[&str] = &[
+            "Mutex<",
+            "RwLock<",
+            "Condvar",
+            "Semaphore",
+            "mpsc::channel",
+            "broadcast::channel",
+            "watch::channel",
+            "oneshot::channel",
+            "crossbeam::channel",
+        ];
+
+        /// Receiver of a lock call: `self.state.lock()` -> `self.state`
+        fn lock_receiver(line: &str, pos: usize) -> String {
+            let prefix: String = line[..pos]
+                .chars()
+                .rev()
+                .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '.')
+                .collect();
+            prefix
+                .chars()
+                .rev()
+                .collect::<String>()
+                .trim_matches('.')
+                .to_string()
+        }
 
-    /// Get control flow graph for a specific function
-    pub async fn get_control_flow(&self, repo: &str, path: &str, function: &str) -> Result<String> {
-        let repo_meta = self
-            .repos
-            .get(repo)
-            .ok_or_else(|| anyhow!("Repository '{}' not found", repo))?;
+        for repo_entry in self.repos.iter() {
+            let repo_name = repo_entry.key();
+            let repo_meta = repo_entry.value();
 
-        let full_path = validate_path(&repo_meta.path, path)?;
-        let content = std::fs::read_to_string(&full_path).context("Failed to read file")?;
+            if let Some(target_repo) = repo {
+                if repo_name != target_repo && !repo_meta.path.ends_with(target_repo) {
+                    continue;
+                }
+            }
 
-        // Parse the file
-        let parsed = self.parser.parse_file(&full_path, &content)?;
+            let repo_path = &repo_meta.path;
 
-        // Get the tree (required for CFG analysis)
-        let tree = parsed
-            .tree
-            .as_ref()
-            .ok_or_else(|| anyhow!("Failed to parse file"))?;
+            for file_entry in self.file_cache.iter() {
+                let file_path = file_entry.key();
+                if !file_path.starts_with(repo_path)
+                    || file_path.extension().and_then(|e| e.to_str()) != Some("rs")
+                {
+                    continue;
+                }
+                let rel_path = file_path
+                    .strip_prefix(repo_path)
+                    .unwrap_or(file_path)
+                    .to_string_lossy()
+                    .to_string();
 
-        // Build CFGs for all functions
-        let cfgs = cfg::analyze_function(tree, &content, path)?;
+                let lines: Vec<&str> = file_entry.value().lines().collect();
 
-        // Find the requested function
-        let cfg = cfgs
-            .iter()
-            .find(|c| c.function_name == function)
-            .ok_or_else(|| anyhow!("Function '{}' not found in {}", function, path))?;
+                for (i, line) in lines.iter().enumerate() {
+                    let trimmed = line.trim_start();
+                    if trimmed.starts_with("//") {
+                        continue;
+                    }
 
-        Ok(cfg.to_markdown())
-    }
+                    for prim in PRIMITIVES {
+                        if line.contains(prim) {
+                            inventory.push((prim, format!("{}:{}", rel_path, i + 1)));
+                        }
+                    }
 
-    /// Find dead (unreachable) code blocks in a file or function
-    pub async fn find_dead_code(
-        &self,
-        repo: &str,
-        path: &str,
-        function: Option<&str>,
-        exclude_tests: Option<bool>,
-    ) -> Result<String> {
-        use crate::security_rules::is_test_file;
+                    // Lock acquisitions; `.lock().await` is an async lock and
+                    // safe to hold across suspension points
+                    for needle in [".lock()", ".write()", ".read()"] {
+                        let Some(pos) = line.find(needle) else {
+                            continue;
+                        };
+                        let receiver = lock_receiver(line, pos);
+                        if receiver.is_empty() {
+                            continue;
+                        }
+                        let is_async_lock = line[pos..].contains(".await");
+
+                        let enclosing = self.symbols.get(repo_name).and_then(|symbols| {
+                            symbols
+                                .iter()
+                                .filter(|s| {
+                                    s.file_path == rel_path
+                                        && s.start_line <= i + 1
+                                        && s.end_line > i
+                                })
+                                .min_by_key(|s| s.end_line - s.start_line)
+                                .cloned()
+                        });
+                        let Some(sym) = enclosing else {
+                            continue;
+                        };
 
-        let exclude_tests = exclude_tests.unwrap_or(true);
-        if exclude_tests && is_test_file(path) {
-            return Ok(format!("# Dead Code Analysis: `{}`\n\nSkipped: test file (use exclude_tests=false to include)", path));
+                        fn_locks
+                            .entry(sym.name.clone())
+                            .or_default()
+                            .push((i + 1, receiver.clone()));
+
+                        if !is_async_lock {
+                            // Any await later in the same function suggests the
+                            // guard may live across the suspension point
+                            let awaits_after = lines[i + 1..sym.end_line.min(lines.len())]
+                                .iter()
+                                .any(|l| l.contains(".await"));
+                            if awaits_after {
+                                across_await.push((
+                                    rel_path.clone(),
+                                    i + 1,
+                                    format!("{}{}", receiver, needle),
+                                    sym.name.clone(),
+                                ));
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Expand lock sequences one call-graph level: locks taken by a
+            // callee count as taken at the call site's line
+            if let Some(call_graph) = self.call_graphs.get(repo_name) {
+                let direct = fn_locks.clone();
+                for (func, locks) in fn_locks.iter_mut() {
+                    for edge in call_graph.get_callees(func) {
+                        if let Some(callee_locks) = direct.get(&edge.target) {
+                            for (_, lock) in callee_locks {
+                                locks.push((edge.line, lock.clone()));
+                            }
+                        }
+                    }
+                    locks.sort();
+                }
+            }
         }
 
-        let repo_meta = self
-            .repos
-            .get(repo)
-            .ok_or_else(|| anyhow!("Repository '{}' not found", repo))?;
+        // Inconsistent ordering: pair (a, b) acquired in both orders anywhere
+        let mut pair_order: HashMap<(String, String), Vec<String>> = HashMap::new();
+        for (func, locks) in &fn_locks {
+            for (idx, (_, a)) in locks.iter().enumerate() {
+                for (_, b) in locks.iter().skip(idx + 1) {
+                    if a != b {
+                        pair_order
+                            .entry((a.clone(), b.clone()))
+                            .or_default()
+                            .push(func.clone());
+                    }
+                }
+            }
+        }
+        type Inversion = ((String, String), Vec<String>, Vec<String>);
+        let mut inversions: Vec<Inversion> = Vec::new();
+        for ((a, b), fns) in &pair_order {
+            if a < b {
+                if let Some(rev_fns) = pair_order.get(&(b.clone(), a.clone())) {
+                    inversions.push(((a.clone(), b.clone()), fns.clone(), rev_fns.clone()));
+                }
+            }
+        }
+        inversions.sort();
 
-        let full_path = validate_path(&repo_meta.path, path)?;
-        let content = std::fs::read_to_string(&full_path).context("Failed to read file")?;
+        let mut output = String::new();
+        output.push_str("# Concurrency Audit\n\n");
+        output.push_str(&format!(
+            "**Primitive declarations**: {}\n",
+            inventory.len()
+        ));
+        output.push_str(&format!(
+            "**Possible locks held across await**: {}\n",
+            across_await.len()
+        ));
+        output.push_str(&format!(
+            "**Inconsistent lock orderings**: {}\n\n",
+            inversions.len()
+        ));
 
-        let parsed = self.parser.parse_file(&full_path, &content)?;
-        let tree = parsed
-            .tree
-            .as_ref()
-            .ok_or_else(|| anyhow!("Failed to parse file"))?;
-        let cfgs = cfg::analyze_function(tree, &content, path)?;
+        if !inventory.is_empty() {
+            output.push_str("## Primitive Inventory\n\n");
+            let mut counts: std::collections::BTreeMap<&str, (usize, Vec<&str>)> =
+                std::collections::BTreeMap::new();
+            for (prim, site) in &inventory {
+                let entry = counts.entry(prim).or_default();
+                entry.0 += 1;
+                if entry.1.len() < 5 {
+                    entry.1.push(site);
+                }
+            }
+            output.push_str("| Primitive | Count | Sites |\n");
+            output.push_str("|-----------|-------|-------|\n");
+            for (prim, (count, sites)) in &counts {
+                let shown: Vec<String> = sites.iter().map(|s| format!("`{}`", s)).collect();
+                let list = if *count > sites.len() {
+                    format!("{} (+{} more)", shown.join(", "), count - sites.len())
+                } else {
+                    shown.join(", ")
+                };
+                output.push_str(&format!(
+                    "| `{}` | {} | {} |\n",
+                    prim.trim_end_matches('<'),
+                    count,
+                    list
+                ));
+            }
+            output.push('\n');
+        }
 
-        let mut output = String::new();
-        output.push_str(&format!("# Dead Code Analysis: `{}`\n\n", path));
+        if !across_await.is_empty() {
+            output.push_str("## Locks Possibly Held Across `.await`\n\n");
+            output.push_str(
+                "A synchronous guard that lives across a suspension point can \
+                 deadlock the executor. Verify the guard is dropped first:\n\n",
+            );
+            for (file, line, expr, func) in &across_await {
+                output.push_str(&format!(
+                    "- `{}` in `{}` at `{}:{}`\n",
+                    expr, func, file, line
+                ));
+            }
+            output.push('\n');
+        }
 
-        let mut total_unreachable = 0;
+        if !inversions.is_empty() {
+            output.push_str("## Inconsistent Lock Ordering\n\n");
+            output.push_str(
+                "These lock pairs are acquired in both orders — a classic \
+                 deadlock setup when the paths run concurrently:\n\n",
+            );
+            for ((a, b), fwd, rev) in &inversions {
+                output.push_str(&format!("### `{}` ↔ `{}`\n\n", a, b));
+                output.push_str(&format!(
+                    "- `{}` → `{}` in: {}\n",
+                    a,
+                    b,
+                    fwd.iter()
+                        .map(|f| format!("`{}`", f))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+                output.push_str(&format!(
+                    "- `{}` → `{}` in: {}\n\n",
+                    b,
+                    a,
+                    rev.iter()
+                        .map(|f| format!("`{}`", f))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+            }
+        }
 
-        for cfg in &cfgs {
-            // Filter by function if specified
-            if let Some(func_name) = function {
-                if cfg.function_name != func_name {
+        if across_await.is_empty() && inversions.is_empty() {
+            output.push_str("No risky lock usage detected.\n");
+        }
+
+        Ok(output)
+    }
+
+    /// Flag blocking calls (synchronous file I/O, `thread::sleep`, blocking
+    /// HTTP clients, sync DB drivers) made inside async functions, found by
+    /// matching known-blocking call patterns against async spans from the
+    /// symbol index
+    pub async fn scan_async_blocking(&self, repo: Option<&str>) -> Result<String> {
+        use crate::security_rules::is_test_file;
+
+        // Per-language (needle, label) pairs for calls that block the executor
+        const RUST_BLOCKING: &[(&str, &str)] = &[
+            ("std::fs::", "std::fs (blocking file I/O)"),
+            ("thread::sleep(", "thread::sleep"),
+            ("reqwest::blocking", "reqwest::blocking"),
+            ("block_on(", "block_on (nested runtime)"),
+            ("std::net::TcpStream", "std::net::TcpStream"),
+            ("rusqlite::", "rusqlite (sync DB driver)"),
+            ("diesel::", "diesel (sync DB driver)"),
+            ("postgres::Client", "postgres (sync DB driver)"),
+        ];
+        const PYTHON_BLOCKING: &[(&str, &str)] = &[
+            ("time.sleep(", "time.sleep"),
+            ("requests.", "requests (blocking HTTP)"),
+            ("urllib.request.", "urllib.request"),
+            ("sqlite3.", "sqlite3 (sync DB driver)"),
+            ("psycopg2.", "psycopg2 (sync DB driver)"),
+            ("subprocess.run(", "subprocess.run"),
+        ];
+
+        // (file, line, enclosing async fn, label, offending line)
+        let mut findings: Vec<(String, usize, String, String, String)> = Vec::new();
+
+        for repo_entry in self.repos.iter() {
+            let repo_name = repo_entry.key();
+            let repo_meta = repo_entry.value();
+
+            if let Some(target_repo) = repo {
+                if repo_name != target_repo && !repo_meta.path.ends_with(target_repo) {
                     continue;
                 }
             }
 
-            if !cfg.unreachable_blocks.is_empty() {
-                output.push_str(&format!("## Function: `{}`\n\n", cfg.function_name));
-                output.push_str("### Unreachable Code Blocks\n\n");
+            let repo_path = &repo_meta.path;
 
-                for &block_id in &cfg.unreachable_blocks {
-                    if let Some(block) = cfg.blocks.get(&block_id) {
-                        output.push_str(&format!(
-                            "- **Block {}** (`{}`): lines {}-{}\n",
-                            block_id, block.label, block.start_line, block.end_line
+            // Async function spans per file, from the symbol index
+            let mut async_spans: HashMap<String, Vec<(usize, usize, String)>> = HashMap::new();
+            if let Some(symbols) = self.symbols.get(repo_name) {
+                for sym in symbols.iter() {
+                    let is_async = sym
+                        .signature
+                        .as_deref()
+                        .map(|s| {
+                            s.contains("async fn ")
+                                || s.contains("async def ")
+                                || s.contains("async function")
+                                || s.starts_with("async ")
+                        })
+                        .unwrap_or(false);
+                    if is_async {
+                        async_spans.entry(sym.file_path.clone()).or_default().push((
+                            sym.start_line,
+                            sym.end_line,
+                            sym.name.clone(),
                         ));
-                        total_unreachable += 1;
                     }
                 }
-                output.push('\n');
+            }
+
+            for file_entry in self.file_cache.iter() {
+                let file_path = file_entry.key();
+                if !file_path.starts_with(repo_path) {
+                    continue;
+                }
+                let path_str = file_path.to_string_lossy();
+                if is_test_file(&path_str) {
+                    continue;
+                }
+                let ext = file_path
+                    .extension()
+                    .map(|e| e.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                let rel_path = file_path
+                    .strip_prefix(repo_path)
+                    .unwrap_or(file_path)
+                    .to_string_lossy()
+                    .to_string();
+
+                let Some(spans) = async_spans.get(&rel_path) else {
+                    continue;
+                };
+
+                let patterns: &[(&str, &str)] = match ext.as_str() {
+                    "rs" => RUST_BLOCKING,
+                    "py" | "pyi" => PYTHON_BLOCKING,
+                    // In JS/TS the `*Sync(` suffix marks the blocking variants
+                    "js" | "jsx" | "ts" | "tsx" | "mjs" | "cjs" => &[("Sync(", "*Sync call")],
+                    _ => continue,
+                };
+
+                let content = file_entry.value();
+                let lines: Vec<&str> = content.lines().collect();
+
+                for (start, end, fn_name) in spans {
+                    for (i, line) in lines
+                        .iter()
+                        .enumerate()
+                        .take((*end).min(lines.len()))
+                        .skip(start.saturating_sub(1))
+                    {
+                        let trimmed = line.trim();
+                        if trimmed.starts_with("//") || trimmed.starts_with('#') {
+                            continue;
+                        }
+                        for (needle, label) in patterns {
+                            if trimmed.contains(needle) {
+                                findings.push((
+                                    rel_path.clone(),
+                                    i + 1,
+                                    fn_name.clone(),
+                                    label.to_string(),
+                                    trimmed.to_string(),
+                                ));
+                                break;
+                            }
+                        }
+                    }
+                }
             }
         }
 
-        if total_unreachable == 0 {
-            output.push_str("✅ No unreachable code detected.\n");
-        } else {
+        findings.sort();
+        findings.dedup();
+
+        let mut output = String::new();
+        output.push_str("# Blocking Calls in Async Functions\n\n");
+        output.push_str(&format!("**Findings**: {}\n\n", findings.len()));
+
+        if findings.is_empty() {
+            output.push_str("No blocking calls found inside async functions.\n");
+            return Ok(output);
+        }
+
+        output.push_str("| Location | Async Fn | Blocking Call | Code |\n");
+        output.push_str("|----------|----------|---------------|------|\n");
+        for (rel_path, line_no, fn_name, label, code) in &findings {
+            let mut code = code.replace('|', "\\|");
+            if code.len() > 80 {
+                code.truncate(77);
+                code.push_str("...");
+            }
             output.push_str(&format!(
-                "\n**Total**: {} unreachable block(s) found.\n",
-                total_unreachable
+                "| `{}:{}` | `{}` | {} | `{}` |\n",
+                rel_path, line_no, fn_name, label, code
             ));
         }
+        output.push_str(
+            "\n*Blocking the executor starves other tasks; prefer the async \
+             equivalent or `spawn_blocking`.*\n",
+        );
+
+        Ok(output)
+    }
+
+    /// Heuristic race-condition detection: check-then-use (TOCTOU) patterns
+    /// on files, `static mut` shared state, and functions that spawn
+    /// threads/tasks while mutating locally shared variables without any
+    /// synchronization primitive in sight — each rated by confidence
+    pub async fn find_race_conditions(&self, repo: Option<&str>) -> Result<String> {
+        use crate::security_rules::is_test_file;
+
+        // (confidence 0=High 1=Medium 2=Low, file, line, kind, detail)
+        let mut findings: Vec<(u8, String, usize, &'static str, String)> = Vec::new();
+
+        const SYNC_TOKENS: &[&str] = &[
+            "Mutex",
+            "RwLock",
+            "Atomic",
+            ".lock(",
+            "mpsc",
+            "channel",
+            "Lock()",
+            "with lock",
+            "sync.",
+            "Semaphore",
+        ];
+
+        /// Argument of a check call: `os.path.exists(path)` -> `path`
+        fn check_argument(line: &str, pos: usize, needle: &str) -> String {
+            let rest = &line[pos + needle.len()..];
+            let mut depth = 1usize;
+            let mut arg = String::new();
+            for c in rest.chars() {
+                match c {
+                    '(' => depth += 1,
+                    ')' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            break;
+                        }
+                    }
+                    _ => {}
+                }
+                arg.push(c);
+            }
+            arg.trim().to_string()
+        }
 
-        Ok(output)
-    }
+        for repo_entry in self.repos.iter() {
+            let repo_name = repo_entry.key();
+            let repo_meta = repo_entry.value();
 
-    // ==================== Data Flow Graph (DFG) Tools ====================
+            if let Some(target_repo) = repo {
+                if repo_name != target_repo && !repo_meta.path.ends_with(target_repo) {
+                    continue;
+                }
+            }
 
-    /// Get data flow analysis for a specific function
-    pub async fn get_data_flow(&self, repo: &str, path: &str, function: &str) -> Result<String> {
-        let repo_meta = self
-            .repos
-            .get(repo)
-            .ok_or_else(|| anyhow!("Repository '{}' not found", repo))?;
+            let repo_path = &repo_meta.path;
 
-        let full_path = validate_path(&repo_meta.path, path)?;
-        let content = std::fs::read_to_string(&full_path).context("Failed to read file")?;
+            for file_entry in self.file_cache.iter() {
+                let file_path = file_entry.key();
+                if !file_path.starts_with(repo_path) {
+                    continue;
+                }
+                let path_str = file_path.to_string_lossy();
+                if is_test_file(&path_str) {
+                    continue;
+                }
+                let ext = file_path
+                    .extension()
+                    .and_then(|e| e.to_str())
+                    .unwrap_or_default();
+                let rel_path = file_path
+                    .strip_prefix(repo_path)
+                    .unwrap_or(file_path)
+                    .to_string_lossy()
+                    .to_string();
 
-        let parsed = self.parser.parse_file(&full_path, &content)?;
-        let tree = parsed
-            .tree
-            .as_ref()
-            .ok_or_else(|| anyhow!("Failed to parse file"))?;
-        let analyses = dfg::analyze_file(tree, &content, path)?;
+                // Existence checks and the file operations they race with
+                let (checks, uses): (&[&str], &[&str]) = match ext {
+                    "rs" => (
+                        &[".exists()", ".try_exists()"],
+                        &[
+                            "File::open",
+                            "File::create",
+                            "OpenOptions",
+                            "fs::read",
+                            "fs::write",
+                            "fs::remove",
+                            "fs::rename",
+                        ],
+                    ),
+                    "py" => (
+                        &["os.path.exists(", "os.path.isfile(", "os.access("],
+                        &["open(", "os.remove(", "os.rename(", "shutil."],
+                    ),
+                    "js" | "jsx" | "ts" | "tsx" | "mjs" | "cjs" => (
+                        &["fs.existsSync(", "fs.exists("],
+                        &["fs.openSync", "fs.readFile", "fs.writeFile", "fs.unlink"],
+                    ),
+                    "go" => (
+                        &["os.Stat("],
+                        &["os.Open", "os.Create", "os.Remove", "os.Rename"],
+                    ),
+                    _ => (&[], &[]),
+                };
 
-        // Find the requested function
-        let analysis = analyses
-            .iter()
-            .find(|a| a.function_name == function)
-            .ok_or_else(|| anyhow!("Function '{}' not found in {}", function, path))?;
+                let lines: Vec<&str> = file_entry.value().lines().collect();
+                let symbols = self.symbols.get(repo_name);
 
-        Ok(analysis.to_markdown())
-    }
+                for (i, line) in lines.iter().enumerate() {
+                    let trimmed = line.trim_start();
+                    if trimmed.starts_with("//") || trimmed.starts_with('#') {
+                        continue;
+                    }
 
-    /// Get reaching definitions analysis for a function
-    pub async fn get_reaching_definitions(
-        &self,
-        repo: &str,
-        path: &str,
-        function: &str,
-    ) -> Result<String> {
-        let repo_meta = self
-            .repos
-            .get(repo)
-            .ok_or_else(|| anyhow!("Repository '{}' not found", repo))?;
+                    // `static mut` is shared mutable state with no guard at all
+                    if ext == "rs" && trimmed.contains("static mut ") {
+                        findings.push((
+                            0,
+                            rel_path.clone(),
+                            i + 1,
+                            "static mut",
+                            trimmed.chars().take(80).collect(),
+                        ));
+                        continue;
+                    }
 
-        let full_path = validate_path(&repo_meta.path, path)?;
-        let content = std::fs::read_to_string(&full_path).context("Failed to read file")?;
+                    for check in checks {
+                        let Some(pos) = line.find(check) else {
+                            continue;
+                        };
+                        // For Rust `.exists()` the path is the receiver, not
+                        // the argument
+                        let path_expr = if check.starts_with('.') {
+                            line[..pos]
+                                .chars()
+                                .rev()
+                                .take_while(|c| c.is_alphanumeric() || matches!(c, '_' | '.' | ':'))
+                                .collect::<String>()
+                                .chars()
+                                .rev()
+                                .collect::<String>()
+                        } else {
+                            check_argument(line, pos, check)
+                        };
 
-        let parsed = self.parser.parse_file(&full_path, &content)?;
-        let tree = parsed
-            .tree
-            .as_ref()
-            .ok_or_else(|| anyhow!("Failed to parse file"))?;
-        let cfgs = cfg::analyze_function(tree, &content, path)?;
+                        let span_end = symbols
+                            .as_ref()
+                            .and_then(|syms| {
+                                syms.iter()
+                                    .filter(|s| {
+                                        s.file_path == rel_path
+                                            && s.start_line <= i + 1
+                                            && s.end_line > i
+                                    })
+                                    .min_by_key(|s| s.end_line - s.start_line)
+                                    .map(|s| s.end_line)
+                            })
+                            .unwrap_or((i + 11).min(lines.len()));
+
+                        for (j, later) in lines[i + 1..span_end.min(lines.len())].iter().enumerate()
+                        {
+                            if let Some(use_pat) = uses.iter().find(|u| later.contains(*u)) {
+                                // Same path expression on both lines is a
+                                // near-certain TOCTOU window
+                                let confidence = if !path_expr.is_empty()
+                                    && later.contains(path_expr.as_str())
+                                {
+                                    0
+                                } else {
+                                    1
+                                };
+                                findings.push((
+                                    confidence,
+                                    rel_path.clone(),
+                                    i + 1,
+                                    "check-then-use (TOCTOU)",
+                                    format!(
+                                        "`{}` at line {}, `{}` at line {}",
+                                        check.trim_start_matches('.'),
+                                        i + 1,
+                                        use_pat,
+                                        i + 2 + j
+                                    ),
+                                ));
+                                break;
+                            }
+                        }
+                    }
+                }
 
-        let cfg = cfgs
-            .iter()
-            .find(|c| c.function_name == function)
-            .ok_or_else(|| anyhow!("Function '{}' not found in {}", function, path))?;
+                // Spawn sites mutating shared variables without synchronization
+                if let Some(syms) = &symbols {
+                    for sym in syms.iter().filter(|s| s.file_path == rel_path) {
+                        let start = sym.start_line.saturating_sub(1);
+                        let end = sym.end_line.min(lines.len());
+                        if start >= end {
+                            continue;
+                        }
+                        let span = &lines[start..end];
+                        let Some(spawn_offset) = span.iter().position(|l| {
+                            l.contains("thread::spawn")
+                                || l.contains("tokio::spawn")
+                                || l.contains("task::spawn")
+                                || l.contains("threading.Thread(")
+                                || l.contains("go func(")
+                        }) else {
+                            continue;
+                        };
+                        if span
+                            .iter()
+                            .any(|l| SYNC_TOKENS.iter().any(|t| l.contains(t)))
+                        {
+                            continue;
+                        }
 
-        let mut analyzer = dfg::DfgAnalyzer::new(cfg, &content);
-        let analysis = analyzer.analyze();
+                        // A `let mut`/`global` binding before the spawn that is
+                        // still touched after it suggests unsynchronized sharing
+                        let shared_before: Vec<String> = span[..spawn_offset]
+                            .iter()
+                            .filter_map(|l| {
+                                let t = l.trim_start();
+                                let name = t
+                                    .strip_prefix("let mut ")
+                                    .or_else(|| t.strip_prefix("global "))?;
+                                let ident: String = name
+                                    .chars()
+                                    .take_while(|c| c.is_alphanumeric() || *c == '_')
+                                    .collect();
+                                (!ident.is_empty()).then_some(ident)
+                            })
+                            .collect();
+                        for ident in shared_before {
+                            if span[spawn_offset..].iter().any(|l| l.contains(&ident)) {
+                                findings.push((
+                                    1,
+                                    rel_path.clone(),
+                                    sym.start_line + spawn_offset,
+                                    "unsynchronized shared state",
+                                    format!(
+                                        "`{}` mutated around a spawn in `{}` with no lock, channel, or atomic in scope",
+                                        ident, sym.name
+                                    ),
+                                ));
+                                break;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        findings.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.cmp(&b.1)).then(a.2.cmp(&b.2)));
+        findings.dedup();
 
         let mut output = String::new();
-        output.push_str(&format!("# Reaching Definitions: `{}`\n\n", function));
-        output.push_str(&format!("**File**: `{}`\n\n", path));
+        output.push_str("# Race Condition Audit\n\n");
+        output.push_str(&format!("**Findings**: {}\n\n", findings.len()));
 
-        output.push_str("## Def-Use Chains\n\n");
-        for chain in &analysis.def_use_chains {
+        if findings.is_empty() {
+            output.push_str("No likely race conditions detected.\n");
+            return Ok(output);
+        }
+
+        let confidence_names = ["High", "Medium", "Low"];
+        output.push_str("| Confidence | Location | Kind | Detail |\n");
+        output.push_str("|------------|----------|------|--------|\n");
+        for (confidence, rel_path, line_no, kind, detail) in &findings {
             output.push_str(&format!(
-                "### `{}` (line {})\n\n",
-                chain.definition.variable, chain.definition.line
+                "| {} | `{}:{}` | {} | {} |\n",
+                confidence_names[*confidence as usize],
+                rel_path,
+                line_no,
+                kind,
+                detail.replace('|', "\\|")
             ));
-
-            if chain.uses.is_empty() {
-                output.push_str("*No uses found (dead store)*\n\n");
-            } else {
-                output.push_str("**Reaches**:\n");
-                for use_ in &chain.uses {
-                    output.push_str(&format!("- Line {}: {:?}\n", use_.line, use_.kind));
-                }
-                output.push('\n');
-            }
         }
+        output.push_str(
+            "\n*Heuristic results: a TOCTOU window is only exploitable when an \
+             attacker can touch the same path between check and use.*\n",
+        );
 
         Ok(output)
     }
@@ -3318,22 +6964,26 @@ impl CodeIntelEngine {
         repo: Option<&str>,
         max_results: usize,
         mode: &str,
-        exclude_tests: Option<bool>,
+        filters: SearchFilters,
     ) -> Result<String> {
         use crate::chunking::AstChunker;
         use crate::embeddings::EmbeddingEngine;
         use crate::hybrid_search::create_hybrid_engine;
         use crate::search::ConcurrentSearchIndex;
-        use crate::security_rules::is_test_file;
         use std::sync::Arc;
 
-        let exclude_tests = exclude_tests.unwrap_or(false); // Default false for search
-
         // Create search engines
         let bm25_index = Arc::new(ConcurrentSearchIndex::new());
         let tfidf_engine = Arc::new(EmbeddingEngine::new(1000));
-        let hybrid_engine = create_hybrid_engine(bm25_index.clone(), tfidf_engine.clone());
-        let chunker = AstChunker::new();
+        let mut hybrid_engine = create_hybrid_engine(bm25_index.clone(), tfidf_engine.clone());
+        // Fuse neural embedding results as a third ranked list when enabled
+        if let Some(ref neural) = self.neural_engine {
+            hybrid_engine = hybrid_engine.with_neural(Arc::clone(neural));
+        }
+        let chunker = AstChunker::with_config(self.options.chunker_config.clone());
+
+        // Per-file recency weights from git history, keyed by absolute path
+        let mut recency: std::collections::HashMap<String, f64> = std::collections::HashMap::new();
 
         // Index all files from relevant repos
         for repo_entry in self.repos.iter() {
@@ -3349,13 +6999,33 @@ impl CodeIntelEngine {
 
             let repo_path = &repo_meta.path;
 
+            // With git integration on, boost recently/frequently changed files
+            if let Some(git) = self.git_repos.get(repo_name) {
+                if let Ok(frequencies) = git.change_frequency(90) {
+                    let now = SystemTime::now()
+                        .duration_since(SystemTime::UNIX_EPOCH)
+                        .map(|d| d.as_secs() as i64)
+                        .unwrap_or(0);
+                    for (rel_path, weight) in
+                        crate::hybrid_search::recency_weights(&frequencies, now)
+                    {
+                        let abs_path = repo_path.join(&rel_path).to_string_lossy().to_string();
+                        recency.insert(abs_path, weight);
+                    }
+                }
+            }
+
             for file_entry in self.file_cache.iter() {
                 let file_path = file_entry.key();
                 if !file_path.starts_with(repo_path) {
                     continue;
                 }
-                // Skip test files if exclude_tests is enabled
-                if exclude_tests && is_test_file(&file_path.to_string_lossy()) {
+                // Apply the common path filters against the repo-relative path
+                let rel_path = file_path
+                    .strip_prefix(repo_path)
+                    .unwrap_or(file_path)
+                    .to_string_lossy();
+                if !filters.matches(&rel_path) {
                     continue;
                 }
 
@@ -3372,6 +7042,10 @@ impl CodeIntelEngine {
             }
         }
 
+        if !recency.is_empty() {
+            hybrid_engine.set_file_recency(recency);
+        }
+
         // Perform search based on mode
         let results = match mode {
             "bm25" => hybrid_engine.search_bm25(query, max_results),
@@ -3438,7 +7112,7 @@ impl CodeIntelEngine {
         use crate::security_rules::is_test_file;
 
         let exclude_tests = exclude_tests.unwrap_or(false); // Default false for search
-        let chunker = AstChunker::new();
+        let chunker = AstChunker::with_config(self.options.chunker_config.clone());
         let query_tokens: std::collections::HashSet<_> = tokenize_code(query).into_iter().collect();
         let mut all_chunks = Vec::new();
 
@@ -3537,27 +7211,264 @@ impl CodeIntelEngine {
                 }
             }
 
-            if let Some(ref doc) = chunk.doc_comment {
-                let doc_preview: String = doc.lines().take(2).collect::<Vec<_>>().join(" ");
-                output.push_str(&format!(
-                    "- **Doc**: {}\n",
-                    doc_preview.chars().take(80).collect::<String>()
-                ));
+            if let Some(ref doc) = chunk.doc_comment {
+                let doc_preview: String = doc.lines().take(2).collect::<Vec<_>>().join(" ");
+                output.push_str(&format!(
+                    "- **Doc**: {}\n",
+                    doc_preview.chars().take(80).collect::<String>()
+                ));
+            }
+
+            output.push_str("\n```\n");
+            let snippet_lines: Vec<&str> = chunk.content.lines().take(15).collect();
+            output.push_str(&snippet_lines.join("\n"));
+            if chunk.content.lines().count() > 15 {
+                output.push_str("\n... (truncated)");
+            }
+            output.push_str("\n```\n\n");
+        }
+
+        if all_chunks.is_empty() {
+            output.push_str("No matching chunks found.\n");
+        }
+
+        Ok(output)
+    }
+
+    /// Assemble a token-budgeted context pack for an agent task
+    ///
+    /// Runs hybrid search with the task as the query, expands the top hits
+    /// into their enclosing symbol definitions, lists immediate callers and
+    /// callees when the call graph is available, and appends project config
+    /// files — everything trimmed to fit an approximate token budget.
+    pub async fn get_context_pack(
+        &self,
+        task: &str,
+        repo: Option<&str>,
+        token_budget: usize,
+    ) -> Result<String> {
+        use crate::chunking::AstChunker;
+        use crate::embeddings::EmbeddingEngine;
+        use crate::hybrid_search::create_hybrid_engine;
+        use crate::search::ConcurrentSearchIndex;
+        use std::sync::Arc;
+
+        // Rough heuristic: ~4 characters per token across code and prose
+        let budget_chars = token_budget.saturating_mul(4).max(1000);
+
+        // Build a hybrid engine over the relevant repos, same as hybrid_search
+        let bm25_index = Arc::new(ConcurrentSearchIndex::new());
+        let tfidf_engine = Arc::new(EmbeddingEngine::new(1000));
+        let mut hybrid_engine = create_hybrid_engine(bm25_index.clone(), tfidf_engine.clone());
+        if let Some(ref neural) = self.neural_engine {
+            hybrid_engine = hybrid_engine.with_neural(Arc::clone(neural));
+        }
+        let chunker = AstChunker::with_config(self.options.chunker_config.clone());
+
+        // Repo roots we actually indexed, for attributing hits back to repos
+        let mut repo_roots: Vec<(String, PathBuf)> = Vec::new();
+
+        for repo_entry in self.repos.iter() {
+            let repo_name = repo_entry.key();
+            let repo_meta = repo_entry.value();
+
+            if let Some(target_repo) = repo {
+                if repo_name != target_repo && !repo_meta.path.ends_with(target_repo) {
+                    continue;
+                }
+            }
+
+            let repo_path = &repo_meta.path;
+            repo_roots.push((repo_name.clone(), repo_path.clone()));
+
+            for file_entry in self.file_cache.iter() {
+                let file_path = file_entry.key();
+                if !file_path.starts_with(repo_path) {
+                    continue;
+                }
+
+                let content = file_entry.value();
+                let file_path_str = file_path.to_string_lossy().to_string();
+
+                for chunk in chunker.chunk_file(content, &file_path_str) {
+                    hybrid_engine.index_chunk(&chunk);
+                }
+            }
+        }
+
+        if repo_roots.is_empty() {
+            return Err(anyhow!(
+                "Repository '{}' not found",
+                repo.unwrap_or("(none)")
+            ));
+        }
+
+        let results = hybrid_engine.search(task, 10);
+
+        let mut output = String::new();
+        output.push_str(&format!("# Context Pack: {}\n\n", task));
+        output.push_str(&format!(
+            "**Token budget**: ~{} (approximate, 4 chars/token)\n\n",
+            token_budget
+        ));
+
+        if results.is_empty() {
+            output.push_str("No relevant code found for this task.\n");
+            return Ok(output);
+        }
+
+        // Expand each hit into its enclosing symbol, avoiding duplicates when
+        // several chunks land in the same definition
+        let mut seen: HashSet<(String, String, usize)> = HashSet::new();
+        let mut truncated = false;
+
+        output.push_str("## Relevant Code\n\n");
+
+        for result in &results {
+            let hit_path = PathBuf::from(&result.file_path);
+            let Some((repo_name, repo_path)) = repo_roots
+                .iter()
+                .find(|(_, root)| hit_path.starts_with(root))
+            else {
+                continue;
+            };
+            let rel_path = hit_path
+                .strip_prefix(repo_path)
+                .unwrap_or(&hit_path)
+                .to_string_lossy()
+                .to_string();
+
+            // Find the symbol enclosing (or overlapping) the matched chunk
+            let enclosing = self.symbols.get(repo_name).and_then(|symbols| {
+                symbols
+                    .iter()
+                    .filter(|s| {
+                        s.file_path == rel_path
+                            && s.start_line <= result.end_line
+                            && s.end_line >= result.start_line
+                    })
+                    .min_by_key(|s| s.end_line - s.start_line)
+                    .cloned()
+            });
+
+            let mut section = String::new();
+
+            if let Some(ref sym) = enclosing {
+                if !seen.insert((repo_name.clone(), sym.name.clone(), sym.start_line)) {
+                    continue;
+                }
+                section.push_str(&format!(
+                    "### `{}` — {}:{}-{} (score {:.3})\n\n",
+                    sym.name, rel_path, sym.start_line, sym.end_line, result.score
+                ));
+                if let Some(ref sig) = sym.signature {
+                    section.push_str(&format!("**Signature**: `{}`\n\n", sig));
+                }
+
+                // Full definition from the file cache, capped at 60 lines
+                if let Some(content) = self.file_cache.get(&hit_path) {
+                    let def_lines: Vec<&str> = content
+                        .lines()
+                        .skip(sym.start_line.saturating_sub(1))
+                        .take((sym.end_line - sym.start_line + 1).min(60))
+                        .collect();
+                    section.push_str("```\n");
+                    section.push_str(&def_lines.join("\n"));
+                    if sym.end_line - sym.start_line + 1 > 60 {
+                        section.push_str("\n... (truncated)");
+                    }
+                    section.push_str("\n```\n\n");
+                }
+
+                // Immediate neighbors in the call graph, when available
+                if let Some(call_graph) = self.call_graphs.get(repo_name) {
+                    let callers = call_graph.get_callers(&sym.name);
+                    if !callers.is_empty() {
+                        let names: Vec<String> = callers
+                            .iter()
+                            .take(8)
+                            .map(|c| format!("`{}`", c.target))
+                            .collect();
+                        section.push_str(&format!("**Called by**: {}\n", names.join(", ")));
+                    }
+                    let callees = call_graph.get_callees(&sym.name);
+                    if !callees.is_empty() {
+                        let names: Vec<String> = callees
+                            .iter()
+                            .take(8)
+                            .map(|c| format!("`{}`", c.target))
+                            .collect();
+                        section.push_str(&format!("**Calls**: {}\n", names.join(", ")));
+                    }
+                    if !callers.is_empty() || !callees.is_empty() {
+                        section.push('\n');
+                    }
+                }
+            } else {
+                // No enclosing symbol (config, docs, ...): show the chunk itself
+                if !seen.insert((repo_name.clone(), rel_path.clone(), result.start_line)) {
+                    continue;
+                }
+                section.push_str(&format!(
+                    "### {}:{}-{} (score {:.3})\n\n",
+                    rel_path, result.start_line, result.end_line, result.score
+                ));
+                section.push_str("```\n");
+                let snippet: Vec<&str> = result.content.lines().take(20).collect();
+                section.push_str(&snippet.join("\n"));
+                if result.content.lines().count() > 20 {
+                    section.push_str("\n... (truncated)");
+                }
+                section.push_str("\n```\n\n");
+            }
+
+            if output.len() + section.len() > budget_chars {
+                truncated = true;
+                break;
             }
-
-            output.push_str("\n```\n");
-            let snippet_lines: Vec<&str> = chunk.content.lines().take(15).collect();
-            output.push_str(&snippet_lines.join("\n"));
-            if chunk.content.lines().count() > 15 {
-                output.push_str("\n... (truncated)");
+            output.push_str(&section);
+        }
+
+        // Project configuration gives agents build/dependency context
+        const CONFIG_FILES: &[&str] = &[
+            "Cargo.toml",
+            "package.json",
+            "pyproject.toml",
+            "go.mod",
+            "tsconfig.json",
+        ];
+
+        let mut config_section = String::new();
+        for (repo_name, repo_path) in &repo_roots {
+            for name in CONFIG_FILES {
+                let candidate = repo_path.join(name);
+                if let Some(content) = self.file_cache.get(&candidate) {
+                    let lines: Vec<&str> = content.lines().take(40).collect();
+                    config_section.push_str(&format!("### {}/{}\n\n```\n", repo_name, name));
+                    config_section.push_str(&lines.join("\n"));
+                    if content.lines().count() > 40 {
+                        config_section.push_str("\n... (truncated)");
+                    }
+                    config_section.push_str("\n```\n\n");
+                }
             }
-            output.push_str("\n```\n\n");
         }
 
-        if all_chunks.is_empty() {
-            output.push_str("No matching chunks found.\n");
+        if !config_section.is_empty() && output.len() + config_section.len() + 24 <= budget_chars {
+            output.push_str("## Project Configuration\n\n");
+            output.push_str(&config_section);
+        }
+
+        if truncated {
+            output.push_str("*Additional results omitted to stay within the token budget.*\n");
         }
 
+        output.push_str(&format!(
+            "\n---\n*~{} tokens used of {} budgeted.*\n",
+            output.len() / 4,
+            token_budget
+        ));
+
         Ok(output)
     }
 
@@ -3580,7 +7491,7 @@ impl CodeIntelEngine {
 
         let config = ChunkerConfig {
             include_context: include_imports,
-            ..Default::default()
+            ..self.options.chunker_config.clone()
         };
         let chunker = AstChunker::with_config(config);
         let chunks = chunker.chunk_file(&content, path);
@@ -3647,7 +7558,7 @@ impl CodeIntelEngine {
         let repo_path = repo_meta.path.clone();
         drop(repo_meta); // Release the lock
 
-        let chunker = AstChunker::new();
+        let chunker = AstChunker::with_config(self.options.chunker_config.clone());
         let mut all_chunks = Vec::new();
         let mut file_count = 0;
 
@@ -3686,7 +7597,7 @@ impl CodeIntelEngine {
         output.push_str("|------|-------|------------|\n");
 
         let mut types: Vec<_> = stats.by_type.into_iter().collect();
-        types.sort_by(|a, b| b.1.cmp(&a.1));
+        types.sort_by_key(|t| std::cmp::Reverse(t.1));
 
         for (chunk_type, count) in types {
             let pct = if stats.total_chunks > 0 {
@@ -3744,6 +7655,24 @@ impl CodeIntelEngine {
             output.push_str(&format!("| {:?} | {} |\n", doc_type, count));
         }
 
+        if let Some(ref neural) = self.neural_engine {
+            let cache_stats = neural.cache_stats();
+            output.push_str("\n## Neural Embedding Cache\n\n");
+            output.push_str(&format!(
+                "- **Cached embeddings**: {}\n",
+                cache_stats.entries
+            ));
+            output.push_str(&format!("- **Cache hits**: {}\n", cache_stats.hits));
+            output.push_str(&format!("- **Cache misses**: {}\n", cache_stats.misses));
+            let lookups = cache_stats.hits + cache_stats.misses;
+            if lookups > 0 {
+                output.push_str(&format!(
+                    "- **Hit rate**: {:.1}%\n",
+                    100.0 * cache_stats.hits as f64 / lookups as f64
+                ));
+            }
+        }
+
         Ok(output)
     }
 
@@ -3756,6 +7685,7 @@ impl CodeIntelEngine {
         path: Option<&str>,
         exclude_tests: Option<bool>,
         vuln_types: &[String],
+        min_severity: Option<&str>,
     ) -> Result<String> {
         use crate::security_rules::is_test_file;
 
@@ -3799,11 +7729,17 @@ impl CodeIntelEngine {
             .map(|entry| entry.key().clone())
             .collect();
 
+        let custom_taint = crate::security_config::CustomTaintConfig::load_from_repo(&repo_path);
+
         for file_path in &files_to_analyze {
             if let Some(content_entry) = self.file_cache.get(file_path) {
                 let content = content_entry.value();
                 let file_str = file_path.to_string_lossy();
-                let result = crate::taint::analyze_code(content, &file_str);
+                let result = crate::taint::analyze_code_with_config(
+                    content,
+                    &file_str,
+                    custom_taint.as_ref(),
+                );
                 all_results.push(result);
             }
         }
@@ -3826,10 +7762,17 @@ impl CodeIntelEngine {
                         crate::taint::VulnerabilityKind::Xss => "xss",
                         crate::taint::VulnerabilityKind::CommandInjection => "command",
                         crate::taint::VulnerabilityKind::PathTraversal => "path",
+                        crate::taint::VulnerabilityKind::Ssrf => "ssrf",
+                        crate::taint::VulnerabilityKind::InsecureDeserialization => {
+                            "deserialization"
+                        }
                         _ => "other",
                     };
 
-                    if include_all || vuln_types.iter().any(|t| t == type_key) {
+                    let severity = vuln.severity.unwrap_or(crate::taint::Severity::Low);
+                    if (include_all || vuln_types.iter().any(|t| t == type_key))
+                        && severity >= parse_severity_threshold(min_severity)
+                    {
                         all_vulns.push(vuln.clone());
                     }
                 }
@@ -3935,12 +7878,11 @@ impl CodeIntelEngine {
         let full_path = validate_path(&repo_path, path)?;
 
         let content = self
-            .file_cache
-            .get(&full_path)
-            .map(|entry| entry.value().clone())
+            .cached_file_content(&full_path)
             .ok_or_else(|| anyhow!("File not found: {}", path))?;
 
-        let result = crate::taint::analyze_code(&content, path);
+        let custom_taint = crate::security_config::CustomTaintConfig::load_from_repo(&repo_path);
+        let result = crate::taint::analyze_code_with_config(&content, path, custom_taint.as_ref());
 
         let mut output = String::new();
         output.push_str(&format!("# Taint Trace: {}:{}\n\n", path, line));
@@ -3987,6 +7929,196 @@ impl CodeIntelEngine {
         Ok(output)
     }
 
+    /// Run a source-to-sink path query across a repository.
+    ///
+    /// The user supplies a source pattern, a sink pattern, and optional
+    /// sanitizer patterns (matched the same way as the built-in taint
+    /// patterns). Intra-procedural flows come from taint analysis; when
+    /// source and sink sit in different functions, the call graph is
+    /// consulted for a connecting call chain.
+    pub async fn query_code_paths(
+        &self,
+        repo_name: &str,
+        source_pattern: &str,
+        sink_pattern: &str,
+        sanitizer_patterns: &[String],
+        path: Option<&str>,
+    ) -> Result<String> {
+        use crate::security_rules::is_test_file;
+
+        let repo_path = self.get_repo_path(repo_name)?;
+
+        let files_to_analyze: Vec<std::path::PathBuf> = self
+            .file_cache
+            .iter()
+            .filter(|entry| entry.key().starts_with(&repo_path))
+            .filter(|entry| {
+                if let Some(specific_path) = path {
+                    entry.key().to_string_lossy().contains(specific_path)
+                } else {
+                    true
+                }
+            })
+            .filter(|entry| !is_test_file(&entry.key().to_string_lossy()))
+            .filter(|entry| {
+                crate::taint::detect_language(&entry.key().to_string_lossy()) != "unknown"
+            })
+            .map(|entry| entry.key().clone())
+            .collect();
+
+        let source_patterns = vec![source_pattern.to_string()];
+        let sink_patterns = vec![sink_pattern.to_string()];
+
+        let mut output = String::new();
+        output.push_str(&format!(
+            "# Path Query: `{}` → `{}`\n\n",
+            source_pattern, sink_pattern
+        ));
+        if !sanitizer_patterns.is_empty() {
+            output.push_str(&format!(
+                "Sanitizers: {}\n\n",
+                sanitizer_patterns
+                    .iter()
+                    .map(|s| format!("`{}`", s))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+
+        // Intra-procedural flows via taint analysis with ad-hoc patterns
+        let mut flow_count = 0;
+        // Locations matching the patterns, for call-graph linking
+        let mut source_sites: Vec<(String, usize)> = Vec::new();
+        let mut sink_sites: Vec<(String, usize)> = Vec::new();
+
+        for file_path in &files_to_analyze {
+            let Some(content_entry) = self.file_cache.get(file_path) else {
+                continue;
+            };
+            let content = content_entry.value().clone();
+            let rel_path = file_path
+                .strip_prefix(&repo_path)
+                .unwrap_or(file_path)
+                .to_string_lossy()
+                .to_string();
+            let language = crate::taint::detect_language(&rel_path);
+
+            let analyzer = crate::taint::TaintAnalyzer::from_query(
+                language,
+                &source_patterns,
+                &sink_patterns,
+                sanitizer_patterns,
+            );
+            let result = analyzer.analyze_code(&content, &rel_path);
+
+            for flow in &result.flows {
+                flow_count += 1;
+                output.push_str(&format!("## Path {}: {}\n\n", flow_count, rel_path));
+                for (i, step) in flow.path.iter().enumerate() {
+                    output.push_str(&format!(
+                        "{}. `{}:{}` — {}\n",
+                        i + 1,
+                        step.file_path,
+                        step.line,
+                        step.code.trim()
+                    ));
+                }
+                if flow.is_sanitized {
+                    output.push_str("\n✅ Sanitized — flow passes through a sanitizer.\n");
+                }
+                output.push('\n');
+            }
+
+            // Record pattern sites for cross-function linking
+            for (line_no, line) in content.lines().enumerate() {
+                if line.contains(source_pattern) {
+                    source_sites.push((rel_path.clone(), line_no + 1));
+                }
+                if line.contains(sink_pattern) {
+                    sink_sites.push((rel_path.clone(), line_no + 1));
+                }
+            }
+        }
+
+        if flow_count == 0 {
+            output.push_str("No intra-procedural source-to-sink flows found.\n\n");
+        }
+
+        // Cross-function paths via the call graph
+        if let Some(graph) = self.call_graphs.get(repo_name) {
+            let source_fns = self.enclosing_functions(repo_name, &source_sites);
+            let sink_fns = self.enclosing_functions(repo_name, &sink_sites);
+
+            let mut chains: Vec<String> = Vec::new();
+            for (src_fn, src_site) in &source_fns {
+                for (sink_fn, sink_site) in &sink_fns {
+                    if src_fn == sink_fn || chains.len() >= 10 {
+                        continue;
+                    }
+                    if let Some(call_path) = graph.find_call_path(src_fn, sink_fn) {
+                        chains.push(format!(
+                            "- `{}:{}` ({}) → {} → `{}:{}` ({})\n",
+                            src_site.0,
+                            src_site.1,
+                            src_fn,
+                            call_path.join(" → "),
+                            sink_site.0,
+                            sink_site.1,
+                            sink_fn
+                        ));
+                    }
+                }
+            }
+
+            if !chains.is_empty() {
+                output.push_str("## Cross-Function Call Chains\n\n");
+                output.push_str(
+                    "Source and sink in different functions, connected through the call graph:\n\n",
+                );
+                for chain in chains {
+                    output.push_str(&chain);
+                }
+                output.push('\n');
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// Map pattern match sites to their enclosing functions using indexed
+    /// symbols. Returns (function name, site) pairs, deduplicated per
+    /// function.
+    fn enclosing_functions(
+        &self,
+        repo_name: &str,
+        sites: &[(String, usize)],
+    ) -> Vec<(String, (String, usize))> {
+        let Some(symbols) = self.symbols.get(repo_name) else {
+            return Vec::new();
+        };
+
+        let mut result: Vec<(String, (String, usize))> = Vec::new();
+        for (file, line) in sites {
+            let enclosing = symbols
+                .iter()
+                .filter(|s| {
+                    matches!(
+                        s.kind,
+                        SymbolKind::Function | SymbolKind::Method | SymbolKind::Constructor
+                    )
+                })
+                .filter(|s| s.file_path == *file && s.start_line <= *line && *line <= s.end_line)
+                .min_by_key(|s| s.end_line - s.start_line);
+
+            if let Some(symbol) = enclosing {
+                if !result.iter().any(|(name, _)| name == &symbol.name) {
+                    result.push((symbol.name.clone(), (file.clone(), *line)));
+                }
+            }
+        }
+        result
+    }
+
     /// Get all taint sources in a repository or file
     pub async fn get_taint_sources(
         &self,
@@ -4037,11 +8169,17 @@ impl CodeIntelEngine {
             .map(|entry| entry.key().clone())
             .collect();
 
+        let custom_taint = crate::security_config::CustomTaintConfig::load_from_repo(&repo_path);
+
         for file_path in &files_to_analyze {
             if let Some(content_entry) = self.file_cache.get(file_path) {
                 let content = content_entry.value();
                 let file_str = file_path.to_string_lossy();
-                let result = crate::taint::analyze_code(content, &file_str);
+                let result = crate::taint::analyze_code_with_config(
+                    content,
+                    &file_str,
+                    custom_taint.as_ref(),
+                );
 
                 for source in result.sources {
                     // Filter by type
@@ -4149,10 +8287,13 @@ impl CodeIntelEngine {
             .map(|entry| (entry.key().clone(), entry.value().clone()))
             .collect();
 
+        let custom_taint = crate::security_config::CustomTaintConfig::load_from_repo(&repo_path);
+
         for (file_path, content) in &files {
             total_files += 1;
             let file_str = file_path.to_string_lossy();
-            let result = crate::taint::analyze_code(content, &file_str);
+            let result =
+                crate::taint::analyze_code_with_config(content, &file_str, custom_taint.as_ref());
 
             total_sources += result.sources.len();
             total_sinks += result.sinks.len();
@@ -4284,11 +8425,13 @@ impl CodeIntelEngine {
         exclude_tests: Option<bool>,
         max_findings: Option<usize>,
         offset: Option<usize>,
+        rule_id: Option<&str>,
     ) -> Result<String> {
         use crate::security_rules::{is_test_file, SecurityRulesEngine};
 
         let repo_path = self.get_repo_path(repo_name)?;
-        let engine = SecurityRulesEngine::new();
+        let mut engine = SecurityRulesEngine::new();
+        engine.load_user_rules_from_repo(&repo_path);
         let exclude_tests = exclude_tests.unwrap_or(true);
         let min_severity = parse_severity_threshold(severity_threshold);
 
@@ -4319,9 +8462,25 @@ impl CodeIntelEngine {
                 }
             })
             .filter(|f| f.severity >= min_severity)
+            .filter(|f| rule_id.is_none_or(|id| f.rule_id.eq_ignore_ascii_case(id)))
             .collect();
 
-        findings.sort_by(|a, b| b.severity.cmp(&a.severity));
+        // Filter out findings recorded in the baseline, if one exists
+        let baseline = crate::security_rules::FindingsBaseline::load_from_repo(&repo_path);
+        let mut baseline_suppressed = 0;
+        if let Some(ref baseline) = baseline {
+            let before = findings.len();
+            findings.retain(|f| {
+                let rel = std::path::Path::new(&f.file_path)
+                    .strip_prefix(&repo_path)
+                    .map(|p| p.to_string_lossy().to_string())
+                    .unwrap_or_else(|_| f.file_path.clone());
+                !baseline.matches(&f.rule_id, &rel, &f.snippet)
+            });
+            baseline_suppressed = before - findings.len();
+        }
+
+        findings.sort_by_key(|f| std::cmp::Reverse(f.severity));
 
         // Phase C2: Apply pagination (offset and limit)
         let total_findings = findings.len();
@@ -4352,6 +8511,12 @@ impl CodeIntelEngine {
         if let Some(ref tags) = ruleset_tags {
             output.push_str(&format!("**Ruleset Filter**: {}\n", tags.join(", ")));
         }
+        if baseline.is_some() {
+            output.push_str(&format!(
+                "**Baseline**: {} known findings suppressed\n",
+                baseline_suppressed
+            ));
+        }
 
         // Phase C2: Show pagination info
         if truncated {
@@ -4390,18 +8555,147 @@ impl CodeIntelEngine {
         Ok(output)
     }
 
+    /// Run security rules and dependency vulnerability checks over a repo,
+    /// returning structured findings for CI gating (the `scan` subcommand)
+    pub async fn scan_for_ci(&self, repo_name: &str) -> Result<CiScanReport> {
+        use crate::security_rules::{is_test_file, SecurityRulesEngine};
+        use crate::supply_chain::SupplyChainAnalyzer;
+
+        let repo_path = self.get_repo_path(repo_name)?;
+        let mut engine = SecurityRulesEngine::new();
+        engine.load_user_rules_from_repo(&repo_path);
+
+        let files: Vec<_> = self
+            .file_cache
+            .iter()
+            .filter(|e| e.key().starts_with(&repo_path))
+            .filter(|e| !is_test_file(&e.key().to_string_lossy()))
+            .filter(|e| is_security_scannable(&e.key().to_string_lossy()))
+            .map(|e| (e.key().clone(), e.value().clone()))
+            .collect();
+
+        let mut security: Vec<_> = files
+            .iter()
+            .flat_map(|(file_path, content)| {
+                let file_str = file_path.to_string_lossy();
+                let lang = detect_language_from_path(&file_str);
+                engine.scan(content, &file_str, &lang)
+            })
+            .collect();
+
+        // Respect the repo baseline, same as scan_security
+        if let Some(baseline) = crate::security_rules::FindingsBaseline::load_from_repo(&repo_path)
+        {
+            security.retain(|f| {
+                let rel = std::path::Path::new(&f.file_path)
+                    .strip_prefix(&repo_path)
+                    .map(|p| p.to_string_lossy().to_string())
+                    .unwrap_or_else(|_| f.file_path.clone());
+                !baseline.matches(&f.rule_id, &rel, &f.snippet)
+            });
+        }
+
+        security.sort_by_key(|f| std::cmp::Reverse(f.severity));
+
+        // A repo without a recognized manifest still gets the code scan
+        let analyzer = SupplyChainAnalyzer::new();
+        let dependencies = match analyzer.parse_dependencies(&repo_path) {
+            Ok(deps) => analyzer.check_vulnerabilities(&deps),
+            Err(_) => Vec::new(),
+        };
+
+        Ok(CiScanReport {
+            security,
+            dependencies,
+        })
+    }
+
+    /// Snapshot current security findings into `.narsil-baseline.json`.
+    ///
+    /// Later `scan_security` runs filter out everything in the baseline,
+    /// so CI reports only new issues. Inline `narsil:ignore` suppressions
+    /// are applied before the snapshot is taken.
+    pub async fn create_security_baseline(
+        &self,
+        repo_name: &str,
+        path: Option<&str>,
+    ) -> Result<String> {
+        use crate::security_rules::{is_test_file, FindingsBaseline, SecurityRulesEngine};
+
+        let repo_path = self.get_repo_path(repo_name)?;
+        let mut engine = SecurityRulesEngine::new();
+        engine.load_user_rules_from_repo(&repo_path);
+
+        let files: Vec<_> = self
+            .file_cache
+            .iter()
+            .filter(|e| e.key().starts_with(&repo_path))
+            .filter(|e| path.is_none_or(|p| e.key().to_string_lossy().contains(p)))
+            .filter(|e| !is_test_file(&e.key().to_string_lossy()))
+            .filter(|e| is_security_scannable(&e.key().to_string_lossy()))
+            .map(|e| (e.key().clone(), e.value().clone()))
+            .collect();
+
+        // Scan with repository-relative paths so baseline entries are
+        // stable across checkouts
+        let findings: Vec<_> = files
+            .iter()
+            .flat_map(|(file_path, content)| {
+                let rel_path = file_path
+                    .strip_prefix(&repo_path)
+                    .unwrap_or(file_path)
+                    .to_string_lossy()
+                    .to_string();
+                let lang = detect_language_from_path(&rel_path);
+                engine.scan(content, &rel_path, &lang)
+            })
+            .collect();
+
+        let baseline = FindingsBaseline::from_findings(&findings);
+        baseline
+            .save_to_repo(&repo_path)
+            .map_err(|e| anyhow!("{}", e))?;
+
+        let mut by_severity: std::collections::HashMap<String, usize> =
+            std::collections::HashMap::new();
+        for finding in &findings {
+            *by_severity
+                .entry(format!("{:?}", finding.severity))
+                .or_insert(0) += 1;
+        }
+
+        let mut output = format!("# Security Baseline: {}\n\n", repo_name);
+        output.push_str(&format!("**Files Scanned**: {}\n", files.len()));
+        output.push_str(&format!("**Findings Recorded**: {}\n", findings.len()));
+        for severity in ["Critical", "High", "Medium", "Low", "Info"] {
+            if let Some(count) = by_severity.get(severity) {
+                output.push_str(&format!("- {}: {}\n", severity, count));
+            }
+        }
+        output.push_str(&format!(
+            "\nBaseline written to `{}`. Subsequent `scan_security` runs will report only new findings.\n",
+            crate::security_rules::BASELINE_FILE_NAME
+        ));
+
+        Ok(output)
+    }
+
     /// Scan for OWASP Top 10 vulnerabilities
     pub async fn check_owasp_top10(
         &self,
         repo_name: &str,
         path: Option<&str>,
         exclude_tests: Option<bool>,
+        min_severity: Option<&str>,
+        rule_id: Option<&str>,
     ) -> Result<String> {
         use crate::security_rules::{is_test_file, SecurityRulesEngine};
 
         let repo_path = self.get_repo_path(repo_name)?;
-        let engine = SecurityRulesEngine::new();
+        let mut engine = SecurityRulesEngine::new();
+        engine.load_user_rules_from_repo(&repo_path);
         let exclude_tests = exclude_tests.unwrap_or(true);
+        let min_severity = parse_severity_threshold(min_severity);
 
         let files: Vec<_> = self
             .file_cache
@@ -4419,9 +8713,11 @@ impl CodeIntelEngine {
                 let file_str = file_path.to_string_lossy();
                 engine.scan_owasp_top10(content, &file_str, &detect_language_from_path(&file_str))
             })
+            .filter(|f| f.severity >= min_severity)
+            .filter(|f| rule_id.is_none_or(|id| f.rule_id.eq_ignore_ascii_case(id)))
             .collect();
 
-        findings.sort_by(|a, b| b.severity.cmp(&a.severity));
+        findings.sort_by_key(|f| std::cmp::Reverse(f.severity));
 
         let mut output = format!("# OWASP Top 10 2021 Scan: {}\n\n", repo_name);
         output.push_str(&format!("**Files Scanned**: {}\n", files.len()));
@@ -4446,12 +8742,16 @@ impl CodeIntelEngine {
         repo_name: &str,
         path: Option<&str>,
         exclude_tests: Option<bool>,
+        min_severity: Option<&str>,
+        rule_id: Option<&str>,
     ) -> Result<String> {
         use crate::security_rules::{is_test_file, SecurityRulesEngine};
 
         let repo_path = self.get_repo_path(repo_name)?;
-        let engine = SecurityRulesEngine::new();
+        let mut engine = SecurityRulesEngine::new();
+        engine.load_user_rules_from_repo(&repo_path);
         let exclude_tests = exclude_tests.unwrap_or(true);
+        let min_severity = parse_severity_threshold(min_severity);
 
         let files: Vec<_> = self
             .file_cache
@@ -4469,9 +8769,11 @@ impl CodeIntelEngine {
                 let file_str = file_path.to_string_lossy();
                 engine.scan_cwe_top25(content, &file_str, &detect_language_from_path(&file_str))
             })
+            .filter(|f| f.severity >= min_severity)
+            .filter(|f| rule_id.is_none_or(|id| f.rule_id.eq_ignore_ascii_case(id)))
             .collect();
 
-        findings.sort_by(|a, b| b.severity.cmp(&a.severity));
+        findings.sort_by_key(|f| std::cmp::Reverse(f.severity));
 
         let mut output = format!("# CWE Top 25 Scan: {}\n\n", repo_name);
         output.push_str(&format!("**Files Scanned**: {}\n", files.len()));
@@ -4608,13 +8910,12 @@ impl CodeIntelEngine {
 
         let repo_path = self.get_repo_path(repo_name)?;
         let full_path = validate_path(&repo_path, path)?;
-        let engine = SecurityRulesEngine::new();
+        let mut engine = SecurityRulesEngine::new();
+        engine.load_user_rules_from_repo(&repo_path);
 
         // Get file content
         let content = self
-            .file_cache
-            .get(&full_path)
-            .map(|entry| entry.value().clone())
+            .cached_file_content(&full_path)
             .ok_or_else(|| anyhow!("File not found: {}", path))?;
 
         let file_str = full_path.to_string_lossy();
@@ -4665,7 +8966,27 @@ impl CodeIntelEngine {
                     output.push_str(&fix.description);
                     output.push_str("\n\n");
 
-                    if !fix.diff.is_empty() {
+                    // Prefer an applyable unified diff; fall back to the
+                    // illustrative snippet diff when we can't build one
+                    let unified = fix
+                        .replacement
+                        .as_deref()
+                        .map(|replacement| {
+                            crate::security_rules::unified_diff(
+                                path,
+                                &content,
+                                f.line,
+                                &f.snippet,
+                                replacement,
+                            )
+                        })
+                        .unwrap_or_default();
+
+                    if !unified.is_empty() {
+                        output.push_str("```diff\n");
+                        output.push_str(&unified);
+                        output.push_str("```\n\n");
+                    } else if !fix.diff.is_empty() {
                         output.push_str("```diff\n");
                         output.push_str(&fix.diff);
                         output.push_str("\n```\n\n");
@@ -4712,6 +9033,88 @@ impl CodeIntelEngine {
         Ok(output)
     }
 
+    /// Apply a unified diff to a repository file.
+    ///
+    /// Opt-in (requires `--allow-writes`): the patch is verified against
+    /// the current file content, the patched result must still parse,
+    /// and the file is re-indexed after writing. `dry_run` validates and
+    /// reports without touching the file.
+    pub async fn apply_patch(
+        &self,
+        repo_name: &str,
+        path: &str,
+        patch: &str,
+        dry_run: bool,
+    ) -> Result<String> {
+        if !self.options.write_enabled {
+            return Err(anyhow!(
+                "apply_patch is disabled. Start the server with --allow-writes to enable write tools."
+            ));
+        }
+
+        let repo_path = self.get_repo_path(repo_name)?;
+        let full_path = validate_path(&repo_path, path)?;
+
+        let content = self
+            .cached_file_content(&full_path)
+            .ok_or_else(|| anyhow!("File not found: {}", path))?;
+
+        let patched = crate::security_rules::apply_unified_diff(&content, patch)
+            .map_err(|e| anyhow!("Patch does not apply: {}", e))?;
+
+        // The patched file must still parse before we write anything
+        let mut parse_validated = false;
+        if let Ok(tree) = self.parser.parse_to_tree(&full_path, &patched) {
+            if tree.root_node().has_error() {
+                return Err(anyhow!(
+                    "Patched content has syntax errors; refusing to write {}",
+                    path
+                ));
+            }
+            parse_validated = true;
+        }
+
+        let added = patch
+            .lines()
+            .filter(|l| l.starts_with('+') && !l.starts_with("+++"))
+            .count();
+        let removed = patch
+            .lines()
+            .filter(|l| l.starts_with('-') && !l.starts_with("---"))
+            .count();
+
+        let mut output = format!("# Apply Patch: {}:{}\n\n", repo_name, path);
+        output.push_str(&format!("**Lines**: +{} -{}\n", added, removed));
+        output.push_str(&format!(
+            "**Syntax Check**: {}\n",
+            if parse_validated {
+                "passed"
+            } else {
+                "skipped (unsupported language)"
+            }
+        ));
+
+        if dry_run {
+            output.push_str("**Mode**: dry run — no changes written\n\n");
+            output.push_str("Patch applies cleanly.\n");
+            return Ok(output);
+        }
+
+        std::fs::write(&full_path, &patched)
+            .map_err(|e| anyhow!("Failed to write {}: {}", path, e))?;
+
+        // Re-index the modified file so symbols and search stay current
+        let changes = vec![crate::persist::FileChange {
+            path: full_path.clone(),
+            change_type: crate::persist::ChangeType::Modified,
+        }];
+        self.process_file_changes(&changes).await?;
+
+        output.push_str("**Mode**: applied\n\n");
+        output.push_str("Patch written and file re-indexed.\n");
+        Ok(output)
+    }
+
     // ========================================================================
     // Phase 5: Supply Chain Security
     // ========================================================================
@@ -4901,7 +9304,7 @@ impl CodeIntelEngine {
         output.push_str("|---------|-------|-------------|\n");
 
         let mut sorted_licenses: Vec<_> = report.dependencies_by_license.iter().collect();
-        sorted_licenses.sort_by(|a, b| b.1.len().cmp(&a.1.len()));
+        sorted_licenses.sort_by_key(|l| std::cmp::Reverse(l.1.len()));
 
         for (license, dep_list) in sorted_licenses.iter().take(15) {
             let deps_preview: String = dep_list
@@ -5037,10 +9440,10 @@ impl CodeIntelEngine {
             output.push_str(&format!("**Upgrades Recommended**: {}\n\n", upgrades.len()));
 
             output.push_str(
-                "| Dependency | Current | Recommended | Breaking | Vulnerabilities Fixed |\n",
+                "| Dependency | Current | Recommended | Latest Compatible | Breaking | Vulnerabilities Fixed |\n",
             );
             output.push_str(
-                "|------------|---------|-------------|----------|----------------------|\n",
+                "|------------|---------|-------------|-------------------|----------|----------------------|\n",
             );
 
             for upgrade in &upgrades {
@@ -5051,10 +9454,11 @@ impl CodeIntelEngine {
                 };
                 let fixed = upgrade.vulnerabilities_fixed.join(", ");
                 output.push_str(&format!(
-                    "| {} | {} | {} | {} | {} |\n",
+                    "| {} | {} | {} | {} | {} | {} |\n",
                     upgrade.dependency,
                     upgrade.current_version,
                     upgrade.recommended_version,
+                    upgrade.latest_compatible.as_deref().unwrap_or("-"),
                     breaking,
                     fixed
                 ));
@@ -5076,6 +9480,18 @@ impl CodeIntelEngine {
                     output.push_str(
                         "- **⚠️ Breaking Changes Expected**: Review changelog before upgrading\n",
                     );
+                    if let Some(compatible) = &upgrade.latest_compatible {
+                        output.push_str(&format!(
+                            "- **Latest Compatible**: {} (stays on the current major)\n",
+                            compatible
+                        ));
+                    }
+                    if !upgrade.intermediate_majors.is_empty() {
+                        output.push_str(&format!(
+                            "- **Majors Crossed**: {} — read each major's changelog\n",
+                            upgrade.intermediate_majors.join(", ")
+                        ));
+                    }
                 }
 
                 if !upgrade.vulnerabilities_fixed.is_empty() {
@@ -5086,6 +9502,24 @@ impl CodeIntelEngine {
                 }
                 output.push('\n');
             }
+
+            // Phased plan: compatible bumps land together, one major at a time
+            let phases = analyzer.plan_upgrades(&upgrades);
+            if !phases.is_empty() {
+                output.push_str("## Ordered Upgrade Plan\n\n");
+                for (step, (title, phase_upgrades)) in phases.iter().enumerate() {
+                    output.push_str(&format!("{}. **{}**\n", step + 1, title));
+                    for upgrade in phase_upgrades {
+                        output.push_str(&format!(
+                            "   - {}: {} -> {}\n",
+                            upgrade.dependency,
+                            upgrade.current_version,
+                            upgrade.recommended_version
+                        ));
+                    }
+                }
+                output.push('\n');
+            }
         }
 
         Ok(output)
@@ -5245,7 +9679,7 @@ impl CodeIntelEngine {
                 })
                 .collect();
 
-            file_stats.sort_by(|a, b| (b.1 + b.2).cmp(&(a.1 + a.2)));
+            file_stats.sort_by_key(|f| std::cmp::Reverse(f.1 + f.2));
             file_stats.truncate(20);
 
             for (file, deps, dependents) in file_stats {
@@ -5329,10 +9763,200 @@ impl CodeIntelEngine {
                 output.push_str("```\n\n");
             }
 
-            output.push_str("## Recommendations\n\n");
-            output.push_str("- Extract shared code to a separate module\n");
-            output.push_str("- Use dependency injection to break cycles\n");
-            output.push_str("- Consider lazy imports or dynamic imports\n");
+            // Minimal set of edges whose removal breaks every cycle, with a
+            // concrete remediation suggestion for each
+            let cut_edges = graph.minimal_break_edges(&cycles);
+            if !cut_edges.is_empty() {
+                output.push_str("## Suggested Fixes\n\n");
+                output.push_str(&format!(
+                    "Removing {} import(s) breaks all {} cycle(s):\n\n",
+                    cut_edges.len(),
+                    cycles.len()
+                ));
+
+                for (from, to) in &cut_edges {
+                    let rel = |p: &std::path::Path| {
+                        p.strip_prefix(&repo_path)
+                            .map(|p| p.to_string_lossy().to_string())
+                            .unwrap_or_else(|_| p.to_string_lossy().to_string())
+                    };
+                    let from_rel = rel(from);
+                    let to_rel = rel(to);
+                    let to_dependents = graph.dependents(to).len();
+                    let from_dependents = graph.dependents(from).len();
+
+                    output.push_str(&format!("- Remove `{}` -> `{}`\n", from_rel, to_rel));
+                    if to_dependents > from_dependents {
+                        output.push_str(&format!(
+                            "  - `{}` has {} importer(s): extract the part `{}` needs into a shared module both can import\n",
+                            to_rel, to_dependents, from_rel
+                        ));
+                    } else {
+                        output.push_str(&format!(
+                            "  - Invert the dependency: move the symbols `{}` uses out of `{}`, or pass them in from the caller\n",
+                            from_rel, to_rel
+                        ));
+                    }
+                }
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// Check configured architecture layering rules against the import graph
+    pub async fn check_architecture(&self, repo_name: &str) -> Result<String> {
+        let arch = &self.options.architecture;
+        if arch.layers.is_empty() || arch.rules.is_empty() {
+            return Ok("# Architecture Check\n\n\
+                No architecture rules configured. Declare layers and rules in your \
+                config (e.g. `.narsil.yaml`):\n\n\
+                ```yaml\n\
+                architecture:\n\
+                \x20 layers:\n\
+                \x20   handlers: [\"src/handlers/**\"]\n\
+                \x20   persistence: [\"src/db/**\"]\n\
+                \x20 rules:\n\
+                \x20   - from: handlers\n\
+                \x20     deny: [persistence]\n\
+                ```\n"
+                .to_string());
+        }
+
+        let repo_path = self.get_repo_path(repo_name)?;
+        let symbols = self
+            .symbols
+            .get(repo_name)
+            .map(|s| s.clone())
+            .unwrap_or_default();
+
+        // Compile layer patterns once
+        let glob_options = glob::MatchOptions {
+            case_sensitive: true,
+            require_literal_separator: true,
+            require_literal_leading_dot: false,
+        };
+        let mut layer_patterns: Vec<(String, Vec<glob::Pattern>)> = Vec::new();
+        for (layer, patterns) in &arch.layers {
+            let mut compiled = Vec::new();
+            for pattern in patterns {
+                compiled.push(
+                    glob::Pattern::new(pattern)
+                        .with_context(|| format!("Invalid pattern for layer '{}'", layer))?,
+                );
+            }
+            layer_patterns.push((layer.clone(), compiled));
+        }
+        layer_patterns.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let layer_of = |rel_path: &str| -> Option<&str> {
+            layer_patterns
+                .iter()
+                .find(|(_, pats)| pats.iter().any(|p| p.matches_with(rel_path, glob_options)))
+                .map(|(name, _)| name.as_str())
+        };
+
+        // Build the import graph, keeping parsed imports for line reporting
+        let mut resolver = crate::incremental::SymbolResolver::new();
+        let mut file_imports: HashMap<String, Vec<crate::incremental::Import>> = HashMap::new();
+        let unique_files: HashSet<_> = symbols.iter().map(|s| s.file_path.clone()).collect();
+        for rel_path in unique_files {
+            let file_path = repo_path.join(&rel_path);
+            if file_path.exists() {
+                if let Ok(content) = std::fs::read_to_string(&file_path) {
+                    let imports = parse_imports_from_content(&content, &rel_path);
+                    resolver.register_imports(&file_path, imports.clone());
+                    file_imports.insert(rel_path, imports);
+                }
+            }
+        }
+        let graph = resolver.build_import_graph(&repo_path);
+
+        // Evaluate every import edge against the rules
+        let mut violations: Vec<(String, usize, String, String, String, Option<String>)> =
+            Vec::new();
+        for (source_rel, imports) in &file_imports {
+            let Some(source_layer) = layer_of(source_rel) else {
+                continue;
+            };
+            let denied: Vec<(&str, Option<&str>)> = arch
+                .rules
+                .iter()
+                .filter(|r| r.from == source_layer)
+                .flat_map(|r| {
+                    r.deny
+                        .iter()
+                        .map(move |d| (d.as_str(), r.reason.as_deref()))
+                })
+                .collect();
+            if denied.is_empty() {
+                continue;
+            }
+
+            let source_path = repo_path.join(source_rel);
+            for target in graph.dependencies(&source_path) {
+                let target_rel = target
+                    .strip_prefix(&repo_path)
+                    .map(|p| p.to_string_lossy().to_string())
+                    .unwrap_or_else(|_| target.to_string_lossy().to_string());
+                let Some(target_layer) = layer_of(&target_rel) else {
+                    continue;
+                };
+                let Some((_, reason)) = denied.iter().find(|(d, _)| *d == target_layer) else {
+                    continue;
+                };
+
+                // Best-effort line: the import whose path resolves to the target
+                let target_stem = std::path::Path::new(&target_rel)
+                    .file_stem()
+                    .map(|s| s.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                let line = imports
+                    .iter()
+                    .find(|i| {
+                        i.import_path.ends_with(&target_stem)
+                            || i.import_path
+                                .rsplit(['/', ':', '.'])
+                                .next()
+                                .is_some_and(|last| last == target_stem)
+                    })
+                    .map(|i| i.line)
+                    .unwrap_or(1);
+
+                violations.push((
+                    source_rel.clone(),
+                    line,
+                    target_rel,
+                    source_layer.to_string(),
+                    target_layer.to_string(),
+                    reason.map(|r| r.to_string()),
+                ));
+            }
+        }
+        violations.sort();
+        violations.dedup();
+
+        let mut output = String::new();
+        output.push_str("# Architecture Check\n\n");
+        output.push_str(&format!(
+            "{} layer(s), {} rule(s) evaluated\n\n",
+            arch.layers.len(),
+            arch.rules.len()
+        ));
+
+        if violations.is_empty() {
+            output.push_str("No layering violations found.\n");
+        } else {
+            output.push_str(&format!("**Found {} violation(s)**\n\n", violations.len()));
+            for (source, line, target, from_layer, to_layer, reason) in &violations {
+                output.push_str(&format!(
+                    "- `{}:{}` imports `{}` ({} -> {} is forbidden)\n",
+                    source, line, target, from_layer, to_layer
+                ));
+                if let Some(reason) = reason {
+                    output.push_str(&format!("  - Reason: {}\n", reason));
+                }
+            }
         }
 
         Ok(output)
@@ -5460,7 +10084,7 @@ impl CodeIntelEngine {
             }
 
             let mut counts: Vec<_> = by_kind.into_iter().collect();
-            counts.sort_by(|a, b| b.1.cmp(&a.1));
+            counts.sort_by_key(|c| std::cmp::Reverse(c.1));
 
             for (kind, count) in counts {
                 output.push_str(&format!("- {:?}: {}\n", kind, count));
@@ -5576,65 +10200,437 @@ impl CodeIntelEngine {
         let repo_path = self.get_repo_path(repo_name)?;
         let file_path = validate_path(&repo_path, path)?;
 
-        let content = std::fs::read_to_string(&file_path).context("Failed to read file")?;
+        let content = std::fs::read_to_string(&file_path).context("Failed to read file")?;
+
+        // Languages with explicit export syntax get the real public
+        // surface (__all__, re-exports, barrels, default exports)
+        if let Some(exports) = crate::exports::extract_exports(path, &content) {
+            return Ok(format_export_entries(path, &exports));
+        }
+
+        let symbols = self
+            .symbols
+            .get(repo_name)
+            .map(|s| s.clone())
+            .unwrap_or_default();
+
+        // Find symbols defined in this file
+        let file_symbols: Vec<_> = symbols.iter().filter(|s| s.file_path == path).collect();
+
+        let mut output = String::new();
+        output.push_str(&format!("# Export Map: {}\n\n", path));
+
+        if file_symbols.is_empty() {
+            output.push_str("No exported symbols found.\n");
+        } else {
+            // Get all symbols - we can't determine visibility without AST info
+            let mut public_symbols: Vec<_> = file_symbols.iter().collect();
+            public_symbols.sort_by_key(|a| a.start_line);
+
+            output.push_str("## Exported Symbols\n\n");
+            output.push_str("| Name | Kind | Line | Signature |\n");
+            output.push_str("|------|------|------|----------|\n");
+
+            for symbol in public_symbols {
+                let sig = symbol.signature.as_deref().unwrap_or("-");
+                output.push_str(&format!(
+                    "| `{}` | {:?} | {} | {} |\n",
+                    symbol.name,
+                    symbol.kind,
+                    symbol.start_line,
+                    if sig.len() > 50 { &sig[..50] } else { sig }
+                ));
+            }
+
+            // Detect export statements in the file
+            let export_lines: Vec<_> = content
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| {
+                    let trimmed = line.trim();
+                    trimmed.starts_with("export ")
+                        || trimmed.starts_with("pub ")
+                        || trimmed.starts_with("module.exports")
+                        || trimmed.starts_with("__all__")
+                })
+                .collect();
+
+            if !export_lines.is_empty() {
+                output.push_str("\n## Export Statements\n\n");
+                for (line_num, line) in export_lines {
+                    output.push_str(&format!(
+                        "- Line {}: `{}`\n",
+                        line_num + 1,
+                        line.trim().chars().take(80).collect::<String>()
+                    ));
+                }
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// Report the public API surface of a crate/package in a stable,
+    /// diff-friendly format for API review
+    pub async fn get_api_surface(&self, repo_name: &str, package: Option<&str>) -> Result<String> {
+        use crate::security_rules::is_test_file;
+        use std::collections::BTreeMap;
+
+        let repo_path = self.get_repo_path(repo_name)?;
+        let symbols = self
+            .symbols
+            .get(repo_name)
+            .ok_or_else(|| self.repo_not_found_error(repo_name))?;
+
+        // Group symbols by file (BTreeMap keeps output order stable)
+        let mut by_file: BTreeMap<String, Vec<Symbol>> = BTreeMap::new();
+        for symbol in symbols.iter() {
+            if is_test_file(&symbol.file_path) {
+                continue;
+            }
+            if let Some(prefix) = package {
+                let prefix = prefix.trim_end_matches('/');
+                if symbol.file_path != prefix
+                    && !symbol.file_path.starts_with(&format!("{}/", prefix))
+                {
+                    continue;
+                }
+            }
+            by_file
+                .entry(symbol.file_path.clone())
+                .or_default()
+                .push(symbol.clone());
+        }
+
+        let mut output = String::new();
+        match package {
+            Some(p) => output.push_str(&format!("# API Surface: {} ({})\n\n", repo_name, p)),
+            None => output.push_str(&format!("# API Surface: {}\n\n", repo_name)),
+        }
+
+        let mut total = 0usize;
+        let mut body = String::new();
+        for (file, mut file_symbols) in by_file {
+            let file_path = repo_path.join(&file);
+            let content = match std::fs::read_to_string(&file_path) {
+                Ok(c) => c,
+                Err(_) => continue,
+            };
+            let lines: Vec<&str> = content.lines().collect();
+
+            // Stable order: by name, then line for same-named overloads
+            file_symbols.sort_by(|a, b| a.name.cmp(&b.name).then(a.start_line.cmp(&b.start_line)));
+
+            let mut entries = String::new();
+            for symbol in &file_symbols {
+                let decl_line = lines
+                    .get(symbol.start_line.saturating_sub(1))
+                    .unwrap_or(&"");
+                if !symbol_is_public(decl_line, &symbol.name, &file) {
+                    continue;
+                }
+                total += 1;
+
+                let display = symbol.qualified_name.as_deref().unwrap_or(&symbol.name);
+                match &symbol.signature {
+                    Some(sig) => {
+                        entries.push_str(&format!("- {:?} `{}`: `{}`\n", symbol.kind, display, sig))
+                    }
+                    None => entries.push_str(&format!("- {:?} `{}`\n", symbol.kind, display)),
+                }
+                if let Some(doc) = &symbol.doc_comment {
+                    if let Some(first) = doc.lines().find(|l| !l.trim().is_empty()) {
+                        let summary: String = first.trim().chars().take(100).collect();
+                        entries.push_str(&format!("  - {}\n", summary));
+                    }
+                }
+            }
+
+            if !entries.is_empty() {
+                body.push_str(&format!("## {}\n\n{}\n", file, entries));
+            }
+        }
+
+        output.push_str(&format!("{} public symbol(s)\n\n", total));
+        if total == 0 {
+            output.push_str("No public symbols found.\n");
+        } else {
+            output.push_str(&body);
+        }
+
+        Ok(output)
+    }
+
+    /// Report documentation coverage of public symbols per module, listing
+    /// undocumented public APIs
+    pub async fn get_doc_coverage(
+        &self,
+        repo_name: &str,
+        package: Option<&str>,
+        limit: usize,
+    ) -> Result<String> {
+        use crate::security_rules::is_test_file;
+        use std::collections::BTreeMap;
+
+        let repo_path = self.get_repo_path(repo_name)?;
+        let symbols = self
+            .symbols
+            .get(repo_name)
+            .ok_or_else(|| self.repo_not_found_error(repo_name))?;
+
+        let mut by_file: BTreeMap<String, Vec<Symbol>> = BTreeMap::new();
+        for symbol in symbols.iter() {
+            if is_test_file(&symbol.file_path) {
+                continue;
+            }
+            if let Some(prefix) = package {
+                let prefix = prefix.trim_end_matches('/');
+                if symbol.file_path != prefix
+                    && !symbol.file_path.starts_with(&format!("{}/", prefix))
+                {
+                    continue;
+                }
+            }
+            by_file
+                .entry(symbol.file_path.clone())
+                .or_default()
+                .push(symbol.clone());
+        }
+
+        // (public, documented) per module plus the undocumented symbols
+        let mut module_stats: Vec<(String, usize, usize)> = Vec::new();
+        let mut undocumented: Vec<(String, String, SymbolKind, usize)> = Vec::new();
+        for (file, file_symbols) in by_file {
+            let file_path = repo_path.join(&file);
+            let content = match std::fs::read_to_string(&file_path) {
+                Ok(c) => c,
+                Err(_) => continue,
+            };
+            let lines: Vec<&str> = content.lines().collect();
+
+            let mut public = 0usize;
+            let mut documented = 0usize;
+            for symbol in &file_symbols {
+                // Variants and fields inherit their parent's docs; skip them
+                if matches!(
+                    symbol.kind,
+                    SymbolKind::EnumMember | SymbolKind::Field | SymbolKind::Parameter
+                ) {
+                    continue;
+                }
+                let decl_line = lines
+                    .get(symbol.start_line.saturating_sub(1))
+                    .unwrap_or(&"");
+                if !symbol_is_public(decl_line, &symbol.name, &file) {
+                    continue;
+                }
+                public += 1;
+                if symbol
+                    .doc_comment
+                    .as_deref()
+                    .is_some_and(|d| !d.trim().is_empty())
+                {
+                    documented += 1;
+                } else {
+                    undocumented.push((
+                        file.clone(),
+                        symbol
+                            .qualified_name
+                            .clone()
+                            .unwrap_or_else(|| symbol.name.clone()),
+                        symbol.kind.clone(),
+                        symbol.start_line,
+                    ));
+                }
+            }
+            if public > 0 {
+                module_stats.push((file, public, documented));
+            }
+        }
+
+        let total_public: usize = module_stats.iter().map(|(_, p, _)| p).sum();
+        let total_documented: usize = module_stats.iter().map(|(_, _, d)| d).sum();
+
+        let mut output = String::new();
+        output.push_str(&format!("# Documentation Coverage: {}\n\n", repo_name));
+        if total_public == 0 {
+            output.push_str("No public symbols found.\n");
+            return Ok(output);
+        }
+
+        output.push_str(&format!(
+            "**Overall**: {:.1}% ({}/{} public symbols documented)\n\n",
+            total_documented as f64 / total_public as f64 * 100.0,
+            total_documented,
+            total_public
+        ));
+
+        // Worst-covered modules first
+        module_stats.sort_by(|a, b| {
+            let pa = a.2 as f64 / a.1 as f64;
+            let pb = b.2 as f64 / b.1 as f64;
+            pa.partial_cmp(&pb)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.0.cmp(&b.0))
+        });
+
+        output.push_str("## Per Module\n\n");
+        output.push_str("| Module | Public | Documented | Coverage |\n");
+        output.push_str("|--------|--------|------------|----------|\n");
+        for (file, public, documented) in &module_stats {
+            output.push_str(&format!(
+                "| `{}` | {} | {} | {:.1}% |\n",
+                file,
+                public,
+                documented,
+                *documented as f64 / *public as f64 * 100.0
+            ));
+        }
+
+        if !undocumented.is_empty() {
+            output.push_str(&format!(
+                "\n## Undocumented Public APIs ({})\n\n",
+                undocumented.len()
+            ));
+            for (file, name, kind, line) in undocumented.iter().take(limit) {
+                output.push_str(&format!("- {:?} `{}` (`{}:{}`)\n", kind, name, file, line));
+            }
+            if undocumented.len() > limit {
+                output.push_str(&format!(
+                    "\n*... and {} more (raise `limit` to see them)*\n",
+                    undocumented.len() - limit
+                ));
+            }
+        }
+
+        Ok(output)
+    }
 
-        let symbols = self
-            .symbols
-            .get(repo_name)
-            .map(|s| s.clone())
-            .unwrap_or_default();
+    /// Compare the public API surface between two refs and classify the
+    /// changes semver-style (major/minor/patch)
+    pub async fn check_breaking_changes(
+        &self,
+        repo: &str,
+        base: &str,
+        head: Option<&str>,
+    ) -> Result<String> {
+        use crate::security_rules::is_test_file;
+        use std::collections::BTreeMap;
 
-        // Find symbols defined in this file
-        let file_symbols: Vec<_> = symbols.iter().filter(|s| s.file_path == path).collect();
+        let git_repo = self
+            .git_repos
+            .get(repo)
+            .ok_or_else(|| anyhow!("Git not available for {}. Enable with --git flag.", repo))?;
+        let head = head.unwrap_or("HEAD");
+
+        // Public symbols of one version of a file: display name -> (kind, signature)
+        let surface_of =
+            |path: &str, content: &str| -> BTreeMap<String, (SymbolKind, Option<String>)> {
+                let mut surface = BTreeMap::new();
+                let Ok(parsed) = self.parser.parse_file(Path::new(path), content) else {
+                    return surface;
+                };
+                let lines: Vec<&str> = content.lines().collect();
+                for symbol in parsed.symbols {
+                    let decl_line = lines
+                        .get(symbol.start_line.saturating_sub(1))
+                        .unwrap_or(&"");
+                    if !symbol_is_public(decl_line, &symbol.name, path) {
+                        continue;
+                    }
+                    let display = symbol
+                        .qualified_name
+                        .clone()
+                        .unwrap_or_else(|| symbol.name.clone());
+                    surface.insert(display, (symbol.kind, symbol.signature));
+                }
+                surface
+            };
 
-        let mut output = String::new();
-        output.push_str(&format!("# Export Map: {}\n\n", path));
+        let mut major: Vec<String> = Vec::new();
+        let mut minor: Vec<String> = Vec::new();
 
-        if file_symbols.is_empty() {
-            output.push_str("No exported symbols found.\n");
-        } else {
-            // Get all symbols - we can't determine visibility without AST info
-            let mut public_symbols: Vec<_> = file_symbols.iter().collect();
-            public_symbols.sort_by(|a, b| a.start_line.cmp(&b.start_line));
+        for (status, path) in git_repo.changed_files_between(base, head)? {
+            if is_test_file(&path) {
+                continue;
+            }
 
-            output.push_str("## Exported Symbols\n\n");
-            output.push_str("| Name | Kind | Line | Signature |\n");
-            output.push_str("|------|------|------|----------|\n");
+            let old_surface = if status != 'A' {
+                git_repo
+                    .file_at_ref(base, &path)
+                    .map(|c| surface_of(&path, &c))
+                    .unwrap_or_default()
+            } else {
+                BTreeMap::new()
+            };
+            let new_surface = if status != 'D' {
+                git_repo
+                    .file_at_ref(head, &path)
+                    .map(|c| surface_of(&path, &c))
+                    .unwrap_or_default()
+            } else {
+                BTreeMap::new()
+            };
 
-            for symbol in public_symbols {
-                let sig = symbol.signature.as_deref().unwrap_or("-");
-                output.push_str(&format!(
-                    "| `{}` | {:?} | {} | {} |\n",
-                    symbol.name,
-                    symbol.kind,
-                    symbol.start_line,
-                    if sig.len() > 50 { &sig[..50] } else { sig }
-                ));
+            for (name, (kind, old_sig)) in &old_surface {
+                match new_surface.get(name) {
+                    None => {
+                        if *kind == SymbolKind::EnumMember {
+                            major.push(format!(
+                                "`{}`: enum narrowed, variant `{}` removed",
+                                path, name
+                            ));
+                        } else {
+                            major.push(format!("`{}`: {:?} `{}` removed", path, kind, name));
+                        }
+                    }
+                    Some((_, new_sig)) if new_sig != old_sig => {
+                        major.push(format!(
+                            "`{}`: `{}` signature changed: `{}` -> `{}`",
+                            path,
+                            name,
+                            old_sig.as_deref().unwrap_or("-"),
+                            new_sig.as_deref().unwrap_or("-")
+                        ));
+                    }
+                    Some(_) => {}
+                }
             }
+            for (name, (kind, _)) in &new_surface {
+                if !old_surface.contains_key(name) {
+                    minor.push(format!("`{}`: {:?} `{}` added", path, kind, name));
+                }
+            }
+        }
 
-            // Detect export statements in the file
-            let export_lines: Vec<_> = content
-                .lines()
-                .enumerate()
-                .filter(|(_, line)| {
-                    let trimmed = line.trim();
-                    trimmed.starts_with("export ")
-                        || trimmed.starts_with("pub ")
-                        || trimmed.starts_with("module.exports")
-                        || trimmed.starts_with("__all__")
-                })
-                .collect();
+        let verdict = if !major.is_empty() {
+            "major"
+        } else if !minor.is_empty() {
+            "minor"
+        } else {
+            "patch"
+        };
 
-            if !export_lines.is_empty() {
-                output.push_str("\n## Export Statements\n\n");
-                for (line_num, line) in export_lines {
-                    output.push_str(&format!(
-                        "- Line {}: `{}`\n",
-                        line_num + 1,
-                        line.trim().chars().take(80).collect::<String>()
-                    ));
-                }
+        let mut output = String::new();
+        output.push_str(&format!("# Breaking Change Check: {}..{}\n\n", base, head));
+        output.push_str(&format!("**Required version bump**: {}\n\n", verdict));
+
+        if !major.is_empty() {
+            output.push_str(&format!("## Breaking Changes ({})\n\n", major.len()));
+            for item in &major {
+                output.push_str(&format!("- {}\n", item));
             }
+            output.push('\n');
+        }
+        if !minor.is_empty() {
+            output.push_str(&format!("## Additions ({})\n\n", minor.len()));
+            for item in &minor {
+                output.push_str(&format!("- {}\n", item));
+            }
+            output.push('\n');
+        }
+        if major.is_empty() && minor.is_empty() {
+            output.push_str("No public API changes detected.\n");
         }
 
         Ok(output)
@@ -5812,6 +10808,21 @@ impl CodeIntelEngine {
         output.push_str(&format!("**Dimension**: {}\n", stats.dimension));
         output.push_str(&format!("**Indexed Documents**: {}\n", stats.indexed_count));
 
+        if let Some(usage) = neural.api_usage() {
+            output.push_str("\n## API Usage\n\n");
+            output.push_str(&format!("- **Requests sent**: {}\n", usage.requests));
+            output.push_str(&format!("- **Retries**: {}\n", usage.retries));
+            output.push_str(&format!("- **Texts embedded**: {}\n", usage.texts_embedded));
+            output.push_str(&format!(
+                "- **Characters embedded**: {}\n",
+                usage.chars_embedded
+            ));
+            output.push_str(&format!(
+                "- **Estimated tokens**: ~{}\n",
+                usage.estimated_tokens()
+            ));
+        }
+
         Ok(output)
     }
 
@@ -5861,9 +10872,16 @@ impl CodeIntelEngine {
 
         let cfg_ref = found_cfg.as_ref();
 
+        // Parameter annotations seed the inference with concrete types
+        let params = if language == "python" {
+            crate::type_inference::extract_function_params(&content, function)
+        } else {
+            Vec::new()
+        };
+
         // Create inferencer and run
         let mut inferencer = TypeInferencer::new(&content, cfg_ref, &language);
-        let result = inferencer.infer_from_cfg(&[]);
+        let result = inferencer.infer_from_cfg(&params);
 
         Ok(result.to_markdown())
     }
@@ -5910,8 +10928,13 @@ impl CodeIntelEngine {
         let mut all_errors: Vec<(String, TypeError)> = Vec::new();
 
         for cfg_item in &cfgs {
+            let params = if language == "python" {
+                crate::type_inference::extract_function_params(&content, &cfg_item.function_name)
+            } else {
+                Vec::new()
+            };
             let mut inferencer = TypeInferencer::new(&content, Some(cfg_item), &language);
-            let result = inferencer.infer_from_cfg(&[]);
+            let result = inferencer.infer_from_cfg(&params);
 
             for error in result.errors {
                 all_errors.push((cfg_item.function_name.clone(), error));
@@ -5995,12 +11018,21 @@ impl CodeIntelEngine {
         if let Some(cfg_item) = containing_cfg {
             output.push_str(&format!("**Function**: `{}`\n\n", cfg_item.function_name));
 
-            // Get type information
+            // Get type information, seeded with parameter annotations so
+            // typed handler arguments (FastAPI/Pydantic) are tracked precisely
+            let params = if language == "python" {
+                crate::type_inference::extract_function_params(&content, &cfg_item.function_name)
+            } else {
+                Vec::new()
+            };
             let mut inferencer = TypeInferencer::new(&content, Some(cfg_item), &language);
-            let types = inferencer.infer_from_cfg(&[]);
+            let types = inferencer.infer_from_cfg(&params);
 
             // Get taint information using the existing analyzer
-            let taint_result = crate::taint::analyze_code(&content, path);
+            let custom_taint =
+                crate::security_config::CustomTaintConfig::load_from_repo(&repo_meta.path);
+            let taint_result =
+                crate::taint::analyze_code_with_config(&content, path, custom_taint.as_ref());
 
             // Combine type and taint info
             output.push_str("## Type Information at Source\n\n");
@@ -6032,6 +11064,15 @@ impl CodeIntelEngine {
                             vars.get(&source.variable)
                         })
                         .map(|t: &crate::type_inference::Type| t.display_name())
+                        .or_else(|| {
+                            // Annotated parameters are typed even without an
+                            // assignment on the source line
+                            types
+                                .parameters
+                                .iter()
+                                .find(|(n, _)| n == &source.variable)
+                                .map(|(_, t)| t.display_name())
+                        })
                         .unwrap_or_else(|| "unknown".to_string());
 
                     output.push_str(&format!(
@@ -6071,6 +11112,13 @@ impl CodeIntelEngine {
                                     vars.get(&step.variable)
                                 })
                                 .map(|t: &crate::type_inference::Type| t.display_name())
+                                .or_else(|| {
+                                    types
+                                        .parameters
+                                        .iter()
+                                        .find(|(n, _)| n == &step.variable)
+                                        .map(|(_, t)| t.display_name())
+                                })
                                 .unwrap_or_else(|| "unknown".to_string());
 
                             output.push_str(&format!(
@@ -6105,6 +11153,36 @@ impl CodeIntelEngine {
                 }
             }
 
+            // Annotated non-string scalars are unlikely to carry exploitable
+            // taint; call them out to reduce false positives
+            for flow in &taint_result.flows {
+                if flow.is_sanitized {
+                    continue;
+                }
+                let source_type = types
+                    .parameters
+                    .iter()
+                    .find(|(n, _)| n == &flow.source.variable)
+                    .map(|(_, t)| t)
+                    .or_else(|| {
+                        types
+                            .variable_types
+                            .get(&flow.source.line)
+                            .and_then(|vars| vars.get(&flow.source.variable))
+                    });
+                if let Some(ty) = source_type {
+                    use crate::type_inference::Type;
+                    if matches!(ty, Type::Int | Type::Float | Type::Bool) {
+                        output.push_str(&format!(
+                            "ℹ️ `{}` is typed `{}`; numeric/boolean values rarely carry exploitable taint\n",
+                            flow.source.variable,
+                            ty.display_name()
+                        ));
+                        has_notes = true;
+                    }
+                }
+            }
+
             if !has_notes {
                 output.push_str("*No immediate security concerns detected*\n");
             }
@@ -6118,6 +11196,165 @@ impl CodeIntelEngine {
         Ok(output)
     }
 
+    /// Find dereferences and `unwrap()` calls on values that may be None/null
+    pub async fn find_null_risks(
+        &self,
+        repo: &str,
+        path: &str,
+        function: Option<&str>,
+        exclude_tests: Option<bool>,
+    ) -> Result<String> {
+        use crate::security_rules::is_test_file;
+
+        let exclude_tests = exclude_tests.unwrap_or(true);
+        if exclude_tests && is_test_file(path) {
+            return Ok(format!("# Null Safety Analysis: `{}`\n\nSkipped: test file (use exclude_tests=false to include)", path));
+        }
+
+        let repo_meta = self
+            .repos
+            .get(repo)
+            .ok_or_else(|| anyhow!("Repository '{}' not found", repo))?;
+
+        let full_path = validate_path(&repo_meta.path, path)?;
+        let content = std::fs::read_to_string(&full_path).context("Failed to read file")?;
+        let language = detect_language_from_path(path);
+
+        // Check if it's a dynamic language
+        if !matches!(language.as_str(), "python" | "javascript" | "typescript") {
+            return Err(anyhow!(
+                "Null safety analysis is only available for Python and JavaScript/TypeScript. Found: {}",
+                language
+            ));
+        }
+
+        // Parse and analyze
+        let parsed = self.parser.parse_file(&full_path, &content)?;
+        let tree = parsed
+            .tree
+            .as_ref()
+            .ok_or_else(|| anyhow!("Failed to parse file"))?;
+        let cfgs = cfg::analyze_function(tree, &content, path)?;
+        let analyses = dfg::analyze_file(tree, &content, path)?;
+
+        let mut output = String::new();
+        output.push_str(&format!("# Null Safety Analysis: `{}`\n\n", path));
+
+        let mut total_risks = 0;
+
+        for cfg_item in &cfgs {
+            if let Some(func_name) = function {
+                if cfg_item.function_name != func_name {
+                    continue;
+                }
+            }
+
+            // Infer types to find variables that can be None along some path
+            let mut inferencer = TypeInferencer::new(&content, Some(cfg_item), &language);
+            let types = inferencer.infer_from_cfg(&[]);
+
+            let mut nullable_since: HashMap<&str, usize> = HashMap::new();
+            let mut nullable_type: HashMap<&str, String> = HashMap::new();
+            for (line, vars) in &types.variable_types {
+                for (var, ty) in vars {
+                    if ty.is_nullable() {
+                        let entry = nullable_since.entry(var.as_str()).or_insert(*line);
+                        if *line < *entry {
+                            *entry = *line;
+                        }
+                        nullable_type.insert(var.as_str(), ty.display_name());
+                    }
+                }
+            }
+
+            if nullable_since.is_empty() {
+                continue;
+            }
+
+            // Statement text by line, and the first line each variable is
+            // guarded against None
+            let mut stmt_text: HashMap<usize, Vec<&str>> = HashMap::new();
+            let mut guarded: HashMap<&str, usize> = HashMap::new();
+            for block in cfg_item.blocks.values() {
+                for stmt in &block.statements {
+                    stmt_text.entry(stmt.line).or_default().push(&stmt.text);
+                    for &var in nullable_since.keys() {
+                        if is_null_guard(&stmt.text, var) {
+                            let entry = guarded.entry(var).or_insert(stmt.line);
+                            if stmt.line < *entry {
+                                *entry = stmt.line;
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Uses come from the DFG; the statement text tells us whether a
+            // use actually dereferences the value
+            let mut risks: Vec<String> = Vec::new();
+            let mut seen: HashSet<(usize, &str)> = HashSet::new();
+            if let Some(analysis) = analyses
+                .iter()
+                .find(|a| a.function_name == cfg_item.function_name)
+            {
+                for use_ in &analysis.uses {
+                    let var = use_.variable.as_str();
+                    let Some(&since) = nullable_since.get(var) else {
+                        continue;
+                    };
+                    if use_.line < since || !seen.insert((use_.line, var)) {
+                        continue;
+                    }
+                    if guarded.get(var).is_some_and(|&g| g <= use_.line) {
+                        continue;
+                    }
+                    let Some(texts) = stmt_text.get(&use_.line) else {
+                        continue;
+                    };
+                    for text in texts {
+                        if text.contains(&format!("{}.unwrap()", var)) {
+                            risks.push(format!(
+                                "- `{}` at line {}: `unwrap()` on value that may be None (type `{}`)",
+                                var, use_.line, nullable_type[var]
+                            ));
+                            total_risks += 1;
+                            break;
+                        }
+                        if has_null_deref(text, var) {
+                            risks.push(format!(
+                                "- `{}` at line {}: dereference of possibly-None value (type `{}`)",
+                                var, use_.line, nullable_type[var]
+                            ));
+                            total_risks += 1;
+                            break;
+                        }
+                    }
+                }
+            }
+
+            if !risks.is_empty() {
+                output.push_str(&format!("## Function: `{}`\n\n", cfg_item.function_name));
+                output.push_str("⚠️ **Possible None/null misuse:**\n\n");
+                for risk in &risks {
+                    output.push_str(risk);
+                    output.push('\n');
+                }
+                output.push('\n');
+            }
+        }
+
+        if total_risks == 0 {
+            output.push_str("✅ No null-safety risks detected.\n");
+        } else {
+            output.push_str(&format!(
+                "\n**Total**: {} potential risk(s) found.\n",
+                total_risks
+            ));
+        }
+
+        Ok(output)
+    }
+
     // ========================================================================
     // Graph Visualization Helper Methods
     // ========================================================================
@@ -6589,7 +11826,7 @@ const SECURITY_SCAN_EXTENSIONS: &[&str] = &[
 /// Parse severity threshold from string
 fn parse_severity_threshold(threshold: Option<&str>) -> crate::taint::Severity {
     use crate::taint::Severity;
-    match threshold {
+    match threshold.map(|t| t.to_lowercase()).as_deref() {
         Some("critical") => Severity::Critical,
         Some("high") => Severity::High,
         Some("medium") => Severity::Medium,
@@ -6745,6 +11982,84 @@ fn detect_language_from_path(path: &str) -> String {
 
 // Helper functions
 
+/// Longest line (in bytes) we still consider hand-written source. Minified
+/// bundles collapse to one enormous line; normal code rarely passes a few
+/// hundred characters.
+const MAX_SOURCE_LINE_BYTES: usize = 5000;
+
+/// Cut content at the last newline at or before `limit` bytes so huge files
+/// can be partially parsed without splitting a line or a UTF-8 sequence.
+fn truncate_for_parse(content: &str, limit: usize) -> &str {
+    if limit == 0 || content.len() <= limit {
+        return content;
+    }
+    match content.as_bytes()[..limit]
+        .iter()
+        .rposition(|&b| b == b'\n')
+    {
+        Some(pos) => &content[..pos + 1],
+        None => {
+            // Single enormous line: fall back to the nearest char boundary
+            let mut end = limit;
+            while end > 0 && !content.is_char_boundary(end) {
+                end -= 1;
+            }
+            &content[..end]
+        }
+    }
+}
+
+/// Classify content that should stay out of the search/embedding indices:
+/// binary blobs (null bytes) and minified bundles (absurd line lengths, a
+/// `.min.*` name, or a trailing source-map pointer). Returns a label for
+/// the skip stats, or `None` for normal source.
+fn detect_unindexable(path: &Path, content: &str) -> Option<&'static str> {
+    if content.contains('\0') {
+        return Some("binary");
+    }
+
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    if name.ends_with(".min.js") || name.ends_with(".min.css") {
+        return Some("minified");
+    }
+
+    if content.lines().any(|l| l.len() > MAX_SOURCE_LINE_BYTES) {
+        return Some("minified");
+    }
+
+    // Generated bundles usually end with a source-map pointer
+    if content.trim_end().lines().next_back().is_some_and(|l| {
+        l.starts_with("//# sourceMappingURL=") || l.starts_with("/*# sourceMappingURL=")
+    }) {
+        return Some("minified");
+    }
+
+    None
+}
+
+/// Reorder files for indexing so the most relevant ones are parsed first:
+/// working-tree changes from `git status`, then most recently modified.
+/// Falls back to mtime ordering alone when the repo is not a git checkout.
+fn prioritize_index_order(repo_root: &Path, files: &mut [PathBuf]) {
+    use std::collections::HashSet;
+
+    let git_modified: HashSet<PathBuf> = crate::git::GitRepo::new(repo_root)
+        .and_then(|repo| repo.modified_files())
+        .map(|paths| paths.into_iter().map(|p| repo_root.join(p)).collect())
+        .unwrap_or_default();
+
+    files.sort_by_cached_key(|path| {
+        let rank = if git_modified.contains(path) { 0u8 } else { 1 };
+        let mtime = std::fs::metadata(path)
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|t| t.duration_since(SystemTime::UNIX_EPOCH).ok())
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        (rank, std::cmp::Reverse(mtime))
+    });
+}
+
 fn expand_path(path: &Path) -> Result<PathBuf> {
     let path_str = path.to_string_lossy();
     if let Some(stripped) = path_str.strip_prefix("~") {
@@ -6841,7 +12156,9 @@ fn get_language_id(path: &str) -> &'static str {
         Some("java") => "java",
         Some("c" | "h") => "c",
         Some("cpp" | "hpp" | "cc") => "cpp",
-        Some("md") => "markdown",
+        Some("md" | "markdown") => "markdown",
+        Some("rst") => "restructuredtext",
+        Some("adoc" | "asciidoc") => "asciidoc",
         Some("json") => "json",
         Some("toml") => "toml",
         Some("yaml" | "yml") => "yaml",
@@ -6853,6 +12170,114 @@ fn get_language_id(path: &str) -> &'static str {
     }
 }
 
+/// Render an export map extracted by [`crate::exports`] as markdown
+fn format_export_entries(path: &str, exports: &[crate::exports::ExportEntry]) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("# Export Map: {}\n\n", path));
+
+    if exports.is_empty() {
+        output.push_str("No exported symbols found.\n");
+        return output;
+    }
+
+    output.push_str("## Public API\n\n");
+    output.push_str("| Name | Kind | Line | From |\n");
+    output.push_str("|------|------|------|------|\n");
+    for entry in exports {
+        output.push_str(&format!(
+            "| `{}` | {} | {} | {} |\n",
+            entry.name,
+            entry.kind,
+            entry.line,
+            entry
+                .source
+                .as_deref()
+                .map(|s| format!("`{}`", s))
+                .unwrap_or_else(|| "-".to_string())
+        ));
+    }
+
+    output
+}
+
+/// Determine whether a symbol is part of the public API, using the
+/// language's visibility conventions on its declaration line
+fn symbol_is_public(decl_line: &str, name: &str, file_path: &str) -> bool {
+    let ext = std::path::Path::new(file_path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("");
+    let trimmed = decl_line.trim_start();
+
+    match ext {
+        // `pub` only; pub(crate)/pub(super) are not part of the external API
+        "rs" => trimmed.starts_with("pub ") || trimmed.starts_with("pub async"),
+        // Capitalized identifiers are exported
+        "go" => name.chars().next().is_some_and(|c| c.is_uppercase()),
+        // Leading underscore marks private by convention
+        "py" => !name.starts_with('_'),
+        "js" | "jsx" | "ts" | "tsx" | "mjs" | "cjs" => {
+            trimmed.starts_with("export") || decl_line.contains("module.exports")
+        }
+        "java" | "kt" | "scala" | "cs" => decl_line.contains("public "),
+        // No visibility info for the rest; include everything
+        _ => true,
+    }
+}
+
+/// Check whether a statement guards `var` against None/null before use
+fn is_null_guard(text: &str, var: &str) -> bool {
+    let text = text.trim();
+
+    // Explicit comparisons against None/null/undefined
+    for op in [
+        "is None",
+        "is not None",
+        "== None",
+        "!= None",
+        "== null",
+        "!= null",
+        "=== null",
+        "!== null",
+        "=== undefined",
+        "!== undefined",
+    ] {
+        if text.contains(&format!("{} {}", var, op)) {
+            return true;
+        }
+    }
+
+    // Truthiness guards: `if x:` / `if x ...` / `if not x` / `if (x)` / `if (!x)`
+    if let Some(rest) = text.strip_prefix("if ").map(str::trim_start) {
+        return rest == var
+            || rest.starts_with(&format!("{}:", var))
+            || rest.starts_with(&format!("{} ", var))
+            || rest.starts_with(&format!("not {}", var))
+            || rest.starts_with(&format!("({})", var))
+            || rest.starts_with(&format!("(!{})", var));
+    }
+
+    false
+}
+
+/// Check whether a statement dereferences `var` (attribute access, method
+/// call, or subscript); optional chaining (`var?.`) is considered safe
+fn has_null_deref(text: &str, var: &str) -> bool {
+    for pat in [format!("{}.", var), format!("{}[", var)] {
+        for (idx, _) in text.match_indices(&pat) {
+            // Require a word boundary before the variable name
+            let preceded_by_word = text[..idx]
+                .chars()
+                .next_back()
+                .is_some_and(|c| c.is_alphanumeric() || c == '_');
+            if !preceded_by_word {
+                return true;
+            }
+        }
+    }
+    false
+}
+
 fn calculate_relevance(line: &str, query: &str) -> f32 {
     let mut score = 1.0;
 
@@ -6,8 +6,10 @@ pub mod callgraph;
 pub mod cfg;
 pub mod chunking;
 pub mod config;
+pub mod coverage;
 pub mod dfg;
 pub mod embeddings;
+pub mod exports;
 pub mod extract;
 pub mod hybrid_search;
 pub mod incremental;
@@ -25,6 +27,8 @@ pub mod type_inference;
 
 // Native-only modules (require tokio, octocrab, lsp, etc.)
 #[cfg(feature = "native")]
+pub mod events;
+#[cfg(feature = "native")]
 pub mod git;
 #[cfg(feature = "native")]
 pub mod index;
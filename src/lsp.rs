@@ -75,18 +75,52 @@ struct LspError {
 
 /// A running LSP server process
 struct LspProcess {
-    _child: Child,
+    child: Mutex<Child>,
     stdin: Arc<Mutex<ChildStdin>>,
     pending_requests: Arc<DashMap<i64, tokio::sync::oneshot::Sender<Value>>>,
     next_id: Arc<AtomicI64>,
     capabilities: Arc<RwLock<Option<ServerCapabilities>>>,
 }
 
+/// Restart tracking for a language server
+///
+/// Crashed servers are restarted lazily on the next request, with
+/// exponential backoff so a server that dies immediately does not get
+/// respawned in a tight loop.
+#[derive(Debug, Clone, Default)]
+struct ServerHealth {
+    /// Number of times the server has crashed and been restarted
+    restarts: u32,
+    /// Do not attempt another start before this instant
+    next_retry: Option<std::time::Instant>,
+    /// Most recent startup or crash error, for status reporting
+    last_error: Option<String>,
+}
+
+/// Status of one language server, as reported by `get_lsp_status`
+#[derive(Debug, Clone)]
+pub struct LspServerStatus {
+    /// Language this server handles
+    pub language: String,
+    /// Server binary (with args) that is or would be launched
+    pub command: String,
+    /// Whether the server process is currently running
+    pub running: bool,
+    /// Number of crash restarts so far
+    pub restarts: u32,
+    /// Most recent startup or crash error, if any
+    pub last_error: Option<String>,
+}
+
 /// Manager for LSP clients per language
 pub struct LspManager {
     config: LspConfig,
     servers: DashMap<String, Arc<LspProcess>>,
     workspace_roots: Vec<PathBuf>,
+    /// Diagnostics pushed by language servers, keyed by file path
+    diagnostics: Arc<DashMap<PathBuf, Vec<Diagnostic>>>,
+    /// Crash/restart bookkeeping per language
+    health: DashMap<String, ServerHealth>,
 }
 
 impl LspManager {
@@ -96,6 +130,8 @@ impl LspManager {
             config,
             servers: DashMap::new(),
             workspace_roots,
+            diagnostics: Arc::new(DashMap::new()),
+            health: DashMap::new(),
         }
     }
 
@@ -119,20 +155,78 @@ impl LspManager {
     }
 
     /// Get or start an LSP server for a language
+    ///
+    /// Crashed servers are detected here and restarted with exponential
+    /// backoff; a server that keeps dying is left alone until its retry
+    /// window has passed.
     async fn get_or_start_server(&self, language: &str) -> Result<Arc<LspProcess>> {
-        // Check if server already running
+        // Check if server already running and still alive
         if let Some(server) = self.servers.get(language) {
-            return Ok(server.clone());
+            let server = server.clone();
+            if Self::process_alive(&server).await {
+                return Ok(server);
+            }
+            // The process exited - record the crash and fall through to
+            // a (possibly backed-off) restart
+            self.servers.remove(language);
+            self.record_crash(language);
+        }
+
+        // Respect the backoff window from previous crashes
+        if let Some(health) = self.health.get(language) {
+            if let Some(next_retry) = health.next_retry {
+                let now = std::time::Instant::now();
+                if now < next_retry {
+                    return Err(anyhow!(
+                        "LSP server for {} is backing off after {} crash(es); retrying in {}s",
+                        language,
+                        health.restarts,
+                        (next_retry - now).as_secs().max(1)
+                    ));
+                }
+            }
         }
 
         // Start new server
-        let server = self.start_server(language).await?;
+        let server = match self.start_server(language).await {
+            Ok(s) => s,
+            Err(e) => {
+                self.health
+                    .entry(language.to_string())
+                    .or_default()
+                    .last_error = Some(e.to_string());
+                return Err(e);
+            }
+        };
         let server_arc = Arc::new(server);
         self.servers
             .insert(language.to_string(), server_arc.clone());
         Ok(server_arc)
     }
 
+    /// Check whether a server process is still running
+    async fn process_alive(process: &LspProcess) -> bool {
+        // try_wait returns Ok(None) while the child is still running
+        matches!(process.child.lock().await.try_wait(), Ok(None))
+    }
+
+    /// Record a server crash and schedule the next restart with backoff
+    fn record_crash(&self, language: &str) {
+        let mut health = self.health.entry(language.to_string()).or_default();
+        health.restarts += 1;
+        // 2s, 4s, 8s, ... capped at 60s
+        let delay = Duration::from_secs(2u64.saturating_pow(health.restarts).min(60));
+        health.next_retry = Some(std::time::Instant::now() + delay);
+        health.last_error = Some(format!(
+            "server process exited (crash #{})",
+            health.restarts
+        ));
+        warn!(
+            "LSP server for {} crashed ({} restart(s)); next retry in {:?}",
+            language, health.restarts, delay
+        );
+    }
+
     /// Start an LSP server process
     async fn start_server(&self, language: &str) -> Result<LspProcess> {
         let (command, args) = self.get_server_command(language)?;
@@ -148,7 +242,21 @@ impl LspManager {
             .stdout(Stdio::piped())
             .stderr(Stdio::null())
             .spawn()
-            .context("Failed to spawn LSP server")?;
+            .map_err(|e| {
+                if e.kind() == std::io::ErrorKind::NotFound {
+                    match install_hint(language) {
+                        Some(hint) => anyhow!(
+                            "LSP server `{}` not found for {}. Install it with: {}",
+                            command,
+                            language,
+                            hint
+                        ),
+                        None => anyhow!("LSP server `{}` not found for {}", command, language),
+                    }
+                } else {
+                    anyhow!("Failed to spawn LSP server `{}`: {}", command, e)
+                }
+            })?;
 
         let stdin = child.stdin.take().ok_or_else(|| anyhow!("No stdin"))?;
         let stdout = child.stdout.take().ok_or_else(|| anyhow!("No stdout"))?;
@@ -159,14 +267,15 @@ impl LspManager {
 
         // Spawn response handler task
         let pending_clone = pending_requests.clone();
+        let diagnostics_clone = self.diagnostics.clone();
         tokio::spawn(async move {
-            if let Err(e) = Self::handle_responses(stdout, pending_clone).await {
+            if let Err(e) = Self::handle_responses(stdout, pending_clone, diagnostics_clone).await {
                 warn!("LSP response handler error: {}", e);
             }
         });
 
         let process = LspProcess {
-            _child: child,
+            child: Mutex::new(child),
             stdin: Arc::new(Mutex::new(stdin)),
             pending_requests,
             next_id,
@@ -183,6 +292,7 @@ impl LspManager {
     async fn handle_responses(
         stdout: ChildStdout,
         pending_requests: Arc<DashMap<i64, tokio::sync::oneshot::Sender<Value>>>,
+        diagnostics: Arc<DashMap<PathBuf, Vec<Diagnostic>>>,
     ) -> Result<()> {
         let mut reader = BufReader::new(stdout);
         let mut content_length = 0;
@@ -220,6 +330,23 @@ impl LspManager {
                             warn!("LSP error response: {:?}", error);
                         }
                     }
+                } else if message.method.as_deref() == Some("textDocument/publishDiagnostics") {
+                    // Server-pushed diagnostics: keep the latest set per file
+                    if let Some(params) = message.params {
+                        match serde_json::from_value::<PublishDiagnosticsParams>(params) {
+                            Ok(published) => {
+                                if let Ok(path) = published.uri.to_file_path() {
+                                    debug!(
+                                        "Received {} diagnostics for {}",
+                                        published.diagnostics.len(),
+                                        path.display()
+                                    );
+                                    diagnostics.insert(path, published.diagnostics);
+                                }
+                            }
+                            Err(e) => warn!("Invalid publishDiagnostics params: {}", e),
+                        }
+                    }
                 }
 
                 content_length = 0;
@@ -354,7 +481,15 @@ impl LspManager {
                 vec!["--stdio".to_string()],
             )),
             "go" => Ok(("gopls".to_string(), vec![])),
-            "c" | "cpp" => Ok(("clangd".to_string(), vec![])),
+            "c" | "cpp" => {
+                // Without a compilation database clangd guesses include
+                // paths and gets real projects wrong
+                let mut args = vec![];
+                if let Some(dir) = self.compile_commands_dir() {
+                    args.push(format!("--compile-commands-dir={}", dir.display()));
+                }
+                Ok(("clangd".to_string(), args))
+            }
             "java" => Ok((
                 "jdtls".to_string(),
                 vec!["-data".to_string(), "/tmp/jdtls-workspace".to_string()],
@@ -363,6 +498,32 @@ impl LspManager {
         }
     }
 
+    /// Locate a directory containing `compile_commands.json` for clangd
+    ///
+    /// Checks the usual build output locations in each workspace root;
+    /// when none exists, generates a minimal fallback database so clangd
+    /// at least knows the project's include roots.
+    fn compile_commands_dir(&self) -> Option<PathBuf> {
+        for root in &self.workspace_roots {
+            if let Some(dir) = find_compile_commands_dir(root) {
+                return Some(dir);
+            }
+        }
+
+        // No database anywhere - generate a fallback for the first root
+        // that actually contains C/C++ sources
+        for root in &self.workspace_roots {
+            if let Some(dir) = generate_fallback_compile_commands(root) {
+                info!(
+                    "Generated fallback compile_commands.json in {}",
+                    dir.display()
+                );
+                return Some(dir);
+            }
+        }
+        None
+    }
+
     /// Get hover information
     pub async fn get_hover(
         &self,
@@ -548,6 +709,233 @@ impl LspManager {
         }
     }
 
+    /// Request a workspace-wide rename of the symbol at a position
+    ///
+    /// Returns the server's proposed `WorkspaceEdit` without applying it;
+    /// the caller decides whether to apply the edits.
+    pub async fn rename(
+        &self,
+        language: &str,
+        file_path: &Path,
+        line: u32,
+        character: u32,
+        new_name: &str,
+    ) -> Result<Option<WorkspaceEdit>> {
+        if !self.is_enabled_for_language(language) {
+            return Ok(None);
+        }
+
+        let server = match self.get_or_start_server(language).await {
+            Ok(s) => s,
+            Err(e) => {
+                debug!("Failed to start LSP server for {}: {}", language, e);
+                return Ok(None);
+            }
+        };
+
+        let uri = Url::from_file_path(file_path).map_err(|_| anyhow!("Invalid file path"))?;
+
+        let params = RenameParams {
+            text_document_position: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri },
+                position: Position { line, character },
+            },
+            new_name: new_name.to_string(),
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        };
+
+        let params_value = serde_json::to_value(&params)?;
+        let response = self
+            .send_request(&server, "textDocument/rename", params_value)
+            .await?;
+
+        if response.is_null() {
+            return Ok(None);
+        }
+
+        let edit: WorkspaceEdit = serde_json::from_value(response)?;
+        Ok(Some(edit))
+    }
+
+    /// Request semantic tokens for a document and decode them against the
+    /// server's legend
+    ///
+    /// Returns `None` when LSP is unavailable or the server does not
+    /// advertise semantic token support.
+    pub async fn get_semantic_tokens(
+        &self,
+        language: &str,
+        file_path: &Path,
+    ) -> Result<Option<Vec<SemanticTokenInfo>>> {
+        if !self.is_enabled_for_language(language) {
+            return Ok(None);
+        }
+
+        let server = match self.get_or_start_server(language).await {
+            Ok(s) => s,
+            Err(e) => {
+                debug!("Failed to start LSP server for {}: {}", language, e);
+                return Ok(None);
+            }
+        };
+
+        // The legend maps token type/modifier indices to names; without it
+        // the encoded data is meaningless
+        let legend = {
+            let caps = server.capabilities.read().await;
+            caps.as_ref().and_then(semantic_tokens_legend)
+        };
+        let legend = match legend {
+            Some(l) => l,
+            None => {
+                debug!("LSP server for {} has no semantic tokens legend", language);
+                return Ok(None);
+            }
+        };
+
+        let uri = Url::from_file_path(file_path).map_err(|_| anyhow!("Invalid file path"))?;
+
+        let params = SemanticTokensParams {
+            text_document: TextDocumentIdentifier { uri },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+        };
+
+        let params_value = serde_json::to_value(&params)?;
+        let response = self
+            .send_request(&server, "textDocument/semanticTokens/full", params_value)
+            .await?;
+
+        if response.is_null() {
+            return Ok(None);
+        }
+
+        let result: SemanticTokensResult = serde_json::from_value(response)?;
+        let tokens = match result {
+            SemanticTokensResult::Tokens(tokens) => tokens.data,
+            SemanticTokensResult::Partial(partial) => partial.data,
+        };
+
+        Ok(Some(decode_semantic_tokens(&tokens, &legend)))
+    }
+
+    /// Send a notification (no response expected) to the LSP server
+    async fn send_notification(
+        &self,
+        process: &LspProcess,
+        method: &str,
+        params: Value,
+    ) -> Result<()> {
+        let notification = LspMessage {
+            jsonrpc: "2.0".to_string(),
+            id: None,
+            method: Some(method.to_string()),
+            params: Some(params),
+            result: None,
+            error: None,
+        };
+
+        let json = serde_json::to_string(&notification)?;
+        let content = format!("Content-Length: {}\r\n\r\n{}", json.len(), json);
+
+        let mut stdin = process.stdin.lock().await;
+        stdin.write_all(content.as_bytes()).await?;
+        stdin.flush().await?;
+        Ok(())
+    }
+
+    /// Notify the server that a document is open so it starts publishing
+    /// diagnostics for it
+    pub async fn did_open(&self, language: &str, file_path: &Path, content: &str) -> Result<()> {
+        if !self.is_enabled_for_language(language) {
+            return Ok(());
+        }
+
+        let server = match self.get_or_start_server(language).await {
+            Ok(s) => s,
+            Err(e) => {
+                debug!("Failed to start LSP server for {}: {}", language, e);
+                return Ok(());
+            }
+        };
+
+        let uri = Url::from_file_path(file_path).map_err(|_| anyhow!("Invalid file path"))?;
+        let params = DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri,
+                language_id: language.to_string(),
+                version: 0,
+                text: content.to_string(),
+            },
+        };
+
+        self.send_notification(
+            &server,
+            "textDocument/didOpen",
+            serde_json::to_value(&params)?,
+        )
+        .await
+    }
+
+    /// Get collected diagnostics, optionally filtered to paths containing
+    /// `path_filter`. Returns (file path, diagnostics) pairs.
+    pub fn get_diagnostics(&self, path_filter: Option<&str>) -> Vec<(PathBuf, Vec<Diagnostic>)> {
+        self.diagnostics
+            .iter()
+            .filter(|entry| path_filter.is_none_or(|p| entry.key().to_string_lossy().contains(p)))
+            .map(|entry| (entry.key().clone(), entry.value().clone()))
+            .collect()
+    }
+
+    /// Report the status of all configured language servers
+    ///
+    /// Covers every language with LSP enabled, whether or not its server
+    /// has been started yet.
+    pub async fn status(&self) -> Vec<LspServerStatus> {
+        let mut languages: Vec<&String> = self
+            .config
+            .enabled_languages
+            .iter()
+            .filter(|(_, enabled)| **enabled)
+            .map(|(lang, _)| lang)
+            .collect();
+        languages.sort();
+
+        let mut statuses = Vec::with_capacity(languages.len());
+        for language in languages {
+            let command = match self.get_server_command(language) {
+                Ok((cmd, args)) => {
+                    if args.is_empty() {
+                        cmd
+                    } else {
+                        format!("{} {}", cmd, args.join(" "))
+                    }
+                }
+                Err(_) => "(none configured)".to_string(),
+            };
+
+            let running = match self.servers.get(language) {
+                Some(server) => Self::process_alive(&server).await,
+                None => false,
+            };
+
+            let (restarts, last_error) = self
+                .health
+                .get(language)
+                .map(|h| (h.restarts, h.last_error.clone()))
+                .unwrap_or((0, None));
+
+            statuses.push(LspServerStatus {
+                language: language.clone(),
+                command,
+                running,
+                restarts,
+                last_error,
+            });
+        }
+        statuses
+    }
+
     /// Shutdown all LSP servers
     pub async fn shutdown_all(&self) -> Result<()> {
         for entry in self.servers.iter() {
@@ -600,6 +988,347 @@ impl Drop for LspManager {
     }
 }
 
+/// A semantic token decoded from the LSP delta encoding
+#[derive(Debug, Clone)]
+pub struct SemanticTokenInfo {
+    /// Line number (1-indexed, matching `Symbol` line numbers)
+    pub line: usize,
+    /// Starting character within the line (0-indexed)
+    pub start: usize,
+    /// Token length in characters
+    pub length: usize,
+    /// Token type name from the server's legend (e.g. "macro", "enumMember")
+    pub token_type: String,
+    /// Modifier names from the server's legend (e.g. "declaration", "static")
+    pub modifiers: Vec<String>,
+}
+
+/// Extract the semantic tokens legend from server capabilities
+fn semantic_tokens_legend(caps: &ServerCapabilities) -> Option<SemanticTokensLegend> {
+    match caps.semantic_tokens_provider.as_ref()? {
+        SemanticTokensServerCapabilities::SemanticTokensOptions(opts) => Some(opts.legend.clone()),
+        SemanticTokensServerCapabilities::SemanticTokensRegistrationOptions(reg) => {
+            Some(reg.semantic_tokens_options.legend.clone())
+        }
+    }
+}
+
+/// Decode the LSP semantic token delta encoding into absolute positions
+///
+/// Each token is encoded relative to the previous one: a line delta, a
+/// character delta (only when on the same line), a length, and indices into
+/// the legend's token type and modifier lists.
+pub fn decode_semantic_tokens(
+    data: &[SemanticToken],
+    legend: &SemanticTokensLegend,
+) -> Vec<SemanticTokenInfo> {
+    let mut decoded = Vec::with_capacity(data.len());
+    let mut line = 0u32;
+    let mut start = 0u32;
+
+    for token in data {
+        line += token.delta_line;
+        if token.delta_line > 0 {
+            start = token.delta_start;
+        } else {
+            start += token.delta_start;
+        }
+
+        let token_type = match legend.token_types.get(token.token_type as usize) {
+            Some(t) => t.as_str().to_string(),
+            None => continue, // Index outside the legend - skip
+        };
+
+        let modifiers = legend
+            .token_modifiers
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| token.token_modifiers_bitset & (1 << i) != 0)
+            .map(|(_, m)| m.as_str().to_string())
+            .collect();
+
+        decoded.push(SemanticTokenInfo {
+            line: line as usize + 1,
+            start: start as usize,
+            length: token.length as usize,
+            token_type,
+            modifiers,
+        });
+    }
+
+    decoded
+}
+
+/// Map an LSP semantic token type to our symbol kind
+pub fn semantic_token_symbol_kind(token_type: &str) -> Option<crate::symbols::SymbolKind> {
+    use crate::symbols::SymbolKind;
+    match token_type {
+        "namespace" => Some(SymbolKind::Namespace),
+        "class" => Some(SymbolKind::Class),
+        "enum" => Some(SymbolKind::Enum),
+        "interface" => Some(SymbolKind::Interface),
+        "struct" => Some(SymbolKind::Struct),
+        "type" => Some(SymbolKind::TypeAlias),
+        "typeParameter" => Some(SymbolKind::TypeParameter),
+        "parameter" => Some(SymbolKind::Parameter),
+        "variable" => Some(SymbolKind::Variable),
+        "property" => Some(SymbolKind::Field),
+        "enumMember" => Some(SymbolKind::EnumMember),
+        "function" => Some(SymbolKind::Function),
+        "method" => Some(SymbolKind::Method),
+        "macro" => Some(SymbolKind::Macro),
+        _ => None,
+    }
+}
+
+/// Refine tree-sitter symbol kinds using decoded semantic tokens
+///
+/// Tree-sitter grammars cannot always distinguish kinds that need type
+/// information (type parameters, macros, enum members), so when a language
+/// server is running we reclassify symbols whose declaration site carries a
+/// more specific semantic token. Returns the number of symbols updated.
+pub fn refine_symbol_kinds(
+    symbols: &mut [crate::symbols::Symbol],
+    tokens: &[SemanticTokenInfo],
+) -> usize {
+    use crate::symbols::SymbolKind;
+
+    let mut refined = 0;
+    for symbol in symbols.iter_mut() {
+        // The declaration token sits on the symbol's first line and spans
+        // exactly its name
+        let token = tokens.iter().find(|t| {
+            t.line == symbol.start_line
+                && t.length == symbol.name.len()
+                && t.modifiers.iter().any(|m| m == "declaration")
+        });
+        let token = match token {
+            Some(t) => t,
+            None => continue,
+        };
+
+        let new_kind = match semantic_token_symbol_kind(&token.token_type) {
+            Some(k) => k,
+            None => continue,
+        };
+
+        // Only apply refinements that add information: either the
+        // tree-sitter kind was Unknown, or the LSP kind is one tree-sitter
+        // cannot produce on its own
+        let is_refinement = symbol.kind == SymbolKind::Unknown
+            || matches!(
+                new_kind,
+                SymbolKind::TypeParameter | SymbolKind::EnumMember | SymbolKind::Macro
+            );
+
+        if is_refinement && symbol.kind != new_kind {
+            symbol.kind = new_kind;
+            refined += 1;
+        }
+    }
+    refined
+}
+
+/// Find the directory holding `compile_commands.json` under a project root
+///
+/// Checks the root itself plus the build directories CMake, Meson and
+/// Bazel conventionally write the database to.
+pub fn find_compile_commands_dir(root: &Path) -> Option<PathBuf> {
+    const CANDIDATES: &[&str] = &[
+        "",
+        "build",
+        "out",
+        "cmake-build-debug",
+        "cmake-build-release",
+        "builddir",
+    ];
+
+    for candidate in CANDIDATES {
+        let dir = if candidate.is_empty() {
+            root.to_path_buf()
+        } else {
+            root.join(candidate)
+        };
+        if dir.join("compile_commands.json").is_file() {
+            return Some(dir);
+        }
+    }
+    None
+}
+
+/// Generate a minimal `compile_commands.json` for a project without one
+///
+/// Writes one entry per C/C++ source with the project root and its
+/// `include/`/`src/` directories on the include path, into a per-project
+/// directory under the system temp dir (never into the user's repo).
+/// Returns `None` if the root contains no C/C++ sources.
+pub fn generate_fallback_compile_commands(root: &Path) -> Option<PathBuf> {
+    let mut sources = Vec::new();
+    collect_c_sources(root, 0, &mut sources);
+    if sources.is_empty() {
+        return None;
+    }
+
+    let mut includes = vec![format!("-I{}", root.display())];
+    for dir in ["include", "src"] {
+        let path = root.join(dir);
+        if path.is_dir() {
+            includes.push(format!("-I{}", path.display()));
+        }
+    }
+    let includes = includes.join(" ");
+
+    let entries: Vec<Value> = sources
+        .iter()
+        .map(|source| {
+            let compiler = if source.extension().is_some_and(|e| e == "c") {
+                "clang"
+            } else {
+                "clang++ -std=c++17"
+            };
+            serde_json::json!({
+                "directory": root.to_string_lossy(),
+                "file": source.to_string_lossy(),
+                "command": format!("{} {} -c {}", compiler, includes, source.display()),
+            })
+        })
+        .collect();
+
+    // Keyed by root path hash so multiple projects do not clobber each other
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    std::hash::Hash::hash(&root, &mut hasher);
+    let dir = std::env::temp_dir().join(format!(
+        "narsil-clangd-{:x}",
+        std::hash::Hasher::finish(&hasher)
+    ));
+    std::fs::create_dir_all(&dir).ok()?;
+    let json = serde_json::to_string_pretty(&entries).ok()?;
+    std::fs::write(dir.join("compile_commands.json"), json).ok()?;
+    Some(dir)
+}
+
+/// Recursively collect C/C++ sources, skipping hidden and build dirs
+fn collect_c_sources(dir: &Path, depth: usize, sources: &mut Vec<PathBuf>) {
+    const MAX_DEPTH: usize = 6;
+    const MAX_SOURCES: usize = 2000;
+
+    if depth > MAX_DEPTH || sources.len() >= MAX_SOURCES {
+        return;
+    }
+    let entries = match std::fs::read_dir(dir) {
+        Ok(e) => e,
+        Err(_) => return,
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if name.starts_with('.') {
+            continue;
+        }
+        if path.is_dir() {
+            if !matches!(name.as_ref(), "build" | "out" | "target" | "node_modules") {
+                collect_c_sources(&path, depth + 1, sources);
+            }
+        } else if path
+            .extension()
+            .is_some_and(|e| matches!(e.to_str(), Some("c" | "cc" | "cpp" | "cxx")))
+        {
+            sources.push(path);
+        }
+    }
+}
+
+/// Suggest how to install the language server for a language
+///
+/// Used in error messages and status output when a server binary is
+/// missing from PATH.
+pub fn install_hint(language: &str) -> Option<&'static str> {
+    match language {
+        "rust" => Some("rustup component add rust-analyzer"),
+        "python" => Some("npm install -g pyright"),
+        "javascript" | "typescript" => Some("npm install -g typescript-language-server typescript"),
+        "go" => Some("go install golang.org/x/tools/gopls@latest"),
+        "c" | "cpp" => Some("apt install clangd (or brew install llvm)"),
+        "java" => Some("install Eclipse JDT Language Server (jdtls)"),
+        _ => None,
+    }
+}
+
+/// Flatten a `WorkspaceEdit` into per-file text edits
+///
+/// Servers report edits either in the legacy `changes` map or in
+/// `document_changes`; resource operations (file create/rename/delete) are
+/// not supported and are skipped.
+pub fn workspace_edit_changes(edit: &WorkspaceEdit) -> Vec<(PathBuf, Vec<TextEdit>)> {
+    let mut result: Vec<(PathBuf, Vec<TextEdit>)> = Vec::new();
+
+    if let Some(changes) = &edit.changes {
+        for (uri, edits) in changes {
+            if let Ok(path) = uri.to_file_path() {
+                result.push((path, edits.clone()));
+            }
+        }
+    }
+
+    if let Some(doc_changes) = &edit.document_changes {
+        let text_edits = match doc_changes {
+            DocumentChanges::Edits(edits) => edits.as_slice(),
+            DocumentChanges::Operations(_) => &[],
+        };
+        for doc_edit in text_edits {
+            if let Ok(path) = doc_edit.text_document.uri.to_file_path() {
+                let edits: Vec<TextEdit> = doc_edit
+                    .edits
+                    .iter()
+                    .map(|e| match e {
+                        OneOf::Left(edit) => edit.clone(),
+                        OneOf::Right(annotated) => annotated.text_edit.clone(),
+                    })
+                    .collect();
+                result.push((path, edits));
+            }
+        }
+    }
+
+    result.sort_by(|a, b| a.0.cmp(&b.0));
+    result
+}
+
+/// Apply text edits to a document, returning the new content
+///
+/// Edits are applied back-to-front so earlier edits do not shift the
+/// positions of later ones. Positions outside the document are ignored.
+pub fn apply_text_edits(content: &str, edits: &[TextEdit]) -> String {
+    // Byte offset of the start of each line
+    let mut line_offsets = vec![0usize];
+    for (i, b) in content.bytes().enumerate() {
+        if b == b'\n' {
+            line_offsets.push(i + 1);
+        }
+    }
+
+    let to_offset = |pos: &Position| -> Option<usize> {
+        let line_start = *line_offsets.get(pos.line as usize)?;
+        let offset = line_start + pos.character as usize;
+        (offset <= content.len()).then_some(offset)
+    };
+
+    let mut sorted: Vec<&TextEdit> = edits.iter().collect();
+    sorted.sort_by_key(|e| (e.range.start.line, e.range.start.character));
+
+    let mut result = content.to_string();
+    for edit in sorted.iter().rev() {
+        let (start, end) = match (to_offset(&edit.range.start), to_offset(&edit.range.end)) {
+            (Some(s), Some(e)) if s <= e => (s, e),
+            _ => continue,
+        };
+        result.replace_range(start..end, &edit.new_text);
+    }
+
+    result
+}
+
 /// Convert LSP hover to markdown string
 pub fn hover_to_markdown(hover: &Hover) -> String {
     match &hover.contents {
@@ -707,6 +1436,376 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_decode_semantic_tokens() {
+        let legend = SemanticTokensLegend {
+            token_types: vec![
+                SemanticTokenType::new("function"),
+                SemanticTokenType::new("macro"),
+            ],
+            token_modifiers: vec![SemanticTokenModifier::new("declaration")],
+        };
+
+        // Two tokens: "foo" at 2:4, "bar!" at 5:0 (delta-encoded)
+        let data = vec![
+            SemanticToken {
+                delta_line: 1,
+                delta_start: 4,
+                length: 3,
+                token_type: 0,
+                token_modifiers_bitset: 1,
+            },
+            SemanticToken {
+                delta_line: 3,
+                delta_start: 0,
+                length: 4,
+                token_type: 1,
+                token_modifiers_bitset: 0,
+            },
+        ];
+
+        let decoded = decode_semantic_tokens(&data, &legend);
+        assert_eq!(decoded.len(), 2);
+
+        assert_eq!(decoded[0].line, 2);
+        assert_eq!(decoded[0].start, 4);
+        assert_eq!(decoded[0].token_type, "function");
+        assert_eq!(decoded[0].modifiers, vec!["declaration".to_string()]);
+
+        assert_eq!(decoded[1].line, 5);
+        assert_eq!(decoded[1].start, 0);
+        assert_eq!(decoded[1].token_type, "macro");
+        assert!(decoded[1].modifiers.is_empty());
+    }
+
+    #[test]
+    fn test_decode_same_line_deltas() {
+        let legend = SemanticTokensLegend {
+            token_types: vec![SemanticTokenType::new("variable")],
+            token_modifiers: vec![],
+        };
+
+        // Two tokens on the same line: starts accumulate
+        let data = vec![
+            SemanticToken {
+                delta_line: 0,
+                delta_start: 2,
+                length: 1,
+                token_type: 0,
+                token_modifiers_bitset: 0,
+            },
+            SemanticToken {
+                delta_line: 0,
+                delta_start: 5,
+                length: 1,
+                token_type: 0,
+                token_modifiers_bitset: 0,
+            },
+        ];
+
+        let decoded = decode_semantic_tokens(&data, &legend);
+        assert_eq!(decoded[0].start, 2);
+        assert_eq!(decoded[1].start, 7);
+    }
+
+    #[test]
+    fn test_semantic_token_kind_mapping() {
+        use crate::symbols::SymbolKind;
+
+        assert_eq!(semantic_token_symbol_kind("macro"), Some(SymbolKind::Macro));
+        assert_eq!(
+            semantic_token_symbol_kind("enumMember"),
+            Some(SymbolKind::EnumMember)
+        );
+        assert_eq!(
+            semantic_token_symbol_kind("typeParameter"),
+            Some(SymbolKind::TypeParameter)
+        );
+        assert_eq!(semantic_token_symbol_kind("comment"), None);
+    }
+
+    #[test]
+    fn test_refine_symbol_kinds() {
+        use crate::symbols::{Symbol, SymbolKind};
+
+        let mut symbols = vec![
+            Symbol {
+                name: "vec".to_string(),
+                kind: SymbolKind::Function,
+                file_path: "src/lib.rs".to_string(),
+                start_line: 3,
+                end_line: 5,
+                signature: None,
+                qualified_name: None,
+                doc_comment: None,
+            },
+            Symbol {
+                name: "helper".to_string(),
+                kind: SymbolKind::Function,
+                file_path: "src/lib.rs".to_string(),
+                start_line: 10,
+                end_line: 12,
+                signature: None,
+                qualified_name: None,
+                doc_comment: None,
+            },
+        ];
+
+        let tokens = vec![
+            // "vec" is actually a macro declaration
+            SemanticTokenInfo {
+                line: 3,
+                start: 0,
+                length: 3,
+                token_type: "macro".to_string(),
+                modifiers: vec!["declaration".to_string()],
+            },
+            // "helper" stays a function - no more specific token
+            SemanticTokenInfo {
+                line: 10,
+                start: 0,
+                length: 6,
+                token_type: "function".to_string(),
+                modifiers: vec!["declaration".to_string()],
+            },
+        ];
+
+        let refined = refine_symbol_kinds(&mut symbols, &tokens);
+        assert_eq!(refined, 1);
+        assert_eq!(symbols[0].kind, SymbolKind::Macro);
+        assert_eq!(symbols[1].kind, SymbolKind::Function);
+    }
+
+    #[test]
+    fn test_refine_ignores_non_declaration_tokens() {
+        use crate::symbols::{Symbol, SymbolKind};
+
+        let mut symbols = vec![Symbol {
+            name: "x".to_string(),
+            kind: SymbolKind::Variable,
+            file_path: "src/lib.rs".to_string(),
+            start_line: 1,
+            end_line: 1,
+            signature: None,
+            qualified_name: None,
+            doc_comment: None,
+        }];
+
+        // A usage token (no declaration modifier) must not reclassify
+        let tokens = vec![SemanticTokenInfo {
+            line: 1,
+            start: 0,
+            length: 1,
+            token_type: "enumMember".to_string(),
+            modifiers: vec![],
+        }];
+
+        assert_eq!(refine_symbol_kinds(&mut symbols, &tokens), 0);
+        assert_eq!(symbols[0].kind, SymbolKind::Variable);
+    }
+
+    #[test]
+    fn test_find_compile_commands_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+
+        // Nothing there yet
+        assert!(find_compile_commands_dir(root).is_none());
+
+        // Database in a CMake build dir is picked up
+        std::fs::create_dir_all(root.join("build")).unwrap();
+        std::fs::write(root.join("build/compile_commands.json"), "[]").unwrap();
+        assert_eq!(find_compile_commands_dir(root), Some(root.join("build")));
+
+        // Database at the root wins over build dirs
+        std::fs::write(root.join("compile_commands.json"), "[]").unwrap();
+        assert_eq!(find_compile_commands_dir(root), Some(root.to_path_buf()));
+    }
+
+    #[test]
+    fn test_generate_fallback_compile_commands() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        std::fs::create_dir_all(root.join("include")).unwrap();
+        std::fs::write(root.join("src/main.cpp"), "int main() { return 0; }\n").unwrap();
+        std::fs::write(root.join("src/util.c"), "int util(void) { return 1; }\n").unwrap();
+
+        let db_dir = generate_fallback_compile_commands(root).unwrap();
+        let json = std::fs::read_to_string(db_dir.join("compile_commands.json")).unwrap();
+        let entries: Vec<serde_json::Value> = serde_json::from_str(&json).unwrap();
+        assert_eq!(entries.len(), 2);
+
+        // C++ sources get clang++, C sources get clang; both see include dirs
+        for entry in &entries {
+            let command = entry["command"].as_str().unwrap();
+            let file = entry["file"].as_str().unwrap();
+            if file.ends_with(".cpp") {
+                assert!(command.starts_with("clang++"));
+            } else {
+                assert!(command.starts_with("clang "));
+            }
+            assert!(command.contains("-I"));
+            assert!(command.contains("include"));
+        }
+    }
+
+    #[test]
+    fn test_fallback_skipped_without_c_sources() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("main.rs"), "fn main() {}\n").unwrap();
+        assert!(generate_fallback_compile_commands(dir.path()).is_none());
+    }
+
+    #[test]
+    fn test_clangd_gets_compile_commands_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().to_path_buf();
+        std::fs::write(root.join("compile_commands.json"), "[]").unwrap();
+
+        let manager = LspManager::new(LspConfig::default(), vec![root.clone()]);
+        let (cmd, args) = manager.get_server_command("cpp").unwrap();
+        assert_eq!(cmd, "clangd");
+        assert_eq!(
+            args,
+            vec![format!("--compile-commands-dir={}", root.display())]
+        );
+    }
+
+    #[test]
+    fn test_install_hints() {
+        assert!(install_hint("rust").unwrap().contains("rust-analyzer"));
+        assert!(install_hint("go").unwrap().contains("gopls"));
+        assert!(install_hint("cobol").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_status_reports_unstarted_servers() {
+        let mut config = LspConfig {
+            enabled: true,
+            ..Default::default()
+        };
+        config.enabled_languages.insert("rust".to_string(), true);
+        config.enabled_languages.insert("go".to_string(), false);
+        let manager = LspManager::new(config, vec![]);
+
+        let statuses = manager.status().await;
+        // Only languages with LSP enabled are listed
+        assert_eq!(statuses.len(), 1);
+        assert_eq!(statuses[0].language, "rust");
+        assert_eq!(statuses[0].command, "rust-analyzer");
+        assert!(!statuses[0].running);
+        assert_eq!(statuses[0].restarts, 0);
+        assert!(statuses[0].last_error.is_none());
+    }
+
+    #[test]
+    fn test_crash_backoff_schedules_retry() {
+        let config = LspConfig::default();
+        let manager = LspManager::new(config, vec![]);
+
+        manager.record_crash("rust");
+        let health = manager.health.get("rust").unwrap();
+        assert_eq!(health.restarts, 1);
+        assert!(health.next_retry.is_some());
+        assert!(health.last_error.as_deref().unwrap().contains("crash #1"));
+    }
+
+    #[test]
+    fn test_apply_text_edits() {
+        let content = "fn old_name() {}\n\nfn caller() {\n    old_name();\n}\n";
+
+        let edits = vec![
+            TextEdit {
+                range: Range {
+                    start: Position {
+                        line: 0,
+                        character: 3,
+                    },
+                    end: Position {
+                        line: 0,
+                        character: 11,
+                    },
+                },
+                new_text: "new_name".to_string(),
+            },
+            TextEdit {
+                range: Range {
+                    start: Position {
+                        line: 3,
+                        character: 4,
+                    },
+                    end: Position {
+                        line: 3,
+                        character: 12,
+                    },
+                },
+                new_text: "new_name".to_string(),
+            },
+        ];
+
+        let result = apply_text_edits(content, &edits);
+        assert_eq!(
+            result,
+            "fn new_name() {}\n\nfn caller() {\n    new_name();\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_apply_text_edits_ignores_out_of_range() {
+        let content = "let x = 1;\n";
+        let edits = vec![TextEdit {
+            range: Range {
+                start: Position {
+                    line: 99,
+                    character: 0,
+                },
+                end: Position {
+                    line: 99,
+                    character: 1,
+                },
+            },
+            new_text: "y".to_string(),
+        }];
+
+        assert_eq!(apply_text_edits(content, &edits), content);
+    }
+
+    #[test]
+    fn test_workspace_edit_changes_from_changes_map() {
+        let uri = Url::from_file_path("/tmp/test.rs").unwrap();
+        let edit = WorkspaceEdit {
+            changes: Some(
+                [(
+                    uri,
+                    vec![TextEdit {
+                        range: Range {
+                            start: Position {
+                                line: 0,
+                                character: 0,
+                            },
+                            end: Position {
+                                line: 0,
+                                character: 3,
+                            },
+                        },
+                        new_text: "bar".to_string(),
+                    }],
+                )]
+                .into_iter()
+                .collect(),
+            ),
+            document_changes: None,
+            change_annotations: None,
+        };
+
+        let changes = workspace_edit_changes(&edit);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].0, PathBuf::from("/tmp/test.rs"));
+        assert_eq!(changes[0].1.len(), 1);
+        assert_eq!(changes[0].1[0].new_text, "bar");
+    }
+
     #[test]
     fn test_hover_to_markdown() {
         let hover = Hover {
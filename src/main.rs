@@ -8,8 +8,11 @@ mod callgraph;
 mod cfg;
 mod chunking;
 mod config;
+mod coverage;
 mod dfg;
 mod embeddings;
+mod events;
+mod exports;
 mod extract;
 mod git;
 mod http_server;
@@ -63,6 +66,122 @@ enum Commands {
     /// Tool listing and information commands
     #[command(subcommand)]
     Tools(config::ToolsCommand),
+
+    /// Build and persist the index, then exit (for CI cache pre-warming)
+    Index(IndexArgs),
+
+    /// Search code and print results, without speaking MCP
+    Search(SearchArgs),
+
+    /// List symbols and print results, without speaking MCP
+    Symbols(SymbolsArgs),
+
+    /// Run security and supply-chain checks, exiting non-zero on findings
+    Scan(ScanArgs),
+}
+
+#[derive(ClapParser, Debug)]
+struct IndexArgs {
+    /// Paths to repositories or directories to index
+    #[arg(short, long)]
+    repos: Vec<PathBuf>,
+
+    /// Output path for the persisted index
+    #[arg(short, long, default_value = "~/.cache/narsil-mcp")]
+    out: PathBuf,
+
+    /// Auto-discover repositories in a directory
+    #[arg(long)]
+    discover: Option<PathBuf>,
+
+    /// Enable call graph analysis
+    #[arg(long)]
+    call_graph: bool,
+
+    /// Enable git integration
+    #[arg(long)]
+    git: bool,
+
+    /// Enable verbose logging (to stderr)
+    #[arg(short, long)]
+    verbose: bool,
+
+    /// Maximum bytes of a file to parse for symbols; larger files are
+    /// partially parsed but remain fully text-searchable (0 = unlimited)
+    #[arg(long, default_value = "1048576")]
+    max_parse_bytes: usize,
+
+    /// Symlink policy for indexing: skip, follow, or follow-within-repo
+    #[arg(long, default_value = "skip")]
+    symlinks: String,
+}
+
+#[derive(ClapParser, Debug)]
+struct SearchArgs {
+    /// The search query
+    query: String,
+
+    /// Paths to repositories to search
+    #[arg(short, long)]
+    repos: Vec<PathBuf>,
+
+    /// Path to persistent index storage (reused if pre-warmed)
+    #[arg(short, long, default_value = "~/.cache/narsil-mcp")]
+    index_path: PathBuf,
+
+    /// Only include paths matching this glob (e.g. "src/**/*.rs")
+    #[arg(long)]
+    file_pattern: Option<String>,
+
+    /// Maximum number of results
+    #[arg(long, default_value = "20")]
+    max_results: usize,
+
+    /// Print results as JSON instead of markdown
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(ClapParser, Debug)]
+struct SymbolsArgs {
+    /// Pattern to filter symbol names (case-insensitive substring)
+    pattern: Option<String>,
+
+    /// Paths to repositories to search
+    #[arg(short, long)]
+    repos: Vec<PathBuf>,
+
+    /// Path to persistent index storage (reused if pre-warmed)
+    #[arg(short, long, default_value = "~/.cache/narsil-mcp")]
+    index_path: PathBuf,
+
+    /// Symbol kind filter (struct, class, enum, interface, function, method, trait, type)
+    #[arg(long)]
+    kind: Option<String>,
+
+    /// Only include paths matching this glob (e.g. "src/**/*.rs")
+    #[arg(long)]
+    file_pattern: Option<String>,
+
+    /// Print results as JSON instead of markdown
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(ClapParser, Debug)]
+struct ScanArgs {
+    /// Paths to repositories to scan
+    #[arg(short, long)]
+    repos: Vec<PathBuf>,
+
+    /// Output format: text or sarif
+    #[arg(long, default_value = "text")]
+    format: String,
+
+    /// Exit non-zero when findings at or above this severity exist
+    /// (critical, high, medium, low, info, or never)
+    #[arg(long, default_value = "high")]
+    fail_on: String,
 }
 
 #[derive(ClapParser, Debug)]
@@ -119,14 +238,18 @@ struct ServerArgs {
     #[arg(long)]
     neural: bool,
 
-    /// Neural embedding backend: "api" (default) or "onnx"
+    /// Neural embedding backend: "api" (default), "onnx", or "local"
     #[arg(long, default_value = "api")]
     neural_backend: String,
 
-    /// Neural embedding model name (e.g., "voyage-code-2", "text-embedding-3-small")
+    /// Neural embedding model name (e.g., "voyage-code-2", "text-embedding-3-small", "jinaai/jina-embeddings-v2-base-code")
     #[arg(long)]
     neural_model: Option<String>,
 
+    /// Allow write tools (apply_patch) to modify repository files
+    #[arg(long)]
+    allow_writes: bool,
+
     /// Enable HTTP server for visualization frontend
     #[arg(long)]
     http: bool,
@@ -139,6 +262,32 @@ struct ServerArgs {
     /// Overrides the preset from config file
     #[arg(long)]
     preset: Option<String>,
+
+    /// Verify persisted indices (format version, staleness) and exit
+    #[arg(long)]
+    check_index: bool,
+
+    /// Lazy indexing: index repositories on first tool use instead of upfront
+    #[arg(long)]
+    lazy: bool,
+
+    /// Maximum bytes of a file to parse for symbols; larger files are
+    /// partially parsed but remain fully text-searchable (0 = unlimited)
+    #[arg(long, default_value = "1048576")]
+    max_parse_bytes: usize,
+
+    /// Symlink policy for indexing: skip, follow, or follow-within-repo
+    #[arg(long, default_value = "skip")]
+    symlinks: String,
+
+    /// Run as a long-lived daemon serving MCP over a Unix domain socket,
+    /// so short-lived editor sessions share one warm engine
+    #[arg(long)]
+    daemon: bool,
+
+    /// Unix socket path for --daemon mode
+    #[arg(long, default_value = "/tmp/narsil-mcp.sock")]
+    socket: PathBuf,
 }
 
 #[tokio::main]
@@ -151,12 +300,34 @@ async fn main() -> Result<()> {
         return match command {
             Commands::Config(config_cmd) => config::handle_config_command(config_cmd).await,
             Commands::Tools(tools_cmd) => config::handle_tools_command(tools_cmd),
+            Commands::Index(index_args) => run_index_command(index_args).await,
+            Commands::Search(search_args) => run_search_command(search_args).await,
+            Commands::Symbols(symbols_args) => run_symbols_command(symbols_args).await,
+            Commands::Scan(scan_args) => run_scan_command(scan_args).await,
         };
     }
 
     // Default: run MCP server
     let server_args = args.server;
 
+    // --check-index: verify persisted indices and exit without starting the server
+    if server_args.check_index {
+        let index_dir = {
+            let path_str = server_args.index_path.to_string_lossy();
+            if let Some(stripped) = path_str.strip_prefix("~/") {
+                directories::BaseDirs::new()
+                    .ok_or_else(|| anyhow::anyhow!("Cannot find home directory"))?
+                    .home_dir()
+                    .join(stripped)
+            } else {
+                server_args.index_path.clone()
+            }
+        };
+        let store = persist::IndexStore::new(index_dir)?;
+        print!("{}", store.check()?);
+        return Ok(());
+    }
+
     // Initialize logging to stderr (stdout is for MCP protocol)
     let level = if server_args.verbose {
         Level::DEBUG
@@ -191,8 +362,8 @@ async fn main() -> Result<()> {
 
     info!("Repos to index: {:?}", repos);
     info!(
-        "Features: call_graph={}, git={}, watch={}, persist={}, lsp={}, streaming={}, remote={}, neural={}",
-        server_args.call_graph, server_args.git, server_args.watch, server_args.persist, server_args.lsp, server_args.streaming, server_args.remote, server_args.neural
+        "Features: call_graph={}, git={}, watch={}, persist={}, lsp={}, streaming={}, remote={}, neural={}, writes={}, lazy={}",
+        server_args.call_graph, server_args.git, server_args.watch, server_args.persist, server_args.lsp, server_args.streaming, server_args.remote, server_args.neural, server_args.allow_writes, server_args.lazy
     );
 
     // Build LSP config
@@ -244,15 +415,32 @@ async fn main() -> Result<()> {
         );
     }
 
+    // Global ignore patterns and chunking settings from config; per-repo
+    // .narsilignore files are picked up automatically during indexing
+    let file_config = config::ConfigLoader::new().load().unwrap_or_default();
+    let global_ignores = file_config.ignore;
+    if !global_ignores.is_empty() {
+        info!("Global ignore patterns: {:?}", global_ignores);
+    }
+
+    let symlink_policy: repo::SymlinkPolicy = server_args.symlinks.parse()?;
+
     // Initialize the code intelligence engine with options
     let options = index::EngineOptions {
         git_enabled: server_args.git,
         call_graph_enabled: server_args.call_graph,
         persist_enabled: server_args.persist,
         watch_enabled: server_args.watch,
+        write_enabled: server_args.allow_writes,
+        lazy_enabled: server_args.lazy,
+        global_ignores,
+        max_parse_bytes: server_args.max_parse_bytes,
+        symlink_policy,
         streaming_config,
         lsp_config,
         neural_config,
+        chunker_config: file_config.chunking,
+        architecture: file_config.architecture,
     };
 
     // NOTE: Engine creation is now fast and returns immediately.
@@ -316,9 +504,405 @@ async fn main() -> Result<()> {
         });
     }
 
-    // Always start the MCP server on stdio (for editor communication)
+    // Start the MCP server: on a Unix socket in daemon mode, else on stdio
     let server = mcp::McpServer::from_arc(engine, server_args.preset);
-    server.run().await?;
+    if server_args.daemon {
+        #[cfg(unix)]
+        Arc::new(server)
+            .run_unix_socket(&server_args.socket)
+            .await?;
+        #[cfg(not(unix))]
+        anyhow::bail!("--daemon is only supported on Unix platforms");
+    } else {
+        server.run().await?;
+    }
+
+    Ok(())
+}
+
+/// Build and persist the index for the given repositories, then exit.
+/// Lets CI pre-warm caches so the MCP server starts with a hot index.
+async fn run_index_command(args: IndexArgs) -> Result<()> {
+    // Logging goes to stderr; stdout stays clean for scripting
+    let level = if args.verbose {
+        Level::DEBUG
+    } else {
+        Level::INFO
+    };
+    let subscriber = FmtSubscriber::builder()
+        .with_max_level(level)
+        .with_writer(std::io::stderr)
+        .finish();
+    tracing::subscriber::set_global_default(subscriber)?;
+
+    // Handle repository discovery if requested
+    let mut repos = args.repos;
+    if let Some(discover_path) = args.discover {
+        info!("Discovering repositories in: {:?}", discover_path);
+        let discovered = repo::discover_repos(&discover_path, 3)?;
+        info!("Found {} repositories", discovered.len());
+        repos.extend(discovered);
+    }
+
+    // Expand "." to current directory
+    if let Ok(cwd) = std::env::current_dir() {
+        let dot_path = Path::new(".");
+
+        if let Some(path) = repos.iter_mut().find(|p| *p == dot_path) {
+            *path = cwd;
+        }
+    }
+
+    if repos.is_empty() {
+        anyhow::bail!("No repositories to index. Pass --repos or --discover.");
+    }
+
+    let file_config = config::ConfigLoader::new().load().unwrap_or_default();
+    let symlink_policy: repo::SymlinkPolicy = args.symlinks.parse()?;
+
+    let options = index::EngineOptions {
+        git_enabled: args.git,
+        call_graph_enabled: args.call_graph,
+        persist_enabled: true,
+        global_ignores: file_config.ignore,
+        max_parse_bytes: args.max_parse_bytes,
+        symlink_policy,
+        chunker_config: file_config.chunking,
+        architecture: file_config.architecture,
+        ..Default::default()
+    };
+
+    info!("Indexing {} repositories to {:?}", repos.len(), args.out);
+    let engine = index::CodeIntelEngine::with_options(args.out, repos, options).await?;
+
+    // Engine creation defers the actual indexing; do it now, synchronously
+    engine.complete_initialization().await?;
+    let summary = engine.save_index().await?;
+    println!("{}", summary);
+
+    Ok(())
+}
+
+/// Build an engine over the given repos for one-shot CLI queries,
+/// reusing a persisted index when one exists
+async fn load_cli_engine(
+    mut repos: Vec<PathBuf>,
+    index_path: PathBuf,
+) -> Result<index::CodeIntelEngine> {
+    // Only warnings to stderr; stdout carries the results
+    let subscriber = FmtSubscriber::builder()
+        .with_max_level(Level::WARN)
+        .with_writer(std::io::stderr)
+        .finish();
+    tracing::subscriber::set_global_default(subscriber)?;
+
+    // Expand "." to current directory
+    if let Ok(cwd) = std::env::current_dir() {
+        let dot_path = Path::new(".");
+
+        if let Some(path) = repos.iter_mut().find(|p| *p == dot_path) {
+            *path = cwd;
+        }
+    }
+
+    if repos.is_empty() {
+        anyhow::bail!("No repositories given. Pass --repos.");
+    }
+
+    let file_config = config::ConfigLoader::new().load().unwrap_or_default();
+    let options = index::EngineOptions {
+        persist_enabled: true,
+        global_ignores: file_config.ignore,
+        chunker_config: file_config.chunking,
+        architecture: file_config.architecture,
+        ..Default::default()
+    };
+
+    let engine = index::CodeIntelEngine::with_options(index_path, repos, options).await?;
+    engine.complete_initialization().await?;
+    Ok(engine)
+}
+
+/// Search code from the shell and print results to stdout
+async fn run_search_command(args: SearchArgs) -> Result<()> {
+    let engine = load_cli_engine(args.repos, args.index_path).await?;
+
+    let output = engine
+        .search_code(
+            None,
+            &args.query,
+            args.file_pattern.as_deref(),
+            args.max_results,
+            index::SearchFilters::default(),
+        )
+        .await?;
+
+    if args.json {
+        println!(
+            "{}",
+            serde_json::json!({ "query": args.query, "result": output })
+        );
+    } else {
+        println!("{}", output);
+    }
+
+    Ok(())
+}
+
+/// List symbols from the shell and print results to stdout
+async fn run_symbols_command(args: SymbolsArgs) -> Result<()> {
+    // Repo names follow the directory names, same as the engine derives them
+    let mut repos = args.repos.clone();
+    if let Ok(cwd) = std::env::current_dir() {
+        let dot_path = Path::new(".");
+
+        if let Some(path) = repos.iter_mut().find(|p| *p == dot_path) {
+            *path = cwd;
+        }
+    }
+    let repo_names: Vec<String> = repos
+        .iter()
+        .filter_map(|p| p.file_name().and_then(|n| n.to_str()))
+        .map(String::from)
+        .collect();
+
+    let engine = load_cli_engine(repos, args.index_path).await?;
+
+    let mut results = Vec::new();
+    for repo_name in &repo_names {
+        let output = engine
+            .find_symbols(
+                repo_name,
+                args.kind.as_deref(),
+                args.pattern.as_deref(),
+                args.file_pattern.as_deref(),
+                None,
+            )
+            .await?;
+        results.push((repo_name.clone(), output));
+    }
+
+    if args.json {
+        let entries: Vec<serde_json::Value> = results
+            .into_iter()
+            .map(|(repo, result)| serde_json::json!({ "repo": repo, "result": result }))
+            .collect();
+        println!("{}", serde_json::Value::Array(entries));
+    } else {
+        for (_, output) in results {
+            println!("{}", output);
+        }
+    }
+
+    Ok(())
+}
+
+/// Numeric rank for a security rule severity, for threshold comparison
+fn rule_severity_rank(severity: taint::Severity) -> u8 {
+    match severity {
+        taint::Severity::Info => 0,
+        taint::Severity::Low => 1,
+        taint::Severity::Medium => 2,
+        taint::Severity::High => 3,
+        taint::Severity::Critical => 4,
+    }
+}
+
+/// Numeric rank for a dependency vulnerability severity; unknown
+/// severities are treated as low rather than silently ignored
+fn vuln_severity_rank(severity: supply_chain::VulnSeverity) -> u8 {
+    match severity {
+        supply_chain::VulnSeverity::Unknown | supply_chain::VulnSeverity::Low => 1,
+        supply_chain::VulnSeverity::Medium => 2,
+        supply_chain::VulnSeverity::High => 3,
+        supply_chain::VulnSeverity::Critical => 4,
+    }
+}
+
+/// SARIF result level for a severity rank
+fn sarif_level(rank: u8) -> &'static str {
+    match rank {
+        3..=4 => "error",
+        2 => "warning",
+        _ => "note",
+    }
+}
+
+/// Build a SARIF 2.1.0 report from scan results
+fn sarif_report(reports: &[(String, index::CiScanReport)]) -> serde_json::Value {
+    let mut results = Vec::new();
+
+    for (_, report) in reports {
+        for finding in &report.security {
+            results.push(serde_json::json!({
+                "ruleId": finding.rule_id,
+                "level": sarif_level(rule_severity_rank(finding.severity)),
+                "message": { "text": finding.message },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": finding.file_path },
+                        "region": {
+                            "startLine": finding.line,
+                            "startColumn": finding.column,
+                            "endLine": finding.end_line,
+                            "endColumn": finding.end_column,
+                        }
+                    }
+                }],
+            }));
+        }
+
+        for dep_vuln in &report.dependencies {
+            for vuln in &dep_vuln.vulnerabilities {
+                results.push(serde_json::json!({
+                    "ruleId": vuln.id,
+                    "level": sarif_level(vuln_severity_rank(vuln.severity)),
+                    "message": {
+                        "text": format!(
+                            "{} {}: {}",
+                            dep_vuln.dependency.name, dep_vuln.dependency.version, vuln.summary
+                        )
+                    },
+                }));
+            }
+        }
+    }
+
+    serde_json::json!({
+        "version": "2.1.0",
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "narsil-mcp",
+                    "version": env!("CARGO_PKG_VERSION"),
+                }
+            },
+            "results": results,
+        }],
+    })
+}
+
+/// Run security and supply-chain checks over the given repositories,
+/// exiting non-zero when findings reach the --fail-on threshold
+async fn run_scan_command(args: ScanArgs) -> Result<()> {
+    // Only warnings to stderr; stdout carries the report
+    let subscriber = FmtSubscriber::builder()
+        .with_max_level(Level::WARN)
+        .with_writer(std::io::stderr)
+        .finish();
+    tracing::subscriber::set_global_default(subscriber)?;
+
+    let fail_threshold: Option<u8> = match args.fail_on.as_str() {
+        "critical" => Some(4),
+        "high" => Some(3),
+        "medium" => Some(2),
+        "low" => Some(1),
+        "info" => Some(0),
+        "never" => None,
+        other => anyhow::bail!(
+            "Unknown --fail-on value '{}'. Use critical, high, medium, low, info, or never.",
+            other
+        ),
+    };
+
+    // Expand "." to current directory
+    let mut repos = args.repos;
+    if let Ok(cwd) = std::env::current_dir() {
+        let dot_path = Path::new(".");
+
+        if let Some(path) = repos.iter_mut().find(|p| *p == dot_path) {
+            *path = cwd;
+        }
+    }
+
+    if repos.is_empty() {
+        anyhow::bail!("No repositories to scan. Pass --repos.");
+    }
+
+    let repo_names: Vec<String> = repos
+        .iter()
+        .filter_map(|p| p.file_name().and_then(|n| n.to_str()))
+        .map(String::from)
+        .collect();
+
+    // Always index fresh: a CI gate must scan what is on disk, not a cache
+    let file_config = config::ConfigLoader::new().load().unwrap_or_default();
+    let options = index::EngineOptions {
+        global_ignores: file_config.ignore,
+        chunker_config: file_config.chunking,
+        architecture: file_config.architecture,
+        ..Default::default()
+    };
+    let engine =
+        index::CodeIntelEngine::with_options(PathBuf::from("~/.cache/narsil-mcp"), repos, options)
+            .await?;
+    engine.complete_initialization().await?;
+
+    let mut reports = Vec::new();
+    for repo_name in &repo_names {
+        let report = engine.scan_for_ci(repo_name).await?;
+        reports.push((repo_name.clone(), report));
+    }
+
+    match args.format.as_str() {
+        "sarif" => println!("{}", serde_json::to_string_pretty(&sarif_report(&reports))?),
+        "text" => {
+            for (repo_name, report) in &reports {
+                println!("# Security Scan: {}", repo_name);
+                println!(
+                    "{} finding(s), {} vulnerable dependencies\n",
+                    report.security.len(),
+                    report.dependencies.len()
+                );
+
+                for finding in &report.security {
+                    println!(
+                        "- [{:?}] {} {}:{} {}",
+                        finding.severity,
+                        finding.rule_id,
+                        finding.file_path,
+                        finding.line,
+                        finding.message
+                    );
+                }
+
+                for dep_vuln in &report.dependencies {
+                    for vuln in &dep_vuln.vulnerabilities {
+                        println!(
+                            "- [{:?}] {} {} {}: {}",
+                            vuln.severity,
+                            vuln.id,
+                            dep_vuln.dependency.name,
+                            dep_vuln.dependency.version,
+                            vuln.summary
+                        );
+                    }
+                }
+                println!();
+            }
+        }
+        other => anyhow::bail!("Unknown --format value '{}'. Use text or sarif.", other),
+    }
+
+    if let Some(threshold) = fail_threshold {
+        let over_threshold = reports.iter().any(|(_, report)| {
+            report
+                .security
+                .iter()
+                .any(|f| rule_severity_rank(f.severity) >= threshold)
+                || report.dependencies.iter().any(|d| {
+                    d.vulnerabilities
+                        .iter()
+                        .any(|v| vuln_severity_rank(v.severity) >= threshold)
+                })
+        });
+
+        if over_threshold {
+            eprintln!("Findings at or above '{}' severity found.", args.fail_on);
+            std::process::exit(1);
+        }
+    }
 
     Ok(())
 }
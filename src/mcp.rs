@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use std::sync::{Arc, Mutex};
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt};
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 use crate::config::{ClientInfo, ConfigLoader, ToolConfig, ToolFilter};
 use crate::index::CodeIntelEngine;
@@ -120,8 +120,46 @@ impl McpServer {
         info!("MCP server starting on stdio");
 
         let stdin = tokio::io::stdin();
-        let mut stdout = tokio::io::stdout();
-        let mut reader = tokio::io::BufReader::new(stdin);
+        let stdout = tokio::io::stdout();
+        let reader = tokio::io::BufReader::new(stdin);
+        self.serve_connection(reader, stdout).await
+    }
+
+    /// Serve multiple short-lived MCP clients over a Unix domain socket.
+    /// The engine outlives individual editor sessions, so reconnecting
+    /// clients skip startup and index-load costs.
+    #[cfg(unix)]
+    pub async fn run_unix_socket(self: Arc<Self>, socket_path: &std::path::Path) -> Result<()> {
+        // Remove a stale socket left by a previous run before binding
+        if socket_path.exists() {
+            std::fs::remove_file(socket_path)?;
+        }
+
+        let listener = tokio::net::UnixListener::bind(socket_path)?;
+        info!("MCP server listening on {:?}", socket_path);
+
+        loop {
+            let (stream, _) = listener.accept().await?;
+            info!("Client connected");
+
+            let server = Arc::clone(&self);
+            tokio::spawn(async move {
+                let (read_half, write_half) = stream.into_split();
+                let reader = tokio::io::BufReader::new(read_half);
+                match server.serve_connection(reader, write_half).await {
+                    Ok(()) => info!("Client disconnected"),
+                    Err(e) => warn!("Client session ended with error: {}", e),
+                }
+            });
+        }
+    }
+
+    /// Serve one MCP session over any line-delimited JSON-RPC transport
+    async fn serve_connection<R, W>(&self, mut reader: R, mut writer: W) -> Result<()>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
         let mut line = String::new();
 
         loop {
@@ -184,8 +222,8 @@ impl McpServer {
 
             let response_str = serde_json::to_string(&response)? + "\n";
             debug!("Sending: {}", response_str.trim());
-            stdout.write_all(response_str.as_bytes()).await?;
-            stdout.flush().await?;
+            writer.write_all(response_str.as_bytes()).await?;
+            writer.flush().await?;
         }
 
         Ok(())
@@ -79,6 +79,24 @@ impl MetricStats {
     }
 }
 
+/// Point-in-time gauges for index data structure sizes
+///
+/// Refreshed by the engine before each metrics report rather than
+/// tracked incrementally.
+#[derive(Debug, Clone, Default)]
+pub struct IndexSizeStats {
+    /// Total symbols across all repositories
+    pub symbol_count: usize,
+    /// Bytes held by the file content cache
+    pub file_cache_bytes: usize,
+    /// Estimated bytes in the BM25 inverted index postings
+    pub bm25_postings_bytes: usize,
+    /// Estimated bytes in TF-IDF embedding vectors
+    pub embedding_bytes: usize,
+    /// Total call graph edges across all repositories
+    pub call_graph_edges: usize,
+}
+
 /// Repository indexing metrics
 #[derive(Debug, Clone)]
 pub struct RepoIndexMetrics {
@@ -95,6 +113,10 @@ pub struct Metrics {
     tool_metrics: Arc<RwLock<HashMap<String, MetricStats>>>,
     repo_index_metrics: Arc<RwLock<Vec<RepoIndexMetrics>>>,
     file_parse_metrics: Arc<RwLock<MetricStats>>,
+    /// Index data structure size gauges (set by the engine)
+    index_sizes: Arc<RwLock<IndexSizeStats>>,
+    /// Cache name -> (hits, misses)
+    cache_counters: Arc<RwLock<HashMap<String, (u64, u64)>>>,
 }
 
 impl Metrics {
@@ -104,6 +126,8 @@ impl Metrics {
             tool_metrics: Arc::new(RwLock::new(HashMap::new())),
             repo_index_metrics: Arc::new(RwLock::new(Vec::new())),
             file_parse_metrics: Arc::new(RwLock::new(MetricStats::new())),
+            index_sizes: Arc::new(RwLock::new(IndexSizeStats::default())),
+            cache_counters: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
@@ -139,6 +163,32 @@ impl Metrics {
         self.file_parse_metrics.write().record(duration_ms);
     }
 
+    /// Replace the index size gauges with a fresh snapshot
+    pub fn set_index_sizes(&self, sizes: IndexSizeStats) {
+        *self.index_sizes.write() = sizes;
+    }
+
+    /// Get the current index size gauges
+    pub fn get_index_sizes(&self) -> IndexSizeStats {
+        self.index_sizes.read().clone()
+    }
+
+    /// Record a cache lookup as a hit or miss
+    pub fn record_cache_access(&self, cache: &str, hit: bool) {
+        let mut counters = self.cache_counters.write();
+        let entry = counters.entry(cache.to_string()).or_insert((0, 0));
+        if hit {
+            entry.0 += 1;
+        } else {
+            entry.1 += 1;
+        }
+    }
+
+    /// Get hit/miss counts per cache
+    pub fn get_cache_counters(&self) -> HashMap<String, (u64, u64)> {
+        self.cache_counters.read().clone()
+    }
+
     /// Get statistics for a specific tool
     pub fn get_tool_stats(&self, tool_name: &str) -> Option<MetricStats> {
         self.tool_metrics.read().get(tool_name).cloned()
@@ -246,6 +296,54 @@ impl Metrics {
             output.push('\n');
         }
 
+        // Memory and index size gauges
+        output.push_str("## Memory & Index Size\n\n");
+        output.push_str("| Metric | Value |\n");
+        output.push_str("|--------|-------|\n");
+        match resident_memory_bytes() {
+            Some(rss) => output.push_str(&format!("| Resident Memory | {} |\n", format_bytes(rss))),
+            None => output.push_str("| Resident Memory | unavailable |\n"),
+        }
+        let sizes = self.get_index_sizes();
+        output.push_str(&format!("| Symbols | {} |\n", sizes.symbol_count));
+        output.push_str(&format!(
+            "| File Cache | {} |\n",
+            format_bytes(sizes.file_cache_bytes as u64)
+        ));
+        output.push_str(&format!(
+            "| BM25 Postings | {} |\n",
+            format_bytes(sizes.bm25_postings_bytes as u64)
+        ));
+        output.push_str(&format!(
+            "| Embeddings | {} |\n",
+            format_bytes(sizes.embedding_bytes as u64)
+        ));
+        output.push_str(&format!(
+            "| Call Graph Edges | {} |\n",
+            sizes.call_graph_edges
+        ));
+        output.push('\n');
+
+        // Cache hit rates
+        let caches = self.get_cache_counters();
+        if !caches.is_empty() {
+            output.push_str("## Cache Hit Rates\n\n");
+            output.push_str("| Cache | Hits | Misses | Hit Rate |\n");
+            output.push_str("|-------|------|--------|----------|\n");
+            let mut caches: Vec<_> = caches.into_iter().collect();
+            caches.sort_by(|a, b| a.0.cmp(&b.0));
+            for (name, (hits, misses)) in caches {
+                output.push_str(&format!(
+                    "| {} | {} | {} | {:.1}% |\n",
+                    name,
+                    hits,
+                    misses,
+                    hit_rate(hits, misses) * 100.0
+                ));
+            }
+            output.push('\n');
+        }
+
         // Tool execution metrics
         output.push_str("## Tool Execution Times\n\n");
         let tool_stats = self.get_all_tool_stats();
@@ -316,10 +414,37 @@ impl Metrics {
 
         let parse_stats = self.get_file_parse_stats();
 
+        let sizes = self.get_index_sizes();
+        let caches: serde_json::Value = self
+            .get_cache_counters()
+            .into_iter()
+            .map(|(name, (hits, misses))| {
+                (
+                    name,
+                    json!({
+                        "hits": hits,
+                        "misses": misses,
+                        "hit_rate": hit_rate(hits, misses),
+                    }),
+                )
+            })
+            .collect();
+
         json!({
             "uptime_seconds": self.uptime_seconds(),
             "uptime_string": self.uptime_string(),
             "total_requests": self.total_requests(),
+            "memory": {
+                "resident_bytes": resident_memory_bytes(),
+                "index": {
+                    "symbol_count": sizes.symbol_count,
+                    "file_cache_bytes": sizes.file_cache_bytes,
+                    "bm25_postings_bytes": sizes.bm25_postings_bytes,
+                    "embedding_bytes": sizes.embedding_bytes,
+                    "call_graph_edges": sizes.call_graph_edges,
+                },
+                "caches": caches,
+            },
             "repository_indexing": repo_json,
             "file_parsing": {
                 "count": parse_stats.count,
@@ -341,6 +466,50 @@ impl Default for Metrics {
     }
 }
 
+/// Resident set size of this process, if the platform exposes it
+///
+/// Reads `VmRSS` from `/proc/self/status` on Linux; other platforms
+/// report `None` rather than a guess.
+pub fn resident_memory_bytes() -> Option<u64> {
+    #[cfg(target_os = "linux")]
+    {
+        let status = std::fs::read_to_string("/proc/self/status").ok()?;
+        let line = status.lines().find(|l| l.starts_with("VmRSS:"))?;
+        let kb: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+        Some(kb * 1024)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+/// Hit rate in [0, 1]; 0 when no lookups have been recorded
+fn hit_rate(hits: u64, misses: u64) -> f64 {
+    let total = hits + misses;
+    if total == 0 {
+        0.0
+    } else {
+        hits as f64 / total as f64
+    }
+}
+
+/// Format a byte count with a binary unit suffix
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: &[&str] = &["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -483,6 +652,72 @@ mod tests {
         assert_eq!(stats.p99(), 42);
     }
 
+    #[test]
+    fn test_index_size_gauges() {
+        let metrics = Metrics::new();
+
+        metrics.set_index_sizes(IndexSizeStats {
+            symbol_count: 1234,
+            file_cache_bytes: 2048,
+            bm25_postings_bytes: 4096,
+            embedding_bytes: 8192,
+            call_graph_edges: 77,
+        });
+
+        let sizes = metrics.get_index_sizes();
+        assert_eq!(sizes.symbol_count, 1234);
+        assert_eq!(sizes.call_graph_edges, 77);
+
+        let report = metrics.report();
+        assert!(report.contains("Memory & Index Size"));
+        assert!(report.contains("| Symbols | 1234 |"));
+        assert!(report.contains("| File Cache | 2.0 KiB |"));
+
+        let json = metrics.report_json();
+        assert_eq!(json["memory"]["index"]["symbol_count"], 1234);
+        assert_eq!(json["memory"]["index"]["call_graph_edges"], 77);
+    }
+
+    #[test]
+    fn test_cache_hit_rates() {
+        let metrics = Metrics::new();
+
+        metrics.record_cache_access("file_cache", true);
+        metrics.record_cache_access("file_cache", true);
+        metrics.record_cache_access("file_cache", false);
+
+        let counters = metrics.get_cache_counters();
+        assert_eq!(counters["file_cache"], (2, 1));
+
+        let json = metrics.report_json();
+        assert_eq!(json["memory"]["caches"]["file_cache"]["hits"], 2);
+        assert_eq!(json["memory"]["caches"]["file_cache"]["misses"], 1);
+        let rate = json["memory"]["caches"]["file_cache"]["hit_rate"]
+            .as_f64()
+            .unwrap();
+        assert!((rate - 2.0 / 3.0).abs() < 1e-9);
+
+        let report = metrics.report();
+        assert!(report.contains("Cache Hit Rates"));
+        assert!(report.contains("66.7%"));
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(2048), "2.0 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn test_resident_memory_on_linux() {
+        // On Linux this must report a plausible non-zero RSS
+        if cfg!(target_os = "linux") {
+            let rss = resident_memory_bytes().unwrap();
+            assert!(rss > 0);
+        }
+    }
+
     #[test]
     fn test_uptime_formatting() {
         let metrics = Metrics::new();
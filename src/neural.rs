@@ -2,6 +2,7 @@
 //!
 //! Supports multiple backends:
 //! - ONNX models (CodeBERT, StarEncoder, etc.) - requires `neural` feature
+//! - Local candle models (CPU/GPU, no network) - requires `neural-local` feature
 //! - API-based (Voyage, OpenAI) for higher quality
 //!
 //! This module provides dense vector embeddings for semantic code search,
@@ -21,7 +22,11 @@ use url::Url;
 use std::path::Path;
 
 // Security constants for input validation
-const MAX_EMBEDDING_BATCH_SIZE: usize = 100; // Maximum texts per API request
+const API_BATCH_SIZE: usize = 64; // Texts sent per embedding API request
+const MAX_CONCURRENT_REQUESTS: usize = 4; // Parallel in-flight embedding API requests
+const MAX_REQUEST_ATTEMPTS: usize = 3; // Tries per API request before giving up
+const INITIAL_RETRY_DELAY_MS: u64 = 500; // Doubled after each retryable failure
+const MAX_RETRY_JITTER_MS: u64 = 250; // Random jitter added to each retry delay
 const MAX_TEXT_LENGTH: usize = 32_000; // Maximum characters per text (~8k tokens for most models)
 const MAX_DIMENSION: usize = 8192; // Maximum embedding dimension (larger than any known model)
 const MIN_DIMENSION: usize = 64; // Minimum reasonable embedding dimension
@@ -35,13 +40,15 @@ const MAX_RESPONSE_SIZE_BYTES: usize = 100 * 1024 * 1024; // 100MB max response
 pub struct NeuralConfig {
     /// Enable neural embeddings
     pub enabled: bool,
-    /// Model backend: "onnx", "api"
+    /// Model backend: "onnx", "api", "local"
     pub backend: String,
-    /// Path to ONNX model file (for onnx backend)
+    /// Path to ONNX model file (for onnx backend) or model directory
+    /// (for local backend)
     pub model_path: Option<String>,
     /// Path to tokenizer file (for onnx backend)
     pub tokenizer_path: Option<String>,
-    /// Model name for API backend (e.g., "voyage-code-2")
+    /// Model name for API backend (e.g., "voyage-code-2") or local
+    /// backend (e.g., "jinaai/jina-embeddings-v2-base-code")
     pub model_name: Option<String>,
     /// API endpoint (for api backend)
     pub api_endpoint: Option<String>,
@@ -51,6 +58,11 @@ pub struct NeuralConfig {
     pub max_seq_length: usize,
     /// Batch size for bulk embedding
     pub batch_size: usize,
+    /// Directory for the on-disk embedding cache. When set, embeddings
+    /// are cached keyed by content hash and model name so unchanged code
+    /// is never re-embedded. None keeps the cache in memory only.
+    #[serde(default)]
+    pub cache_dir: Option<String>,
 }
 
 impl Default for NeuralConfig {
@@ -65,6 +77,7 @@ impl Default for NeuralConfig {
             dimension: 1536,
             max_seq_length: 512,
             batch_size: 32,
+            cache_dir: None,
         }
     }
 }
@@ -189,6 +202,12 @@ pub trait EmbeddingBackend: Send + Sync {
 
     /// Get the dimensionality of embeddings
     fn dimension(&self) -> usize;
+
+    /// Cumulative API usage, for backends that call out to paid services.
+    /// Local backends return None.
+    fn usage(&self) -> Option<ApiUsageStats> {
+        None
+    }
 }
 
 // ============================================================================
@@ -354,12 +373,368 @@ pub mod onnx {
             self.dimension
         }
     }
+
+    /// ONNX-based cross-encoder for reranking search results.
+    ///
+    /// Unlike the bi-encoder embedder above, a cross-encoder scores the
+    /// query and document together in a single forward pass, which is
+    /// much more precise for natural-language queries. Expects a model
+    /// exported with a single relevance logit output (e.g.
+    /// cross-encoder/ms-marco-MiniLM-L-6-v2).
+    pub struct OnnxCrossEncoder {
+        session: Mutex<Session>,
+        tokenizer: Tokenizer,
+        max_seq_length: usize,
+    }
+
+    impl OnnxCrossEncoder {
+        /// Create a new cross-encoder from model and tokenizer paths
+        pub fn new(model_path: &Path, tokenizer_path: &Path) -> Result<Self> {
+            let session = Session::builder()?
+                .with_optimization_level(GraphOptimizationLevel::Level3)?
+                .with_intra_threads(4)?
+                .commit_from_file(model_path)?;
+
+            let tokenizer = Tokenizer::from_file(tokenizer_path)
+                .map_err(|e| anyhow::anyhow!("Failed to load tokenizer: {}", e))?;
+
+            Ok(Self {
+                session: Mutex::new(session),
+                tokenizer,
+                max_seq_length: 512,
+            })
+        }
+
+        /// Create from a pretrained model name (pre-downloaded)
+        pub fn from_pretrained(model_name: &str, cache_dir: &Path) -> Result<Self> {
+            let model_dir = cache_dir.join(model_name.replace('/', "_"));
+
+            if !model_dir.exists() {
+                anyhow::bail!(
+                    "Model not found at {:?}. Please download manually:\n\
+                     optimum-cli export onnx --model {} {}\n\
+                     Or download from: https://huggingface.co/{}/tree/main",
+                    model_dir,
+                    model_name,
+                    model_dir.display(),
+                    model_name
+                );
+            }
+
+            Self::new(
+                &model_dir.join("model.onnx"),
+                &model_dir.join("tokenizer.json"),
+            )
+        }
+
+        /// Score one query/document pair with the cross-encoder
+        fn score_pair(&self, query: &str, document: &str) -> Result<f32> {
+            let encoding = self
+                .tokenizer
+                .encode((query, document), true)
+                .map_err(|e| anyhow::anyhow!("Tokenization failed: {}", e))?;
+
+            let input_ids: Vec<i64> = encoding
+                .get_ids()
+                .iter()
+                .take(self.max_seq_length)
+                .map(|&id| id as i64)
+                .collect();
+
+            let attention_mask: Vec<i64> = encoding
+                .get_attention_mask()
+                .iter()
+                .take(self.max_seq_length)
+                .map(|&m| m as i64)
+                .collect();
+
+            let seq_len = input_ids.len();
+
+            let input_ids_array =
+                Array2::from_shape_vec((1, seq_len), input_ids).context("Invalid input shape")?;
+            let attention_mask_array = Array2::from_shape_vec((1, seq_len), attention_mask)
+                .context("Invalid mask shape")?;
+
+            let input_ids_tensor = TensorRef::from_array_view(input_ids_array.view())?;
+            let attention_mask_tensor = TensorRef::from_array_view(attention_mask_array.view())?;
+
+            let mut session = self
+                .session
+                .lock()
+                .map_err(|e| anyhow::anyhow!("Failed to lock session: {}", e))?;
+
+            let outputs = session.run(ort::inputs![
+                "input_ids" => input_ids_tensor,
+                "attention_mask" => attention_mask_tensor,
+            ])?;
+
+            let output: &ort::value::Value = outputs
+                .get("logits")
+                .or_else(|| outputs.iter().next().map(|(_, v)| v))
+                .ok_or_else(|| anyhow::anyhow!("No output tensor found from ONNX model"))?;
+
+            let (_, data) = output.try_extract_tensor::<f32>()?;
+            data.first()
+                .copied()
+                .context("Cross-encoder returned an empty logit tensor")
+        }
+    }
+
+    impl crate::hybrid_search::Reranker for OnnxCrossEncoder {
+        fn rerank(&self, query: &str, documents: &[String]) -> Result<Vec<f32>> {
+            documents
+                .iter()
+                .map(|doc| self.score_pair(query, doc))
+                .collect()
+        }
+    }
+}
+
+// ============================================================================
+// Local Candle Backend (requires `neural-local` feature)
+// ============================================================================
+
+#[cfg(feature = "neural-local")]
+pub mod local {
+    use super::*;
+    use candle_core::{Device, Tensor};
+    use candle_nn::VarBuilder;
+    use candle_transformers::models::bert::{BertModel, Config as BertConfig, DTYPE};
+    use parking_lot::Mutex;
+    use std::path::PathBuf;
+    use tokenizers::Tokenizer;
+
+    /// Everything needed for inference, loaded lazily on first use
+    struct LoadedModel {
+        model: BertModel,
+        tokenizer: Tokenizer,
+        device: Device,
+    }
+
+    /// Fully local embedding model running on CPU/GPU via candle.
+    ///
+    /// Loads a BERT-family code-embedding model (e.g. jina-embeddings-v2-base-code,
+    /// all-MiniLM-L6-v2, bge-small-en) from a directory containing
+    /// `model.safetensors` (or `pytorch_model.bin`), `tokenizer.json`, and
+    /// `config.json`. No API keys and no network access are needed, so this
+    /// works air-gapped. The weights are loaded lazily on the first
+    /// embedding, keeping server startup fast.
+    pub struct LocalEmbedder {
+        model_dir: PathBuf,
+        dimension: usize,
+        max_seq_length: usize,
+        loaded: Mutex<Option<Arc<LoadedModel>>>,
+    }
+
+    impl LocalEmbedder {
+        /// Create an embedder for the model in `model_dir`. Validates the
+        /// files and reads the dimension from config.json, but defers the
+        /// heavy weight loading until the first embedding.
+        pub fn new(model_dir: &Path, max_seq_length: usize) -> Result<Self> {
+            for required in ["config.json", "tokenizer.json"] {
+                if !model_dir.join(required).exists() {
+                    bail!("Missing {} in {:?}", required, model_dir);
+                }
+            }
+            if weights_path(model_dir).is_none() {
+                bail!(
+                    "No model.safetensors or pytorch_model.bin found in {:?}",
+                    model_dir
+                );
+            }
+
+            let config: BertConfig = serde_json::from_str(
+                &std::fs::read_to_string(model_dir.join("config.json"))
+                    .context("Failed to read config.json")?,
+            )
+            .context("Failed to parse config.json")?;
+
+            Ok(Self {
+                model_dir: model_dir.to_path_buf(),
+                dimension: config.hidden_size,
+                max_seq_length,
+                loaded: Mutex::new(None),
+            })
+        }
+
+        /// Create from a model name, resolved inside `cache_dir` the same
+        /// way the ONNX backend does. The model must already be on disk;
+        /// this backend never touches the network.
+        pub fn from_pretrained(
+            model_name: &str,
+            cache_dir: &Path,
+            max_seq_length: usize,
+        ) -> Result<Self> {
+            let model_dir = cache_dir.join(model_name.replace('/', "_"));
+
+            if !model_dir.exists() {
+                bail!(
+                    "Model not found at {:?}. Download it once on a connected machine:\n\
+                     huggingface-cli download {} --local-dir {}\n\
+                     (needs model.safetensors, tokenizer.json, and config.json)",
+                    model_dir,
+                    model_name,
+                    model_dir.display()
+                );
+            }
+
+            Self::new(&model_dir, max_seq_length)
+        }
+
+        /// Load the weights if not yet loaded; the first embedding pays
+        /// the cost once and later calls reuse the model
+        fn ensure_loaded(&self) -> Result<Arc<LoadedModel>> {
+            let mut guard = self.loaded.lock();
+            if let Some(loaded) = guard.as_ref() {
+                return Ok(loaded.clone());
+            }
+
+            let device = Device::cuda_if_available(0).unwrap_or(Device::Cpu);
+            tracing::info!(
+                "Loading local embedding model from {:?} on {:?}",
+                self.model_dir,
+                device
+            );
+
+            let config: BertConfig = serde_json::from_str(
+                &std::fs::read_to_string(self.model_dir.join("config.json"))
+                    .context("Failed to read config.json")?,
+            )
+            .context("Failed to parse config.json")?;
+
+            let tokenizer = Tokenizer::from_file(self.model_dir.join("tokenizer.json"))
+                .map_err(|e| anyhow::anyhow!("Failed to load tokenizer: {}", e))?;
+
+            let weights = weights_path(&self.model_dir)
+                .context("Model weights disappeared after validation")?;
+            let vb = if weights.extension().is_some_and(|e| e == "safetensors") {
+                // Safety: the mmap'd file must not be mutated while in use
+                unsafe { VarBuilder::from_mmaped_safetensors(&[weights], DTYPE, &device)? }
+            } else {
+                VarBuilder::from_pth(&weights, DTYPE, &device)?
+            };
+            let model = BertModel::load(vb, &config).context("Failed to load model weights")?;
+
+            let loaded = Arc::new(LoadedModel {
+                model,
+                tokenizer,
+                device,
+            });
+            *guard = Some(loaded.clone());
+            Ok(loaded)
+        }
+    }
+
+    impl EmbeddingBackend for LocalEmbedder {
+        fn embed(&self, text: &str) -> Result<Vec<f32>> {
+            let loaded = self.ensure_loaded()?;
+
+            let encoding = loaded
+                .tokenizer
+                .encode(text, true)
+                .map_err(|e| anyhow::anyhow!("Tokenization failed: {}", e))?;
+            let input_ids: Vec<u32> = encoding
+                .get_ids()
+                .iter()
+                .take(self.max_seq_length)
+                .copied()
+                .collect();
+            let seq_len = input_ids.len();
+            if seq_len == 0 {
+                bail!("Cannot embed empty text");
+            }
+
+            let input_ids = Tensor::new(input_ids, &loaded.device)?.unsqueeze(0)?;
+            let token_type_ids = input_ids.zeros_like()?;
+            let output = loaded
+                .model
+                .forward(&input_ids, &token_type_ids, None)
+                .context("Local model inference failed")?;
+
+            // Mean-pool over the sequence, then L2 normalize
+            let pooled = (output.sum(1)? / (seq_len as f64))?;
+            let mut embedding = pooled.squeeze(0)?.to_vec1::<f32>()?;
+            let norm: f32 = embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
+            if norm > 0.0 {
+                for x in &mut embedding {
+                    *x /= norm;
+                }
+            }
+
+            Ok(embedding)
+        }
+
+        fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+            // Sequential like the ONNX backend; the model itself is the
+            // bottleneck, not the dispatch
+            texts.iter().map(|t| self.embed(t)).collect()
+        }
+
+        fn dimension(&self) -> usize {
+            self.dimension
+        }
+    }
+
+    /// Prefer safetensors; fall back to a PyTorch checkpoint
+    fn weights_path(model_dir: &Path) -> Option<PathBuf> {
+        ["model.safetensors", "pytorch_model.bin"]
+            .iter()
+            .map(|name| model_dir.join(name))
+            .find(|p| p.exists())
+    }
 }
 
 // ============================================================================
 // API Backend (Voyage, OpenAI, etc.)
 // ============================================================================
 
+/// Cumulative usage of an API embedding client, for cost accounting
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ApiUsageStats {
+    /// Requests sent (including retries)
+    pub requests: usize,
+    /// Requests that were retries of a failed attempt
+    pub retries: usize,
+    /// Texts successfully embedded
+    pub texts_embedded: usize,
+    /// Characters successfully embedded
+    pub chars_embedded: usize,
+}
+
+impl ApiUsageStats {
+    /// Rough token estimate (~4 characters per token for code)
+    pub fn estimated_tokens(&self) -> usize {
+        self.chars_embedded / 4
+    }
+}
+
+#[derive(Default)]
+struct ApiUsageCounters {
+    requests: std::sync::atomic::AtomicUsize,
+    retries: std::sync::atomic::AtomicUsize,
+    texts: std::sync::atomic::AtomicUsize,
+    chars: std::sync::atomic::AtomicUsize,
+}
+
+lazy_static::lazy_static! {
+    /// Bounded worker pool so concurrent embedding API requests never
+    /// exceed MAX_CONCURRENT_REQUESTS, regardless of batch size
+    static ref EMBED_POOL: rayon::ThreadPool = rayon::ThreadPoolBuilder::new()
+        .num_threads(MAX_CONCURRENT_REQUESTS)
+        .thread_name(|i| format!("embed-api-{}", i))
+        .build()
+        .expect("Failed to create embedding API thread pool");
+}
+
+/// Small random jitter so concurrent retries don't thunder in lockstep
+fn retry_jitter() -> Duration {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    Duration::from_millis(u64::from(nanos) % MAX_RETRY_JITTER_MS)
+}
+
 /// API-based embedding provider (Voyage, OpenAI, etc.)
 pub struct ApiEmbedder {
     client: reqwest::blocking::Client,
@@ -367,6 +742,7 @@ pub struct ApiEmbedder {
     model: String,
     api_key: Option<String>,
     dimension: usize,
+    usage: ApiUsageCounters,
 }
 
 impl ApiEmbedder {
@@ -387,6 +763,7 @@ impl ApiEmbedder {
             model: "voyage-code-2".to_string(),
             api_key: Some(api_key.to_string()),
             dimension: 1536,
+            usage: ApiUsageCounters::default(),
         }
     }
 
@@ -398,6 +775,7 @@ impl ApiEmbedder {
             model: model.to_string(),
             api_key: Some(api_key.to_string()),
             dimension: 1536,
+            usage: ApiUsageCounters::default(),
         }
     }
 
@@ -409,6 +787,7 @@ impl ApiEmbedder {
             model: "text-embedding-3-small".to_string(),
             api_key: Some(api_key.to_string()),
             dimension: 1536,
+            usage: ApiUsageCounters::default(),
         }
     }
 
@@ -420,6 +799,7 @@ impl ApiEmbedder {
             model: model.to_string(),
             api_key: Some(api_key.to_string()),
             dimension,
+            usage: ApiUsageCounters::default(),
         }
     }
 
@@ -431,57 +811,21 @@ impl ApiEmbedder {
             model: model.to_string(),
             api_key: api_key.map(|s| s.to_string()),
             dimension,
+            usage: ApiUsageCounters::default(),
         }
     }
 }
 
-impl EmbeddingBackend for ApiEmbedder {
-    fn embed(&self, text: &str) -> Result<Vec<f32>> {
-        let results = self.embed_batch(&[text.to_string()])?;
-        results.into_iter().next().context("No embedding returned")
-    }
-
-    fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
-        // Input validation - batch size
-        if texts.is_empty() {
-            bail!("Cannot embed empty batch");
-        }
-        if texts.len() > MAX_EMBEDDING_BATCH_SIZE {
-            bail!(
-                "Batch size {} exceeds maximum of {}",
-                texts.len(),
-                MAX_EMBEDDING_BATCH_SIZE
-            );
-        }
-
-        // Input validation - text length
-        for (i, text) in texts.iter().enumerate() {
-            if text.len() > MAX_TEXT_LENGTH {
-                bail!(
-                    "Text at index {} is {} characters, exceeds maximum of {}",
-                    i,
-                    text.len(),
-                    MAX_TEXT_LENGTH
-                );
-            }
-        }
-
+impl ApiEmbedder {
+    /// Send one embedding request and return the status plus body.
+    /// Transport errors are returned as Err and treated as retryable.
+    fn send_request(&self, texts: &[String]) -> Result<(reqwest::StatusCode, String)> {
         #[derive(Serialize)]
         struct Request<'a> {
             model: &'a str,
             input: &'a [String],
         }
 
-        #[derive(Deserialize)]
-        struct Response {
-            data: Vec<EmbeddingData>,
-        }
-
-        #[derive(Deserialize)]
-        struct EmbeddingData {
-            embedding: Vec<f32>,
-        }
-
         let mut request = self
             .client
             .post(&self.endpoint)
@@ -529,18 +873,22 @@ impl EmbeddingBackend for ApiEmbedder {
         }
 
         let text = String::from_utf8(limited_body).context("Response body is not valid UTF-8")?;
+        Ok((status, text))
+    }
 
-        if !status.is_success() {
-            // Redact potential sensitive info from error messages
-            let safe_text = if text.len() > 500 {
-                format!("{}... (truncated)", &text[..500])
-            } else {
-                text.clone()
-            };
-            bail!("API error ({}): {}", status, safe_text);
+    /// Parse a successful embedding response and validate dimensions
+    fn parse_response(&self, text: &str) -> Result<Vec<Vec<f32>>> {
+        #[derive(Deserialize)]
+        struct Response {
+            data: Vec<EmbeddingData>,
         }
 
-        let response: Response = serde_json::from_str(&text).with_context(|| {
+        #[derive(Deserialize)]
+        struct EmbeddingData {
+            embedding: Vec<f32>,
+        }
+
+        let response: Response = serde_json::from_str(text).with_context(|| {
             format!(
                 "Failed to parse embedding response: {}",
                 &text[..text.len().min(200)]
@@ -562,9 +910,123 @@ impl EmbeddingBackend for ApiEmbedder {
         Ok(response.data.into_iter().map(|d| d.embedding).collect())
     }
 
+    /// Send one batch with retry: rate limits (429), server errors (5xx)
+    /// and transport failures are retried with exponential backoff plus
+    /// jitter; other errors fail immediately
+    fn request_with_retry(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        use std::sync::atomic::Ordering;
+
+        let mut delay = Duration::from_millis(INITIAL_RETRY_DELAY_MS);
+        let mut last_error = None;
+
+        for attempt in 0..MAX_REQUEST_ATTEMPTS {
+            if attempt > 0 {
+                std::thread::sleep(delay + retry_jitter());
+                delay *= 2;
+                self.usage.retries.fetch_add(1, Ordering::Relaxed);
+            }
+            self.usage.requests.fetch_add(1, Ordering::Relaxed);
+
+            match self.send_request(texts) {
+                Ok((status, text)) if status.is_success() => {
+                    let embeddings = self.parse_response(&text)?;
+                    self.usage.texts.fetch_add(texts.len(), Ordering::Relaxed);
+                    self.usage
+                        .chars
+                        .fetch_add(texts.iter().map(|t| t.len()).sum(), Ordering::Relaxed);
+                    return Ok(embeddings);
+                }
+                Ok((status, text)) => {
+                    // Redact potential sensitive info from error messages
+                    let safe_text = if text.len() > 500 {
+                        format!("{}... (truncated)", &text[..500])
+                    } else {
+                        text
+                    };
+                    if status.as_u16() != 429 && !status.is_server_error() {
+                        bail!("API error ({}): {}", status, safe_text);
+                    }
+                    tracing::warn!(
+                        "Embedding API returned {} (attempt {}/{})",
+                        status,
+                        attempt + 1,
+                        MAX_REQUEST_ATTEMPTS
+                    );
+                    last_error = Some(anyhow::anyhow!("API error ({}): {}", status, safe_text));
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        "Embedding request failed (attempt {}/{}): {}",
+                        attempt + 1,
+                        MAX_REQUEST_ATTEMPTS,
+                        e
+                    );
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        Err(last_error.expect("at least one attempt was made")).context(format!(
+            "Embedding request failed after {} attempts",
+            MAX_REQUEST_ATTEMPTS
+        ))
+    }
+}
+
+impl EmbeddingBackend for ApiEmbedder {
+    fn embed(&self, text: &str) -> Result<Vec<f32>> {
+        let results = self.embed_batch(&[text.to_string()])?;
+        results.into_iter().next().context("No embedding returned")
+    }
+
+    fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        if texts.is_empty() {
+            bail!("Cannot embed empty batch");
+        }
+
+        // Input validation - text length
+        for (i, text) in texts.iter().enumerate() {
+            if text.len() > MAX_TEXT_LENGTH {
+                bail!(
+                    "Text at index {} is {} characters, exceeds maximum of {}",
+                    i,
+                    text.len(),
+                    MAX_TEXT_LENGTH
+                );
+            }
+        }
+
+        if texts.len() <= API_BATCH_SIZE {
+            return self.request_with_retry(texts);
+        }
+
+        // Large batches are split into API-sized requests and sent through
+        // a bounded pool, so big repos index quickly without tripping
+        // provider rate limits
+        use rayon::prelude::*;
+        let batches: Result<Vec<Vec<Vec<f32>>>> = EMBED_POOL.install(|| {
+            texts
+                .par_chunks(API_BATCH_SIZE)
+                .map(|chunk| self.request_with_retry(chunk))
+                .collect()
+        });
+
+        Ok(batches?.into_iter().flatten().collect())
+    }
+
     fn dimension(&self) -> usize {
         self.dimension
     }
+
+    fn usage(&self) -> Option<ApiUsageStats> {
+        use std::sync::atomic::Ordering;
+        Some(ApiUsageStats {
+            requests: self.usage.requests.load(Ordering::Relaxed),
+            retries: self.usage.retries.load(Ordering::Relaxed),
+            texts_embedded: self.usage.texts.load(Ordering::Relaxed),
+            chars_embedded: self.usage.chars.load(Ordering::Relaxed),
+        })
+    }
 }
 
 // ============================================================================
@@ -765,6 +1227,120 @@ pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
     }
 }
 
+// ============================================================================
+// Embedding Cache
+// ============================================================================
+
+/// Statistics about the embedding cache
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EmbeddingCacheStats {
+    pub entries: usize,
+    pub hits: usize,
+    pub misses: usize,
+}
+
+/// Disk-backed cache of embeddings keyed by content hash and model name.
+///
+/// Re-indexing or restarting hits the cache for unchanged chunks instead
+/// of re-sending them to (paid) embedding APIs. Entries are keyed by
+/// `{model}:{sha256(content)}` so switching models never returns stale
+/// vectors, and the cache file survives restarts.
+pub struct EmbeddingCache {
+    path: Option<std::path::PathBuf>,
+    entries: RwLock<HashMap<String, Vec<f32>>>,
+    hits: std::sync::atomic::AtomicUsize,
+    misses: std::sync::atomic::AtomicUsize,
+}
+
+impl EmbeddingCache {
+    /// Create an in-memory cache that is never persisted
+    pub fn in_memory() -> Self {
+        Self {
+            path: None,
+            entries: RwLock::new(HashMap::new()),
+            hits: std::sync::atomic::AtomicUsize::new(0),
+            misses: std::sync::atomic::AtomicUsize::new(0),
+        }
+    }
+
+    /// Open a cache backed by `path`, loading existing entries if the file
+    /// exists. An unreadable or corrupt file starts the cache empty rather
+    /// than failing engine startup.
+    pub fn load(path: &std::path::Path) -> Self {
+        let entries = match std::fs::read(path) {
+            Ok(bytes) => match bincode::deserialize::<HashMap<String, Vec<f32>>>(&bytes) {
+                Ok(map) => map,
+                Err(e) => {
+                    tracing::warn!("Ignoring corrupt embedding cache at {:?}: {}", path, e);
+                    HashMap::new()
+                }
+            },
+            Err(_) => HashMap::new(),
+        };
+
+        Self {
+            path: Some(path.to_path_buf()),
+            entries: RwLock::new(entries),
+            hits: std::sync::atomic::AtomicUsize::new(0),
+            misses: std::sync::atomic::AtomicUsize::new(0),
+        }
+    }
+
+    /// Build a cache key from the model name and chunk content
+    pub fn key(model: &str, content: &str) -> String {
+        use sha2::{Digest, Sha256};
+        let mut hasher = Sha256::new();
+        hasher.update(content.as_bytes());
+        format!("{}:{:x}", model, hasher.finalize())
+    }
+
+    /// Look up a cached embedding, recording a hit or miss
+    pub fn get(&self, key: &str) -> Option<Vec<f32>> {
+        use std::sync::atomic::Ordering;
+        match self.entries.read().get(key) {
+            Some(embedding) => {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                Some(embedding.clone())
+            }
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    /// Store an embedding under `key` (in memory; call `flush` to persist)
+    pub fn insert(&self, key: String, embedding: Vec<f32>) {
+        self.entries.write().insert(key, embedding);
+    }
+
+    /// Write the cache to disk. No-op for in-memory caches.
+    pub fn flush(&self) -> Result<()> {
+        let Some(ref path) = self.path else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create cache directory {:?}", parent))?;
+        }
+        let bytes = bincode::serialize(&*self.entries.read())
+            .context("Failed to serialize embedding cache")?;
+        std::fs::write(path, bytes)
+            .with_context(|| format!("Failed to write embedding cache to {:?}", path))?;
+        Ok(())
+    }
+
+    /// Get cache statistics
+    pub fn stats(&self) -> EmbeddingCacheStats {
+        use std::sync::atomic::Ordering;
+        EmbeddingCacheStats {
+            entries: self.entries.read().len(),
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+}
+
 // ============================================================================
 // Neural Engine
 // ============================================================================
@@ -801,6 +1377,7 @@ pub struct NeuralEngine {
     backend: Arc<dyn EmbeddingBackend>,
     store: SimpleVectorStore,
     documents: RwLock<HashMap<String, NeuralDocument>>,
+    cache: EmbeddingCache,
     config: NeuralConfig,
 }
 
@@ -906,6 +1483,7 @@ impl NeuralEngine {
             backend,
             store,
             documents: RwLock::new(HashMap::new()),
+            cache: Self::open_cache(&config),
             config,
         })
     }
@@ -933,6 +1511,43 @@ impl NeuralEngine {
             backend,
             store,
             documents: RwLock::new(HashMap::new()),
+            cache: Self::open_cache(&config),
+            config,
+        })
+    }
+
+    /// Create a new neural engine with the fully local candle backend
+    /// (requires neural-local feature). Runs on CPU or GPU with no API
+    /// keys and no network access.
+    ///
+    /// `model_path` points at a model directory; otherwise `model_name`
+    /// is resolved under `~/.cache/narsil-mcp/models`.
+    #[cfg(feature = "neural-local")]
+    pub fn with_local(config: NeuralConfig) -> Result<Self> {
+        let embedder = if let Some(ref model_path) = config.model_path {
+            local::LocalEmbedder::new(Path::new(model_path), config.max_seq_length)?
+        } else {
+            let model_name = config
+                .model_name
+                .as_deref()
+                .context("model_path or model_name required for local backend")?;
+            let cache_dir = directories::BaseDirs::new()
+                .context("Cannot find home directory for the model cache")?
+                .home_dir()
+                .join(".cache/narsil-mcp/models");
+            local::LocalEmbedder::from_pretrained(model_name, &cache_dir, config.max_seq_length)?
+        };
+
+        // The store must match the model, not whatever the config guessed
+        let dimension = embedder.dimension();
+        let backend: Arc<dyn EmbeddingBackend> = Arc::new(embedder);
+        let store = SimpleVectorStore::new(dimension);
+
+        Ok(Self {
+            backend,
+            store,
+            documents: RwLock::new(HashMap::new()),
+            cache: Self::open_cache(&config),
             config,
         })
     }
@@ -942,10 +1557,38 @@ impl NeuralEngine {
         match config.backend.as_str() {
             #[cfg(feature = "neural-onnx")]
             "onnx" => Self::with_onnx(config),
+            #[cfg(feature = "neural-local")]
+            "local" => Self::with_local(config),
+            #[cfg(not(feature = "neural-local"))]
+            "local" => bail!(
+                "Local backend requested but this build lacks the neural-local feature. \
+                 Rebuild with --features neural-local."
+            ),
             _ => Self::with_api(config),
         }
     }
 
+    /// Open the embedding cache configured in `cache_dir`, or fall back
+    /// to an in-memory cache when no directory is set
+    fn open_cache(config: &NeuralConfig) -> EmbeddingCache {
+        match config.cache_dir {
+            Some(ref dir) => {
+                EmbeddingCache::load(&std::path::Path::new(dir).join("embedding-cache.bin"))
+            }
+            None => EmbeddingCache::in_memory(),
+        }
+    }
+
+    /// Cache key for a chunk of content under the configured model
+    fn cache_key(&self, content: &str) -> String {
+        let model = self
+            .config
+            .model_name
+            .as_deref()
+            .unwrap_or(&self.config.backend);
+        EmbeddingCache::key(model, content)
+    }
+
     /// Index a code snippet
     pub fn index_snippet(
         &self,
@@ -956,7 +1599,18 @@ impl NeuralEngine {
         end_line: usize,
         symbol_name: Option<String>,
     ) -> Result<()> {
-        let embedding = self.backend.embed(&content)?;
+        let key = self.cache_key(&content);
+        let embedding = match self.cache.get(&key) {
+            Some(cached) => cached,
+            None => {
+                let embedding = self.backend.embed(&content)?;
+                self.cache.insert(key, embedding.clone());
+                if let Err(e) = self.cache.flush() {
+                    tracing::warn!("Failed to persist embedding cache: {}", e);
+                }
+                embedding
+            }
+        };
         self.store.add(&id, &embedding);
 
         let doc = NeuralDocument {
@@ -974,21 +1628,70 @@ impl NeuralEngine {
 
     /// Index multiple snippets in batch (with chunking to respect API limits)
     pub fn index_batch(&self, items: &[(NeuralDocument,)]) -> Result<()> {
-        const BATCH_SIZE: usize = 96; // Voyage API limit is 128, use 96 for safety
+        // The API client splits further into API_BATCH_SIZE requests; this
+        // just bounds memory and cache-flush granularity per round
+        const BATCH_SIZE: usize = 96;
 
+        let mut embedded_any = false;
         for chunk in items.chunks(BATCH_SIZE) {
-            let contents: Vec<String> = chunk.iter().map(|(doc,)| doc.content.clone()).collect();
-            let embeddings = self.backend.embed_batch(&contents)?;
+            // Serve unchanged chunks from the cache; only cache misses go
+            // to the backend (which may be a paid API)
+            let mut embeddings: Vec<Option<Vec<f32>>> = Vec::with_capacity(chunk.len());
+            let mut missing: Vec<usize> = Vec::new();
+            for (i, (doc,)) in chunk.iter().enumerate() {
+                let cached = self.cache.get(&self.cache_key(&doc.content));
+                if cached.is_none() {
+                    missing.push(i);
+                }
+                embeddings.push(cached);
+            }
+
+            if !missing.is_empty() {
+                let contents: Vec<String> = missing
+                    .iter()
+                    .map(|&i| chunk[i].0.content.clone())
+                    .collect();
+                let fresh = self.backend.embed_batch(&contents)?;
+                for (&i, embedding) in missing.iter().zip(fresh) {
+                    self.cache
+                        .insert(self.cache_key(&chunk[i].0.content), embedding.clone());
+                    embeddings[i] = Some(embedding);
+                }
+                embedded_any = true;
+            }
 
             for ((doc,), embedding) in chunk.iter().zip(embeddings.iter()) {
+                // Every slot was filled from the cache or the backend above
+                let embedding = embedding.as_ref().expect("embedding resolved");
                 self.store.add(&doc.id, embedding);
                 self.documents.write().insert(doc.id.clone(), doc.clone());
             }
         }
 
+        if embedded_any {
+            if let Err(e) = self.cache.flush() {
+                tracing::warn!("Failed to persist embedding cache: {}", e);
+            }
+        }
+
         Ok(())
     }
 
+    /// Re-point indexed documents to a new file path after a rename. The
+    /// stored embeddings are reused as-is; nothing is re-embedded. Returns
+    /// the number of documents moved.
+    pub fn rename_file(&self, old_path: &str, new_path: &str) -> usize {
+        let mut documents = self.documents.write();
+        let mut moved = 0;
+        for doc in documents.values_mut() {
+            if doc.file_path == old_path {
+                doc.file_path = new_path.to_string();
+                moved += 1;
+            }
+        }
+        moved
+    }
+
     /// Search for similar code
     pub fn search(&self, query: &str, k: usize) -> Result<Vec<NeuralSearchResult>> {
         let query_embedding = self.backend.embed(query)?;
@@ -1040,6 +1743,16 @@ impl NeuralEngine {
         }
     }
 
+    /// Get statistics about the embedding cache
+    pub fn cache_stats(&self) -> EmbeddingCacheStats {
+        self.cache.stats()
+    }
+
+    /// Get cumulative API usage, if the backend calls an external service
+    pub fn api_usage(&self) -> Option<ApiUsageStats> {
+        self.backend.usage()
+    }
+
     /// Clear all indexed data
     pub fn clear(&self) {
         self.store.clear();
@@ -1092,6 +1805,86 @@ mod tests {
         assert_eq!(config.dimension, 1536);
     }
 
+    #[test]
+    fn test_embedding_cache_key_includes_model() {
+        let a = EmbeddingCache::key("voyage-code-2", "fn main() {}");
+        let b = EmbeddingCache::key("text-embedding-3-small", "fn main() {}");
+        let c = EmbeddingCache::key("voyage-code-2", "fn main() {}");
+
+        assert_ne!(a, b, "Different models must not share cache entries");
+        assert_eq!(a, c, "Same model and content must produce the same key");
+        assert_ne!(a, EmbeddingCache::key("voyage-code-2", "fn other() {}"));
+    }
+
+    #[test]
+    fn test_embedding_cache_hit_miss_counting() {
+        let cache = EmbeddingCache::in_memory();
+        let key = EmbeddingCache::key("test-model", "some content");
+
+        assert!(cache.get(&key).is_none());
+        cache.insert(key.clone(), vec![1.0, 2.0, 3.0]);
+        assert_eq!(cache.get(&key), Some(vec![1.0, 2.0, 3.0]));
+
+        let stats = cache.stats();
+        assert_eq!(stats.entries, 1);
+        assert_eq!(stats.hits, 1);
+        assert_eq!(stats.misses, 1);
+    }
+
+    #[test]
+    fn test_embedding_cache_persists_across_loads() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("embedding-cache.bin");
+        let key = EmbeddingCache::key("test-model", "cached content");
+
+        let cache = EmbeddingCache::load(&path);
+        cache.insert(key.clone(), vec![0.5, -0.5]);
+        cache.flush().unwrap();
+
+        let reloaded = EmbeddingCache::load(&path);
+        assert_eq!(reloaded.get(&key), Some(vec![0.5, -0.5]));
+        // Hit/miss counters are per-session, not persisted
+        assert_eq!(reloaded.stats().hits, 1);
+    }
+
+    #[test]
+    fn test_embedding_cache_ignores_corrupt_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("embedding-cache.bin");
+        std::fs::write(&path, b"not a bincode cache").unwrap();
+
+        let cache = EmbeddingCache::load(&path);
+        assert_eq!(cache.stats().entries, 0);
+    }
+
+    #[test]
+    #[cfg(not(feature = "neural-local"))]
+    fn test_local_backend_requires_feature() {
+        let config = NeuralConfig {
+            backend: "local".to_string(),
+            ..Default::default()
+        };
+        let err = match NeuralEngine::new(config) {
+            Ok(_) => panic!("Expected local backend to be unavailable"),
+            Err(e) => e,
+        };
+        assert!(err.to_string().contains("neural-local"));
+    }
+
+    #[test]
+    #[cfg(feature = "neural-local")]
+    fn test_local_embedder_missing_model_dir() {
+        let err = match local::LocalEmbedder::from_pretrained(
+            "acme/does-not-exist",
+            Path::new("/nonexistent-model-cache"),
+            512,
+        ) {
+            Ok(_) => panic!("Expected missing model to fail"),
+            Err(e) => e,
+        };
+        assert!(err.to_string().contains("Model not found"));
+    }
+
     #[test]
     fn test_api_embedder_creation() {
         // Test that embedders can be created (won't actually call APIs)
@@ -1573,12 +2366,6 @@ mod tests {
             let result = embedder.embed_batch(&[]);
             assert!(result.is_err());
             assert!(result.unwrap_err().to_string().contains("empty batch"));
-
-            // Too large batch
-            let large_batch: Vec<String> = (0..200).map(|i| format!("text {}", i)).collect();
-            let result = embedder.embed_batch(&large_batch);
-            assert!(result.is_err());
-            assert!(result.unwrap_err().to_string().contains("exceeds maximum"));
         }
 
         #[test]
@@ -1621,14 +2408,36 @@ mod tests {
             // Note: This will fail at HTTP level (no actual server), but should pass validation
             let _result = embedder.embed_batch(&texts);
 
-            // Maximum valid batch size
-            let max_batch: Vec<String> = (0..MAX_EMBEDDING_BATCH_SIZE)
-                .map(|i| format!("text {}", i))
-                .collect();
+            // A full API-sized batch also passes validation
+            let max_batch: Vec<String> =
+                (0..API_BATCH_SIZE).map(|i| format!("text {}", i)).collect();
             // Note: This will fail at HTTP level, but should pass validation
             let _result = embedder.embed_batch(&max_batch);
         }
 
+        #[test]
+        fn test_failed_requests_are_retried_and_counted() {
+            // Connection refused locally, so every attempt fails fast
+            let embedder = ApiEmbedder::custom(
+                "http://127.0.0.1:9/embeddings",
+                "test-model",
+                Some("test-key"),
+                768,
+            );
+
+            let result = embedder.embed_batch(&["test text".to_string()]);
+            assert!(result.is_err());
+            assert!(result
+                .unwrap_err()
+                .to_string()
+                .contains("failed after 3 attempts"));
+
+            let usage = embedder.usage().expect("API backend reports usage");
+            assert_eq!(usage.requests, MAX_REQUEST_ATTEMPTS);
+            assert_eq!(usage.retries, MAX_REQUEST_ATTEMPTS - 1);
+            assert_eq!(usage.texts_embedded, 0);
+        }
+
         #[test]
         fn test_http_client_has_timeout() {
             // Create an embedder and verify the client has timeout configured
@@ -2,7 +2,7 @@ use anyhow::{anyhow, Result};
 use std::path::Path;
 use std::sync::{Arc, OnceLock};
 use streaming_iterator::StreamingIterator;
-use tree_sitter::{Language, Parser, Query, QueryCursor, Tree};
+use tree_sitter::{Language, Node, Parser, Query, QueryCursor, Tree};
 
 use crate::symbols::{Symbol, SymbolKind};
 
@@ -85,6 +85,7 @@ impl LanguageParser {
                     (const_item name: (identifier) @const.name) @const.def
                     (static_item name: (identifier) @static.name) @static.def
                     (mod_item name: (identifier) @mod.name) @mod.def
+                    (macro_definition name: (identifier) @macro.name) @macro.def
                 "#,
             },
             // Python
@@ -304,7 +305,14 @@ impl LanguageParser {
             .parse(content, None)
             .ok_or_else(|| anyhow!("Failed to parse file"))?;
 
-        let symbols = self.extract_symbols(&tree, content, lazy_config)?;
+        let mut symbols = self.extract_symbols(&tree, content, lazy_config)?;
+
+        // Rust macro-generated items are invisible to the symbol query:
+        // reclassify proc-macro entry points and synthesize Implementation
+        // symbols for derive attributes
+        if lazy_config.config.name == "rust" {
+            annotate_rust_macro_symbols(&tree, content, &mut symbols);
+        }
 
         Ok(ParsedFile {
             path: path.to_string_lossy().to_string(),
@@ -354,6 +362,7 @@ impl LanguageParser {
             let mut start_line = 0;
             let mut end_line = 0;
             let mut signature: Option<String> = None;
+            let mut def_node: Option<Node> = None;
 
             for capture in match_.captures {
                 let capture_name = query.capture_names()[capture.index as usize];
@@ -366,6 +375,7 @@ impl LanguageParser {
                 } else if capture_name.ends_with(".def") {
                     start_line = node.start_position().row + 1;
                     end_line = node.end_position().row + 1;
+                    def_node = Some(node);
 
                     // Extract first line as signature
                     let first_line_end = text.find('\n').unwrap_or(text.len());
@@ -374,6 +384,7 @@ impl LanguageParser {
             }
 
             if let (Some(name), Some(kind)) = (name, kind) {
+                let qualified_name = def_node.and_then(|n| qualify_name(&n, &name, source));
                 symbols.push(Symbol {
                     name,
                     kind,
@@ -381,7 +392,7 @@ impl LanguageParser {
                     start_line,
                     end_line,
                     signature,
-                    qualified_name: None,
+                    qualified_name,
                     doc_comment: None,
                 });
             }
@@ -400,6 +411,55 @@ impl LanguageParser {
     }
 }
 
+/// Build a fully-qualified name (e.g. `config::ConfigLoader::load`) by
+/// walking the containers — modules, classes, impl blocks — that enclose a
+/// definition. Returns `None` for top-level symbols with no container.
+fn qualify_name(node: &Node, name: &str, source: &str) -> Option<String> {
+    let mut parts: Vec<String> = Vec::new();
+    let mut current = node.parent();
+
+    while let Some(n) = current {
+        if let Some(name_node) = container_name_node(&n) {
+            if let Ok(text) = name_node.utf8_text(source.as_bytes()) {
+                parts.push(text.to_string());
+            }
+        }
+        current = n.parent();
+    }
+
+    if parts.is_empty() {
+        return None;
+    }
+    parts.reverse();
+    parts.push(name.to_string());
+    Some(parts.join("::"))
+}
+
+/// The child node naming a container, if `node` is a kind that scopes the
+/// symbols declared inside it (covers the languages in the symbol queries)
+fn container_name_node<'a>(node: &Node<'a>) -> Option<Node<'a>> {
+    match node.kind() {
+        // Rust: impl blocks qualify by the implemented type
+        "impl_item" => node.child_by_field_name("type"),
+        "mod_item" | "trait_item" => node.child_by_field_name("name"),
+        // Python, JS/TS, Java, C#, C++, PHP, Ruby, Swift
+        "class_definition"
+        | "class_declaration"
+        | "class_specifier"
+        | "class"
+        | "module"
+        | "object_declaration"
+        | "interface_declaration"
+        | "enum_declaration"
+        | "struct_declaration"
+        | "trait_declaration"
+        | "protocol_declaration"
+        | "namespace_definition"
+        | "namespace_declaration" => node.child_by_field_name("name"),
+        _ => None,
+    }
+}
+
 fn parse_symbol_kind(capture_name: &str) -> SymbolKind {
     let prefix = capture_name.split('.').next().unwrap_or("");
     match prefix {
@@ -412,6 +472,7 @@ fn parse_symbol_kind(capture_name: &str) -> SymbolKind {
         "trait" => SymbolKind::Trait,
         "type" => SymbolKind::TypeAlias,
         "const" | "static" => SymbolKind::Constant,
+        "macro" => SymbolKind::Macro,
         "mod" | "module" | "namespace" => SymbolKind::Module,
         "impl" => SymbolKind::Implementation,
         "var" | "arrow" => SymbolKind::Variable,
@@ -419,6 +480,142 @@ fn parse_symbol_kind(capture_name: &str) -> SymbolKind {
     }
 }
 
+/// Record Rust macro-generated items the symbol query cannot see
+///
+/// Functions annotated with `#[proc_macro]`, `#[proc_macro_derive]`, or
+/// `#[proc_macro_attribute]` are reclassified as [`SymbolKind::Macro`]
+/// (derive macros also get a symbol under their derive name), and each
+/// trait in a `#[derive(...)]` attribute becomes an Implementation symbol
+/// (e.g. `Serialize for Config`) so the generated impls are findable.
+fn annotate_rust_macro_symbols(tree: &Tree, source: &str, symbols: &mut Vec<Symbol>) {
+    let mut extra = Vec::new();
+    visit_rust_attributes(&tree.root_node(), source, symbols, &mut extra);
+    symbols.extend(extra);
+}
+
+fn visit_rust_attributes(
+    node: &Node,
+    source: &str,
+    symbols: &mut [Symbol],
+    extra: &mut Vec<Symbol>,
+) {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.kind() == "attribute_item" {
+            process_rust_attribute(&child, source, symbols, extra);
+        }
+        // Recurse so items inside modules are covered too
+        visit_rust_attributes(&child, source, symbols, extra);
+    }
+}
+
+fn process_rust_attribute(
+    attr: &Node,
+    source: &str,
+    symbols: &mut [Symbol],
+    extra: &mut Vec<Symbol>,
+) {
+    let text = attr.utf8_text(source.as_bytes()).unwrap_or("");
+    let inner = text.trim_start_matches("#[").trim_end_matches(']').trim();
+    let attr_name = inner.split('(').next().unwrap_or("").trim();
+
+    // The item the attribute applies to: the next sibling that is not
+    // another attribute or a comment
+    let mut item = attr.next_named_sibling();
+    while let Some(n) = item {
+        if matches!(
+            n.kind(),
+            "attribute_item" | "line_comment" | "block_comment"
+        ) {
+            item = n.next_named_sibling();
+        } else {
+            break;
+        }
+    }
+    let Some(item) = item else { return };
+
+    let item_name = item
+        .child_by_field_name("name")
+        .and_then(|n| n.utf8_text(source.as_bytes()).ok())
+        .unwrap_or("");
+    if item_name.is_empty() {
+        return;
+    }
+    let item_line = item.start_position().row + 1;
+
+    match attr_name {
+        "derive" => {
+            if !matches!(item.kind(), "struct_item" | "enum_item" | "union_item") {
+                return;
+            }
+            let item_kw = item.kind().trim_end_matches("_item");
+            for derived in attribute_arguments(inner) {
+                extra.push(Symbol {
+                    name: derived.clone(),
+                    kind: SymbolKind::Implementation,
+                    file_path: String::new(), // Will be set by caller
+                    start_line: item_line,
+                    end_line: item.end_position().row + 1,
+                    signature: Some(format!("#[derive({})] {} {}", derived, item_kw, item_name)),
+                    qualified_name: Some(format!("{} for {}", derived, item_name)),
+                    doc_comment: None,
+                });
+            }
+        }
+        "proc_macro" | "proc_macro_attribute" | "proc_macro_derive" => {
+            if item.kind() != "function_item" {
+                return;
+            }
+            // The entry-point function defines a macro, not a callable
+            let signature = symbols
+                .iter_mut()
+                .find(|s| s.kind == SymbolKind::Function && s.start_line == item_line)
+                .map(|s| {
+                    s.kind = SymbolKind::Macro;
+                    s.signature.clone()
+                })
+                .unwrap_or(None);
+
+            // A derive macro is invoked by its derive name, which usually
+            // differs from the function name
+            if attr_name == "proc_macro_derive" {
+                if let Some(derive_name) = attribute_arguments(inner).into_iter().next() {
+                    if derive_name != item_name {
+                        extra.push(Symbol {
+                            name: derive_name.clone(),
+                            kind: SymbolKind::Macro,
+                            file_path: String::new(), // Will be set by caller
+                            start_line: item_line,
+                            end_line: item.end_position().row + 1,
+                            signature,
+                            qualified_name: Some(format!(
+                                "derive({}) via {}",
+                                derive_name, item_name
+                            )),
+                            doc_comment: None,
+                        });
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Extract the comma-separated arguments of an attribute like
+/// `derive(Debug, serde::Serialize)`, reduced to their final path segment
+fn attribute_arguments(inner: &str) -> Vec<String> {
+    let Some(open) = inner.find('(') else {
+        return Vec::new();
+    };
+    let args = inner[open + 1..].trim_end_matches(')');
+    args.split(',')
+        .map(|a| a.trim())
+        .filter(|a| !a.is_empty())
+        .map(|a| a.rsplit("::").next().unwrap_or(a).to_string())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -449,6 +646,154 @@ mod tests {
         assert!(names.contains(&&"my_function".to_string()));
     }
 
+    #[test]
+    fn test_qualified_names_rust() {
+        let parser = LanguageParser::new().unwrap();
+        let content = r#"
+            mod config {
+                pub struct ConfigLoader;
+
+                impl ConfigLoader {
+                    pub fn load(&self) {}
+                }
+            }
+
+            pub fn top_level() {}
+        "#;
+
+        let parsed = parser.parse_file(Path::new("test.rs"), content).unwrap();
+
+        let load = parsed.symbols.iter().find(|s| s.name == "load").unwrap();
+        assert_eq!(
+            load.qualified_name.as_deref(),
+            Some("config::ConfigLoader::load")
+        );
+
+        let loader = parsed
+            .symbols
+            .iter()
+            .find(|s| s.name == "ConfigLoader" && s.kind == SymbolKind::Struct)
+            .unwrap();
+        assert_eq!(
+            loader.qualified_name.as_deref(),
+            Some("config::ConfigLoader")
+        );
+
+        // Top-level symbols have no container and no qualified name
+        let top = parsed
+            .symbols
+            .iter()
+            .find(|s| s.name == "top_level")
+            .unwrap();
+        assert_eq!(top.qualified_name, None);
+    }
+
+    #[test]
+    fn test_qualified_names_python() {
+        let parser = LanguageParser::new().unwrap();
+        let content = r#"
+class Repository:
+    def save(self):
+        pass
+
+def helper():
+    pass
+"#;
+
+        let parsed = parser.parse_file(Path::new("test.py"), content).unwrap();
+
+        let save = parsed.symbols.iter().find(|s| s.name == "save").unwrap();
+        assert_eq!(save.qualified_name.as_deref(), Some("Repository::save"));
+
+        let helper = parsed.symbols.iter().find(|s| s.name == "helper").unwrap();
+        assert_eq!(helper.qualified_name, None);
+    }
+
+    #[test]
+    fn test_parse_rust_macro_rules() {
+        let parser = LanguageParser::new().unwrap();
+        let content = r#"
+            macro_rules! my_macro {
+                () => {};
+            }
+        "#;
+
+        let parsed = parser.parse_file(Path::new("test.rs"), content).unwrap();
+        let macro_sym = parsed
+            .symbols
+            .iter()
+            .find(|s| s.name == "my_macro")
+            .expect("macro_rules! definition should be a symbol");
+        assert_eq!(macro_sym.kind, SymbolKind::Macro);
+    }
+
+    #[test]
+    fn test_parse_rust_proc_macro_derive() {
+        let parser = LanguageParser::new().unwrap();
+        let content = r#"
+            #[proc_macro_derive(MyTrait)]
+            pub fn derive_my_trait(input: TokenStream) -> TokenStream {
+                input
+            }
+        "#;
+
+        let parsed = parser.parse_file(Path::new("test.rs"), content).unwrap();
+
+        // The entry-point function is a macro, not a regular function
+        let entry = parsed
+            .symbols
+            .iter()
+            .find(|s| s.name == "derive_my_trait")
+            .unwrap();
+        assert_eq!(entry.kind, SymbolKind::Macro);
+
+        // The derive name is also findable
+        let derive = parsed
+            .symbols
+            .iter()
+            .find(|s| s.name == "MyTrait")
+            .expect("derive name should be a symbol");
+        assert_eq!(derive.kind, SymbolKind::Macro);
+        assert_eq!(
+            derive.qualified_name.as_deref(),
+            Some("derive(MyTrait) via derive_my_trait")
+        );
+    }
+
+    #[test]
+    fn test_parse_rust_derive_attributes() {
+        let parser = LanguageParser::new().unwrap();
+        let content = r#"
+            #[derive(Debug, Clone, serde::Serialize)]
+            pub struct Config {
+                name: String,
+            }
+        "#;
+
+        let parsed = parser.parse_file(Path::new("test.rs"), content).unwrap();
+
+        let impls: Vec<_> = parsed
+            .symbols
+            .iter()
+            .filter(|s| s.kind == SymbolKind::Implementation)
+            .collect();
+        assert_eq!(impls.len(), 3);
+
+        let serialize = impls.iter().find(|s| s.name == "Serialize").unwrap();
+        assert_eq!(
+            serialize.qualified_name.as_deref(),
+            Some("Serialize for Config")
+        );
+        assert_eq!(
+            serialize.signature.as_deref(),
+            Some("#[derive(Serialize)] struct Config")
+        );
+
+        // The struct itself is still extracted normally
+        let config = parsed.symbols.iter().find(|s| s.name == "Config").unwrap();
+        assert_eq!(config.kind, SymbolKind::Struct);
+    }
+
     #[test]
     fn test_parse_python() {
         let parser = LanguageParser::new().unwrap();
@@ -13,7 +13,7 @@ use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{Duration, SystemTime};
@@ -40,10 +40,38 @@ pub struct PersistedIndex {
     pub updated_at: u64,
     pub repo_root: PathBuf,
     pub files: HashMap<PathBuf, FileMetadata>,
+    /// Files changed since the last save (not persisted; drives incremental saves)
+    #[serde(skip)]
+    dirty: HashSet<PathBuf>,
+    /// Files removed since the last save (not persisted)
+    #[serde(skip)]
+    removed: HashSet<PathBuf>,
 }
 
+/// A single change record in the incremental save journal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum JournalEntry {
+    Update(FileMetadata),
+    Remove(PathBuf),
+}
+
+/// Magic header identifying a zstd-compressed index file. Files without it
+/// are assumed to be raw bincode from older versions and load unchanged.
+const ZSTD_MAGIC: &[u8; 4] = b"NMZ1";
+
+/// Compression level for persisted indices. Level 3 is zstd's default and
+/// keeps save times negligible while still shrinking symbol-heavy indices
+/// several times over.
+const ZSTD_LEVEL: i32 = 3;
+
 impl PersistedIndex {
-    const CURRENT_VERSION: u32 = 1;
+    /// Current on-disk format version. Bump this whenever the serialized
+    /// layout changes and add a matching arm to `migrate_from`.
+    pub const CURRENT_VERSION: u32 = 1;
+
+    /// Oldest format version that can still be migrated forward. Anything
+    /// older forces a reindex.
+    pub const MIN_SUPPORTED_VERSION: u32 = 1;
 
     pub fn new(repo_root: PathBuf) -> Self {
         let now = SystemTime::now()
@@ -57,32 +85,76 @@ impl PersistedIndex {
             updated_at: now,
             repo_root,
             files: HashMap::new(),
+            dirty: HashSet::new(),
+            removed: HashSet::new(),
         }
     }
 
-    /// Load index from disk
+    /// Load index from disk (transparently decompresses zstd-framed files)
     pub fn load(path: &Path) -> Result<Self> {
-        let data = std::fs::read(path).context("Failed to read index file")?;
-        let index: Self = bincode::deserialize(&data).context("Failed to deserialize index")?;
-
-        if index.version != Self::CURRENT_VERSION {
-            return Err(anyhow::anyhow!(
-                "Index version mismatch: {} != {}",
-                index.version,
+        let raw = std::fs::read(path).context("Failed to read index file")?;
+        let data = if raw.starts_with(ZSTD_MAGIC) {
+            zstd::decode_all(&raw[ZSTD_MAGIC.len()..]).context("Failed to decompress index")?
+        } else {
+            // Pre-compression format: raw bincode
+            raw
+        };
+        // The version is the first field of the struct, so it can be peeked
+        // before committing to a layout (bincode uses fixed-width LE ints)
+        let version = peek_version(&data)
+            .ok_or_else(|| anyhow::anyhow!("Index file too short to contain a version"))?;
+
+        match version {
+            Self::CURRENT_VERSION => {
+                bincode::deserialize(&data).context("Failed to deserialize index")
+            }
+            v if v > Self::CURRENT_VERSION => Err(anyhow::anyhow!(
+                "Index was written by a newer narsil-mcp (format v{} > v{}); \
+                 upgrade narsil-mcp or delete the cached index",
+                v,
                 Self::CURRENT_VERSION
-            ));
+            )),
+            v if v < Self::MIN_SUPPORTED_VERSION => Err(anyhow::anyhow!(
+                "Index format v{} is too old to migrate (minimum supported: v{}); \
+                 a full reindex is required",
+                v,
+                Self::MIN_SUPPORTED_VERSION
+            )),
+            v => Self::migrate_from(v, &data),
         }
+    }
 
-        Ok(index)
+    /// Migrate an index serialized with an older (but still supported)
+    /// format version up to `CURRENT_VERSION`.
+    ///
+    /// Each arm deserializes the legacy layout for that version, converts it,
+    /// and hands off to the next step until the index is current.
+    fn migrate_from(version: u32, _data: &[u8]) -> Result<Self> {
+        #[allow(clippy::match_single_binding)]
+        match version {
+            // Future migrations slot in here, e.g.:
+            // 1 => { let legacy: PersistedIndexV1 = bincode::deserialize(data)?; ... }
+            v => Err(anyhow::anyhow!(
+                "No migration path from index format v{} to v{}",
+                v,
+                Self::CURRENT_VERSION
+            )),
+        }
     }
 
-    /// Save index to disk
+    /// Save index to disk, zstd-compressed with a magic header
     pub fn save(&self, path: &Path) -> Result<()> {
         let data = bincode::serialize(self).context("Failed to serialize index")?;
+        let compressed =
+            zstd::encode_all(data.as_slice(), ZSTD_LEVEL).context("Failed to compress index")?;
+
+        let mut framed = Vec::with_capacity(ZSTD_MAGIC.len() + compressed.len());
+        framed.extend_from_slice(ZSTD_MAGIC);
+        framed.extend_from_slice(&compressed);
 
         // Write to temp file then rename for atomicity
         let temp_path = path.with_extension("tmp");
-        std::fs::write(&temp_path, &data).context("Failed to write temp index")?;
+        std::fs::write(&temp_path, &framed).context("Failed to write temp index")?;
         std::fs::rename(&temp_path, path).context("Failed to rename index file")?;
 
         Ok(())
@@ -118,6 +190,8 @@ impl PersistedIndex {
         let metadata = std::fs::metadata(&path)?;
         let hash = hash_file(&path)?;
 
+        self.dirty.insert(path.clone());
+        self.removed.remove(&path);
         self.files.insert(
             path.clone(),
             FileMetadata {
@@ -144,6 +218,8 @@ impl PersistedIndex {
     #[allow(dead_code)]
     pub fn remove_file(&mut self, path: &Path) {
         self.files.remove(path);
+        self.dirty.remove(path);
+        self.removed.insert(path.to_path_buf());
         self.updated_at = SystemTime::now()
             .duration_since(SystemTime::UNIX_EPOCH)
             .unwrap()
@@ -161,6 +237,54 @@ impl PersistedIndex {
     pub fn file_symbols(&self, path: &Path) -> Option<&[Symbol]> {
         self.files.get(path).map(|f| f.symbols.as_slice())
     }
+
+    /// Whether there are unsaved changes
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty() || !self.removed.is_empty()
+    }
+
+    /// Number of files changed or removed since the last save
+    pub fn dirty_count(&self) -> usize {
+        self.dirty.len() + self.removed.len()
+    }
+
+    /// Drain pending changes as journal entries, clearing the dirty sets
+    fn take_journal_entries(&mut self) -> Vec<JournalEntry> {
+        let mut entries = Vec::with_capacity(self.dirty_count());
+        for path in self.dirty.drain() {
+            if let Some(meta) = self.files.get(&path) {
+                entries.push(JournalEntry::Update(meta.clone()));
+            }
+        }
+        for path in self.removed.drain() {
+            entries.push(JournalEntry::Remove(path));
+        }
+        entries
+    }
+
+    /// Mark all pending changes as saved (after a full rewrite)
+    fn clear_dirty(&mut self) {
+        self.dirty.clear();
+        self.removed.clear();
+    }
+
+    /// Apply a journal entry (used when replaying the journal on load)
+    fn apply_journal_entry(&mut self, entry: JournalEntry) {
+        match entry {
+            JournalEntry::Update(meta) => {
+                self.files.insert(meta.path.clone(), meta);
+            }
+            JournalEntry::Remove(path) => {
+                self.files.remove(&path);
+            }
+        }
+    }
+}
+
+/// Read the format version from the first field of a serialized index
+fn peek_version(data: &[u8]) -> Option<u32> {
+    data.get(..4)
+        .map(|b| u32::from_le_bytes(b.try_into().unwrap()))
 }
 
 /// Compute SHA256 hash of file content
@@ -171,15 +295,109 @@ fn hash_file(path: &Path) -> Result<String> {
     Ok(format!("{:x}", hasher.finalize()))
 }
 
+/// Append journal entries as length-prefixed bincode frames
+fn append_journal(path: &Path, entries: &[JournalEntry]) -> Result<()> {
+    use std::io::Write;
+
+    let mut buf = Vec::new();
+    for entry in entries {
+        let frame = bincode::serialize(entry).context("Failed to serialize journal entry")?;
+        buf.extend_from_slice(&(frame.len() as u32).to_le_bytes());
+        buf.extend_from_slice(&frame);
+    }
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .context("Failed to open journal file")?;
+    file.write_all(&buf).context("Failed to append journal")?;
+    Ok(())
+}
+
+/// Replay a journal onto a loaded index, returning the number of entries
+/// applied. A truncated tail (e.g. from a crash mid-append) is tolerated:
+/// complete frames before it are applied and the rest is ignored.
+fn replay_journal(path: &Path, index: &mut PersistedIndex) -> usize {
+    let data = match std::fs::read(path) {
+        Ok(data) => data,
+        Err(_) => return 0,
+    };
+
+    let mut applied = 0;
+    let mut offset = 0;
+    while offset + 4 <= data.len() {
+        let len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+        offset += 4;
+        if offset + len > data.len() {
+            warn!("Journal {:?} has a truncated tail; ignoring it", path);
+            break;
+        }
+        match bincode::deserialize::<JournalEntry>(&data[offset..offset + len]) {
+            Ok(entry) => {
+                index.apply_journal_entry(entry);
+                applied += 1;
+            }
+            Err(e) => {
+                warn!("Skipping corrupt journal entry in {:?}: {}", path, e);
+            }
+        }
+        offset += len;
+    }
+
+    applied
+}
+
 /// Index storage manager
+///
+/// Multiple narsil-mcp instances (e.g. one per editor window) can point at
+/// the same cache directory. The first instance takes a writer lease; later
+/// ones become read-only followers that can load indices but skip saves, so
+/// concurrent instances never clobber each other's writes.
 pub struct IndexStore {
     index_dir: PathBuf,
+    read_only: bool,
+}
+
+/// Compact (rewrite the full index and truncate the journal) once the journal
+/// grows past this size; below it, incremental saves only append deltas.
+const JOURNAL_COMPACT_BYTES: u64 = 1024 * 1024;
+
+/// Name of the writer lease file inside the cache directory
+const WRITER_LOCK_FILE: &str = "writer.lock";
+
+/// How long a lease from a process we cannot health-check stays valid.
+/// On Linux liveness is checked via /proc instead.
+const LEASE_TTL: Duration = Duration::from_secs(300);
+
+/// Contents of the writer lease file
+#[derive(Debug, Serialize, Deserialize)]
+struct WriterLease {
+    pid: u32,
+    acquired_at: u64,
 }
 
 impl IndexStore {
     pub fn new(index_dir: PathBuf) -> Result<Self> {
         std::fs::create_dir_all(&index_dir)?;
-        Ok(Self { index_dir })
+        let read_only = !try_acquire_writer_lease(&index_dir)?;
+        if read_only {
+            warn!(
+                "Another narsil-mcp instance holds the writer lease for {:?}; \
+                 running as read-only follower (index saves disabled)",
+                index_dir
+            );
+        }
+        Ok(Self {
+            index_dir,
+            read_only,
+        })
+    }
+
+    /// Whether this instance is a read-only follower (another instance holds
+    /// the writer lease)
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
     }
 
     /// Get the index file path for a repository
@@ -192,14 +410,23 @@ impl IndexStore {
         self.index_dir.join(format!("{}.idx", &hash[..16]))
     }
 
+    /// Get the journal file path for a repository (sidecar of the index file)
+    pub fn journal_path(&self, repo_root: &Path) -> PathBuf {
+        self.index_path(repo_root).with_extension("idx.journal")
+    }
+
     /// Load or create index for a repository
     pub fn load_or_create(&self, repo_root: &Path) -> Result<PersistedIndex> {
         let index_path = self.index_path(repo_root);
 
         if index_path.exists() {
             match PersistedIndex::load(&index_path) {
-                Ok(index) => {
+                Ok(mut index) => {
                     info!("Loaded existing index from {:?}", index_path);
+                    let replayed = replay_journal(&self.journal_path(repo_root), &mut index);
+                    if replayed > 0 {
+                        debug!("Replayed {} journal entries", replayed);
+                    }
                     return Ok(index);
                 }
                 Err(e) => {
@@ -212,14 +439,130 @@ impl IndexStore {
         Ok(PersistedIndex::new(repo_root.to_path_buf()))
     }
 
-    /// Save index for a repository
-    pub fn save(&self, index: &PersistedIndex) -> Result<()> {
+    /// Save index for a repository (full rewrite; truncates the journal)
+    pub fn save(&self, index: &mut PersistedIndex) -> Result<()> {
+        if self.read_only {
+            debug!("Read-only follower; skipping index save");
+            return Ok(());
+        }
+
         let index_path = self.index_path(&index.repo_root);
         index.save(&index_path)?;
+        index.clear_dirty();
+
+        let journal_path = self.journal_path(&index.repo_root);
+        if journal_path.exists() {
+            let _ = std::fs::remove_file(&journal_path);
+        }
+
         info!("Saved index to {:?}", index_path);
         Ok(())
     }
 
+    /// Save only the changes since the last save, appending to the journal.
+    ///
+    /// Falls back to a full rewrite (compaction) when the journal has grown
+    /// past `JOURNAL_COMPACT_BYTES`, or when no base index file exists yet.
+    pub fn save_incremental(&self, index: &mut PersistedIndex) -> Result<()> {
+        if self.read_only {
+            debug!("Read-only follower; skipping incremental index save");
+            return Ok(());
+        }
+        if !index.is_dirty() {
+            return Ok(());
+        }
+
+        let index_path = self.index_path(&index.repo_root);
+        let journal_path = self.journal_path(&index.repo_root);
+
+        let journal_size = std::fs::metadata(&journal_path)
+            .map(|m| m.len())
+            .unwrap_or(0);
+        if !index_path.exists() || journal_size >= JOURNAL_COMPACT_BYTES {
+            return self.save(index);
+        }
+
+        let entries = index.take_journal_entries();
+        let count = entries.len();
+        append_journal(&journal_path, &entries)?;
+        debug!("Appended {} entries to journal {:?}", count, journal_path);
+        Ok(())
+    }
+
+    /// Verify all cached indices and produce a human-readable report.
+    ///
+    /// Used by `--check-index`: loads each index (exercising decompression,
+    /// version checks and journal replay), and flags stale or missing files
+    /// without modifying anything on disk.
+    pub fn check(&self) -> Result<String> {
+        let mut output = String::from("# Index Check\n\n");
+        let mut checked = 0;
+        let mut problems = 0;
+
+        let mut entries: Vec<PathBuf> = std::fs::read_dir(&self.index_dir)?
+            .filter_map(|e| e.ok().map(|e| e.path()))
+            .filter(|p| p.extension().map(|e| e == "idx").unwrap_or(false))
+            .collect();
+        entries.sort();
+
+        for path in entries {
+            checked += 1;
+            match PersistedIndex::load(&path) {
+                Ok(mut index) => {
+                    let replayed = replay_journal(&self.journal_path(&index.repo_root), &mut index);
+
+                    let mut missing = 0;
+                    let mut stale = 0;
+                    for file in index.files.keys() {
+                        if !file.exists() {
+                            missing += 1;
+                        } else if index.needs_reindex(file).unwrap_or(true) {
+                            stale += 1;
+                        }
+                    }
+
+                    output.push_str(&format!(
+                        "## {}\n\n- Index file: {}\n- Format version: {}\n- Files: {}\n",
+                        index.repo_root.display(),
+                        path.display(),
+                        index.version,
+                        index.files.len()
+                    ));
+                    if replayed > 0 {
+                        output.push_str(&format!("- Journal entries pending: {}\n", replayed));
+                    }
+                    if missing > 0 {
+                        output.push_str(&format!("- ⚠️ Missing files: {}\n", missing));
+                        problems += 1;
+                    }
+                    if stale > 0 {
+                        output.push_str(&format!("- Stale files (need reindex): {}\n", stale));
+                    }
+                    output.push('\n');
+                }
+                Err(e) => {
+                    output.push_str(&format!(
+                        "## {}\n\n- ❌ Unreadable: {}\n\n",
+                        path.display(),
+                        e
+                    ));
+                    problems += 1;
+                }
+            }
+        }
+
+        if checked == 0 {
+            output.push_str("No cached indices found.\n");
+        } else {
+            output.push_str(&format!(
+                "Checked {} index(es), {} problem(s) found.\n",
+                checked, problems
+            ));
+        }
+
+        Ok(output)
+    }
+
     /// List all cached repositories
     pub fn list_cached(&self) -> Result<Vec<PathBuf>> {
         let mut repos = Vec::new();
@@ -239,6 +582,110 @@ impl IndexStore {
     }
 }
 
+impl Drop for IndexStore {
+    fn drop(&mut self) {
+        if !self.read_only {
+            release_writer_lease(&self.index_dir);
+        }
+    }
+}
+
+/// Try to take the writer lease for a cache directory.
+///
+/// Returns `true` when this process is (or becomes) the writer. A lease held
+/// by a dead process — or, off Linux, one older than `LEASE_TTL` — is treated
+/// as stale and reclaimed.
+fn try_acquire_writer_lease(index_dir: &Path) -> Result<bool> {
+    let lock_path = index_dir.join(WRITER_LOCK_FILE);
+    let pid = std::process::id();
+
+    for _ in 0..2 {
+        match std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&lock_path)
+        {
+            Ok(mut file) => {
+                use std::io::Write;
+                let lease = WriterLease {
+                    pid,
+                    acquired_at: SystemTime::now()
+                        .duration_since(SystemTime::UNIX_EPOCH)
+                        .unwrap()
+                        .as_secs(),
+                };
+                let data = serde_json::to_vec(&lease)?;
+                file.write_all(&data)
+                    .context("Failed to write writer lease")?;
+                debug!("Acquired writer lease at {:?}", lock_path);
+                return Ok(true);
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                let lease: Option<WriterLease> = std::fs::read(&lock_path)
+                    .ok()
+                    .and_then(|data| serde_json::from_slice(&data).ok());
+
+                match lease {
+                    // This process already holds the lease (e.g. a second
+                    // IndexStore over the same directory)
+                    Some(lease) if lease.pid == pid => return Ok(true),
+                    Some(lease) if lease_is_stale(&lease, &lock_path) => {
+                        info!(
+                            "Reclaiming stale writer lease from pid {} at {:?}",
+                            lease.pid, lock_path
+                        );
+                        let _ = std::fs::remove_file(&lock_path);
+                        // Retry acquisition with create_new
+                    }
+                    Some(_) => return Ok(false),
+                    // Unreadable lease file: treat as corrupt and reclaim
+                    None => {
+                        let _ = std::fs::remove_file(&lock_path);
+                    }
+                }
+            }
+            Err(e) => return Err(e).context("Failed to create writer lease"),
+        }
+    }
+
+    // Lost the re-acquisition race to another instance
+    Ok(false)
+}
+
+/// Check whether a writer lease belongs to a process that is no longer running
+fn lease_is_stale(lease: &WriterLease, lock_path: &Path) -> bool {
+    #[cfg(target_os = "linux")]
+    {
+        let _ = lock_path;
+        !Path::new(&format!("/proc/{}", lease.pid)).exists()
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = lease;
+        std::fs::metadata(lock_path)
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|mtime| SystemTime::now().duration_since(mtime).ok())
+            .map(|age| age > LEASE_TTL)
+            .unwrap_or(true)
+    }
+}
+
+/// Release the writer lease if this process holds it
+fn release_writer_lease(index_dir: &Path) {
+    let lock_path = index_dir.join(WRITER_LOCK_FILE);
+    let held_by_us = std::fs::read(&lock_path)
+        .ok()
+        .and_then(|data| serde_json::from_slice::<WriterLease>(&data).ok())
+        .map(|lease| lease.pid == std::process::id())
+        .unwrap_or(false);
+
+    if held_by_us {
+        let _ = std::fs::remove_file(&lock_path);
+        debug!("Released writer lease at {:?}", lock_path);
+    }
+}
+
 /// File watcher for incremental updates (legacy, sync-based polling)
 #[cfg(feature = "native")]
 pub struct FileWatcher {
@@ -371,16 +818,38 @@ impl AsyncFileWatcher {
                     // Receive events from notify
                     Some(result) = notify_rx.recv() => {
                         if let Ok(event) = result {
+                            use notify::event::{ModifyKind, RenameMode};
+
+                            // Backend-paired renames arrive as one event with
+                            // [from, to]; directories pass too so moves of
+                            // whole folders reach the engine
+                            if let EventKind::Modify(ModifyKind::Name(RenameMode::Both)) = event.kind {
+                                if event.paths.len() == 2 {
+                                    let from = event.paths[0].clone();
+                                    let to = event.paths[1].clone();
+                                    if is_source_file(&to) || to.is_dir() {
+                                        debounce_buffer.insert(to.clone(), FileChange { path: to, change_type: ChangeType::Renamed { from } });
+                                    }
+                                    continue;
+                                }
+                            }
+
                             for path in event.paths {
                                 let change_type = match event.kind {
                                     EventKind::Create(_) => ChangeType::Created,
+                                    // Unpaired rename halves; the flush-time
+                                    // coalescing below re-pairs them
+                                    EventKind::Modify(ModifyKind::Name(RenameMode::From)) => ChangeType::Deleted,
+                                    EventKind::Modify(ModifyKind::Name(RenameMode::To)) => ChangeType::Created,
                                     EventKind::Modify(_) => ChangeType::Modified,
                                     EventKind::Remove(_) => ChangeType::Deleted,
                                     _ => continue,
                                 };
 
-                                // Filter to source files
-                                if is_source_file(&path) {
+                                // Filter to source files; .git/HEAD passes
+                                // too so the engine can detect branch
+                                // switches and diff-refresh
+                                if is_source_file(&path) || path.ends_with(".git/HEAD") {
                                     // Add to debounce buffer (overwrites previous events for same file)
                                     let path_buf = path.to_path_buf();
                                     debounce_buffer.insert(path_buf.clone(), FileChange { path: path_buf, change_type });
@@ -391,7 +860,8 @@ impl AsyncFileWatcher {
                     // Debounce timer tick - flush buffered changes
                     _ = debounce_timer.tick() => {
                         if !debounce_buffer.is_empty() {
-                            let changes: Vec<FileChange> = debounce_buffer.drain().map(|(_, v)| v).collect();
+                            let mut changes: Vec<FileChange> = debounce_buffer.drain().map(|(_, v)| v).collect();
+                            coalesce_renames(&mut changes);
                             if tx.send(changes).await.is_err() {
                                 // Receiver dropped, exit task
                                 break;
@@ -444,6 +914,60 @@ pub enum ChangeType {
     Created,
     Modified,
     Deleted,
+    /// File or directory moved; `from` is the old path and the `FileChange`
+    /// path is the new one
+    Renamed {
+        from: PathBuf,
+    },
+}
+
+/// Pair Deleted+Created events for the same file name into a single rename.
+///
+/// Backends (and editors doing atomic saves via a temp dir) often report a
+/// move as separate remove/create events; coalescing them lets the engine
+/// carry symbols and embeddings over instead of re-parsing and re-embedding
+/// the moved file. Only unambiguous pairs (exactly one delete and one create
+/// sharing a file name) are coalesced.
+fn coalesce_renames(changes: &mut Vec<FileChange>) {
+    use std::ffi::OsString;
+
+    let mut deletes: HashMap<OsString, Vec<usize>> = HashMap::new();
+    let mut creates: HashMap<OsString, Vec<usize>> = HashMap::new();
+    for (i, change) in changes.iter().enumerate() {
+        let name = match change.path.file_name() {
+            Some(n) => n.to_os_string(),
+            None => continue,
+        };
+        match change.change_type {
+            ChangeType::Deleted => deletes.entry(name).or_default().push(i),
+            ChangeType::Created => creates.entry(name).or_default().push(i),
+            _ => {}
+        }
+    }
+
+    let mut renames: Vec<(usize, PathBuf)> = Vec::new();
+    let mut drop_idx: Vec<usize> = Vec::new();
+    for (name, del_idx) in deletes {
+        let create_idx = match creates.get(&name) {
+            Some(c) => c,
+            None => continue,
+        };
+        if del_idx.len() == 1 && create_idx.len() == 1 {
+            let (d, c) = (del_idx[0], create_idx[0]);
+            if changes[d].path != changes[c].path {
+                renames.push((c, changes[d].path.clone()));
+                drop_idx.push(d);
+            }
+        }
+    }
+
+    for (c, from) in renames {
+        changes[c].change_type = ChangeType::Renamed { from };
+    }
+    drop_idx.sort_unstable_by(|a, b| b.cmp(a));
+    for idx in drop_idx {
+        changes.remove(idx);
+    }
 }
 
 /// Check if a path is a source file we care about
@@ -519,6 +1043,19 @@ impl IncrementalIndexer {
                         }
                     }
                 }
+                ChangeType::Renamed { ref from } => {
+                    debug!("Re-indexing renamed: {:?} -> {:?}", from, change.path);
+                    index.remove_file(from);
+                    match reindex_fn(&change.path) {
+                        Ok(symbols) => {
+                            index.update_file(change.path, symbols)?;
+                            count += 1;
+                        }
+                        Err(e) => {
+                            warn!("Failed to index {:?}: {}", change.path, e);
+                        }
+                    }
+                }
                 ChangeType::Deleted => {
                     debug!("Removing from index: {:?}", change.path);
                     index.remove_file(&change.path);
@@ -528,7 +1065,7 @@ impl IncrementalIndexer {
         }
 
         if count > 0 {
-            self.store.save(&index)?;
+            self.store.save_incremental(&mut index)?;
         }
 
         Ok(count)
@@ -539,10 +1076,10 @@ impl IncrementalIndexer {
         Arc::clone(&self.index)
     }
 
-    /// Force save the current index
+    /// Force a full save of the current index (compacts the journal)
     pub fn save(&self) -> Result<()> {
-        let index = self.index.read();
-        self.store.save(&index)
+        let mut index = self.index.write();
+        self.store.save(&mut index)
     }
 
     /// Get files that need re-indexing
@@ -589,17 +1126,323 @@ mod tests {
         assert!(!is_source_file(Path::new("data.json")));
     }
 
+    #[test]
+    fn test_coalesce_renames_pairs_delete_and_create() {
+        let mut changes = vec![
+            FileChange {
+                path: PathBuf::from("src/old_dir/util.rs"),
+                change_type: ChangeType::Deleted,
+            },
+            FileChange {
+                path: PathBuf::from("src/new_dir/util.rs"),
+                change_type: ChangeType::Created,
+            },
+            FileChange {
+                path: PathBuf::from("src/lib.rs"),
+                change_type: ChangeType::Modified,
+            },
+        ];
+
+        coalesce_renames(&mut changes);
+
+        assert_eq!(changes.len(), 2);
+        let rename = changes
+            .iter()
+            .find(|c| c.path == Path::new("src/new_dir/util.rs"))
+            .expect("coalesced rename");
+        assert_eq!(
+            rename.change_type,
+            ChangeType::Renamed {
+                from: PathBuf::from("src/old_dir/util.rs")
+            }
+        );
+        // Unrelated changes pass through untouched
+        assert!(changes
+            .iter()
+            .any(|c| c.change_type == ChangeType::Modified));
+    }
+
+    #[test]
+    fn test_coalesce_renames_skips_ambiguous_pairs() {
+        // Two creates share the deleted file's name: no safe pairing
+        let mut changes = vec![
+            FileChange {
+                path: PathBuf::from("a/mod.rs"),
+                change_type: ChangeType::Deleted,
+            },
+            FileChange {
+                path: PathBuf::from("b/mod.rs"),
+                change_type: ChangeType::Created,
+            },
+            FileChange {
+                path: PathBuf::from("c/mod.rs"),
+                change_type: ChangeType::Created,
+            },
+        ];
+
+        coalesce_renames(&mut changes);
+
+        assert_eq!(changes.len(), 3);
+        assert!(!changes
+            .iter()
+            .any(|c| matches!(c.change_type, ChangeType::Renamed { .. })));
+    }
+
+    #[test]
+    fn test_save_writes_zstd_magic() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("test.idx");
+
+        let index = PersistedIndex::new(dir.path().to_path_buf());
+        index.save(&path).unwrap();
+
+        let raw = std::fs::read(&path).unwrap();
+        assert!(raw.starts_with(ZSTD_MAGIC));
+
+        let loaded = PersistedIndex::load(&path).unwrap();
+        assert_eq!(loaded.repo_root, index.repo_root);
+    }
+
+    #[test]
+    fn test_load_uncompressed_legacy_index() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("legacy.idx");
+
+        // Simulate an index written before compression: raw bincode, no magic
+        let index = PersistedIndex::new(dir.path().to_path_buf());
+        let data = bincode::serialize(&index).unwrap();
+        std::fs::write(&path, &data).unwrap();
+
+        let loaded = PersistedIndex::load(&path).unwrap();
+        assert_eq!(loaded.repo_root, index.repo_root);
+    }
+
     #[test]
     fn test_index_store() {
         let dir = tempdir().unwrap();
         let store = IndexStore::new(dir.path().to_path_buf()).unwrap();
 
         let repo = tempdir().unwrap();
-        let index = PersistedIndex::new(repo.path().to_path_buf());
+        let mut index = PersistedIndex::new(repo.path().to_path_buf());
 
-        store.save(&index).unwrap();
+        store.save(&mut index).unwrap();
 
         let loaded = store.load_or_create(repo.path()).unwrap();
         assert_eq!(loaded.version, PersistedIndex::CURRENT_VERSION);
     }
+
+    #[test]
+    fn test_load_rejects_newer_format_version() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("future.idx");
+
+        let mut index = PersistedIndex::new(dir.path().to_path_buf());
+        index.version = PersistedIndex::CURRENT_VERSION + 1;
+        index.save(&path).unwrap();
+
+        let err = PersistedIndex::load(&path).unwrap_err();
+        assert!(err.to_string().contains("newer narsil-mcp"));
+    }
+
+    #[test]
+    fn test_peek_version() {
+        let index = PersistedIndex::new(PathBuf::from("/tmp/repo"));
+        let data = bincode::serialize(&index).unwrap();
+        assert_eq!(peek_version(&data), Some(PersistedIndex::CURRENT_VERSION));
+        assert_eq!(peek_version(&[1, 2]), None);
+    }
+
+    #[test]
+    fn test_check_reports_cached_indices() {
+        let dir = tempdir().unwrap();
+        let store = IndexStore::new(dir.path().to_path_buf()).unwrap();
+
+        let repo = tempdir().unwrap();
+        let file = repo.path().join("a.rs");
+        std::fs::write(&file, "fn a() {}").unwrap();
+
+        let mut index = PersistedIndex::new(repo.path().to_path_buf());
+        index.update_file(file.clone(), Vec::new()).unwrap();
+        store.save(&mut index).unwrap();
+
+        // Delete the file so the check flags it as missing
+        std::fs::remove_file(&file).unwrap();
+
+        let report = store.check().unwrap();
+        assert!(report.contains("Format version: 1"));
+        assert!(report.contains("Missing files: 1"));
+        assert!(report.contains("1 problem(s) found"));
+    }
+
+    #[test]
+    fn test_check_with_empty_cache() {
+        let dir = tempdir().unwrap();
+        let store = IndexStore::new(dir.path().to_path_buf()).unwrap();
+        let report = store.check().unwrap();
+        assert!(report.contains("No cached indices found"));
+    }
+
+    #[test]
+    fn test_writer_lease_lifecycle() {
+        let dir = tempdir().unwrap();
+        let lock_path = dir.path().join(WRITER_LOCK_FILE);
+
+        let store = IndexStore::new(dir.path().to_path_buf()).unwrap();
+        assert!(!store.is_read_only());
+        assert!(lock_path.exists());
+
+        drop(store);
+        assert!(!lock_path.exists());
+    }
+
+    #[test]
+    fn test_follower_skips_saves() {
+        let dir = tempdir().unwrap();
+
+        // Simulate a live lease held by another process (pid 1 is always
+        // running on Linux; elsewhere the fresh mtime keeps it valid)
+        let lease = WriterLease {
+            pid: 1,
+            acquired_at: 0,
+        };
+        std::fs::write(
+            dir.path().join(WRITER_LOCK_FILE),
+            serde_json::to_vec(&lease).unwrap(),
+        )
+        .unwrap();
+
+        let store = IndexStore::new(dir.path().to_path_buf()).unwrap();
+        assert!(store.is_read_only());
+
+        let repo = tempdir().unwrap();
+        let mut index = PersistedIndex::new(repo.path().to_path_buf());
+        store.save(&mut index).unwrap();
+        assert!(!store.index_path(repo.path()).exists());
+
+        // Dropping a follower must not remove the writer's lease
+        drop(store);
+        assert!(dir.path().join(WRITER_LOCK_FILE).exists());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_stale_lease_is_reclaimed() {
+        let dir = tempdir().unwrap();
+
+        // A pid this large cannot be a running process
+        let lease = WriterLease {
+            pid: u32::MAX,
+            acquired_at: 0,
+        };
+        std::fs::write(
+            dir.path().join(WRITER_LOCK_FILE),
+            serde_json::to_vec(&lease).unwrap(),
+        )
+        .unwrap();
+
+        let store = IndexStore::new(dir.path().to_path_buf()).unwrap();
+        assert!(!store.is_read_only());
+    }
+
+    #[test]
+    fn test_incremental_save_appends_journal() {
+        let dir = tempdir().unwrap();
+        let store = IndexStore::new(dir.path().to_path_buf()).unwrap();
+
+        let repo = tempdir().unwrap();
+        let file = repo.path().join("a.rs");
+        std::fs::write(&file, "fn a() {}").unwrap();
+
+        let mut index = PersistedIndex::new(repo.path().to_path_buf());
+        store.save(&mut index).unwrap();
+        let base_mtime = std::fs::metadata(store.index_path(repo.path()))
+            .unwrap()
+            .modified()
+            .unwrap();
+
+        index.update_file(file.clone(), Vec::new()).unwrap();
+        assert!(index.is_dirty());
+        store.save_incremental(&mut index).unwrap();
+        assert!(!index.is_dirty());
+
+        // The base index file is untouched; the delta went to the journal
+        let journal = store.journal_path(repo.path());
+        assert!(journal.exists());
+        let mtime = std::fs::metadata(store.index_path(repo.path()))
+            .unwrap()
+            .modified()
+            .unwrap();
+        assert_eq!(mtime, base_mtime);
+
+        // Reloading replays the journal
+        let loaded = store.load_or_create(repo.path()).unwrap();
+        assert!(loaded.files.contains_key(&file));
+    }
+
+    #[test]
+    fn test_journal_replays_removals() {
+        let dir = tempdir().unwrap();
+        let store = IndexStore::new(dir.path().to_path_buf()).unwrap();
+
+        let repo = tempdir().unwrap();
+        let file = repo.path().join("a.rs");
+        std::fs::write(&file, "fn a() {}").unwrap();
+
+        let mut index = PersistedIndex::new(repo.path().to_path_buf());
+        index.update_file(file.clone(), Vec::new()).unwrap();
+        store.save(&mut index).unwrap();
+
+        index.remove_file(&file);
+        store.save_incremental(&mut index).unwrap();
+
+        let loaded = store.load_or_create(repo.path()).unwrap();
+        assert!(!loaded.files.contains_key(&file));
+    }
+
+    #[test]
+    fn test_full_save_truncates_journal() {
+        let dir = tempdir().unwrap();
+        let store = IndexStore::new(dir.path().to_path_buf()).unwrap();
+
+        let repo = tempdir().unwrap();
+        let file = repo.path().join("a.rs");
+        std::fs::write(&file, "fn a() {}").unwrap();
+
+        let mut index = PersistedIndex::new(repo.path().to_path_buf());
+        store.save(&mut index).unwrap();
+        index.update_file(file, Vec::new()).unwrap();
+        store.save_incremental(&mut index).unwrap();
+        assert!(store.journal_path(repo.path()).exists());
+
+        store.save(&mut index).unwrap();
+        assert!(!store.journal_path(repo.path()).exists());
+    }
+
+    #[test]
+    fn test_truncated_journal_tail_is_tolerated() {
+        let dir = tempdir().unwrap();
+        let store = IndexStore::new(dir.path().to_path_buf()).unwrap();
+
+        let repo = tempdir().unwrap();
+        let file = repo.path().join("a.rs");
+        std::fs::write(&file, "fn a() {}").unwrap();
+
+        let mut index = PersistedIndex::new(repo.path().to_path_buf());
+        store.save(&mut index).unwrap();
+        index.update_file(file.clone(), Vec::new()).unwrap();
+        store.save_incremental(&mut index).unwrap();
+
+        // Simulate a crash mid-append: a frame header with no payload
+        use std::io::Write;
+        let mut journal = std::fs::OpenOptions::new()
+            .append(true)
+            .open(store.journal_path(repo.path()))
+            .unwrap();
+        journal.write_all(&1000u32.to_le_bytes()).unwrap();
+        journal.write_all(b"partial").unwrap();
+        drop(journal);
+
+        let loaded = store.load_or_create(repo.path()).unwrap();
+        assert!(loaded.files.contains_key(&file));
+    }
 }
@@ -14,16 +14,27 @@
 #![allow(dead_code)]
 
 use anyhow::{anyhow, Context, Result};
+use http::header::{HeaderMap, ETAG, IF_NONE_MATCH};
+use http::StatusCode;
 use octocrab::Octocrab;
+use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 use tempfile::TempDir;
-use tracing::{info, warn};
+use tracing::{debug, info, warn};
+
+/// Host serving github.com proper, as opposed to a GitHub Enterprise install
+const GITHUB_HOST: &str = "github.com";
+
+fn default_github_host() -> String {
+    GITHUB_HOST.to_string()
+}
 
 /// Represents a remote GitHub repository
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RemoteRepo {
     /// Owner/organization name
     pub owner: String,
@@ -33,14 +44,18 @@ pub struct RemoteRepo {
     pub branch: Option<String>,
     /// Full URL
     pub url: String,
+    /// Hostname; github.com unless the URL names a GitHub Enterprise host
+    #[serde(default = "default_github_host")]
+    pub host: String,
 }
 
 impl RemoteRepo {
     /// Parse a GitHub URL into RemoteRepo
     /// Supports formats:
-    /// - `github.com/owner/repo`
+    /// - `owner/repo` or `github.com/owner/repo`
     /// - `https://github.com/owner/repo`
     /// - `https://github.com/owner/repo/tree/branch`
+    /// - `github.mycorp.com/owner/repo` (GitHub Enterprise)
     pub fn from_url(url: &str) -> Result<Self> {
         let url = url.trim();
 
@@ -50,13 +65,17 @@ impl RemoteRepo {
             .or_else(|| url.strip_prefix("http://"))
             .unwrap_or(url);
 
-        // Remove github.com prefix
-        let url = url.strip_prefix("github.com/").unwrap_or(url);
+        // A leading segment containing a dot is a hostname: github.com
+        // itself or a GitHub Enterprise install
+        let (host, url) = match url.split_once('/') {
+            Some((first, rest)) if first.contains('.') => (first.to_string(), rest),
+            _ => (default_github_host(), url),
+        };
 
         // Split by slashes
         let parts: Vec<&str> = url.split('/').collect();
 
-        if parts.len() < 2 {
+        if parts.len() < 2 || parts[0].is_empty() || parts[1].is_empty() {
             return Err(anyhow!(
                 "Invalid GitHub URL format. Expected: github.com/owner/repo"
             ));
@@ -76,7 +95,8 @@ impl RemoteRepo {
             owner: owner.clone(),
             repo: repo.clone(),
             branch,
-            url: format!("https://github.com/{}/{}", owner, repo),
+            url: format!("https://{}/{}/{}", host, owner, repo),
+            host,
         })
     }
 
@@ -89,47 +109,371 @@ impl RemoteRepo {
     pub fn clone_url(&self) -> String {
         format!("{}.git", self.url)
     }
+
+    /// Base URI of this host's REST API, or None for api.github.com.
+    /// GitHub Enterprise serves the API under `/api/v3`.
+    pub fn api_base(&self) -> Option<String> {
+        if self.host == GITHUB_HOST {
+            None
+        } else {
+            Some(format!("https://{}/api/v3", self.host))
+        }
+    }
+}
+
+/// Clones untouched for longer than this are evicted when the manager starts
+const CLONE_TTL: Duration = Duration::from_secs(14 * 24 * 60 * 60);
+
+/// Marker file inside each cached clone recording when it was last used
+const LAST_USED_MARKER: &str = ".narsil-last-used";
+
+/// Marker file identifying a cache entry as an extracted tarball snapshot
+/// (no `.git` directory, so it cannot be refreshed with a fetch)
+const TARBALL_MARKER: &str = ".narsil-tarball";
+
+/// How many times an API request is retried when rate limited or the
+/// server errors, with exponential backoff between attempts
+const MAX_API_RETRIES: u32 = 4;
+
+/// Delay before the first retry; doubles on each subsequent attempt
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Snapshot of GitHub's API quota, taken from the `X-RateLimit-*` headers
+/// of the most recent response (or the `/rate_limit` endpoint)
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct RateLimitStatus {
+    /// Maximum requests per hour (60 unauthenticated, 5000 with a token)
+    pub limit: u64,
+    /// Requests remaining in the current window
+    pub remaining: u64,
+    /// Unix timestamp at which the window resets
+    pub reset: u64,
+}
+
+/// A single remote file or gist resolved from a URL, indexable as a
+/// virtual one-file repository
+#[derive(Debug, Clone, PartialEq)]
+pub enum SnippetSource {
+    /// One file within a repository (raw or blob URL)
+    File {
+        /// The repository, with the URL's ref carried in `branch`
+        remote: RemoteRepo,
+        /// Path of the file within the repository
+        path: String,
+    },
+    /// A gist, identified by its hex id
+    Gist { id: String },
+}
+
+impl SnippetSource {
+    /// Name for the virtual repository holding the snippet
+    pub fn repo_name(&self) -> String {
+        match self {
+            SnippetSource::File { remote, path } => {
+                let stem = Path::new(path)
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .unwrap_or("snippet");
+                format!("{}-{}", remote.repo, stem)
+            }
+            SnippetSource::Gist { id } => {
+                format!("gist-{}", &id[..id.len().min(8)])
+            }
+        }
+    }
+}
+
+/// Recognize URLs pointing at a single file or gist rather than a whole
+/// repository:
+/// - `raw.githubusercontent.com/owner/repo/ref/path/to/file`
+/// - `github.com/owner/repo/blob/ref/path/to/file` (any GitHub host)
+/// - `gist.github.com/[user/]id`
+pub fn parse_snippet_url(url: &str) -> Option<SnippetSource> {
+    let url = url.trim();
+    let url = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .unwrap_or(url);
+
+    if let Some(rest) = url.strip_prefix("raw.githubusercontent.com/") {
+        // owner/repo/ref/path...
+        let parts: Vec<&str> = rest.splitn(4, '/').collect();
+        if parts.len() == 4 && !parts[3].is_empty() {
+            let mut remote = RemoteRepo::from_url(&format!("{}/{}", parts[0], parts[1])).ok()?;
+            remote.branch = Some(parts[2].to_string());
+            return Some(SnippetSource::File {
+                remote,
+                path: parts[3].to_string(),
+            });
+        }
+        return None;
+    }
+
+    if let Some(rest) = url.strip_prefix("gist.github.com/") {
+        // The id is the last path segment, with or without a username
+        let id = rest.trim_end_matches('/').rsplit('/').next()?;
+        if !id.is_empty() && id.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Some(SnippetSource::Gist { id: id.to_string() });
+        }
+        return None;
+    }
+
+    // host/owner/repo/blob/ref/path...
+    let parts: Vec<&str> = url.splitn(6, '/').collect();
+    if parts.len() == 6 && parts[0].contains('.') && parts[3] == "blob" && !parts[5].is_empty() {
+        let mut remote =
+            RemoteRepo::from_url(&format!("{}/{}/{}", parts[0], parts[1], parts[2])).ok()?;
+        remote.branch = Some(parts[4].to_string());
+        return Some(SnippetSource::File {
+            remote,
+            path: parts[5].to_string(),
+        });
+    }
+
+    None
+}
+
+/// Cached response body keyed by route, revalidated with `If-None-Match`
+struct CachedResponse {
+    etag: String,
+    body: String,
 }
 
 /// Manager for remote repositories
 pub struct RemoteRepoManager {
     /// GitHub API client
     octocrab: Arc<Octocrab>,
-    /// Temporary directory for clones
-    temp_dir: TempDir,
+    /// Root directory for clones; persists across restarts when the manager
+    /// is created with `with_cache_dir`
+    clone_root: PathBuf,
+    /// Keeps the fallback temp directory alive for managers without a cache
+    _temp_dir: Option<TempDir>,
     /// Map of repo identifier to local path
     cloned_repos: HashMap<String, PathBuf>,
+    /// Per-host clients for GitHub Enterprise remotes, created on first use
+    host_clients: Mutex<HashMap<String, Arc<Octocrab>>>,
+    /// Last observed API quota, updated after every API response
+    rate_limit: Mutex<Option<RateLimitStatus>>,
+    /// ETag cache; 304 revalidations serve from here without spending quota
+    etag_cache: Mutex<HashMap<String, CachedResponse>>,
 }
 
 impl RemoteRepoManager {
-    /// Create a new RemoteRepoManager
+    /// Create a new RemoteRepoManager with a throwaway clone directory
     /// Looks for GITHUB_TOKEN environment variable for authentication
     pub fn new() -> Result<Self> {
-        let octocrab = if let Ok(token) = std::env::var("GITHUB_TOKEN") {
-            info!("Using GITHUB_TOKEN for authentication");
-            Octocrab::builder()
-                .personal_token(token)
-                .build()
-                .context("Failed to create GitHub client with token")?
-        } else {
-            warn!("No GITHUB_TOKEN found - using unauthenticated access (lower rate limits)");
-            Octocrab::builder()
-                .build()
-                .context("Failed to create GitHub client")?
-        };
-
         let temp_dir =
             TempDir::new().context("Failed to create temporary directory for remote repos")?;
 
         info!("Remote repository temp directory: {:?}", temp_dir.path());
 
         Ok(Self {
-            octocrab: Arc::new(octocrab),
-            temp_dir,
+            octocrab: Arc::new(github_client(GITHUB_HOST)?),
+            clone_root: temp_dir.path().to_path_buf(),
+            _temp_dir: Some(temp_dir),
             cloned_repos: HashMap::new(),
+            host_clients: Mutex::new(HashMap::new()),
+            rate_limit: Mutex::new(None),
+            etag_cache: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Create a RemoteRepoManager with a persistent clone cache.
+    ///
+    /// Clones survive restarts, so re-adding a remote repo refreshes it with
+    /// `git fetch` instead of re-cloning. Clones that have not been used for
+    /// `CLONE_TTL` are evicted on startup.
+    pub fn with_cache_dir(cache_dir: PathBuf) -> Result<Self> {
+        std::fs::create_dir_all(&cache_dir).context("Failed to create remote clone cache")?;
+        evict_stale_clones(&cache_dir, CLONE_TTL);
+
+        // Re-discover clones that survived from a previous run
+        let mut cloned_repos = HashMap::new();
+        if let Ok(entries) = std::fs::read_dir(&cache_dir) {
+            for entry in entries.flatten() {
+                let path = entry.path();
+                let name = entry.file_name().to_string_lossy().to_string();
+                if (path.join(".git").exists() || path.join(TARBALL_MARKER).exists())
+                    && name.contains("__")
+                {
+                    cloned_repos.insert(name.replacen("__", "/", 1), path);
+                }
+            }
+        }
+        if !cloned_repos.is_empty() {
+            info!(
+                "Reusing {} cached remote clone(s) from {:?}",
+                cloned_repos.len(),
+                cache_dir
+            );
+        }
+
+        Ok(Self {
+            octocrab: Arc::new(github_client(GITHUB_HOST)?),
+            clone_root: cache_dir,
+            _temp_dir: None,
+            cloned_repos,
+            host_clients: Mutex::new(HashMap::new()),
+            rate_limit: Mutex::new(None),
+            etag_cache: Mutex::new(HashMap::new()),
         })
     }
 
+    /// GET a GitHub API route, returning the response body.
+    ///
+    /// Sends `If-None-Match` when a previous response for the same route is
+    /// cached, so a 304 serves the cached body without spending quota.
+    /// Rate-limited (403/429) and server-error responses are retried with
+    /// exponential backoff instead of failing immediately, which matters for
+    /// unauthenticated users with a 60 requests/hour budget.
+    async fn get_cached(&self, host: &str, route: &str) -> Result<String> {
+        let client = self.client_for(host)?;
+        // Key the ETag cache by host so identically-named repos on
+        // different hosts cannot shadow each other
+        let cache_key = format!("{}{}", host, route);
+        let mut delay = INITIAL_BACKOFF;
+
+        for attempt in 0..MAX_API_RETRIES {
+            let mut headers = HeaderMap::new();
+            if let Some(cached) = self.etag_cache.lock().get(&cache_key) {
+                if let Ok(value) = cached.etag.parse() {
+                    headers.insert(IF_NONE_MATCH, value);
+                }
+            }
+
+            let response = client
+                ._get_with_headers(route, Some(headers))
+                .await
+                .map_err(|e| anyhow!("GitHub API request to {} failed: {}", route, e))?;
+
+            self.record_rate_limit(response.headers());
+            let status = response.status();
+
+            if status == StatusCode::NOT_MODIFIED {
+                if let Some(cached) = self.etag_cache.lock().get(&cache_key) {
+                    debug!("Serving {} from ETag cache (304 Not Modified)", route);
+                    return Ok(cached.body.clone());
+                }
+                // Cache entry vanished between the request and now; retry
+                continue;
+            }
+
+            if self.is_rate_limited(status) || status.is_server_error() {
+                if attempt + 1 == MAX_API_RETRIES {
+                    break;
+                }
+                warn!(
+                    "GitHub API returned {} for {}; retrying in {:?}",
+                    status, route, delay
+                );
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+                continue;
+            }
+
+            if !status.is_success() {
+                return Err(anyhow!("GitHub API returned {} for {}", status, route));
+            }
+
+            let etag = response
+                .headers()
+                .get(ETAG)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string);
+            let body = client
+                .body_to_string(response)
+                .await
+                .map_err(|e| anyhow!("Failed to read GitHub API response: {}", e))?;
+            if let Some(etag) = etag {
+                self.etag_cache.lock().insert(
+                    cache_key.clone(),
+                    CachedResponse {
+                        etag,
+                        body: body.clone(),
+                    },
+                );
+            }
+            return Ok(body);
+        }
+
+        let hint = match self.rate_limit_status() {
+            Some(rl) if rl.remaining == 0 => format!(
+                " (rate limit of {} exhausted, resets at unix {}{})",
+                rl.limit,
+                rl.reset,
+                if token_for_host(host).is_none() {
+                    format!("; set {} for a higher limit", token_env_var(host))
+                } else {
+                    String::new()
+                }
+            ),
+            _ => String::new(),
+        };
+        Err(anyhow!(
+            "GitHub API request to {} failed after {} attempts{}",
+            route,
+            MAX_API_RETRIES,
+            hint
+        ))
+    }
+
+    /// API client for the remote's host. github.com uses the default
+    /// client; GitHub Enterprise hosts get a dedicated client (with their
+    /// own base URI and token), created on first use and then cached.
+    fn client_for(&self, host: &str) -> Result<Arc<Octocrab>> {
+        if host == GITHUB_HOST {
+            return Ok(self.octocrab.clone());
+        }
+        let mut clients = self.host_clients.lock();
+        if let Some(client) = clients.get(host) {
+            return Ok(client.clone());
+        }
+        let client = Arc::new(github_client(host)?);
+        clients.insert(host.to_string(), client.clone());
+        Ok(client)
+    }
+
+    /// True if the response indicates a rate limit rather than a real error:
+    /// 429, or 403 with the quota reported as exhausted
+    fn is_rate_limited(&self, status: StatusCode) -> bool {
+        status == StatusCode::TOO_MANY_REQUESTS
+            || (status == StatusCode::FORBIDDEN
+                && self
+                    .rate_limit_status()
+                    .map(|rl| rl.remaining == 0)
+                    .unwrap_or(false))
+    }
+
+    /// Update the quota snapshot from a response's `X-RateLimit-*` headers
+    fn record_rate_limit(&self, headers: &HeaderMap) {
+        if let Some(status) = parse_rate_limit_headers(headers) {
+            *self.rate_limit.lock() = Some(status);
+        }
+    }
+
+    /// Last observed API quota, if any request has been made yet
+    pub fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        *self.rate_limit.lock()
+    }
+
+    /// Query the `/rate_limit` endpoint for the current quota.
+    /// This endpoint does not count against the quota itself.
+    pub async fn fetch_rate_limit(&self) -> Result<RateLimitStatus> {
+        let limits = self
+            .octocrab
+            .ratelimit()
+            .get()
+            .await
+            .map_err(|e| anyhow!("Failed to query GitHub rate limit: {}", e))?;
+        let status = RateLimitStatus {
+            limit: limits.rate.limit as u64,
+            remaining: limits.rate.remaining as u64,
+            reset: limits.rate.reset,
+        };
+        *self.rate_limit.lock() = Some(status);
+        Ok(status)
+    }
+
     /// List files in a remote repository without cloning
     /// Returns a list of file paths
     /// Note: This only lists the immediate contents of the specified path
@@ -141,23 +485,24 @@ impl RemoteRepoManager {
             remote.owner, remote.repo, path
         );
 
-        let contents = self
-            .octocrab
-            .repos(&remote.owner, &remote.repo)
-            .get_content()
-            .path(path)
-            .r#ref(remote.branch.as_deref().unwrap_or(""))
-            .send()
+        let body = self
+            .get_cached(&remote.host, &contents_route(remote, path))
             .await
             .context("Failed to fetch repository contents")?;
+        let contents: serde_json::Value =
+            serde_json::from_str(&body).context("Failed to parse repository contents")?;
 
         let mut files = Vec::new();
 
-        // The result is ContentItems which contains a Vec of Content
+        // A directory listing is a JSON array of entries
         // For now, only list immediate contents (non-recursive to avoid API rate limits)
-        for item in contents.items {
-            if item.r#type == "file" {
-                files.push(item.path);
+        if let Some(items) = contents.as_array() {
+            for item in items {
+                if item["type"] == "file" {
+                    if let Some(path) = item["path"].as_str() {
+                        files.push(path.to_string());
+                    }
+                }
             }
         }
 
@@ -171,29 +516,66 @@ impl RemoteRepoManager {
             path, remote.owner, remote.repo
         );
 
-        let contents = self
-            .octocrab
-            .repos(&remote.owner, &remote.repo)
-            .get_content()
-            .path(path)
-            .r#ref(remote.branch.as_deref().unwrap_or(""))
-            .send()
+        let body = self
+            .get_cached(&remote.host, &contents_route(remote, path))
             .await
             .context(format!("Failed to fetch file: {}", path))?;
+        let contents: serde_json::Value =
+            serde_json::from_str(&body).context(format!("Failed to parse file: {}", path))?;
+
+        // The contents endpoint returns a single object for a file
+        if let Some(content) = contents["content"].as_str() {
+            use base64::Engine;
+            let decoded = base64::engine::general_purpose::STANDARD
+                .decode(content.replace('\n', ""))
+                .context("Failed to decode base64 content")?;
+            String::from_utf8(decoded).context("File content is not valid UTF-8")
+        } else if contents.is_array() {
+            Err(anyhow!("Path is a directory, not a file: {}", path))
+        } else {
+            Err(anyhow!("File content not available for: {}", path))
+        }
+    }
 
-        // The result is ContentItems with a single file
-        if let Some(item) = contents.items.first() {
-            if let Some(content) = &item.content {
-                use base64::Engine;
-                let decoded = base64::engine::general_purpose::STANDARD
-                    .decode(content.replace('\n', ""))
-                    .context("Failed to decode base64 content")?;
-                String::from_utf8(decoded).context("File content is not valid UTF-8")
-            } else {
-                Err(anyhow!("File content not available for: {}", path))
+    /// Fetch the content of a snippet (single file or gist) as
+    /// (file name, content) pairs
+    pub async fn fetch_snippet(&self, source: &SnippetSource) -> Result<Vec<(String, String)>> {
+        match source {
+            SnippetSource::File { remote, path } => {
+                let content = self.get_file(remote, path).await?;
+                let name = Path::new(path)
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or("snippet.txt")
+                    .to_string();
+                Ok(vec![(name, content)])
+            }
+            SnippetSource::Gist { id } => {
+                info!("Fetching gist {}", id);
+                let body = self
+                    .get_cached(GITHUB_HOST, &format!("/gists/{}", id))
+                    .await
+                    .context(format!("Failed to fetch gist: {}", id))?;
+                let gist: serde_json::Value =
+                    serde_json::from_str(&body).context("Failed to parse gist")?;
+
+                let mut files = Vec::new();
+                if let Some(map) = gist["files"].as_object() {
+                    for (name, file) in map {
+                        // Skip names that could escape the snippet directory
+                        if name.contains('/') || name.contains("..") {
+                            continue;
+                        }
+                        if let Some(content) = file["content"].as_str() {
+                            files.push((name.clone(), content.to_string()));
+                        }
+                    }
+                }
+                if files.is_empty() {
+                    return Err(anyhow!("Gist {} has no retrievable files", id));
+                }
+                Ok(files)
             }
-        } else {
-            Err(anyhow!("File not found: {}", path))
         }
     }
 
@@ -211,44 +593,67 @@ impl RemoteRepoManager {
 
         // Construct search query with repo scope
         let search_query = format!("{} repo:{}/{}", query, remote.owner, remote.repo);
+        let route = format!("/search/code?q={}", encode_query_value(&search_query));
 
-        let results = self
-            .octocrab
-            .search()
-            .code(&search_query)
-            .send()
+        let body = self
+            .get_cached(&remote.host, &route)
             .await
             .context("GitHub code search failed")?;
+        let results: serde_json::Value =
+            serde_json::from_str(&body).context("Failed to parse code search results")?;
 
         let mut search_results = Vec::new();
 
-        for item in results.items.iter().take(max_results) {
-            search_results.push(SearchResult {
-                file_path: item.path.clone(),
-                repository: format!("{}/{}", remote.owner, remote.repo),
-                url: item.html_url.to_string(),
-                score: 0.0, // GitHub doesn't provide scores in this format
-            });
+        if let Some(items) = results["items"].as_array() {
+            for item in items.iter().take(max_results) {
+                search_results.push(SearchResult {
+                    file_path: item["path"].as_str().unwrap_or_default().to_string(),
+                    repository: format!("{}/{}", remote.owner, remote.repo),
+                    url: item["html_url"].as_str().unwrap_or_default().to_string(),
+                    score: 0.0, // GitHub doesn't provide scores in this format
+                });
+            }
         }
 
         Ok(search_results)
     }
 
-    /// Clone a remote repository to a temporary location
+    /// Clone a remote repository into the clone cache
     /// Returns the path to the cloned repository
     pub async fn clone_repo(&mut self, remote: &RemoteRepo) -> Result<PathBuf> {
         let identifier = remote.identifier();
+        let repo_dir = self.clone_root.join(clone_dir_name(remote));
+
+        // A previous tarball snapshot has no .git to fetch into, so refresh
+        // it by downloading a fresh tarball
+        if repo_dir.join(TARBALL_MARKER).exists() {
+            return self.download_tarball(remote).await;
+        }
+
+        // Without a git executable, fall back to a tarball download
+        if !git_available().await {
+            warn!(
+                "No git executable found; downloading tarball of {} instead",
+                identifier
+            );
+            return self.download_tarball(remote).await;
+        }
 
-        // Check if already cloned
-        if let Some(path) = self.cloned_repos.get(&identifier) {
-            if path.exists() {
-                info!("Repository {} already cloned at {:?}", identifier, path);
-                return Ok(path.clone());
+        // Reuse an existing clone (from this run or a previous one) and
+        // refresh it with a shallow fetch instead of re-cloning
+        if repo_dir.join(".git").exists() {
+            info!("Repository {} already cloned at {:?}", identifier, repo_dir);
+            if let Err(e) = fetch_refresh(&repo_dir, remote).await {
+                warn!(
+                    "Failed to refresh cached clone of {}: {} (using cached state)",
+                    identifier, e
+                );
             }
+            touch_last_used(&repo_dir);
+            self.cloned_repos.insert(identifier, repo_dir.clone());
+            return Ok(repo_dir);
         }
 
-        // Create subdirectory in temp for this repo
-        let repo_dir = self.temp_dir.path().join(&remote.repo);
         std::fs::create_dir_all(&repo_dir).context("Failed to create repository directory")?;
 
         info!("Cloning {} to {:?}", identifier, repo_dir);
@@ -281,6 +686,7 @@ impl RemoteRepoManager {
         }
 
         info!("Successfully cloned {} to {:?}", identifier, repo_dir);
+        touch_last_used(&repo_dir);
 
         // Store in map
         self.cloned_repos.insert(identifier, repo_dir.clone());
@@ -288,6 +694,59 @@ impl RemoteRepoManager {
         Ok(repo_dir)
     }
 
+    /// Download and extract the GitHub tarball for the remote's ref into
+    /// the clone cache, without requiring a local `git` executable.
+    /// Returns the path to the extracted snapshot.
+    pub async fn download_tarball(&mut self, remote: &RemoteRepo) -> Result<PathBuf> {
+        let identifier = remote.identifier();
+        let repo_dir = self.clone_root.join(clone_dir_name(remote));
+        // An empty reference resolves to the repository's default branch
+        let reference = remote.branch.clone().unwrap_or_default();
+
+        info!("Downloading tarball of {} to {:?}", identifier, repo_dir);
+
+        let response = self
+            .client_for(&remote.host)?
+            .repos(&remote.owner, &remote.repo)
+            .download_tarball(reference)
+            .await
+            .map_err(|e| anyhow!("Failed to download tarball for {}: {}", identifier, e))?;
+        self.record_rate_limit(response.headers());
+        if !response.status().is_success() {
+            return Err(anyhow!(
+                "GitHub returned {} for tarball of {}",
+                response.status(),
+                identifier
+            ));
+        }
+
+        use http_body_util::BodyExt;
+        let bytes = response
+            .into_body()
+            .collect()
+            .await
+            .map_err(|e| anyhow!("Failed to read tarball body: {}", e))?
+            .to_bytes();
+
+        // Replace any previous snapshot wholesale; unlike a clone, a
+        // tarball cannot be refreshed incrementally
+        if repo_dir.exists() {
+            std::fs::remove_dir_all(&repo_dir)
+                .context("Failed to clear previous tarball snapshot")?;
+        }
+        std::fs::create_dir_all(&repo_dir).context("Failed to create repository directory")?;
+
+        extract_tarball(&bytes, &repo_dir)?;
+        std::fs::write(repo_dir.join(TARBALL_MARKER), b"")
+            .context("Failed to write tarball marker")?;
+        touch_last_used(&repo_dir);
+
+        info!("Extracted tarball of {} to {:?}", identifier, repo_dir);
+        self.cloned_repos.insert(identifier, repo_dir.clone());
+
+        Ok(repo_dir)
+    }
+
     /// Perform a sparse checkout of specific directories
     /// This is more efficient than cloning the entire repo
     pub async fn sparse_checkout(
@@ -296,7 +755,7 @@ impl RemoteRepoManager {
         paths: &[&str],
     ) -> Result<PathBuf> {
         let identifier = remote.identifier();
-        let repo_dir = self.temp_dir.path().join(&remote.repo);
+        let repo_dir = self.clone_root.join(clone_dir_name(remote));
 
         std::fs::create_dir_all(&repo_dir).context("Failed to create repository directory")?;
 
@@ -381,6 +840,7 @@ impl RemoteRepoManager {
             "Successfully sparse-checked out {} to {:?}",
             identifier, repo_dir
         );
+        touch_last_used(&repo_dir);
 
         self.cloned_repos.insert(identifier, repo_dir.clone());
 
@@ -398,7 +858,8 @@ impl RemoteRepoManager {
         RemoteStats {
             cloned_count: self.cloned_repos.len(),
             total_size_bytes: total_size,
-            temp_dir: self.temp_dir.path().to_path_buf(),
+            clone_dir: self.clone_root.clone(),
+            rate_limit: self.rate_limit_status(),
         }
     }
 
@@ -408,11 +869,223 @@ impl RemoteRepoManager {
             "Cleaning up {} cloned repositories",
             self.cloned_repos.len()
         );
+        for path in self.cloned_repos.values() {
+            let _ = std::fs::remove_dir_all(path);
+        }
         self.cloned_repos.clear();
-        // TempDir will be cleaned up on drop
     }
 }
 
+/// Build a GitHub API client for a host, authenticating with the host's
+/// token when one is set. GitHub Enterprise hosts get their API base URI
+/// pointed at `https://{host}/api/v3`.
+fn github_client(host: &str) -> Result<Octocrab> {
+    let mut builder = Octocrab::builder();
+    if host != GITHUB_HOST {
+        builder = builder
+            .base_uri(format!("https://{}/api/v3", host))
+            .map_err(|e| anyhow!("Invalid API base URI for {}: {}", host, e))?;
+    }
+
+    if let Some(token) = token_for_host(host) {
+        info!("Using token from {} for {}", token_env_var(host), host);
+        builder
+            .personal_token(token)
+            .build()
+            .context("Failed to create GitHub client with token")
+    } else {
+        warn!(
+            "No token found for {} - using unauthenticated access (lower rate limits)",
+            host
+        );
+        builder.build().context("Failed to create GitHub client")
+    }
+}
+
+/// Environment variable holding the token for a host, derived from the
+/// hostname: `github.mycorp.com` reads `GITHUB_TOKEN_GITHUB_MYCORP_COM`
+fn token_env_var(host: &str) -> String {
+    if host == GITHUB_HOST {
+        "GITHUB_TOKEN".to_string()
+    } else {
+        format!(
+            "GITHUB_TOKEN_{}",
+            host.to_uppercase().replace(['.', '-'], "_")
+        )
+    }
+}
+
+/// Token for a host, read from its environment variable. Tokens are kept
+/// per host so an enterprise token is never sent to github.com or vice
+/// versa.
+fn token_for_host(host: &str) -> Option<String> {
+    std::env::var(token_env_var(host)).ok()
+}
+
+/// Parse a quota snapshot out of GitHub's `X-RateLimit-*` response headers
+fn parse_rate_limit_headers(headers: &HeaderMap) -> Option<RateLimitStatus> {
+    let parse = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok())
+    };
+    Some(RateLimitStatus {
+        limit: parse("x-ratelimit-limit")?,
+        remaining: parse("x-ratelimit-remaining")?,
+        reset: parse("x-ratelimit-reset").unwrap_or(0),
+    })
+}
+
+/// API route for the contents endpoint, scoped to the remote's branch if set
+fn contents_route(remote: &RemoteRepo, path: &str) -> String {
+    let mut route = format!("/repos/{}/{}/contents/{}", remote.owner, remote.repo, path);
+    if let Some(ref branch) = remote.branch {
+        route.push_str(&format!("?ref={}", encode_query_value(branch)));
+    }
+    route
+}
+
+/// Percent-encode a query-string value (RFC 3986 unreserved bytes pass through)
+fn encode_query_value(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(byte as char)
+            }
+            _ => out.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    out
+}
+
+/// Directory name for a cached clone; owner is included so repos with the
+/// same name from different owners do not collide
+fn clone_dir_name(remote: &RemoteRepo) -> String {
+    format!("{}__{}", remote.owner, remote.repo)
+}
+
+/// Record that a cached clone was just used (the marker's mtime is the data)
+fn touch_last_used(repo_dir: &Path) {
+    let _ = std::fs::write(repo_dir.join(LAST_USED_MARKER), b"");
+}
+
+/// Remove cached clones that have not been used within `ttl`
+fn evict_stale_clones(cache_dir: &Path, ttl: Duration) {
+    let entries = match std::fs::read_dir(cache_dir) {
+        Ok(e) => e,
+        Err(_) => return,
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+        // Fall back to the directory's own mtime for clones predating the marker
+        let last_used = std::fs::metadata(path.join(LAST_USED_MARKER))
+            .or_else(|_| std::fs::metadata(&path))
+            .and_then(|m| m.modified())
+            .ok();
+        let stale = last_used
+            .and_then(|t| SystemTime::now().duration_since(t).ok())
+            .map(|age| age > ttl)
+            .unwrap_or(false);
+        if stale {
+            info!("Evicting stale remote clone: {:?}", path);
+            if let Err(e) = std::fs::remove_dir_all(&path) {
+                warn!("Failed to evict {:?}: {}", path, e);
+            }
+        }
+    }
+}
+
+/// True if a `git` executable is available on PATH
+async fn git_available() -> bool {
+    tokio::process::Command::new("git")
+        .arg("--version")
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .await
+        .map(|s| s.success())
+        .unwrap_or(false)
+}
+
+/// Extract a gzipped tarball into `dest`, stripping the `owner-repo-sha/`
+/// top-level directory GitHub puts in its archives
+fn extract_tarball(bytes: &[u8], dest: &Path) -> Result<()> {
+    let decoder = flate2::read::GzDecoder::new(bytes);
+    let mut archive = tar::Archive::new(decoder);
+
+    for entry in archive.entries().context("Failed to read tarball")? {
+        let mut entry = entry.context("Failed to read tarball entry")?;
+        let path = entry.path().context("Invalid path in tarball")?;
+        let stripped: PathBuf = path.components().skip(1).collect();
+        // Skip the top-level directory itself and anything trying to
+        // escape the destination
+        if stripped.as_os_str().is_empty()
+            || stripped
+                .components()
+                .any(|c| matches!(c, std::path::Component::ParentDir))
+        {
+            continue;
+        }
+        let target = dest.join(&stripped);
+        // Tarballs do not always carry explicit directory entries
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent).context("Failed to create extraction directory")?;
+        }
+        entry
+            .unpack(&target)
+            .with_context(|| format!("Failed to extract {:?} from tarball", stripped))?;
+    }
+
+    Ok(())
+}
+
+/// Refresh an existing clone with a shallow fetch and hard reset
+async fn fetch_refresh(repo_dir: &Path, remote: &RemoteRepo) -> Result<()> {
+    let mut cmd = tokio::process::Command::new("git");
+    cmd.args(["fetch", "--depth=1", "origin"]);
+    if let Some(ref branch) = remote.branch {
+        cmd.arg(branch);
+    }
+    cmd.current_dir(repo_dir)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::piped());
+
+    let output = cmd
+        .spawn()
+        .context("Failed to spawn git fetch")?
+        .wait_with_output()
+        .await
+        .context("Failed to wait for git fetch")?;
+
+    if !output.status.success() {
+        return Err(anyhow!(
+            "git fetch failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    let status = tokio::process::Command::new("git")
+        .args(["reset", "--hard", "FETCH_HEAD"])
+        .current_dir(repo_dir)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .await
+        .context("Failed to run git reset")?;
+
+    if !status.success() {
+        return Err(anyhow!("git reset to FETCH_HEAD failed"));
+    }
+
+    Ok(())
+}
+
 /// Search result from GitHub code search
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchResult {
@@ -427,7 +1100,9 @@ pub struct SearchResult {
 pub struct RemoteStats {
     pub cloned_count: usize,
     pub total_size_bytes: u64,
-    pub temp_dir: PathBuf,
+    pub clone_dir: PathBuf,
+    /// Last observed GitHub API quota, if any API request has been made
+    pub rate_limit: Option<RateLimitStatus>,
 }
 
 /// Calculate the size of a directory recursively
@@ -479,6 +1154,88 @@ mod tests {
         assert_eq!(remote.branch, Some("master".to_string()));
     }
 
+    #[test]
+    fn test_parse_ghe_url() {
+        let remote = RemoteRepo::from_url("github.mycorp.com/team/service").unwrap();
+        assert_eq!(remote.host, "github.mycorp.com");
+        assert_eq!(remote.owner, "team");
+        assert_eq!(remote.repo, "service");
+        assert_eq!(remote.url, "https://github.mycorp.com/team/service");
+        assert_eq!(
+            remote.api_base(),
+            Some("https://github.mycorp.com/api/v3".to_string())
+        );
+
+        // github.com remotes keep using the public API host
+        let public = RemoteRepo::from_url("github.com/owner/repo").unwrap();
+        assert_eq!(public.host, "github.com");
+        assert_eq!(public.api_base(), None);
+    }
+
+    #[test]
+    fn test_parse_ghe_url_with_branch() {
+        let remote =
+            RemoteRepo::from_url("https://github.mycorp.com/team/service/tree/dev").unwrap();
+        assert_eq!(remote.host, "github.mycorp.com");
+        assert_eq!(remote.branch, Some("dev".to_string()));
+    }
+
+    #[test]
+    fn test_token_env_var_per_host() {
+        assert_eq!(token_env_var("github.com"), "GITHUB_TOKEN");
+        assert_eq!(
+            token_env_var("github.my-corp.com"),
+            "GITHUB_TOKEN_GITHUB_MY_CORP_COM"
+        );
+    }
+
+    #[test]
+    fn test_parse_snippet_url_raw() {
+        let source =
+            parse_snippet_url("https://raw.githubusercontent.com/owner/repo/main/src/lib.rs")
+                .unwrap();
+        match source {
+            SnippetSource::File {
+                ref remote,
+                ref path,
+            } => {
+                assert_eq!(remote.identifier(), "owner/repo");
+                assert_eq!(remote.branch, Some("main".to_string()));
+                assert_eq!(path, "src/lib.rs");
+            }
+            other => panic!("Expected File source, got {:?}", other),
+        }
+        assert_eq!(source.repo_name(), "repo-lib");
+    }
+
+    #[test]
+    fn test_parse_snippet_url_blob() {
+        let source =
+            parse_snippet_url("https://github.com/owner/repo/blob/v1.0/docs/guide.md").unwrap();
+        match source {
+            SnippetSource::File { remote, path } => {
+                assert_eq!(remote.branch, Some("v1.0".to_string()));
+                assert_eq!(path, "docs/guide.md");
+            }
+            other => panic!("Expected File source, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_snippet_url_gist() {
+        let with_user = parse_snippet_url("https://gist.github.com/alice/abc123def456").unwrap();
+        let bare = parse_snippet_url("gist.github.com/abc123def456").unwrap();
+        assert_eq!(with_user, bare);
+        assert_eq!(with_user.repo_name(), "gist-abc123de");
+    }
+
+    #[test]
+    fn test_parse_snippet_url_rejects_repos() {
+        // Plain repository URLs are not snippets
+        assert!(parse_snippet_url("github.com/owner/repo").is_none());
+        assert!(parse_snippet_url("https://github.com/owner/repo/tree/main").is_none());
+    }
+
     #[test]
     fn test_parse_invalid_url() {
         assert!(RemoteRepo::from_url("not-a-url").is_err());
@@ -496,4 +1253,116 @@ mod tests {
         let remote = RemoteRepo::from_url("github.com/owner/repo").unwrap();
         assert_eq!(remote.clone_url(), "https://github.com/owner/repo.git");
     }
+
+    #[test]
+    fn test_clone_dir_name_includes_owner() {
+        let a = RemoteRepo::from_url("github.com/alice/utils").unwrap();
+        let b = RemoteRepo::from_url("github.com/bob/utils").unwrap();
+        assert_ne!(clone_dir_name(&a), clone_dir_name(&b));
+    }
+
+    #[test]
+    fn test_evict_stale_clones() {
+        let cache = TempDir::new().unwrap();
+        let stale = cache.path().join("alice__old");
+        let fresh = cache.path().join("bob__new");
+        std::fs::create_dir_all(&stale).unwrap();
+        std::fs::create_dir_all(&fresh).unwrap();
+        touch_last_used(&stale);
+        touch_last_used(&fresh);
+
+        // With a zero TTL everything qualifies; with a long one nothing does
+        std::thread::sleep(Duration::from_millis(10));
+        evict_stale_clones(cache.path(), Duration::from_secs(3600));
+        assert!(stale.exists() && fresh.exists());
+
+        evict_stale_clones(cache.path(), Duration::ZERO);
+        assert!(!stale.exists());
+        assert!(!fresh.exists());
+    }
+
+    #[test]
+    fn test_parse_rate_limit_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-limit", "60".parse().unwrap());
+        headers.insert("x-ratelimit-remaining", "42".parse().unwrap());
+        headers.insert("x-ratelimit-reset", "1700000000".parse().unwrap());
+
+        let status = parse_rate_limit_headers(&headers).unwrap();
+        assert_eq!(status.limit, 60);
+        assert_eq!(status.remaining, 42);
+        assert_eq!(status.reset, 1700000000);
+
+        // Responses without the headers (e.g. from a proxy) yield no snapshot
+        assert!(parse_rate_limit_headers(&HeaderMap::new()).is_none());
+    }
+
+    #[test]
+    fn test_contents_route_includes_branch() {
+        let mut remote = RemoteRepo::from_url("github.com/owner/repo").unwrap();
+        assert_eq!(
+            contents_route(&remote, "src/lib.rs"),
+            "/repos/owner/repo/contents/src/lib.rs"
+        );
+
+        remote.branch = Some("dev".to_string());
+        assert_eq!(
+            contents_route(&remote, ""),
+            "/repos/owner/repo/contents/?ref=dev"
+        );
+    }
+
+    #[test]
+    fn test_encode_query_value() {
+        assert_eq!(encode_query_value("plain-value_1.2~"), "plain-value_1.2~");
+        assert_eq!(
+            encode_query_value("fn main repo:a/b"),
+            "fn%20main%20repo%3Aa%2Fb"
+        );
+    }
+
+    #[test]
+    fn test_extract_tarball_strips_top_level_dir() {
+        // Build a gzipped tarball the way GitHub does: everything nested
+        // under an `owner-repo-sha/` directory
+        let mut builder = tar::Builder::new(flate2::write::GzEncoder::new(
+            Vec::new(),
+            flate2::Compression::default(),
+        ));
+        let add_file = |builder: &mut tar::Builder<_>, path: &str, data: &[u8]| {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(data.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append_data(&mut header, path, data).unwrap();
+        };
+        add_file(&mut builder, "owner-repo-abc123/README.md", b"hello");
+        add_file(
+            &mut builder,
+            "owner-repo-abc123/src/lib.rs",
+            b"fn main() {}",
+        );
+        let bytes = builder.into_inner().unwrap().finish().unwrap();
+
+        let dest = TempDir::new().unwrap();
+        extract_tarball(&bytes, dest.path()).unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(dest.path().join("README.md")).unwrap(),
+            "hello"
+        );
+        assert!(dest.path().join("src/lib.rs").exists());
+        assert!(!dest.path().join("owner-repo-abc123").exists());
+    }
+
+    #[tokio::test]
+    async fn test_with_cache_dir_rediscovers_clones() {
+        let cache = TempDir::new().unwrap();
+        let clone = cache.path().join("alice__utils");
+        std::fs::create_dir_all(clone.join(".git")).unwrap();
+        touch_last_used(&clone);
+
+        let manager = RemoteRepoManager::with_cache_dir(cache.path().to_path_buf()).unwrap();
+        assert_eq!(manager.cloned_repos.get("alice/utils"), Some(&clone));
+    }
 }
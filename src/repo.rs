@@ -27,8 +27,37 @@ pub struct RepoConfig {
     /// Maximum file size to index (bytes)
     pub max_file_size: u64,
 
-    /// Whether to follow symlinks
-    pub follow_symlinks: bool,
+    /// How symbolic links are treated during indexing
+    pub symlink_policy: SymlinkPolicy,
+}
+
+/// Policy for symbolic links encountered while walking a repository
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SymlinkPolicy {
+    /// Never follow symlinks (default)
+    #[default]
+    Skip,
+    /// Follow all symlinks; directory cycles are detected and not descended into
+    Follow,
+    /// Follow only symlinks whose target resolves inside the repository root
+    FollowWithinRepo,
+}
+
+impl std::str::FromStr for SymlinkPolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "skip" => Ok(Self::Skip),
+            "follow" => Ok(Self::Follow),
+            "follow-within-repo" => Ok(Self::FollowWithinRepo),
+            other => Err(anyhow!(
+                "Unknown symlink policy {:?} (expected skip, follow, or follow-within-repo)",
+                other
+            )),
+        }
+    }
 }
 
 impl Default for RepoConfig {
@@ -52,7 +81,7 @@ impl Default for RepoConfig {
             ],
             include_patterns: vec![],
             max_file_size: 1024 * 1024, // 1MB
-            follow_symlinks: false,
+            symlink_policy: SymlinkPolicy::default(),
         }
     }
 }
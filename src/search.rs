@@ -1,6 +1,9 @@
 //! Semantic code search with BM25 ranking and code-aware tokenization
 //!
 //! Provides intelligent code search that understands programming patterns.
+//! Queries may use quoted phrases, `AND`/`OR`/`NOT` operators, and
+//! parentheses; boolean queries are resolved against the inverted index
+//! directly rather than by post-filtering ranked results.
 
 // Allow dead code for planned search improvements
 #![allow(dead_code)]
@@ -27,6 +30,227 @@ fn validate_regex_pattern(pattern: &str) -> Result<regex::Regex, String> {
     regex::Regex::new(pattern).map_err(|e| e.to_string())
 }
 
+/// A parsed boolean query expression
+///
+/// Bare terms at the same level are implicitly OR-ed, matching the
+/// union-with-summed-scores behavior of plain term queries.
+#[derive(Debug, Clone, PartialEq)]
+enum QueryExpr {
+    /// A single term; matches any of its code-aware tokens
+    Term(String),
+    /// A quoted phrase; tokens must appear adjacent and in order
+    Phrase(Vec<String>),
+    And(Vec<QueryExpr>),
+    Or(Vec<QueryExpr>),
+    Not(Box<QueryExpr>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum QueryToken {
+    LParen,
+    RParen,
+    And,
+    Or,
+    Not,
+    Phrase(String),
+    Word(String),
+}
+
+/// Check whether a query uses boolean syntax (quotes, parentheses, or
+/// uppercase AND/OR/NOT operators)
+fn is_structured_query(query: &str) -> bool {
+    query.contains('"')
+        || query.contains('(')
+        || query.contains(')')
+        || query
+            .split_whitespace()
+            .any(|w| matches!(w, "AND" | "OR" | "NOT"))
+}
+
+/// Split a query into boolean query tokens
+fn lex_query(query: &str) -> Result<Vec<QueryToken>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = query.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            c if c.is_whitespace() => {}
+            '(' => tokens.push(QueryToken::LParen),
+            ')' => tokens.push(QueryToken::RParen),
+            '"' => {
+                let mut phrase = String::new();
+                let mut closed = false;
+                for c in chars.by_ref() {
+                    if c == '"' {
+                        closed = true;
+                        break;
+                    }
+                    phrase.push(c);
+                }
+                if !closed {
+                    return Err("Unterminated phrase quote".to_string());
+                }
+                tokens.push(QueryToken::Phrase(phrase));
+            }
+            _ => {
+                let mut word = String::from(ch);
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || c == '(' || c == ')' || c == '"' {
+                        break;
+                    }
+                    word.push(c);
+                    chars.next();
+                }
+                // Operators are case-sensitive so lowercase "and"/"or"/"not"
+                // still search as ordinary terms
+                tokens.push(match word.as_str() {
+                    "AND" => QueryToken::And,
+                    "OR" => QueryToken::Or,
+                    "NOT" => QueryToken::Not,
+                    _ => QueryToken::Word(word),
+                });
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Recursive-descent parser for boolean queries
+///
+/// Precedence (loosest to tightest): OR (explicit or implicit), AND, NOT.
+struct QueryParser {
+    tokens: Vec<QueryToken>,
+    pos: usize,
+}
+
+impl QueryParser {
+    fn parse(query: &str) -> Result<QueryExpr, String> {
+        let tokens = lex_query(query)?;
+        if tokens.is_empty() {
+            return Err("Empty query".to_string());
+        }
+        let mut parser = Self { tokens, pos: 0 };
+        let expr = parser.parse_or()?;
+        if let Some(tok) = parser.peek() {
+            return Err(format!("Unexpected token: {:?}", tok));
+        }
+        Ok(expr)
+    }
+
+    fn peek(&self) -> Option<&QueryToken> {
+        self.tokens.get(self.pos)
+    }
+
+    fn advance(&mut self) -> Option<&QueryToken> {
+        let tok = self.tokens.get(self.pos);
+        self.pos += 1;
+        tok
+    }
+
+    fn parse_or(&mut self) -> Result<QueryExpr, String> {
+        let mut parts = vec![self.parse_and()?];
+
+        loop {
+            match self.peek() {
+                Some(QueryToken::Or) => {
+                    self.advance();
+                    parts.push(self.parse_and()?);
+                }
+                // Adjacent expressions without an operator are OR-ed
+                Some(QueryToken::LParen)
+                | Some(QueryToken::Not)
+                | Some(QueryToken::Phrase(_))
+                | Some(QueryToken::Word(_)) => {
+                    parts.push(self.parse_and()?);
+                }
+                _ => break,
+            }
+        }
+
+        Ok(if parts.len() == 1 {
+            parts.pop().unwrap()
+        } else {
+            QueryExpr::Or(parts)
+        })
+    }
+
+    fn parse_and(&mut self) -> Result<QueryExpr, String> {
+        let mut parts = vec![self.parse_unary()?];
+
+        while self.peek() == Some(&QueryToken::And) {
+            self.advance();
+            parts.push(self.parse_unary()?);
+        }
+
+        Ok(if parts.len() == 1 {
+            parts.pop().unwrap()
+        } else {
+            QueryExpr::And(parts)
+        })
+    }
+
+    fn parse_unary(&mut self) -> Result<QueryExpr, String> {
+        if self.peek() == Some(&QueryToken::Not) {
+            self.advance();
+            return Ok(QueryExpr::Not(Box::new(self.parse_unary()?)));
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<QueryExpr, String> {
+        match self.advance().cloned() {
+            Some(QueryToken::LParen) => {
+                let expr = self.parse_or()?;
+                if self.advance() != Some(&QueryToken::RParen) {
+                    return Err("Missing closing parenthesis".to_string());
+                }
+                Ok(expr)
+            }
+            Some(QueryToken::Phrase(text)) => {
+                // tokenize_code emits single-part identifiers twice (the part
+                // plus the full identifier); collapse the repeats so the
+                // adjacency check sees one token per word
+                let mut tokens = tokenize_code(&text);
+                tokens.dedup();
+                if tokens.is_empty() {
+                    return Err(format!("Phrase \"{}\" has no searchable terms", text));
+                }
+                Ok(QueryExpr::Phrase(tokens))
+            }
+            Some(QueryToken::Word(word)) => Ok(QueryExpr::Term(word)),
+            Some(tok) => Err(format!("Unexpected token: {:?}", tok)),
+            None => Err("Unexpected end of query".to_string()),
+        }
+    }
+}
+
+/// Collect the scoring terms of a query (everything outside NOT clauses)
+fn collect_positive_terms(expr: &QueryExpr, out: &mut Vec<String>) {
+    match expr {
+        QueryExpr::Term(word) => {
+            for token in tokenize_code(word) {
+                if !out.contains(&token) {
+                    out.push(token);
+                }
+            }
+        }
+        QueryExpr::Phrase(tokens) => {
+            for token in tokens {
+                if !out.contains(token) {
+                    out.push(token.clone());
+                }
+            }
+        }
+        QueryExpr::And(parts) | QueryExpr::Or(parts) => {
+            for part in parts {
+                collect_positive_terms(part, out);
+            }
+        }
+        QueryExpr::Not(_) => {}
+    }
+}
+
 /// A searchable document (file or symbol)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchDocument {
@@ -49,6 +273,8 @@ pub enum DocType {
     Class,
     Struct,
     Method,
+    /// A section of a documentation file (markdown, reStructuredText, AsciiDoc)
+    Documentation,
     Other,
 }
 
@@ -59,6 +285,63 @@ pub struct SearchResult {
     pub score: f64,
     pub matched_terms: Vec<String>,
     pub snippet: String,
+    /// Byte offsets of matched terms within `snippet`, for highlighting
+    pub highlights: Vec<HighlightSpan>,
+}
+
+/// A matched-term location inside a snippet
+///
+/// Offsets are byte positions into the snippet string (`start..end` is a
+/// valid slice range), so frontends can highlight matches without
+/// re-searching the text.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HighlightSpan {
+    /// Byte offset of the match start within the snippet
+    pub start: usize,
+    /// Byte offset one past the match end
+    pub end: usize,
+    /// The term that matched at this location
+    pub term: String,
+}
+
+/// Locate matched terms inside a snippet, case-insensitively
+///
+/// Returns non-overlapping spans sorted by position; when matches overlap
+/// (e.g. a term inside a longer matched identifier), the earliest and
+/// longest span wins.
+pub fn highlight_spans(snippet: &str, matched_terms: &[String]) -> Vec<HighlightSpan> {
+    let bytes = snippet.as_bytes();
+    let mut spans = Vec::new();
+
+    for term in matched_terms {
+        if term.is_empty() {
+            continue;
+        }
+        let needle = term.as_bytes();
+        let mut i = 0;
+        while i + needle.len() <= bytes.len() {
+            if bytes[i..i + needle.len()].eq_ignore_ascii_case(needle) {
+                spans.push(HighlightSpan {
+                    start: i,
+                    end: i + needle.len(),
+                    term: term.clone(),
+                });
+                i += needle.len();
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    // Sort by start, longest first, then drop spans inside an earlier one
+    spans.sort_by(|a, b| a.start.cmp(&b.start).then(b.end.cmp(&a.end)));
+    let mut result: Vec<HighlightSpan> = Vec::new();
+    for span in spans {
+        if result.last().is_none_or(|prev| span.start >= prev.end) {
+            result.push(span);
+        }
+    }
+    result
 }
 
 /// BM25 parameters
@@ -229,8 +512,50 @@ impl SearchIndex {
         });
     }
 
+    /// Index a documentation file as heading-delimited sections
+    ///
+    /// Markdown (`#`), AsciiDoc (`=`), and reStructuredText (underlined
+    /// titles) headings each start a section, so results point at the part
+    /// of the document that answers the query instead of the whole file.
+    pub fn index_documentation(&mut self, file_path: &str, content: &str) {
+        let ext = file_path
+            .rsplit('.')
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase();
+
+        for section in split_doc_sections(content, &ext) {
+            let tokens = tokenize_code(&section.content);
+            let term_freq = count_terms(&tokens);
+
+            self.add_document(SearchDocument {
+                id: format!("{}#{}", file_path, section.start_line),
+                file_path: file_path.to_string(),
+                content: section.content,
+                doc_type: DocType::Documentation,
+                start_line: section.start_line,
+                end_line: section.end_line,
+                tokens,
+                term_freq,
+            });
+        }
+    }
+
     /// Search the index with BM25 ranking
+    ///
+    /// Queries containing quotes, parentheses, or AND/OR/NOT are parsed as
+    /// boolean expressions and resolved at the postings level; plain queries
+    /// keep the ranked-union behavior with synonym expansion.
     pub fn search(&self, query: &str, max_results: usize) -> Vec<SearchResult> {
+        if is_structured_query(query) {
+            match QueryParser::parse(query) {
+                Ok(expr) => return self.search_boolean(&expr, max_results),
+                Err(e) => {
+                    eprintln!("Invalid boolean query ({}), treating as plain terms", e);
+                }
+            }
+        }
+
         // Validate query pattern to prevent ReDoS attacks
         if let Err(e) = validate_regex_pattern(query) {
             eprintln!("Invalid search pattern: {}", e);
@@ -282,17 +607,138 @@ impl SearchIndex {
             .map(|(doc_idx, (score, matched_terms))| {
                 let doc = self.documents[doc_idx].clone();
                 let snippet = self.generate_snippet(&doc, &matched_terms);
+                let highlights = highlight_spans(&snippet, &matched_terms);
 
                 SearchResult {
                     document: doc,
                     score,
                     matched_terms,
                     snippet,
+                    highlights,
                 }
             })
             .collect()
     }
 
+    /// Execute a parsed boolean query
+    ///
+    /// Candidates come from set operations over the inverted index; BM25
+    /// scoring then ranks them by the query's positive terms.
+    fn search_boolean(&self, expr: &QueryExpr, max_results: usize) -> Vec<SearchResult> {
+        let candidates = self.eval_query(expr);
+
+        let mut positive_terms = Vec::new();
+        collect_positive_terms(expr, &mut positive_terms);
+
+        let mut results: Vec<(usize, f64, Vec<String>)> = candidates
+            .into_iter()
+            .map(|doc_idx| {
+                let doc = &self.documents[doc_idx];
+                let doc_len = doc.tokens.len() as f64;
+                let mut score = 0.0;
+                let mut matched_terms = Vec::new();
+
+                for term in &positive_terms {
+                    let tf = doc.term_freq.get(term).copied().unwrap_or(0) as f64;
+                    if tf > 0.0 {
+                        score += self.bm25_score(tf, doc_len, self.compute_idf(term));
+                        matched_terms.push(term.clone());
+                    }
+                }
+
+                (doc_idx, score, matched_terms)
+            })
+            .collect();
+
+        results.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+        results.truncate(max_results);
+
+        results
+            .into_iter()
+            .map(|(doc_idx, score, matched_terms)| {
+                let doc = self.documents[doc_idx].clone();
+                let snippet = self.generate_snippet(&doc, &matched_terms);
+                let highlights = highlight_spans(&snippet, &matched_terms);
+
+                SearchResult {
+                    document: doc,
+                    score,
+                    matched_terms,
+                    snippet,
+                    highlights,
+                }
+            })
+            .collect()
+    }
+
+    /// Evaluate a query expression to the set of matching document indices
+    fn eval_query(&self, expr: &QueryExpr) -> HashSet<usize> {
+        match expr {
+            QueryExpr::Term(word) => {
+                // A term matches any of its code-aware tokens, mirroring how
+                // identifiers are split at index time
+                let mut matches = HashSet::new();
+                for token in tokenize_code(word) {
+                    if let Some(doc_indices) = self.inverted_index.get(&token) {
+                        matches.extend(doc_indices.iter().copied());
+                    }
+                }
+                matches
+            }
+            QueryExpr::Phrase(tokens) => {
+                // Intersect postings first, then verify adjacency against the
+                // pre-computed token streams of the surviving documents
+                let mut matches: Option<HashSet<usize>> = None;
+                for token in tokens {
+                    let postings: HashSet<usize> = self
+                        .inverted_index
+                        .get(token)
+                        .map(|indices| indices.iter().copied().collect())
+                        .unwrap_or_default();
+                    matches = Some(match matches {
+                        Some(m) => m.intersection(&postings).copied().collect(),
+                        None => postings,
+                    });
+                }
+                matches
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|&doc_idx| {
+                        let doc_tokens = &self.documents[doc_idx].tokens;
+                        doc_tokens.len() >= tokens.len()
+                            && doc_tokens
+                                .windows(tokens.len())
+                                .any(|w| w == tokens.as_slice())
+                    })
+                    .collect()
+            }
+            QueryExpr::And(parts) => {
+                let mut matches: Option<HashSet<usize>> = None;
+                for part in parts {
+                    let part_matches = self.eval_query(part);
+                    matches = Some(match matches {
+                        Some(m) => m.intersection(&part_matches).copied().collect(),
+                        None => part_matches,
+                    });
+                }
+                matches.unwrap_or_default()
+            }
+            QueryExpr::Or(parts) => {
+                let mut matches = HashSet::new();
+                for part in parts {
+                    matches.extend(self.eval_query(part));
+                }
+                matches
+            }
+            QueryExpr::Not(inner) => {
+                let excluded = self.eval_query(inner);
+                (0..self.documents.len())
+                    .filter(|idx| !excluded.contains(idx))
+                    .collect()
+            }
+        }
+    }
+
     /// Compute IDF (Inverse Document Frequency)
     fn compute_idf(&self, term: &str) -> f64 {
         let n = self.documents.len() as f64;
@@ -362,6 +808,17 @@ impl SearchIndex {
             .join("\n")
     }
 
+    /// Estimate the bytes held by the inverted index postings
+    ///
+    /// Counts term string bytes plus one `usize` per posting; close
+    /// enough for capacity planning without walking allocator internals.
+    pub fn postings_bytes(&self) -> usize {
+        self.inverted_index
+            .iter()
+            .map(|(term, postings)| term.len() + postings.len() * std::mem::size_of::<usize>())
+            .sum()
+    }
+
     /// Get statistics about the index
     pub fn stats(&self) -> IndexStats {
         let doc_types: HashMap<DocType, usize> =
@@ -476,6 +933,113 @@ fn count_terms(tokens: &[String]) -> HashMap<String, usize> {
     counts
 }
 
+/// Check whether a path points at a documentation file that should be
+/// indexed as heading-delimited sections rather than parsed as code
+pub fn is_documentation_file(path: &str) -> bool {
+    matches!(
+        path.rsplit('.')
+            .next()
+            .map(|e| e.to_ascii_lowercase())
+            .as_deref(),
+        Some("md" | "markdown" | "rst" | "adoc" | "asciidoc")
+    )
+}
+
+/// A heading-delimited section of a documentation file (1-based lines)
+struct DocSection {
+    start_line: usize,
+    end_line: usize,
+    content: String,
+}
+
+/// Split documentation content into sections at headings
+///
+/// Markdown and AsciiDoc use prefix headings (`#` / `=`); reStructuredText
+/// titles are detected by their punctuation underline. Files without any
+/// heading become a single whole-file section, and text before the first
+/// heading is kept as a preamble section.
+fn split_doc_sections(content: &str, ext: &str) -> Vec<DocSection> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut heading_lines: Vec<usize> = Vec::new();
+    let mut in_fence = false;
+
+    for (i, line) in lines.iter().enumerate() {
+        let trimmed = line.trim_end();
+
+        // Headings inside fenced code blocks are code, not structure
+        if matches!(ext, "md" | "markdown") && trimmed.starts_with("```") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+
+        let is_heading = match ext {
+            "md" | "markdown" => {
+                let hashes = trimmed.chars().take_while(|&c| c == '#').count();
+                (1..=6).contains(&hashes) && trimmed[hashes..].starts_with(' ')
+            }
+            "adoc" | "asciidoc" => {
+                let eqs = trimmed.chars().take_while(|&c| c == '=').count();
+                (1..=6).contains(&eqs) && trimmed[eqs..].starts_with(' ')
+            }
+            "rst" => {
+                !trimmed.is_empty()
+                    && !is_rst_underline(trimmed)
+                    && lines
+                        .get(i + 1)
+                        .is_some_and(|next| is_rst_underline(next.trim_end()))
+            }
+            _ => false,
+        };
+
+        if is_heading {
+            heading_lines.push(i);
+        }
+    }
+
+    let mut sections = Vec::new();
+    let mut push_section = |start: usize, end: usize| {
+        let text = lines[start..end].join("\n");
+        if !text.trim().is_empty() {
+            sections.push(DocSection {
+                start_line: start + 1,
+                end_line: end,
+                content: text,
+            });
+        }
+    };
+
+    if heading_lines.is_empty() {
+        push_section(0, lines.len());
+        return sections;
+    }
+
+    // Preamble before the first heading
+    if heading_lines[0] > 0 {
+        push_section(0, heading_lines[0]);
+    }
+    for (idx, &start) in heading_lines.iter().enumerate() {
+        let end = heading_lines.get(idx + 1).copied().unwrap_or(lines.len());
+        push_section(start, end);
+    }
+
+    sections
+}
+
+/// Whether a line is a reStructuredText title underline: at least two
+/// repetitions of a single section punctuation character
+fn is_rst_underline(line: &str) -> bool {
+    let mut chars = line.chars();
+    match chars.next() {
+        Some(first) if "=-~^\"'`#*+:._".contains(first) => {
+            !line.is_empty() && line.len() >= 2 && chars.all(|c| c == first)
+        }
+        _ => false,
+    }
+}
+
 /// Check if a token is a common stop word in code
 fn is_stop_word(token: &str) -> bool {
     const STOP_WORDS: &[&str] = &[
@@ -515,6 +1079,10 @@ impl ConcurrentSearchIndex {
         self.inner.write().index_file(file_path, content);
     }
 
+    pub fn index_documentation(&self, file_path: &str, content: &str) {
+        self.inner.write().index_documentation(file_path, content);
+    }
+
     pub fn search(&self, query: &str, max_results: usize) -> Vec<SearchResult> {
         self.inner.read().search(query, max_results)
     }
@@ -523,6 +1091,10 @@ impl ConcurrentSearchIndex {
         self.inner.read().stats()
     }
 
+    pub fn postings_bytes(&self) -> usize {
+        self.inner.read().postings_bytes()
+    }
+
     pub fn clear(&self) {
         self.inner.write().clear();
     }
@@ -594,6 +1166,198 @@ mod tests {
         assert!(expanded.contains(&"function".to_string()) || expanded.contains(&"fn".to_string()));
     }
 
+    fn boolean_test_index() -> SearchIndex {
+        let mut index = SearchIndex::new();
+        index.index_file(
+            "user.rs",
+            "pub fn get_user_by_id(id: u32) -> User { fetch_user(id) }",
+        );
+        index.index_file(
+            "order.rs",
+            "pub fn create_order(user: &User) -> Order { insert_order(user) }",
+        );
+        index.index_file(
+            "report.rs",
+            "pub fn render_report(data: &Data) -> String { format_report(data) }",
+        );
+        index
+    }
+
+    #[test]
+    fn test_is_structured_query() {
+        assert!(is_structured_query("\"exact phrase\""));
+        assert!(is_structured_query("user AND order"));
+        assert!(is_structured_query("(user OR order)"));
+        assert!(is_structured_query("NOT order"));
+        // Lowercase operators are plain terms
+        assert!(!is_structured_query("user and order"));
+        assert!(!is_structured_query("plain query"));
+    }
+
+    #[test]
+    fn test_phrase_query_requires_adjacency() {
+        let index = boolean_test_index();
+
+        let results = index.search("\"get user\"", 10);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].document.file_path, "user.rs");
+
+        // Both tokens exist in order.rs but never adjacent
+        let results = index.search("\"get order\"", 10);
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_boolean_and() {
+        let index = boolean_test_index();
+
+        let results = index.search("user AND order", 10);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].document.file_path, "order.rs");
+    }
+
+    #[test]
+    fn test_boolean_not() {
+        let index = boolean_test_index();
+
+        let results = index.search("user AND NOT order", 10);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].document.file_path, "user.rs");
+    }
+
+    #[test]
+    fn test_boolean_parentheses() {
+        let index = boolean_test_index();
+
+        let results = index.search("(report OR order) AND user", 10);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].document.file_path, "order.rs");
+    }
+
+    #[test]
+    fn test_malformed_boolean_query_falls_back() {
+        let index = boolean_test_index();
+
+        // Unterminated quote parses as an error but still searches the terms
+        let results = index.search("\"user", 10);
+        assert!(!results.is_empty());
+    }
+
+    #[test]
+    fn test_query_parser_errors() {
+        assert!(QueryParser::parse("").is_err());
+        assert!(QueryParser::parse("(user").is_err());
+        assert!(QueryParser::parse("user AND").is_err());
+        assert!(QueryParser::parse("\"user").is_err());
+    }
+
+    #[test]
+    fn test_highlight_spans_case_insensitive() {
+        let spans = highlight_spans("let user = User::new();", &["user".to_string()]);
+        assert_eq!(spans.len(), 2);
+        assert_eq!((spans[0].start, spans[0].end), (4, 8));
+        assert_eq!((spans[1].start, spans[1].end), (11, 15));
+    }
+
+    #[test]
+    fn test_highlight_spans_overlaps_keep_longest() {
+        // "user" occurs inside "get_user"; only the longer span survives
+        let spans = highlight_spans(
+            "fn get_user() {}",
+            &["user".to_string(), "get_user".to_string()],
+        );
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].term, "get_user");
+        assert_eq!((spans[0].start, spans[0].end), (3, 11));
+    }
+
+    #[test]
+    fn test_search_results_include_highlights() {
+        let index = boolean_test_index();
+
+        let results = index.search("user", 10);
+        assert!(!results.is_empty());
+        let result = &results[0];
+        assert!(!result.highlights.is_empty());
+        for span in &result.highlights {
+            let matched = &result.snippet[span.start..span.end];
+            assert!(matched.eq_ignore_ascii_case(&span.term));
+        }
+    }
+
+    #[test]
+    fn test_is_documentation_file() {
+        assert!(is_documentation_file("README.md"));
+        assert!(is_documentation_file("docs/guide.RST"));
+        assert!(is_documentation_file("manual.adoc"));
+        assert!(!is_documentation_file("main.rs"));
+        assert!(!is_documentation_file("notes.txt"));
+    }
+
+    #[test]
+    fn test_split_markdown_sections() {
+        let content = "Intro text before headings.\n\n# Install\n\nRun cargo install.\n\n## Configure\n\nEdit the config file.\n";
+        let sections = split_doc_sections(content, "md");
+
+        assert_eq!(sections.len(), 3);
+        // Preamble before the first heading
+        assert_eq!(sections[0].start_line, 1);
+        assert!(sections[0].content.contains("Intro text"));
+        // Each heading starts its own section
+        assert!(sections[1].content.starts_with("# Install"));
+        assert!(sections[1].content.contains("cargo install"));
+        assert!(sections[2].content.starts_with("## Configure"));
+    }
+
+    #[test]
+    fn test_split_markdown_ignores_fenced_headings() {
+        let content = "# Real heading\n\n```sh\n# not a heading, just a comment\necho hi\n```\n";
+        let sections = split_doc_sections(content, "md");
+        assert_eq!(sections.len(), 1);
+    }
+
+    #[test]
+    fn test_split_rst_sections() {
+        let content = "Overview\n========\n\nSome intro.\n\nUsage\n-----\n\nRun the tool.\n";
+        let sections = split_doc_sections(content, "rst");
+
+        assert_eq!(sections.len(), 2);
+        assert!(sections[0].content.starts_with("Overview"));
+        assert!(sections[1].content.starts_with("Usage"));
+        assert!(sections[1].content.contains("Run the tool"));
+    }
+
+    #[test]
+    fn test_split_doc_without_headings_is_single_section() {
+        let content = "Just some prose\nwith no headings at all.\n";
+        let sections = split_doc_sections(content, "md");
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].start_line, 1);
+        assert_eq!(sections[0].end_line, 2);
+    }
+
+    #[test]
+    fn test_index_documentation_searchable_by_section() {
+        let mut index = SearchIndex::new();
+        index.index_documentation(
+            "README.md",
+            "# Install\n\nRun cargo install narsil.\n\n# Authentication\n\nSet the API token in your environment.\n",
+        );
+        index.index_file("src/auth.rs", "fn authenticate(token: &str) {}");
+
+        let results = index.search("token", 10);
+        assert!(!results.is_empty());
+
+        let doc_hit = results
+            .iter()
+            .find(|r| r.document.doc_type == DocType::Documentation)
+            .expect("documentation section should match");
+        assert_eq!(doc_hit.document.file_path, "README.md");
+        // Points at the Authentication section, not the whole file
+        assert_eq!(doc_hit.document.start_line, 5);
+        assert!(doc_hit.document.content.contains("API token"));
+    }
+
     // Security tests for regex DoS prevention
     #[test]
     fn test_validate_regex_pattern_valid() {
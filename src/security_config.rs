@@ -4,8 +4,11 @@
 //! - Maximum file size limits to prevent DoS
 //! - Secret redaction from tool outputs
 //! - Configurable security policies
+//! - User-declared taint sources, sinks, and sanitizers
 
 use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
 use std::sync::LazyLock;
 
 /// Security configuration options
@@ -31,6 +34,172 @@ impl Default for SecurityConfig {
     }
 }
 
+/// User-declared taint sources, sinks, and sanitizers, loaded from YAML.
+///
+/// These are merged with the built-in patterns by `taint::TaintAnalyzer` at
+/// analysis time, so security teams can cover internal frameworks without
+/// patching the built-in pattern lists.
+///
+/// Example `.narsil-taint.yaml`:
+///
+/// ```yaml
+/// frameworks: [django, express]
+/// sources:
+///   - name: internal_rpc
+///     kind: user_input
+///     languages: [python]
+///     functions: ["rpc.get_payload"]
+/// sinks:
+///   - name: legacy_query
+///     kind: sql
+///     languages: [python]
+///     functions: ["db.raw_query"]
+///     dangerous_arg: 0
+/// sanitizers:
+///   - name: escape_legacy
+///     languages: [python]
+///     functions: ["db.escape"]
+///     sanitizes_for: [sql]
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CustomTaintConfig {
+    /// Framework taint packs to enable (`django`, `flask`, `express`,
+    /// `spring`, `actix`, `axum`); each expands to built-in source/sink
+    /// patterns from `security_rules::framework_taint_pack`
+    #[serde(default)]
+    pub frameworks: Vec<String>,
+    /// Additional taint sources
+    #[serde(default)]
+    pub sources: Vec<CustomTaintSource>,
+    /// Additional taint sinks
+    #[serde(default)]
+    pub sinks: Vec<CustomTaintSink>,
+    /// Additional sanitizer functions
+    #[serde(default)]
+    pub sanitizers: Vec<CustomTaintSanitizer>,
+}
+
+/// A user-declared taint source
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomTaintSource {
+    /// Pattern name (used in findings)
+    pub name: String,
+    /// Source kind: `user_input`, `file_read`, `database`, `environment`,
+    /// `network`, `command_args`, `deserialization`, or anything else for a
+    /// custom kind
+    #[serde(default)]
+    pub kind: Option<String>,
+    /// Languages this source applies to (empty = all)
+    #[serde(default)]
+    pub languages: Vec<String>,
+    /// Function name patterns (substring match, like the built-ins)
+    #[serde(default)]
+    pub functions: Vec<String>,
+    /// Object/module property patterns (e.g. `request.payload`)
+    #[serde(default)]
+    pub properties: Vec<String>,
+    /// Match confidence: `high`, `medium`, or `low` (default: medium)
+    #[serde(default)]
+    pub confidence: Option<String>,
+}
+
+/// A user-declared taint sink
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomTaintSink {
+    /// Pattern name (used in findings)
+    pub name: String,
+    /// Sink kind: `sql`, `command`, `html`, `file_write`, `file_path`,
+    /// `eval`, `deserialization`, `ldap`, `xml`, `regex`, `logging`,
+    /// `redirect`, or anything else for a custom kind
+    #[serde(default)]
+    pub kind: Option<String>,
+    /// Languages this sink applies to (empty = all)
+    #[serde(default)]
+    pub languages: Vec<String>,
+    /// Function name patterns (substring match)
+    #[serde(default)]
+    pub functions: Vec<String>,
+    /// Which argument carries the dangerous value (0-indexed)
+    #[serde(default)]
+    pub dangerous_arg: usize,
+}
+
+/// A user-declared sanitizer function
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomTaintSanitizer {
+    /// Pattern name
+    pub name: String,
+    /// Languages this sanitizer applies to (empty = all)
+    #[serde(default)]
+    pub languages: Vec<String>,
+    /// Function name patterns (substring match)
+    #[serde(default)]
+    pub functions: Vec<String>,
+    /// Sink kinds this sanitizer neutralizes (empty = all)
+    #[serde(default)]
+    pub sanitizes_for: Vec<String>,
+}
+
+impl CustomTaintConfig {
+    /// Parse a custom taint configuration from YAML
+    pub fn from_yaml(yaml: &str) -> Result<Self, String> {
+        serde_yaml::from_str(yaml).map_err(|e| format!("Failed to parse taint config: {}", e))
+    }
+
+    /// Load the custom taint configuration from a repository root, if one
+    /// is present (`.narsil-taint.yaml` or `.narsil-taint.yml`)
+    pub fn load_from_repo(repo_path: &Path) -> Option<Self> {
+        for file_name in [".narsil-taint.yaml", ".narsil-taint.yml"] {
+            let path = repo_path.join(file_name);
+            if !path.exists() {
+                continue;
+            }
+            match std::fs::read_to_string(&path) {
+                Ok(content) => match Self::from_yaml(&content) {
+                    Ok(mut config) => {
+                        config.apply_framework_packs();
+                        return Some(config);
+                    }
+                    Err(e) => {
+                        tracing::warn!("Ignoring invalid {}: {}", path.display(), e);
+                    }
+                },
+                Err(e) => {
+                    tracing::warn!("Failed to read {}: {}", path.display(), e);
+                }
+            }
+        }
+        None
+    }
+
+    /// Expand the `frameworks:` list into the corresponding built-in packs,
+    /// appending their sources and sinks to this config. Unknown framework
+    /// names are logged and skipped.
+    pub fn apply_framework_packs(&mut self) {
+        for framework in &self.frameworks {
+            match crate::security_rules::framework_taint_pack(framework) {
+                Some(pack) => {
+                    self.sources.extend(pack.sources);
+                    self.sinks.extend(pack.sinks);
+                    self.sanitizers.extend(pack.sanitizers);
+                }
+                None => {
+                    tracing::warn!(
+                        "Unknown framework taint pack '{}' (known: {})",
+                        framework,
+                        crate::security_rules::FRAMEWORK_TAINT_PACKS.join(", ")
+                    );
+                }
+            }
+        }
+    }
+
+    /// Does this config declare any patterns at all?
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty() && self.sinks.is_empty() && self.sanitizers.is_empty()
+    }
+}
+
 /// Patterns for detecting secrets that should be redacted
 static SECRET_PATTERNS: LazyLock<Vec<(Regex, &'static str)>> = LazyLock::new(|| {
     vec![
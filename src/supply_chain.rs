@@ -303,6 +303,12 @@ pub struct UpgradeRecommendation {
     pub dependency: String,
     pub current_version: String,
     pub recommended_version: String,
+    /// Highest known fixed version that stays within the current major,
+    /// when the recommended version crosses one
+    pub latest_compatible: Option<String>,
+    /// Major versions crossed between current and recommended, each a
+    /// likely source of breaking changes worth a changelog read
+    pub intermediate_majors: Vec<String>,
     pub reason: UpgradeReason,
     pub breaking_changes: bool,
     pub vulnerabilities_fixed: Vec<String>,
@@ -1527,9 +1533,9 @@ impl SupplyChainAnalyzer {
                     });
                 }
             }
-            Ecosystem::PyPI => {
+            Ecosystem::PyPI
                 // requests < 2.31.0 has vulnerability
-                if dep.name == "requests" && self.version_lt(&dep.version, "2.31.0") {
+                if dep.name == "requests" && self.version_lt(&dep.version, "2.31.0") => {
                     vulns.push(Vulnerability {
                         id: "GHSA-j8r2-6x86-q33q".to_string(),
                         aliases: vec!["CVE-2023-32681".to_string()],
@@ -1544,10 +1550,9 @@ impl SupplyChainAnalyzer {
                         modified: Some("2023-05-26".to_string()),
                     });
                 }
-            }
-            Ecosystem::Cargo => {
+            Ecosystem::Cargo
                 // regex < 1.5.5 has ReDoS
-                if dep.name == "regex" && self.version_lt(&dep.version, "1.5.5") {
+                if dep.name == "regex" && self.version_lt(&dep.version, "1.5.5") => {
                     vulns.push(Vulnerability {
                         id: "RUSTSEC-2022-0013".to_string(),
                         aliases: vec!["CVE-2022-24713".to_string()],
@@ -1562,11 +1567,10 @@ impl SupplyChainAnalyzer {
                         modified: Some("2022-03-08".to_string()),
                     });
                 }
-            }
-            Ecosystem::Go => {
+            Ecosystem::Go
                 // net/http in Go has various vulnerabilities based on version
                 if dep.name.contains("golang.org/x/net") && self.version_lt(&dep.version, "0.17.0")
-                {
+                => {
                     vulns.push(Vulnerability {
                         id: "GO-2023-2102".to_string(),
                         aliases: vec!["CVE-2023-44487".to_string()],
@@ -1581,7 +1585,6 @@ impl SupplyChainAnalyzer {
                         modified: Some("2023-10-12".to_string()),
                     });
                 }
-            }
             _ => {}
         }
 
@@ -1738,15 +1741,50 @@ impl SupplyChainAnalyzer {
                         .cloned()
                 })?;
 
+                let current_major = major_version(&v.dependency.version);
+                let recommended_major = major_version(&recommended);
+                let breaking = self.has_major_version_change(&v.dependency.version, &recommended);
+
+                // All fixed versions we know about, across advisories
+                let fixed_versions: Vec<&String> = v
+                    .vulnerabilities
+                    .iter()
+                    .flat_map(|vuln| vuln.fixed_versions.iter())
+                    .collect();
+
+                // Highest fix that does not leave the current major
+                let latest_compatible = if breaking {
+                    fixed_versions
+                        .iter()
+                        .filter(|f| major_version(f) == current_major)
+                        .max_by(|a, b| {
+                            if self.version_lt(a, b) {
+                                std::cmp::Ordering::Less
+                            } else {
+                                std::cmp::Ordering::Greater
+                            }
+                        })
+                        .map(|f| f.to_string())
+                } else {
+                    None
+                };
+
+                // Majors between current and recommended
+                let intermediate_majors = match (current_major, recommended_major) {
+                    (Some(from), Some(to)) if to > from => {
+                        (from + 1..=to).map(|m| format!("{}.x", m)).collect()
+                    }
+                    _ => Vec::new(),
+                };
+
                 Some(UpgradeRecommendation {
                     dependency: v.dependency.name.clone(),
                     current_version: v.dependency.version.clone(),
                     recommended_version: recommended,
+                    latest_compatible,
+                    intermediate_majors,
                     reason: UpgradeReason::Security,
-                    breaking_changes: self.has_major_version_change(
-                        &v.dependency.version,
-                        v.upgrade_to.as_deref().unwrap_or("0.0.0"),
-                    ),
+                    breaking_changes: breaking,
                     vulnerabilities_fixed: v
                         .vulnerabilities
                         .iter()
@@ -1757,25 +1795,59 @@ impl SupplyChainAnalyzer {
             .collect()
     }
 
+    /// Order upgrades into phases so compatible bumps land together first
+    /// and each major upgrade gets its own phase, keeping the number of
+    /// simultaneous breaking changes at one
+    pub fn plan_upgrades<'a>(
+        &self,
+        upgrades: &'a [UpgradeRecommendation],
+    ) -> Vec<(String, Vec<&'a UpgradeRecommendation>)> {
+        let mut phases: Vec<(String, Vec<&UpgradeRecommendation>)> = Vec::new();
+
+        let compatible: Vec<&UpgradeRecommendation> =
+            upgrades.iter().filter(|u| !u.breaking_changes).collect();
+        if !compatible.is_empty() {
+            phases.push((
+                "Compatible upgrades (no major bump)".to_string(),
+                compatible,
+            ));
+        }
+
+        // Breaking upgrades: fewest majors crossed first, so the riskiest
+        // migration comes last with the most context
+        let mut breaking: Vec<&UpgradeRecommendation> =
+            upgrades.iter().filter(|u| u.breaking_changes).collect();
+        breaking.sort_by_key(|u| (u.intermediate_majors.len(), u.dependency.clone()));
+        for upgrade in breaking {
+            phases.push((
+                format!(
+                    "Major upgrade: {} {} -> {}",
+                    upgrade.dependency, upgrade.current_version, upgrade.recommended_version
+                ),
+                vec![upgrade],
+            ));
+        }
+
+        phases
+    }
+
     fn has_major_version_change(&self, from: &str, to: &str) -> bool {
-        let from_major: Option<u32> = from
-            .trim_start_matches(['v', '^', '~', '=', '>', '<'])
-            .split('.')
-            .next()
-            .and_then(|s| s.parse().ok());
-        let to_major: Option<u32> = to
-            .trim_start_matches(['v', '^', '~', '=', '>', '<'])
-            .split('.')
-            .next()
-            .and_then(|s| s.parse().ok());
-
-        match (from_major, to_major) {
+        match (major_version(from), major_version(to)) {
             (Some(f), Some(t)) => f != t,
             _ => false,
         }
     }
 }
 
+/// Major component of a version string, ignoring range prefixes
+fn major_version(version: &str) -> Option<u32> {
+    version
+        .trim_start_matches(['v', '^', '~', '=', '>', '<'])
+        .split('.')
+        .next()
+        .and_then(|s| s.parse().ok())
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -2286,6 +2358,57 @@ tokio = "1.0"
         assert_eq!(upgrades[0].recommended_version, "4.17.21");
         assert_eq!(upgrades[0].reason, UpgradeReason::Security);
         assert!(!upgrades[0].breaking_changes);
+        // Same major, so no compatible fallback and no majors crossed
+        assert!(upgrades[0].latest_compatible.is_none());
+        assert!(upgrades[0].intermediate_majors.is_empty());
+    }
+
+    #[test]
+    fn test_plan_upgrades_one_major_per_phase() {
+        let analyzer = SupplyChainAnalyzer::new();
+        let base = UpgradeRecommendation {
+            dependency: String::new(),
+            current_version: String::new(),
+            recommended_version: String::new(),
+            latest_compatible: None,
+            intermediate_majors: Vec::new(),
+            reason: UpgradeReason::Security,
+            breaking_changes: false,
+            vulnerabilities_fixed: Vec::new(),
+        };
+        let upgrades = vec![
+            UpgradeRecommendation {
+                dependency: "minor-bump".to_string(),
+                current_version: "1.2.0".to_string(),
+                recommended_version: "1.3.0".to_string(),
+                ..base.clone()
+            },
+            UpgradeRecommendation {
+                dependency: "two-majors".to_string(),
+                current_version: "1.0.0".to_string(),
+                recommended_version: "3.0.0".to_string(),
+                intermediate_majors: vec!["2.x".to_string(), "3.x".to_string()],
+                breaking_changes: true,
+                ..base.clone()
+            },
+            UpgradeRecommendation {
+                dependency: "one-major".to_string(),
+                current_version: "1.0.0".to_string(),
+                recommended_version: "2.0.0".to_string(),
+                intermediate_majors: vec!["2.x".to_string()],
+                breaking_changes: true,
+                ..base
+            },
+        ];
+
+        let phases = analyzer.plan_upgrades(&upgrades);
+
+        // Compatible bump first, then majors one per phase, fewest crossed first
+        assert_eq!(phases.len(), 3);
+        assert_eq!(phases[0].1[0].dependency, "minor-bump");
+        assert_eq!(phases[1].1[0].dependency, "one-major");
+        assert_eq!(phases[2].1[0].dependency, "two-majors");
+        assert!(phases[1..].iter().all(|(_, u)| u.len() == 1));
     }
 
     #[test]
@@ -31,6 +31,8 @@ pub enum SymbolKind {
     Variable,
     Field,
     Parameter,
+    TypeParameter,
+    EnumMember,
 
     // Special
     Implementation,
@@ -81,6 +83,8 @@ impl SymbolKind {
             SymbolKind::Variable => "ðŸ’¾",
             SymbolKind::Field => "ðŸ”·",
             SymbolKind::Parameter => "ðŸ“¥",
+            SymbolKind::TypeParameter => "ðŸ§¬",
+            SymbolKind::EnumMember => "ðŸ”¸",
             SymbolKind::Implementation => "âš™ï¸",
             SymbolKind::Macro => "ðŸŽ¯",
             SymbolKind::Unknown => "â“",
@@ -23,6 +23,149 @@ impl ToolHandler for GetControlFlowHandler {
     }
 }
 
+/// Handler for explain_function tool
+pub struct ExplainFunctionHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for ExplainFunctionHandler {
+    fn name(&self) -> &'static str {
+        "explain_function"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo").unwrap_or("");
+        let path = args.get_str("path").unwrap_or("");
+        let function = args.get_str("function").unwrap_or("");
+        engine.explain_function(repo, path, function).await
+    }
+}
+
+/// Handler for get_routes tool
+pub struct GetRoutesHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for GetRoutesHandler {
+    fn name(&self) -> &'static str {
+        "get_routes"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo");
+        let framework = args.get_str("framework");
+        engine.get_routes(repo, framework).await
+    }
+}
+
+/// Handler for find_config_usage tool
+pub struct FindConfigUsageHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for FindConfigUsageHandler {
+    fn name(&self) -> &'static str {
+        "find_config_usage"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo");
+        engine.find_config_usage(repo).await
+    }
+}
+
+/// Handler for find_feature_flags tool
+pub struct FindFeatureFlagsHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for FindFeatureFlagsHandler {
+    fn name(&self) -> &'static str {
+        "find_feature_flags"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo");
+        engine.find_feature_flags(repo).await
+    }
+}
+
+/// Handler for audit_error_handling tool
+pub struct AuditErrorHandlingHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for AuditErrorHandlingHandler {
+    fn name(&self) -> &'static str {
+        "audit_error_handling"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo");
+        engine.audit_error_handling(repo).await
+    }
+}
+
+/// Handler for audit_concurrency tool
+pub struct AuditConcurrencyHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for AuditConcurrencyHandler {
+    fn name(&self) -> &'static str {
+        "audit_concurrency"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo");
+        engine.audit_concurrency(repo).await
+    }
+}
+
+/// Handler for scan_async_blocking tool
+pub struct ScanAsyncBlockingHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for ScanAsyncBlockingHandler {
+    fn name(&self) -> &'static str {
+        "scan_async_blocking"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo");
+        engine.scan_async_blocking(repo).await
+    }
+}
+
+/// Handler for find_race_conditions tool
+pub struct FindRaceConditionsHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for FindRaceConditionsHandler {
+    fn name(&self) -> &'static str {
+        "find_race_conditions"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo");
+        engine.find_race_conditions(repo).await
+    }
+}
+
+/// Handler for get_execution_paths tool
+pub struct GetExecutionPathsHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for GetExecutionPathsHandler {
+    fn name(&self) -> &'static str {
+        "get_execution_paths"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo").unwrap_or("");
+        let path = args.get_str("path").unwrap_or("");
+        let function = args.get_str("function").unwrap_or("");
+        let max_paths = args.get_u64_or("max_paths", 20) as usize;
+        engine
+            .get_execution_paths(repo, path, function, max_paths)
+            .await
+    }
+}
+
 /// Handler for find_dead_code tool
 pub struct FindDeadCodeHandler;
 
@@ -56,7 +199,8 @@ impl ToolHandler for GetDataFlowHandler {
         let repo = args.get_str("repo").unwrap_or("");
         let path = args.get_str("path").unwrap_or("");
         let function = args.get_str("function").unwrap_or("");
-        engine.get_data_flow(repo, path, function).await
+        let format = args.get_str("format");
+        engine.get_data_flow(repo, path, function, format).await
     }
 }
 
@@ -168,6 +312,64 @@ impl ToolHandler for GetTypedTaintFlowHandler {
     }
 }
 
+/// Handler for find_null_risks tool
+pub struct FindNullRisksHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for FindNullRisksHandler {
+    fn name(&self) -> &'static str {
+        "find_null_risks"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo").unwrap_or("");
+        let path = args.get_str("path").unwrap_or("");
+        let function = args.get_str("function");
+        let exclude_tests = args.get_bool("exclude_tests");
+        engine
+            .find_null_risks(repo, path, function, exclude_tests)
+            .await
+    }
+}
+
+/// Handler for load_coverage tool
+pub struct LoadCoverageHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for LoadCoverageHandler {
+    fn name(&self) -> &'static str {
+        "load_coverage"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo").unwrap_or("");
+        let path = args.get_str("path").unwrap_or("");
+        engine.load_coverage(repo, path).await
+    }
+}
+
+/// Handler for find_uncovered_functions tool
+pub struct FindUncoveredFunctionsHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for FindUncoveredFunctionsHandler {
+    fn name(&self) -> &'static str {
+        "find_uncovered_functions"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo").unwrap_or("");
+        let threshold = args
+            .get("threshold")
+            .and_then(|v| v.as_f64())
+            .unwrap_or(100.0);
+        let limit = args.get_u64_or("limit", 50) as usize;
+        engine
+            .find_uncovered_functions(repo, threshold, limit)
+            .await
+    }
+}
+
 /// Handler for get_import_graph tool
 pub struct GetImportGraphHandler;
 
@@ -185,6 +387,38 @@ impl ToolHandler for GetImportGraphHandler {
     }
 }
 
+/// Handler for find_todos tool
+pub struct FindTodosHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for FindTodosHandler {
+    fn name(&self) -> &'static str {
+        "find_todos"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo").unwrap_or("");
+        let marker = args.get_str("marker");
+        let limit = args.get_u64_or("limit", 50) as usize;
+        engine.find_todos(repo, marker, limit).await
+    }
+}
+
+/// Handler for check_architecture tool
+pub struct CheckArchitectureHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for CheckArchitectureHandler {
+    fn name(&self) -> &'static str {
+        "check_architecture"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo").unwrap_or("");
+        engine.check_architecture(repo).await
+    }
+}
+
 /// Handler for find_circular_imports tool
 pub struct FindCircularImportsHandler;
 
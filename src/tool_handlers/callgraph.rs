@@ -101,6 +101,72 @@ impl ToolHandler for GetComplexityHandler {
     }
 }
 
+/// Handler for export_call_graph tool
+pub struct ExportCallGraphHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for ExportCallGraphHandler {
+    fn name(&self) -> &'static str {
+        "export_call_graph"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo").unwrap_or("");
+        let format = args.get_str("format").unwrap_or("graphml");
+        let file = args.get_str("file");
+        engine.export_call_graph(repo, format, file).await
+    }
+}
+
+/// Handler for get_key_functions tool
+pub struct GetKeyFunctionsHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for GetKeyFunctionsHandler {
+    fn name(&self) -> &'static str {
+        "get_key_functions"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo").unwrap_or("");
+        let limit = args.get_u64_or("limit", 20) as usize;
+        engine.get_key_functions(repo, limit).await
+    }
+}
+
+/// Handler for find_recursion_cycles tool
+pub struct FindRecursionCyclesHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for FindRecursionCyclesHandler {
+    fn name(&self) -> &'static str {
+        "find_recursion_cycles"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo").unwrap_or("");
+        engine.find_recursion_cycles(repo).await
+    }
+}
+
+/// Handler for get_impacted_tests tool
+pub struct GetImpactedTestsHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for GetImpactedTestsHandler {
+    fn name(&self) -> &'static str {
+        "get_impacted_tests"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo").unwrap_or("");
+        let base = args.get_str("base").unwrap_or("HEAD");
+        let head = args.get_str("head");
+        let max_depth = args.get_u64_or("max_depth", 10) as usize;
+        engine.get_impacted_tests(repo, base, head, max_depth).await
+    }
+}
+
 /// Handler for get_function_hotspots tool
 pub struct GetFunctionHotspotsHandler;
 
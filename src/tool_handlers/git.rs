@@ -156,3 +156,56 @@ impl ToolHandler for GetModifiedFilesHandler {
         engine.get_modified_files(repo).await
     }
 }
+
+/// Handler for get_owners tool
+pub struct GetOwnersHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for GetOwnersHandler {
+    fn name(&self) -> &'static str {
+        "get_owners"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo").unwrap_or("");
+        let path = args.get_str("path");
+        let symbol = args.get_str("symbol");
+        engine.get_owners(repo, path, symbol).await
+    }
+}
+
+/// Handler for check_breaking_changes tool
+pub struct CheckBreakingChangesHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for CheckBreakingChangesHandler {
+    fn name(&self) -> &'static str {
+        "check_breaking_changes"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo").unwrap_or("");
+        let base = args.get_str("base").unwrap_or("HEAD~1");
+        let head = args.get_str("head");
+        engine.check_breaking_changes(repo, base, head).await
+    }
+}
+
+/// Handler for check_commit_conventions tool
+pub struct CheckCommitConventionsHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for CheckCommitConventionsHandler {
+    fn name(&self) -> &'static str {
+        "check_commit_conventions"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo").unwrap_or("");
+        let max_commits = args.get_u64_or("max_commits", 50) as usize;
+        let types = args.get_str("types");
+        engine
+            .check_commit_conventions(repo, max_commits, types)
+            .await
+    }
+}
@@ -868,6 +868,8 @@ fn call_type_string(ct: &CallType) -> String {
         CallType::Closure => "closure",
         CallType::Async => "async",
         CallType::Spawn => "spawn",
+        CallType::Callback => "callback",
+        CallType::Possible => "possible",
         CallType::Unknown => "unknown",
     }
     .to_string()
@@ -59,3 +59,56 @@ impl ToolHandler for GoToDefinitionHandler {
         engine.go_to_definition(repo, path, line, character).await
     }
 }
+
+/// Handler for get_diagnostics tool
+pub struct GetDiagnosticsHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for GetDiagnosticsHandler {
+    fn name(&self) -> &'static str {
+        "get_diagnostics"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo").unwrap_or("");
+        let path = args.get_str("path");
+        let min_severity = args.get_str("min_severity");
+        engine.get_diagnostics(repo, path, min_severity).await
+    }
+}
+
+/// Handler for rename_symbol tool
+pub struct RenameSymbolHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for RenameSymbolHandler {
+    fn name(&self) -> &'static str {
+        "rename_symbol"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo").unwrap_or("");
+        let path = args.get_str("path").unwrap_or("");
+        let line = args.get_u64_or("line", 1) as usize;
+        let character = args.get_u64_or("character", 0) as usize;
+        let new_name = args.get_str("new_name").unwrap_or("");
+        let apply = args.get_bool_or("apply", false);
+        engine
+            .rename_symbol(repo, path, line, character, new_name, apply)
+            .await
+    }
+}
+
+/// Handler for get_lsp_status tool
+pub struct GetLspStatusHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for GetLspStatusHandler {
+    fn name(&self) -> &'static str {
+        "get_lsp_status"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, _args: Value) -> Result<String> {
+        engine.get_lsp_status().await
+    }
+}
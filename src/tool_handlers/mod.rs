@@ -67,6 +67,7 @@ impl ToolRegistry {
         registry.register(Box::new(repo::GetIndexStatusHandler));
         registry.register(Box::new(repo::GetIncrementalStatusHandler));
         registry.register(Box::new(repo::GetMetricsHandler));
+        registry.register(Box::new(repo::ApplyPatchHandler));
 
         // Register symbol handlers
         registry.register(Box::new(symbols::FindSymbolsHandler));
@@ -75,12 +76,15 @@ impl ToolRegistry {
         registry.register(Box::new(symbols::GetDependenciesHandler));
         registry.register(Box::new(symbols::FindSymbolUsagesHandler));
         registry.register(Box::new(symbols::GetExportMapHandler));
+        registry.register(Box::new(symbols::GetApiSurfaceHandler));
+        registry.register(Box::new(symbols::GetDocCoverageHandler));
         registry.register(Box::new(symbols::WorkspaceSymbolSearchHandler));
 
         // Register search handlers
         registry.register(Box::new(search::SearchCodeHandler));
         registry.register(Box::new(search::SemanticSearchHandler));
         registry.register(Box::new(search::HybridSearchHandler));
+        registry.register(Box::new(search::GetContextPackHandler));
         registry.register(Box::new(search::NeuralSearchHandler));
         registry.register(Box::new(search::SearchChunksHandler));
         registry.register(Box::new(search::FindSimilarCodeHandler));
@@ -98,6 +102,10 @@ impl ToolRegistry {
         registry.register(Box::new(callgraph::FindCallPathHandler));
         registry.register(Box::new(callgraph::GetComplexityHandler));
         registry.register(Box::new(callgraph::GetFunctionHotspotsHandler));
+        registry.register(Box::new(callgraph::ExportCallGraphHandler));
+        registry.register(Box::new(callgraph::GetKeyFunctionsHandler));
+        registry.register(Box::new(callgraph::FindRecursionCyclesHandler));
+        registry.register(Box::new(callgraph::GetImpactedTestsHandler));
 
         // Register git handlers
         registry.register(Box::new(git::GetBlameHandler));
@@ -109,19 +117,28 @@ impl ToolRegistry {
         registry.register(Box::new(git::GetSymbolHistoryHandler));
         registry.register(Box::new(git::GetBranchInfoHandler));
         registry.register(Box::new(git::GetModifiedFilesHandler));
+        registry.register(Box::new(git::GetOwnersHandler));
+        registry.register(Box::new(git::CheckBreakingChangesHandler));
+        registry.register(Box::new(git::CheckCommitConventionsHandler));
 
         // Register LSP handlers
         registry.register(Box::new(lsp::GetHoverInfoHandler));
         registry.register(Box::new(lsp::GetTypeInfoHandler));
         registry.register(Box::new(lsp::GoToDefinitionHandler));
+        registry.register(Box::new(lsp::GetDiagnosticsHandler));
+        registry.register(Box::new(lsp::RenameSymbolHandler));
+        registry.register(Box::new(lsp::GetLspStatusHandler));
 
         // Register remote handlers
         registry.register(Box::new(remote::AddRemoteRepoHandler));
         registry.register(Box::new(remote::ListRemoteFilesHandler));
         registry.register(Box::new(remote::GetRemoteFileHandler));
+        registry.register(Box::new(remote::GetRemoteStatsHandler));
 
         // Register security handlers
         registry.register(Box::new(security::ScanSecurityHandler));
+        registry.register(Box::new(security::FindUnprotectedRoutesHandler));
+        registry.register(Box::new(security::FindUnsafeHandler));
         registry.register(Box::new(security::CheckOwaspTop10Handler));
         registry.register(Box::new(security::CheckCweTop25Handler));
         registry.register(Box::new(security::FindInjectionVulnerabilitiesHandler));
@@ -130,6 +147,8 @@ impl ToolRegistry {
         registry.register(Box::new(security::GetSecuritySummaryHandler));
         registry.register(Box::new(security::ExplainVulnerabilityHandler));
         registry.register(Box::new(security::SuggestFixHandler));
+        registry.register(Box::new(security::QueryCodePathsHandler));
+        registry.register(Box::new(security::CreateSecurityBaselineHandler));
 
         // Register supply chain handlers
         registry.register(Box::new(supply_chain::GenerateSbomHandler));
@@ -139,6 +158,15 @@ impl ToolRegistry {
 
         // Register analysis handlers
         registry.register(Box::new(analysis::GetControlFlowHandler));
+        registry.register(Box::new(analysis::ExplainFunctionHandler));
+        registry.register(Box::new(analysis::GetRoutesHandler));
+        registry.register(Box::new(analysis::FindConfigUsageHandler));
+        registry.register(Box::new(analysis::FindFeatureFlagsHandler));
+        registry.register(Box::new(analysis::AuditErrorHandlingHandler));
+        registry.register(Box::new(analysis::AuditConcurrencyHandler));
+        registry.register(Box::new(analysis::ScanAsyncBlockingHandler));
+        registry.register(Box::new(analysis::FindRaceConditionsHandler));
+        registry.register(Box::new(analysis::GetExecutionPathsHandler));
         registry.register(Box::new(analysis::FindDeadCodeHandler));
         registry.register(Box::new(analysis::GetDataFlowHandler));
         registry.register(Box::new(analysis::GetReachingDefinitionsHandler));
@@ -147,7 +175,12 @@ impl ToolRegistry {
         registry.register(Box::new(analysis::InferTypesHandler));
         registry.register(Box::new(analysis::CheckTypeErrorsHandler));
         registry.register(Box::new(analysis::GetTypedTaintFlowHandler));
+        registry.register(Box::new(analysis::FindNullRisksHandler));
+        registry.register(Box::new(analysis::LoadCoverageHandler));
+        registry.register(Box::new(analysis::FindUncoveredFunctionsHandler));
         registry.register(Box::new(analysis::GetImportGraphHandler));
+        registry.register(Box::new(analysis::FindTodosHandler));
+        registry.register(Box::new(analysis::CheckArchitectureHandler));
         registry.register(Box::new(analysis::FindCircularImportsHandler));
 
         // Register graph visualization handler
@@ -176,11 +209,20 @@ impl ToolRegistry {
         engine: &CodeIntelEngine,
         args: Value,
     ) -> Result<String> {
-        self.handlers
+        let handler = self
+            .handlers
             .get(name)
-            .ok_or_else(|| anyhow::anyhow!("Unknown tool: {}", name))?
-            .execute(engine, args)
-            .await
+            .ok_or_else(|| anyhow::anyhow!("Unknown tool: {}", name))?;
+
+        // Lazy mode: make sure the target repo is indexed before the tool
+        // runs (no-op unless the repo was deferred with --lazy)
+        if let Some(repo) = args.get_str("repo") {
+            if let Err(e) = engine.ensure_repo_indexed(repo).await {
+                tracing::warn!("Lazy indexing of {} failed: {}", repo, e);
+            }
+        }
+
+        handler.execute(engine, args).await
     }
 
     /// Check if a tool exists
@@ -22,7 +22,10 @@ impl ToolHandler for AddRemoteRepoHandler {
                 .filter_map(|v| v.as_str().map(|s| s.to_string()))
                 .collect()
         });
-        engine.add_remote_repo(url, sparse_paths.as_deref()).await
+        let tarball = args.get_bool_or("tarball", false);
+        engine
+            .add_remote_repo(url, sparse_paths.as_deref(), tarball)
+            .await
     }
 }
 
@@ -57,3 +60,17 @@ impl ToolHandler for GetRemoteFileHandler {
         engine.get_remote_file(url, path).await
     }
 }
+
+/// Handler for get_remote_stats tool
+pub struct GetRemoteStatsHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for GetRemoteStatsHandler {
+    fn name(&self) -> &'static str {
+        "get_remote_stats"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, _args: Value) -> Result<String> {
+        engine.get_remote_stats().await
+    }
+}
@@ -177,3 +177,21 @@ impl ToolHandler for GetMetricsHandler {
         engine.get_metrics(format).await
     }
 }
+
+/// Handler for apply_patch tool
+pub struct ApplyPatchHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for ApplyPatchHandler {
+    fn name(&self) -> &'static str {
+        "apply_patch"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo").unwrap_or("");
+        let path = args.get_str("path").unwrap_or("");
+        let patch = args.get_str("patch").unwrap_or("");
+        let dry_run = args.get_bool_or("dry_run", false);
+        engine.apply_patch(repo, path, patch, dry_run).await
+    }
+}
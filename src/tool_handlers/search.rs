@@ -4,7 +4,18 @@ use anyhow::Result;
 use serde_json::Value;
 
 use super::{ArgExtractor, ToolHandler};
-use crate::index::CodeIntelEngine;
+use crate::index::{CodeIntelEngine, SearchFilters};
+
+/// Extract the common search filters (`exclude_tests`, `only_tests`,
+/// `exclude_generated`, `path_glob`) from tool arguments
+fn extract_filters(args: &Value) -> SearchFilters {
+    SearchFilters::from_args(
+        args.get_bool("exclude_tests"),
+        args.get_bool("only_tests"),
+        args.get_bool("exclude_generated"),
+        args.get_str("path_glob"),
+    )
+}
 
 /// Handler for search_code tool
 pub struct SearchCodeHandler;
@@ -20,9 +31,9 @@ impl ToolHandler for SearchCodeHandler {
         let query = args.get_str("query").unwrap_or("");
         let file_pattern = args.get_str("file_pattern");
         let max_results = args.get_u64_or("max_results", 10) as usize;
-        let exclude_tests = args.get_bool("exclude_tests");
+        let filters = extract_filters(&args);
         engine
-            .search_code(repo, query, file_pattern, max_results, exclude_tests)
+            .search_code(repo, query, file_pattern, max_results, filters)
             .await
     }
 }
@@ -41,9 +52,9 @@ impl ToolHandler for SemanticSearchHandler {
         let query = args.get_str("query").unwrap_or("");
         let max_results = args.get_u64_or("max_results", 10) as usize;
         let doc_type = args.get_str("doc_type");
-        let exclude_tests = args.get_bool("exclude_tests");
+        let filters = extract_filters(&args);
         engine
-            .semantic_search(repo, query, max_results, doc_type, exclude_tests)
+            .semantic_search(repo, query, max_results, doc_type, filters)
             .await
     }
 }
@@ -62,13 +73,30 @@ impl ToolHandler for HybridSearchHandler {
         let query = args.get_str("query").unwrap_or("");
         let max_results = args.get_u64_or("max_results", 10) as usize;
         let mode = args.get_str("mode").unwrap_or("hybrid");
-        let exclude_tests = args.get_bool("exclude_tests");
+        let filters = extract_filters(&args);
         engine
-            .hybrid_search(query, repo, max_results, mode, exclude_tests)
+            .hybrid_search(query, repo, max_results, mode, filters)
             .await
     }
 }
 
+/// Handler for get_context_pack tool
+pub struct GetContextPackHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for GetContextPackHandler {
+    fn name(&self) -> &'static str {
+        "get_context_pack"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let task = args.get_str("task").unwrap_or("");
+        let repo = args.get_str("repo");
+        let token_budget = args.get_u64_or("token_budget", 8000) as usize;
+        engine.get_context_pack(task, repo, token_budget).await
+    }
+}
+
 /// Handler for neural_search tool
 pub struct NeuralSearchHandler;
 
@@ -6,6 +6,36 @@ use serde_json::Value;
 use super::{ArgExtractor, ToolHandler};
 use crate::index::CodeIntelEngine;
 
+/// Handler for find_unprotected_routes tool
+pub struct FindUnprotectedRoutesHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for FindUnprotectedRoutesHandler {
+    fn name(&self) -> &'static str {
+        "find_unprotected_routes"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo");
+        engine.find_unprotected_routes(repo).await
+    }
+}
+
+/// Handler for find_unsafe tool
+pub struct FindUnsafeHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for FindUnsafeHandler {
+    fn name(&self) -> &'static str {
+        "find_unsafe"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo");
+        engine.find_unsafe(repo).await
+    }
+}
+
 /// Handler for scan_security tool
 ///
 /// Phase C2: Added max_findings and offset parameters for pagination
@@ -25,6 +55,7 @@ impl ToolHandler for ScanSecurityHandler {
         let exclude_tests = args.get_bool("exclude_tests");
         let max_findings = args.get_u64("max_findings").map(|v| v as usize);
         let offset = args.get_u64("offset").map(|v| v as usize);
+        let rule_id = args.get_str("rule_id");
         engine
             .scan_security(
                 repo,
@@ -34,6 +65,7 @@ impl ToolHandler for ScanSecurityHandler {
                 exclude_tests,
                 max_findings,
                 offset,
+                rule_id,
             )
             .await
     }
@@ -52,7 +84,11 @@ impl ToolHandler for CheckOwaspTop10Handler {
         let repo = args.get_str("repo").unwrap_or("");
         let path = args.get_str("path");
         let exclude_tests = args.get_bool("exclude_tests");
-        engine.check_owasp_top10(repo, path, exclude_tests).await
+        let min_severity = args.get_str("min_severity");
+        let rule_id = args.get_str("rule_id");
+        engine
+            .check_owasp_top10(repo, path, exclude_tests, min_severity, rule_id)
+            .await
     }
 }
 
@@ -69,7 +105,11 @@ impl ToolHandler for CheckCweTop25Handler {
         let repo = args.get_str("repo").unwrap_or("");
         let path = args.get_str("path");
         let exclude_tests = args.get_bool("exclude_tests");
-        engine.check_cwe_top25(repo, path, exclude_tests).await
+        let min_severity = args.get_str("min_severity");
+        let rule_id = args.get_str("rule_id");
+        engine
+            .check_cwe_top25(repo, path, exclude_tests, min_severity, rule_id)
+            .await
     }
 }
 
@@ -94,8 +134,15 @@ impl ToolHandler for FindInjectionVulnerabilitiesHandler {
                     .collect()
             })
             .unwrap_or_else(|| vec!["all".to_string()]);
+        let min_severity = args.get_str("min_severity");
         engine
-            .find_injection_vulnerabilities(repo, path, exclude_tests, &vulnerability_types)
+            .find_injection_vulnerabilities(
+                repo,
+                path,
+                exclude_tests,
+                &vulnerability_types,
+                min_severity,
+            )
             .await
     }
 }
@@ -193,3 +240,53 @@ impl ToolHandler for SuggestFixHandler {
         engine.suggest_fix(repo, path, line, rule_id).await
     }
 }
+
+/// Handler for create_security_baseline tool
+pub struct CreateSecurityBaselineHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for CreateSecurityBaselineHandler {
+    fn name(&self) -> &'static str {
+        "create_security_baseline"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo").unwrap_or("");
+        let path = args.get_str("path");
+        engine.create_security_baseline(repo, path).await
+    }
+}
+
+/// Handler for query_code_paths tool
+pub struct QueryCodePathsHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for QueryCodePathsHandler {
+    fn name(&self) -> &'static str {
+        "query_code_paths"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo").unwrap_or("");
+        let source_pattern = args.get_str("source_pattern").unwrap_or("");
+        let sink_pattern = args.get_str("sink_pattern").unwrap_or("");
+        let sanitizer_patterns: Vec<String> = args
+            .get_array("sanitizer_patterns")
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let path = args.get_str("path");
+        engine
+            .query_code_paths(
+                repo,
+                source_pattern,
+                sink_pattern,
+                &sanitizer_patterns,
+                path,
+            )
+            .await
+    }
+}
@@ -119,6 +119,39 @@ impl ToolHandler for GetExportMapHandler {
     }
 }
 
+/// Handler for get_api_surface tool
+pub struct GetApiSurfaceHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for GetApiSurfaceHandler {
+    fn name(&self) -> &'static str {
+        "get_api_surface"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo").unwrap_or("");
+        let package = args.get_str("package");
+        engine.get_api_surface(repo, package).await
+    }
+}
+
+/// Handler for get_doc_coverage tool
+pub struct GetDocCoverageHandler;
+
+#[async_trait::async_trait]
+impl ToolHandler for GetDocCoverageHandler {
+    fn name(&self) -> &'static str {
+        "get_doc_coverage"
+    }
+
+    async fn execute(&self, engine: &CodeIntelEngine, args: Value) -> Result<String> {
+        let repo = args.get_str("repo").unwrap_or("");
+        let package = args.get_str("package");
+        let limit = args.get_u64_or("limit", 50) as usize;
+        engine.get_doc_coverage(repo, package, limit).await
+    }
+}
+
 /// Handler for workspace_symbol_search tool
 pub struct WorkspaceSymbolSearchHandler;
 
@@ -1,6 +1,6 @@
 /// Tool Metadata Registry
 ///
-/// This module provides comprehensive metadata for all 75 MCP tools,
+/// This module provides comprehensive metadata for all 108 MCP tools,
 /// including categorization, performance indicators, required feature flags,
 /// and JSON schemas.
 use lazy_static::lazy_static;
@@ -97,6 +97,7 @@ pub enum FeatureFlag {
     Remote,
     Persist,
     Watch,
+    Write,
 }
 
 impl ToolMetadata {
@@ -126,7 +127,7 @@ lazy_static! {
     pub static ref TOOL_METADATA: HashMap<&'static str, ToolMetadata> = {
         let mut map = HashMap::new();
 
-        // ===== Repository Tools (10) =====
+        // ===== Repository Tools (11) =====
 
         map.insert("list_repos", ToolMetadata {
             name: "list_repos",
@@ -323,7 +324,29 @@ lazy_static! {
             aliases: vec!["performance", "stats"],
         });
 
-        // ===== Symbol Tools (7) =====
+        map.insert("apply_patch", ToolMetadata {
+            name: "apply_patch",
+            description: "Apply a unified diff to a repository file. Validates the patch against current content, checks the result still parses, and re-indexes the file. Requires --allow-writes.",
+            category: ToolCategory::Repository,
+            tags: ["patch", "diff", "write", "fix", "edit"].iter().copied().collect(),
+            stability: StabilityLevel::Experimental,
+            performance: PerformanceImpact::Medium,
+            required_flags: [FeatureFlag::Write].iter().copied().collect(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string"},
+                    "path": {"type": "string", "description": "File path relative to the repository root"},
+                    "patch": {"type": "string", "description": "Unified diff to apply (as produced by suggest_fix)"},
+                    "dry_run": {"type": "boolean", "description": "Validate the patch without writing (default: false)"}
+                },
+                "required": ["repo", "path", "patch"]
+            }),
+            requires_api_key: false,
+            aliases: vec!["patch", "apply_diff"],
+        });
+
+        // ===== Symbol Tools (9) =====
 
         map.insert("find_symbols", ToolMetadata {
             name: "find_symbols",
@@ -454,6 +477,47 @@ lazy_static! {
             aliases: vec!["exports", "export_map"],
         });
 
+        map.insert("get_api_surface", ToolMetadata {
+            name: "get_api_surface",
+            description: "Extract the public API surface of a crate/package (signatures, visibility, doc summaries) as a stable, diff-friendly report for API review.",
+            category: ToolCategory::Symbols,
+            tags: ["api", "public", "surface", "exports", "review"].iter().copied().collect(),
+            stability: StabilityLevel::Beta,
+            performance: PerformanceImpact::Medium,
+            required_flags: HashSet::new(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string"},
+                    "package": {"type": "string", "description": "Restrict to a package/crate subdirectory (e.g. crates/core)"}
+                },
+                "required": ["repo"]
+            }),
+            requires_api_key: false,
+            aliases: vec!["api_surface", "public_api"],
+        });
+
+        map.insert("get_doc_coverage", ToolMetadata {
+            name: "get_doc_coverage",
+            description: "Report the percentage of public symbols with doc comments per module, listing undocumented public APIs.",
+            category: ToolCategory::Symbols,
+            tags: ["documentation", "coverage", "api", "doc_comments"].iter().copied().collect(),
+            stability: StabilityLevel::Beta,
+            performance: PerformanceImpact::Medium,
+            required_flags: HashSet::new(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string"},
+                    "package": {"type": "string", "description": "Restrict to a package/crate subdirectory"},
+                    "limit": {"type": "number", "description": "Max undocumented symbols to list (default: 50)"}
+                },
+                "required": ["repo"]
+            }),
+            requires_api_key: false,
+            aliases: vec!["doc_coverage", "undocumented_apis"],
+        });
+
         map.insert("workspace_symbol_search", ToolMetadata {
             name: "workspace_symbol_search",
             description: "Fuzzy search for symbols across the entire workspace. Uses trigram matching for typo-tolerant search.",
@@ -475,7 +539,7 @@ lazy_static! {
             aliases: vec!["search_symbols", "fuzzy_symbols"],
         });
 
-        // ===== Search Tools (12) =====
+        // ===== Search Tools (13) =====
 
         map.insert("search_code", ToolMetadata {
             name: "search_code",
@@ -492,7 +556,10 @@ lazy_static! {
                     "repo": {"type": "string", "description": "Repository name (optional, searches all if omitted)"},
                     "file_pattern": {"type": "string", "description": "Glob pattern to filter files"},
                     "max_results": {"type": "integer", "description": "Maximum results to return (default: 10)"},
-                    "exclude_tests": {"type": "boolean", "description": "Exclude test files from results (default: false)"}
+                    "exclude_tests": {"type": "boolean", "description": "Exclude test files from results (default: false)"},
+                    "only_tests": {"type": "boolean", "description": "Only include test files (default: false)"},
+                    "exclude_generated": {"type": "boolean", "description": "Exclude generated files like protobuf output, minified bundles, and lockfiles (default: false)"},
+                    "path_glob": {"type": "string", "description": "Only include paths matching this glob (e.g. src/**/*.rs)"}
                 },
                 "required": ["query"]
             }),
@@ -515,7 +582,10 @@ lazy_static! {
                     "repo": {"type": "string", "description": "Repository name (optional, searches all if omitted)"},
                     "doc_type": {"type": "string", "enum": ["file", "function", "class", "struct", "method"], "description": "Filter by document type"},
                     "max_results": {"type": "integer", "description": "Maximum results to return (default: 10)"},
-                    "exclude_tests": {"type": "boolean", "description": "Exclude test files from results (default: false)"}
+                    "exclude_tests": {"type": "boolean", "description": "Exclude test files from results (default: false)"},
+                    "only_tests": {"type": "boolean", "description": "Only include test files (default: false)"},
+                    "exclude_generated": {"type": "boolean", "description": "Exclude generated files like protobuf output, minified bundles, and lockfiles (default: false)"},
+                    "path_glob": {"type": "string", "description": "Only include paths matching this glob (e.g. src/**/*.rs)"}
                 },
                 "required": ["query"]
             }),
@@ -538,7 +608,10 @@ lazy_static! {
                     "repo": {"type": "string", "description": "Optional: limit to specific repository"},
                     "max_results": {"type": "integer", "description": "Maximum results to return (default: 10)"},
                     "mode": {"type": "string", "enum": ["hybrid", "bm25", "tfidf"], "description": "Search mode: hybrid (default), bm25 only, or tfidf only"},
-                    "exclude_tests": {"type": "boolean", "description": "Exclude test files from results (default: false)"}
+                    "exclude_tests": {"type": "boolean", "description": "Exclude test files from results (default: false)"},
+                    "only_tests": {"type": "boolean", "description": "Only include test files (default: false)"},
+                    "exclude_generated": {"type": "boolean", "description": "Exclude generated files like protobuf output, minified bundles, and lockfiles (default: false)"},
+                    "path_glob": {"type": "string", "description": "Only include paths matching this glob (e.g. src/**/*.rs)"}
                 },
                 "required": ["query"]
             }),
@@ -546,6 +619,27 @@ lazy_static! {
             aliases: vec!["combined_search", "rrf_search"],
         });
 
+        map.insert("get_context_pack", ToolMetadata {
+            name: "get_context_pack",
+            description: "Assemble a token-budgeted context bundle for an agent task: hybrid search results expanded into symbol definitions with immediate callers/callees, plus project configuration.",
+            category: ToolCategory::Search,
+            tags: ["context", "search", "agent", "bundle", "hybrid"].iter().copied().collect(),
+            stability: StabilityLevel::Beta,
+            performance: PerformanceImpact::High,
+            required_flags: HashSet::new(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "task": {"type": "string", "description": "Natural-language description of the task needing context"},
+                    "repo": {"type": "string", "description": "Optional: limit to specific repository"},
+                    "token_budget": {"type": "integer", "description": "Approximate token budget for the bundle (default: 8000)"}
+                },
+                "required": ["task"]
+            }),
+            requires_api_key: false,
+            aliases: vec!["context_pack", "assemble_context"],
+        });
+
         map.insert("neural_search", ToolMetadata {
             name: "neural_search",
             description: "Search code using neural semantic embeddings. Finds semantically similar code even with different variable names. Requires --neural flag and EMBEDDING_API_KEY.",
@@ -657,9 +751,9 @@ lazy_static! {
 
         map.insert("get_embedding_stats", ToolMetadata {
             name: "get_embedding_stats",
-            description: "Get statistics about the embedding index.",
+            description: "Get statistics about the embedding index, including neural embedding cache hit rates when neural search is enabled.",
             category: ToolCategory::Search,
-            tags: ["stats", "embedding", "tfidf", "index"].iter().copied().collect(),
+            tags: ["stats", "embedding", "tfidf", "index", "cache"].iter().copied().collect(),
             stability: StabilityLevel::Stable,
             performance: PerformanceImpact::Low,
             required_flags: HashSet::new(),
@@ -853,7 +947,89 @@ lazy_static! {
             aliases: vec!["hotspots", "function_hotspots"],
         });
 
-        // ===== Git Tools (9) =====
+        map.insert("export_call_graph", ToolMetadata {
+            name: "export_call_graph",
+            description: "Export the call graph in GraphML, Cytoscape JSON, or DOT format with node metadata (file, complexity, LOC) for Gephi or custom dashboards. Requires --call-graph flag.",
+            category: ToolCategory::CallGraph,
+            tags: ["callgraph", "export", "graphml", "visualization"].iter().copied().collect(),
+            stability: StabilityLevel::Beta,
+            performance: PerformanceImpact::Low,
+            required_flags: [FeatureFlag::CallGraph].iter().copied().collect(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string"},
+                    "format": {"type": "string", "description": "Export format: graphml, cytoscape, or dot (default: graphml)"},
+                    "file": {"type": "string", "description": "Only include functions defined in files matching this substring (optional)"}
+                },
+                "required": ["repo"]
+            }),
+            requires_api_key: false,
+            aliases: vec!["graph_export", "call_graph_export"],
+        });
+
+        map.insert("get_key_functions", ToolMetadata {
+            name: "get_key_functions",
+            description: "Rank the most structurally important functions by PageRank centrality over the call graph - a good starting point for exploring an unfamiliar codebase. Requires --call-graph flag.",
+            category: ToolCategory::CallGraph,
+            tags: ["callgraph", "pagerank", "importance", "exploration"].iter().copied().collect(),
+            stability: StabilityLevel::Beta,
+            performance: PerformanceImpact::Medium,
+            required_flags: [FeatureFlag::CallGraph].iter().copied().collect(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string"},
+                    "limit": {"type": "integer", "description": "Maximum number of functions to return (default: 20)"}
+                },
+                "required": ["repo"]
+            }),
+            requires_api_key: false,
+            aliases: vec!["key_functions", "important_functions"],
+        });
+
+        map.insert("find_recursion_cycles", ToolMetadata {
+            name: "find_recursion_cycles",
+            description: "Find mutually recursive function groups (strongly connected components of the call graph) with members and entry edges. Useful for stack-depth and termination reviews. Requires --call-graph flag.",
+            category: ToolCategory::CallGraph,
+            tags: ["callgraph", "recursion", "cycles", "analysis"].iter().copied().collect(),
+            stability: StabilityLevel::Beta,
+            performance: PerformanceImpact::Medium,
+            required_flags: [FeatureFlag::CallGraph].iter().copied().collect(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string"}
+                },
+                "required": ["repo"]
+            }),
+            requires_api_key: false,
+            aliases: vec!["recursion_cycles", "find_sccs"],
+        });
+
+        map.insert("get_impacted_tests", ToolMetadata {
+            name: "get_impacted_tests",
+            description: "Find test files/functions likely affected by a diff or ref range, by mapping changed lines to functions and walking the call graph backwards to tests. Ideal for CI test selection. Requires --git and --call-graph flags.",
+            category: ToolCategory::CallGraph,
+            tags: ["tests", "callgraph", "diff", "ci"].iter().copied().collect(),
+            stability: StabilityLevel::Beta,
+            performance: PerformanceImpact::Medium,
+            required_flags: [FeatureFlag::Git, FeatureFlag::CallGraph].iter().copied().collect(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string"},
+                    "base": {"type": "string", "description": "Base ref to diff from (default: HEAD)"},
+                    "head": {"type": "string", "description": "Head ref to diff to; omit to diff against the working tree"},
+                    "max_depth": {"type": "integer", "description": "Maximum caller depth to walk (default: 10)"}
+                },
+                "required": ["repo"]
+            }),
+            requires_api_key: false,
+            aliases: vec!["impacted_tests", "test_impact"],
+        });
+
+        // ===== Git Tools (12) =====
 
         map.insert("get_blame", ToolMetadata {
             name: "get_blame",
@@ -1040,7 +1216,70 @@ lazy_static! {
             aliases: vec!["modified_files", "git_status"],
         });
 
-        // ===== LSP Tools (3) =====
+        map.insert("get_owners", ToolMetadata {
+            name: "get_owners",
+            description: "Determine who owns a file or symbol, combining CODEOWNERS rules with git contributor statistics. Contributor data requires --git flag.",
+            category: ToolCategory::Git,
+            tags: ["git", "owners", "codeowners", "ownership", "contributors"].iter().copied().collect(),
+            stability: StabilityLevel::Beta,
+            performance: PerformanceImpact::Low,
+            required_flags: HashSet::new(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string"},
+                    "path": {"type": "string", "description": "File path relative to the repo root"},
+                    "symbol": {"type": "string", "description": "Symbol name (used when path is omitted)"}
+                },
+                "required": ["repo"]
+            }),
+            requires_api_key: false,
+            aliases: vec!["owners", "code_owners"],
+        });
+
+        map.insert("check_breaking_changes", ToolMetadata {
+            name: "check_breaking_changes",
+            description: "Compare the public API surface between two refs and classify changes as major/minor/patch (removed symbols, changed signatures, narrowed enums). Requires --git flag.",
+            category: ToolCategory::Git,
+            tags: ["git", "semver", "breaking", "api", "diff"].iter().copied().collect(),
+            stability: StabilityLevel::Beta,
+            performance: PerformanceImpact::Medium,
+            required_flags: [FeatureFlag::Git].iter().copied().collect(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string"},
+                    "base": {"type": "string", "description": "Base ref to compare from (default: HEAD~1)"},
+                    "head": {"type": "string", "description": "Head ref to compare to (default: HEAD)"}
+                },
+                "required": ["repo"]
+            }),
+            requires_api_key: false,
+            aliases: vec!["breaking_changes", "semver_check"],
+        });
+
+        map.insert("check_commit_conventions", ToolMetadata {
+            name: "check_commit_conventions",
+            description: "Validate recent commit messages against conventional-commit rules and report violation stats per author. Requires --git flag.",
+            category: ToolCategory::Git,
+            tags: ["git", "commits", "conventions", "lint", "release"].iter().copied().collect(),
+            stability: StabilityLevel::Beta,
+            performance: PerformanceImpact::Low,
+            required_flags: [FeatureFlag::Git].iter().copied().collect(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string"},
+                    "max_commits": {"type": "integer", "description": "Number of recent commits to check (default: 50)"},
+                    "types": {"type": "string", "description": "Comma-separated allowed commit types (default: feat,fix,docs,style,refactor,perf,test,build,ci,chore,revert)"}
+                },
+                "required": ["repo"]
+            }),
+            requires_api_key: false,
+            aliases: vec!["commit_conventions", "lint_commits"],
+        });
+
+        // ===== LSP Tools (6) =====
 
         map.insert("get_hover_info", ToolMetadata {
             name: "get_hover_info",
@@ -1108,11 +1347,72 @@ lazy_static! {
             aliases: vec!["definition", "goto_def"],
         });
 
-        // ===== Remote Tools (3) =====
+        map.insert("get_diagnostics", ToolMetadata {
+            name: "get_diagnostics",
+            description: "Get compiler/linter diagnostics published by language servers, filterable by file and severity. Requires LSP to be enabled.",
+            category: ToolCategory::Lsp,
+            tags: ["lsp", "diagnostics", "errors", "lint"].iter().copied().collect(),
+            stability: StabilityLevel::Stable,
+            performance: PerformanceImpact::Medium,
+            required_flags: [FeatureFlag::Lsp].iter().copied().collect(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string"},
+                    "path": {"type": "string", "description": "Optional file path filter; the file is opened with the language server to trigger analysis"},
+                    "min_severity": {"type": "string", "description": "Minimum severity to report: error, warning, info, or hint (default: hint)"}
+                },
+                "required": ["repo"]
+            }),
+            requires_api_key: false,
+            aliases: vec!["diagnostics", "lint_errors"],
+        });
+
+        map.insert("rename_symbol", ToolMetadata {
+            name: "rename_symbol",
+            description: "Rename a symbol across the workspace via the language server. Returns the proposed edits as a reviewable diff; applying them requires --allow-writes.",
+            category: ToolCategory::Lsp,
+            tags: ["lsp", "rename", "refactor", "workspace-edit"].iter().copied().collect(),
+            stability: StabilityLevel::Experimental,
+            performance: PerformanceImpact::Medium,
+            required_flags: [FeatureFlag::Lsp].iter().copied().collect(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string"},
+                    "path": {"type": "string"},
+                    "line": {"type": "integer"},
+                    "character": {"type": "integer"},
+                    "new_name": {"type": "string", "description": "New name for the symbol"},
+                    "apply": {"type": "boolean", "description": "Write the edits and re-index (default: false, requires --allow-writes)"}
+                },
+                "required": ["repo", "path", "line", "character", "new_name"]
+            }),
+            requires_api_key: false,
+            aliases: vec!["rename", "refactor_rename"],
+        });
+
+        map.insert("get_lsp_status", ToolMetadata {
+            name: "get_lsp_status",
+            description: "Report the health of configured language servers: running state, crash restarts, and install hints for missing binaries.",
+            category: ToolCategory::Lsp,
+            tags: ["lsp", "status", "health", "diagnostics"].iter().copied().collect(),
+            stability: StabilityLevel::Stable,
+            performance: PerformanceImpact::Low,
+            required_flags: HashSet::new(), // Reports "not enabled" without LSP
+            input_schema: json!({
+                "type": "object",
+                "properties": {}
+            }),
+            requires_api_key: false,
+            aliases: vec!["lsp_status", "lsp_health"],
+        });
+
+        // ===== Remote Tools (4) =====
 
         map.insert("add_remote_repo", ToolMetadata {
             name: "add_remote_repo",
-            description: "Add a remote GitHub repository for indexing. Clones the repo to a temporary location.",
+            description: "Add a remote GitHub repository for indexing. Clones the repo to a temporary location. Raw file, blob, and gist URLs are indexed directly as virtual one-file repos.",
             category: ToolCategory::Remote,
             tags: ["remote", "github", "clone", "repository"].iter().copied().collect(),
             stability: StabilityLevel::Stable,
@@ -1121,8 +1421,9 @@ lazy_static! {
             input_schema: json!({
                 "type": "object",
                 "properties": {
-                    "url": {"type": "string", "description": "GitHub URL (e.g., github.com/owner/repo or https://github.com/owner/repo)"},
-                    "sparse_paths": {"type": "array", "items": {"type": "string"}, "description": "Optional: only clone these paths for efficiency"}
+                    "url": {"type": "string", "description": "GitHub URL: a repository (github.com/owner/repo), a single file (raw or /blob/ URL), or a gist (gist.github.com/id)"},
+                    "sparse_paths": {"type": "array", "items": {"type": "string"}, "description": "Optional: only clone these paths for efficiency"},
+                    "tarball": {"type": "boolean", "description": "Download and extract a tarball snapshot instead of cloning (no git executable needed)", "default": false}
                 },
                 "required": ["url"]
             }),
@@ -1170,7 +1471,23 @@ lazy_static! {
             aliases: vec!["remote_file", "fetch_file"],
         });
 
-        // ===== Security Tools (9) =====
+        map.insert("get_remote_stats", ToolMetadata {
+            name: "get_remote_stats",
+            description: "Show remote clone-cache statistics and remaining GitHub API quota. Useful for checking rate limits before heavy API use.",
+            category: ToolCategory::Remote,
+            tags: ["remote", "github", "stats", "rate-limit", "quota"].iter().copied().collect(),
+            stability: StabilityLevel::Stable,
+            performance: PerformanceImpact::Low,
+            required_flags: [FeatureFlag::Remote].iter().copied().collect(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {}
+            }),
+            requires_api_key: false,
+            aliases: vec!["remote_stats", "rate_limit"],
+        });
+
+        // ===== Security Tools (13) =====
 
         map.insert("scan_security", ToolMetadata {
             name: "scan_security",
@@ -1189,7 +1506,8 @@ lazy_static! {
                     "severity_threshold": {"type": "string", "enum": ["critical", "high", "medium", "low", "info"], "description": "Minimum severity level to report (default: low)"},
                     "exclude_tests": {"type": "boolean", "description": "Exclude test files from scanning (default: true)"},
                     "max_findings": {"type": "integer", "description": "Maximum number of findings to return"},
-                    "offset": {"type": "integer", "description": "Skip this many findings before returning results"}
+                    "offset": {"type": "integer", "description": "Skip this many findings before returning results"},
+                    "rule_id": {"type": "string", "description": "Only report findings from this rule ID"}
                 },
                 "required": ["repo"]
             }),
@@ -1197,6 +1515,42 @@ lazy_static! {
             aliases: vec!["security", "scan", "vulnerabilities"],
         });
 
+        map.insert("find_unprotected_routes", ToolMetadata {
+            name: "find_unprotected_routes",
+            description: "Report HTTP routes lacking recognizable auth middleware or decorators, cross-referenced with taint sinks reachable from their handlers — the unauthenticated attack surface.",
+            category: ToolCategory::Security,
+            tags: ["security", "routes", "auth", "attack-surface", "taint"].iter().copied().collect(),
+            stability: StabilityLevel::Beta,
+            performance: PerformanceImpact::Medium,
+            required_flags: HashSet::new(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string", "description": "Optional: limit to specific repository"}
+                }
+            }),
+            requires_api_key: false,
+            aliases: vec!["attack_surface", "unauthenticated_endpoints"],
+        });
+
+        map.insert("find_unsafe", ToolMetadata {
+            name: "find_unsafe",
+            description: "Inventory every unsafe block, fn, impl, and trait in Rust code with enclosing function, span size, and whether a SAFETY comment is present, aggregated per file.",
+            category: ToolCategory::Security,
+            tags: ["security", "unsafe", "rust", "audit", "inventory"].iter().copied().collect(),
+            stability: StabilityLevel::Stable,
+            performance: PerformanceImpact::Low,
+            required_flags: HashSet::new(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string", "description": "Optional: limit to specific repository"}
+                }
+            }),
+            requires_api_key: false,
+            aliases: vec!["unsafe_inventory", "list_unsafe"],
+        });
+
         map.insert("check_owasp_top10", ToolMetadata {
             name: "check_owasp_top10",
             description: "Scan specifically for OWASP Top 10 2021 vulnerabilities including injection, broken auth, XSS, SSRF, etc.",
@@ -1210,7 +1564,9 @@ lazy_static! {
                 "properties": {
                     "repo": {"type": "string"},
                     "path": {"type": "string", "description": "Optional specific file or directory path to scan"},
-                    "exclude_tests": {"type": "boolean", "description": "Exclude test files from scanning (default: true)"}
+                    "exclude_tests": {"type": "boolean", "description": "Exclude test files from scanning (default: true)"},
+                    "min_severity": {"type": "string", "enum": ["critical", "high", "medium", "low", "info"], "description": "Minimum severity level to report (default: low)"},
+                    "rule_id": {"type": "string", "description": "Only report findings from this rule ID"}
                 },
                 "required": ["repo"]
             }),
@@ -1231,7 +1587,9 @@ lazy_static! {
                 "properties": {
                     "repo": {"type": "string"},
                     "path": {"type": "string", "description": "Optional specific file or directory path to scan"},
-                    "exclude_tests": {"type": "boolean", "description": "Exclude test files from scanning (default: true)"}
+                    "exclude_tests": {"type": "boolean", "description": "Exclude test files from scanning (default: true)"},
+                    "min_severity": {"type": "string", "enum": ["critical", "high", "medium", "low", "info"], "description": "Minimum severity level to report (default: low)"},
+                    "rule_id": {"type": "string", "description": "Only report findings from this rule ID"}
                 },
                 "required": ["repo"]
             }),
@@ -1252,8 +1610,9 @@ lazy_static! {
                 "properties": {
                     "repo": {"type": "string"},
                     "path": {"type": "string", "description": "Optional: specific file to analyze"},
-                    "vulnerability_types": {"type": "array", "items": {"type": "string", "enum": ["sql", "xss", "command", "path", "all"]}, "description": "Types of vulnerabilities to find (default: all)"},
-                    "exclude_tests": {"type": "boolean", "description": "Exclude test files from scanning (default: true)"}
+                    "vulnerability_types": {"type": "array", "items": {"type": "string", "enum": ["sql", "xss", "command", "path", "ssrf", "deserialization", "all"]}, "description": "Types of vulnerabilities to find (default: all)"},
+                    "exclude_tests": {"type": "boolean", "description": "Exclude test files from scanning (default: true)"},
+                    "min_severity": {"type": "string", "enum": ["critical", "high", "medium", "low", "info"], "description": "Minimum severity level to report (default: low)"}
                 },
                 "required": ["repo"]
             }),
@@ -1366,6 +1725,49 @@ lazy_static! {
             aliases: vec!["fix", "remediation"],
         });
 
+        map.insert("query_code_paths", ToolMetadata {
+            name: "query_code_paths",
+            description: "Run a source-to-sink path query: specify source, sink, and optional sanitizer patterns, and get full code-flow paths (file/line steps) using taint analysis and the call graph.",
+            category: ToolCategory::Security,
+            tags: ["security", "taint", "path", "query", "dataflow"].iter().copied().collect(),
+            stability: StabilityLevel::Experimental,
+            performance: PerformanceImpact::High,
+            required_flags: HashSet::new(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string"},
+                    "source_pattern": {"type": "string", "description": "Pattern identifying taint sources (e.g., request.args)"},
+                    "sink_pattern": {"type": "string", "description": "Pattern identifying dangerous sinks (e.g., cursor.execute)"},
+                    "sanitizer_patterns": {"type": "array", "items": {"type": "string"}, "description": "Patterns that break the flow (optional)"},
+                    "path": {"type": "string", "description": "Limit the query to a file or directory (optional)"}
+                },
+                "required": ["repo", "source_pattern", "sink_pattern"]
+            }),
+            requires_api_key: false,
+            aliases: vec!["path_query", "source_to_sink"],
+        });
+
+        map.insert("create_security_baseline", ToolMetadata {
+            name: "create_security_baseline",
+            description: "Snapshot current security findings into .narsil-baseline.json so later scans report only new issues. Inline 'narsil:ignore RULE reason' comments also suppress findings.",
+            category: ToolCategory::Security,
+            tags: ["security", "baseline", "suppression", "ci"].iter().copied().collect(),
+            stability: StabilityLevel::Stable,
+            performance: PerformanceImpact::High,
+            required_flags: HashSet::new(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string"},
+                    "path": {"type": "string", "description": "Limit the baseline to a file or directory (optional)"}
+                },
+                "required": ["repo"]
+            }),
+            requires_api_key: false,
+            aliases: vec!["baseline", "security_baseline"],
+        });
+
         // ===== Supply Chain Tools (4) =====
 
         map.insert("generate_sbom", ToolMetadata {
@@ -1451,7 +1853,155 @@ lazy_static! {
             aliases: vec!["upgrade_path", "upgrade"],
         });
 
-        // ===== Analysis Tools (11) =====
+        // ===== Analysis Tools (25) =====
+
+        map.insert("explain_function", ToolMetadata {
+            name: "explain_function",
+            description: "Explain a function in one report: signature, doc comment, control-flow summary (branches/loops), data-flow highlights, direct callers, and recent git history.",
+            category: ToolCategory::Analysis,
+            tags: ["analysis", "explain", "function", "summary", "composite"].iter().copied().collect(),
+            stability: StabilityLevel::Beta,
+            performance: PerformanceImpact::Medium,
+            required_flags: HashSet::new(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string"},
+                    "path": {"type": "string", "description": "File path relative to repository root"},
+                    "function": {"type": "string", "description": "Function name to explain"}
+                },
+                "required": ["repo", "path", "function"]
+            }),
+            requires_api_key: false,
+            aliases: vec!["function_summary", "describe_function"],
+        });
+
+        map.insert("get_routes", ToolMetadata {
+            name: "get_routes",
+            description: "Extract HTTP routes from popular web frameworks (axum, actix-web, Express, Flask, FastAPI, Spring) with method, path, handler symbol, and middleware.",
+            category: ToolCategory::Analysis,
+            tags: ["routes", "http", "api", "web", "endpoints"].iter().copied().collect(),
+            stability: StabilityLevel::Beta,
+            performance: PerformanceImpact::Medium,
+            required_flags: HashSet::new(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string", "description": "Optional: limit to specific repository"},
+                    "framework": {"type": "string", "enum": ["axum", "actix-web", "express", "flask", "fastapi", "spring"], "description": "Optional: only routes from this framework"}
+                }
+            }),
+            requires_api_key: false,
+            aliases: vec!["list_routes", "get_endpoints"],
+        });
+
+        map.insert("find_config_usage", ToolMetadata {
+            name: "find_config_usage",
+            description: "Inventory environment-variable and config-key reads (env::var, os.environ, process.env, config.get, ...) with default values and usage sites.",
+            category: ToolCategory::Analysis,
+            tags: ["config", "environment", "settings", "inventory"].iter().copied().collect(),
+            stability: StabilityLevel::Beta,
+            performance: PerformanceImpact::Medium,
+            required_flags: HashSet::new(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string", "description": "Optional: limit to specific repository"}
+                }
+            }),
+            requires_api_key: false,
+            aliases: vec!["env_usage", "config_inventory"],
+        });
+
+        map.insert("find_feature_flags", ToolMetadata {
+            name: "find_feature_flags",
+            description: "Detect feature-flag checks (LaunchDarkly, Unleash, cfg!(feature), FEATURE/FLAG env reads) with call sites, flagging apparently-dead flags never toggled in config.",
+            category: ToolCategory::Analysis,
+            tags: ["feature-flags", "launchdarkly", "unleash", "toggles", "dead-code"].iter().copied().collect(),
+            stability: StabilityLevel::Beta,
+            performance: PerformanceImpact::Medium,
+            required_flags: HashSet::new(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string", "description": "Optional: limit to specific repository"}
+                }
+            }),
+            requires_api_key: false,
+            aliases: vec!["feature_flags", "find_toggles"],
+        });
+
+        map.insert("audit_error_handling", ToolMetadata {
+            name: "audit_error_handling",
+            description: "Audit error handling: unwrap()/expect()/panic! in non-test Rust, bare except: in Python, swallowed promise rejections in JS — each with context and a risk rating (request handlers rank highest).",
+            category: ToolCategory::Analysis,
+            tags: ["errors", "panic", "unwrap", "audit", "robustness"].iter().copied().collect(),
+            stability: StabilityLevel::Beta,
+            performance: PerformanceImpact::Medium,
+            required_flags: HashSet::new(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string", "description": "Optional: limit to specific repository"}
+                }
+            }),
+            requires_api_key: false,
+            aliases: vec!["error_audit", "find_panics"],
+        });
+
+        map.insert("audit_concurrency", ToolMetadata {
+            name: "audit_concurrency",
+            description: "Catalog Mutex/RwLock/channel usage and flag locks held across .await or lock pairs acquired in inconsistent orders (one call-graph level deep).",
+            category: ToolCategory::Analysis,
+            tags: ["concurrency", "locks", "deadlock", "async", "audit"].iter().copied().collect(),
+            stability: StabilityLevel::Beta,
+            performance: PerformanceImpact::Medium,
+            required_flags: HashSet::new(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string", "description": "Optional: limit to specific repository"}
+                }
+            }),
+            requires_api_key: false,
+            aliases: vec!["concurrency_audit", "find_lock_issues"],
+        });
+
+        map.insert("scan_async_blocking", ToolMetadata {
+            name: "scan_async_blocking",
+            description: "Flag blocking calls (sync file I/O, thread::sleep, blocking HTTP clients, sync DB drivers) made inside async functions.",
+            category: ToolCategory::Analysis,
+            tags: ["async", "blocking", "executor", "performance", "audit"].iter().copied().collect(),
+            stability: StabilityLevel::Beta,
+            performance: PerformanceImpact::Medium,
+            required_flags: HashSet::new(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string", "description": "Optional: limit to specific repository"}
+                }
+            }),
+            requires_api_key: false,
+            aliases: vec!["async_blocking", "find_blocking_calls"],
+        });
+
+        map.insert("find_race_conditions", ToolMetadata {
+            name: "find_race_conditions",
+            description: "Heuristically detect check-then-use (TOCTOU) file patterns, static mut state, and thread/task spawns mutating shared variables without synchronization.",
+            category: ToolCategory::Analysis,
+            tags: ["race", "toctou", "concurrency", "audit"].iter().copied().collect(),
+            stability: StabilityLevel::Beta,
+            performance: PerformanceImpact::Medium,
+            required_flags: HashSet::new(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string", "description": "Optional: limit to specific repository"}
+                }
+            }),
+            requires_api_key: false,
+            aliases: vec!["race_audit", "find_toctou"],
+        });
 
         map.insert("get_control_flow", ToolMetadata {
             name: "get_control_flow",
@@ -1474,6 +2024,28 @@ lazy_static! {
             aliases: vec!["cfg", "control_flow"],
         });
 
+        map.insert("get_execution_paths", ToolMetadata {
+            name: "get_execution_paths",
+            description: "Enumerate feasible entry-to-exit paths through a function's CFG (bounded), listing the branch conditions along each path.",
+            category: ToolCategory::Analysis,
+            tags: ["cfg", "paths", "analysis", "testing"].iter().copied().collect(),
+            stability: StabilityLevel::Stable,
+            performance: PerformanceImpact::Medium,
+            required_flags: HashSet::new(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string"},
+                    "path": {"type": "string", "description": "File path containing the function"},
+                    "function": {"type": "string", "description": "Function name to analyze"},
+                    "max_paths": {"type": "number", "description": "Maximum number of paths to enumerate (default: 20)"}
+                },
+                "required": ["repo", "path", "function"]
+            }),
+            requires_api_key: false,
+            aliases: vec!["execution_paths", "paths"],
+        });
+
         map.insert("find_dead_code", ToolMetadata {
             name: "find_dead_code",
             description: "Find unreachable code blocks in a function or file using control flow analysis.",
@@ -1509,7 +2081,8 @@ lazy_static! {
                 "properties": {
                     "repo": {"type": "string"},
                     "path": {"type": "string"},
-                    "function": {"type": "string"}
+                    "function": {"type": "string"},
+                    "format": {"type": "string", "enum": ["markdown", "dot", "json"], "description": "Output format (default: markdown)"}
                 },
                 "required": ["repo", "path", "function"]
             }),
@@ -1645,6 +2218,69 @@ lazy_static! {
             aliases: vec!["typed_taint", "taint_flow"],
         });
 
+        map.insert("find_null_risks", ToolMetadata {
+            name: "find_null_risks",
+            description: "Find dereferences of possibly-None/null/undefined values and unchecked unwrap() calls. Combines data flow analysis with type inference.",
+            category: ToolCategory::Analysis,
+            tags: ["analysis", "null", "optional", "types", "bugs"].iter().copied().collect(),
+            stability: StabilityLevel::Beta,
+            performance: PerformanceImpact::Medium,
+            required_flags: HashSet::new(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string"},
+                    "path": {"type": "string"},
+                    "function": {"type": "string", "description": "Optional: specific function to analyze"},
+                    "exclude_tests": {"type": "boolean", "description": "Exclude test files from analysis (default: true)"}
+                },
+                "required": ["repo", "path"]
+            }),
+            requires_api_key: false,
+            aliases: vec!["null_risks", "null_safety"],
+        });
+
+        map.insert("load_coverage", ToolMetadata {
+            name: "load_coverage",
+            description: "Ingest an LCOV or Cobertura coverage report, attaching coverage percentages to symbols in the index.",
+            category: ToolCategory::Analysis,
+            tags: ["coverage", "tests", "lcov", "cobertura"].iter().copied().collect(),
+            stability: StabilityLevel::Beta,
+            performance: PerformanceImpact::Low,
+            required_flags: HashSet::new(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string"},
+                    "path": {"type": "string", "description": "Path to the coverage report (lcov.info or cobertura.xml), relative to the repo root"}
+                },
+                "required": ["repo", "path"]
+            }),
+            requires_api_key: false,
+            aliases: vec!["ingest_coverage", "coverage_load"],
+        });
+
+        map.insert("find_uncovered_functions", ToolMetadata {
+            name: "find_uncovered_functions",
+            description: "List functions whose line coverage falls below a threshold, worst first. Requires a report loaded with load_coverage.",
+            category: ToolCategory::Analysis,
+            tags: ["coverage", "tests", "quality", "analysis"].iter().copied().collect(),
+            stability: StabilityLevel::Beta,
+            performance: PerformanceImpact::Low,
+            required_flags: HashSet::new(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string"},
+                    "threshold": {"type": "number", "description": "Report functions below this line-coverage percentage (default: 100)"},
+                    "limit": {"type": "integer", "description": "Maximum number of functions to list (default: 50)"}
+                },
+                "required": ["repo"]
+            }),
+            requires_api_key: false,
+            aliases: vec!["uncovered_functions", "coverage_gaps"],
+        });
+
         map.insert("get_import_graph", ToolMetadata {
             name: "get_import_graph",
             description: "Build and analyze the import/dependency graph for a codebase. Shows which files import which other files, helps identify circular dependencies.",
@@ -1686,6 +2322,46 @@ lazy_static! {
             aliases: vec!["circular_imports", "import_cycles"],
         });
 
+        map.insert("find_todos", ToolMetadata {
+            name: "find_todos",
+            description: "Inventory TODO/FIXME/HACK comments with author and age from git blame, sorted oldest first. Attribution requires --git flag.",
+            category: ToolCategory::Analysis,
+            tags: ["todo", "fixme", "debt", "comments", "blame"].iter().copied().collect(),
+            stability: StabilityLevel::Beta,
+            performance: PerformanceImpact::Medium,
+            required_flags: HashSet::new(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string"},
+                    "marker": {"type": "string", "description": "Restrict to one marker (TODO, FIXME, HACK, XXX)"},
+                    "limit": {"type": "number", "description": "Max comments to list (default: 50)"}
+                },
+                "required": ["repo"]
+            }),
+            requires_api_key: false,
+            aliases: vec!["todos", "comment_debt"],
+        });
+
+        map.insert("check_architecture", ToolMetadata {
+            name: "check_architecture",
+            description: "Check configured architecture layering rules against the import graph. Reports violating imports with file and line.",
+            category: ToolCategory::Analysis,
+            tags: ["analysis", "architecture", "layers", "imports", "rules"].iter().copied().collect(),
+            stability: StabilityLevel::Beta,
+            performance: PerformanceImpact::Medium,
+            required_flags: HashSet::new(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "repo": {"type": "string"}
+                },
+                "required": ["repo"]
+            }),
+            requires_api_key: false,
+            aliases: vec!["architecture_check", "check_layers"],
+        });
+
         // ===== Graph Tools (1) =====
 
         map.insert("get_code_graph", ToolMetadata {
@@ -146,6 +146,30 @@ impl Type {
         }
     }
 
+    /// Check whether this type contains any unresolved type variables
+    pub fn contains_var(&self) -> bool {
+        match self {
+            Type::Var(_) => true,
+            Type::List(inner) | Type::Set(inner) | Type::Optional(inner) => inner.contains_var(),
+            Type::Dict(k, v) => k.contains_var() || v.contains_var(),
+            Type::Tuple(types) | Type::Union(types) => types.iter().any(|t| t.contains_var()),
+            Type::Function { params, ret } => {
+                params.iter().any(|t| t.contains_var()) || ret.contains_var()
+            }
+            Type::Instance { type_args, .. } => type_args.iter().any(|t| t.contains_var()),
+            _ => false,
+        }
+    }
+
+    /// Check whether this type can be None/null/undefined at runtime
+    pub fn is_nullable(&self) -> bool {
+        match self {
+            Type::None | Type::Optional(_) => true,
+            Type::Union(types) => types.iter().any(|t| t.is_nullable()),
+            _ => false,
+        }
+    }
+
     /// Get human-readable type name
     pub fn display_name(&self) -> String {
         match self {
@@ -299,6 +323,52 @@ impl TypeEnv {
         }
     }
 
+    /// Instantiate a polymorphic type by replacing each distinct type variable
+    /// with a fresh one, so separate call sites infer independently
+    pub fn instantiate(&mut self, ty: &Type, mapping: &mut HashMap<TypeVarId, TypeVarId>) -> Type {
+        match ty {
+            Type::Var(id) => {
+                let fresh = *mapping.entry(*id).or_insert_with(|| {
+                    let var = self.next_var;
+                    self.next_var += 1;
+                    var
+                });
+                Type::Var(fresh)
+            }
+            Type::List(inner) => Type::List(Box::new(self.instantiate(inner, mapping))),
+            Type::Dict(k, v) => Type::Dict(
+                Box::new(self.instantiate(k, mapping)),
+                Box::new(self.instantiate(v, mapping)),
+            ),
+            Type::Set(inner) => Type::Set(Box::new(self.instantiate(inner, mapping))),
+            Type::Tuple(types) => {
+                Type::Tuple(types.iter().map(|t| self.instantiate(t, mapping)).collect())
+            }
+            Type::Union(types) => {
+                Type::Union(types.iter().map(|t| self.instantiate(t, mapping)).collect())
+            }
+            Type::Optional(inner) => Type::Optional(Box::new(self.instantiate(inner, mapping))),
+            Type::Function { params, ret } => Type::Function {
+                params: params
+                    .iter()
+                    .map(|t| self.instantiate(t, mapping))
+                    .collect(),
+                ret: Box::new(self.instantiate(ret, mapping)),
+            },
+            Type::Instance {
+                class_name,
+                type_args,
+            } => Type::Instance {
+                class_name: class_name.clone(),
+                type_args: type_args
+                    .iter()
+                    .map(|t| self.instantiate(t, mapping))
+                    .collect(),
+            },
+            _ => ty.clone(),
+        }
+    }
+
     /// Add a type variable substitution
     pub fn add_substitution(&mut self, var: TypeVarId, ty: Type) {
         self.substitutions.insert(var, ty);
@@ -395,8 +465,16 @@ impl TypeStubs {
         stubs.builtins.insert(
             "list".to_string(),
             FunctionSig {
-                params: vec![("iterable".to_string(), Type::Unknown)],
-                ret: Type::List(Box::new(Type::Unknown)),
+                params: vec![("iterable".to_string(), Type::List(Box::new(Type::Var(0))))],
+                ret: Type::List(Box::new(Type::Var(0))),
+                is_method: false,
+            },
+        );
+        stubs.builtins.insert(
+            "sorted".to_string(),
+            FunctionSig {
+                params: vec![("iterable".to_string(), Type::List(Box::new(Type::Var(0))))],
+                ret: Type::List(Box::new(Type::Var(0))),
                 is_method: false,
             },
         );
@@ -411,8 +489,8 @@ impl TypeStubs {
         stubs.builtins.insert(
             "set".to_string(),
             FunctionSig {
-                params: vec![("iterable".to_string(), Type::Unknown)],
-                ret: Type::Set(Box::new(Type::Unknown)),
+                params: vec![("iterable".to_string(), Type::List(Box::new(Type::Var(0))))],
+                ret: Type::Set(Box::new(Type::Var(0))),
                 is_method: false,
             },
         );
@@ -893,6 +971,42 @@ impl TypeStubs {
                 is_method: false,
             },
         );
+        stubs.builtins.insert(
+            "Promise.resolve".to_string(),
+            FunctionSig {
+                params: vec![("value".to_string(), Type::Var(0))],
+                ret: Type::Instance {
+                    class_name: "Promise".to_string(),
+                    type_args: vec![Type::Var(0)],
+                },
+                is_method: false,
+            },
+        );
+        stubs.builtins.insert(
+            "Promise.all".to_string(),
+            FunctionSig {
+                params: vec![(
+                    "values".to_string(),
+                    Type::List(Box::new(Type::Instance {
+                        class_name: "Promise".to_string(),
+                        type_args: vec![Type::Var(0)],
+                    })),
+                )],
+                ret: Type::Instance {
+                    class_name: "Promise".to_string(),
+                    type_args: vec![Type::List(Box::new(Type::Var(0)))],
+                },
+                is_method: false,
+            },
+        );
+        stubs.builtins.insert(
+            "Array.from".to_string(),
+            FunctionSig {
+                params: vec![("iterable".to_string(), Type::List(Box::new(Type::Var(0))))],
+                ret: Type::List(Box::new(Type::Var(0))),
+                is_method: false,
+            },
+        );
 
         // Array methods
         let mut array_class = ClassDef {
@@ -1094,6 +1208,16 @@ impl TypeStubs {
             .get(class_name)
             .and_then(|c| c.attributes.get(attr))
     }
+
+    /// Register a user-defined class so attribute access can be typed
+    pub fn register_class(&mut self, class: ClassDef) {
+        self.classes.insert(class.name.clone(), class);
+    }
+
+    /// Check whether a class is known (builtin or registered)
+    pub fn has_class(&self, name: &str) -> bool {
+        self.classes.contains_key(name)
+    }
 }
 
 /// Type error during inference
@@ -1266,12 +1390,19 @@ pub struct TypeInferencer<'a> {
 impl<'a> TypeInferencer<'a> {
     /// Create a new type inferencer
     pub fn new(source: &'a str, cfg: Option<&'a ControlFlowGraph>, language: &str) -> Self {
-        let stubs = match language {
-            "python" | "py" => TypeStubs::python_stdlib(),
+        let mut stubs = match language {
             "javascript" | "js" | "typescript" | "ts" => TypeStubs::javascript_stdlib(),
             _ => TypeStubs::python_stdlib(),
         };
 
+        // Annotated dataclasses and Pydantic-style models in the source act
+        // as additional stubs so their fields get concrete types
+        if matches!(language, "python" | "py") {
+            for class in extract_annotated_classes(source) {
+                stubs.register_class(class);
+            }
+        }
+
         Self {
             source,
             cfg,
@@ -1329,10 +1460,21 @@ impl<'a> TypeInferencer<'a> {
             .into_iter()
             .map(|(n, t)| (n, self.env.substitute(&t)))
             .collect();
+        self.substitute_variable_types(&mut result);
 
         result
     }
 
+    /// Resolve per-line variable types once constraints are solved, so use
+    /// sites report concrete types instead of leftover type variables
+    fn substitute_variable_types(&self, result: &mut InferredTypes) {
+        for vars in result.variable_types.values_mut() {
+            for ty in vars.values_mut() {
+                *ty = self.env.substitute(ty);
+            }
+        }
+    }
+
     fn infer_block(&mut self, block_id: BlockId, result: &mut InferredTypes) -> Result<()> {
         let cfg = self.cfg.ok_or_else(|| anyhow!("No CFG available"))?;
         let block = cfg
@@ -1343,15 +1485,28 @@ impl<'a> TypeInferencer<'a> {
         for stmt in &block.statements {
             match &stmt.kind {
                 StatementKind::Assignment { variable } => {
-                    let rhs_type = self.infer_expr_from_text(&stmt.text)?;
-                    self.env.bind(variable.clone(), rhs_type.clone());
+                    // `name: annotation = value` assignments carry their own type
+                    let (name, annotation) = match variable.split_once(':') {
+                        Some((n, ann)) => (n.trim().to_string(), parse_annotation(ann)),
+                        None => (variable.clone(), None),
+                    };
+                    let rhs = stmt
+                        .text
+                        .split_once('=')
+                        .map(|(_, r)| r.trim())
+                        .unwrap_or(&stmt.text);
+                    let rhs_type = match annotation {
+                        Some(ty) => ty,
+                        None => self.infer_expr_from_text(rhs)?,
+                    };
+                    self.env.bind(name.clone(), rhs_type.clone());
 
                     // Record variable type at this line
                     result
                         .variable_types
                         .entry(stmt.line)
                         .or_default()
-                        .insert(variable.clone(), rhs_type);
+                        .insert(name, rhs_type);
                 }
                 StatementKind::Return => {
                     let ret_type = self.infer_expr_from_text(&stmt.text)?;
@@ -1386,14 +1541,36 @@ impl<'a> TypeInferencer<'a> {
             if let Some(ty) = self.env.lookup(text) {
                 return Ok(ty.clone());
             } else if let Some(sig) = self.stubs.lookup_builtin(text) {
+                let params: Vec<Type> = sig.params.iter().map(|(_, t)| t.clone()).collect();
+                let ret = sig.ret.clone();
+                // Instantiate so stub type variables never leak into the environment
+                let mut mapping = HashMap::new();
                 return Ok(Type::Function {
-                    params: sig.params.iter().map(|(_, t)| t.clone()).collect(),
-                    ret: Box::new(sig.ret.clone()),
+                    params: params
+                        .iter()
+                        .map(|t| self.env.instantiate(t, &mut mapping))
+                        .collect(),
+                    ret: Box::new(self.env.instantiate(&ret, &mut mapping)),
                 });
             }
             return Ok(self.env.fresh_var());
         }
 
+        // Awaiting a promise yields its resolved type
+        if let Some(inner) = text.strip_prefix("await ") {
+            let ty = self.infer_expr_from_text(inner)?;
+            if let Type::Instance {
+                class_name,
+                type_args,
+            } = &ty
+            {
+                if class_name == "Promise" && !type_args.is_empty() {
+                    return Ok(type_args[0].clone());
+                }
+            }
+            return Ok(ty);
+        }
+
         // Check for list literal
         if text.starts_with('[') && text.ends_with(']') {
             let inner = &text[1..text.len() - 1];
@@ -1414,27 +1591,77 @@ impl<'a> TypeInferencer<'a> {
             ));
         }
 
+        // Check for attribute access: `obj.attr`
+        if !text.contains('(') {
+            if let Some((obj_name, attr)) = text.split_once('.') {
+                if is_identifier(obj_name) && is_identifier(attr) {
+                    if let Some(obj_type) = self.env.lookup(obj_name) {
+                        let class_names: Vec<&str> = match obj_type {
+                            Type::String => vec!["str", "String"],
+                            Type::List(_) => vec!["list", "Array"],
+                            Type::Dict(_, _) => vec!["dict", "Object"],
+                            Type::Instance { class_name, .. } => vec![class_name.as_str()],
+                            _ => vec![],
+                        };
+                        for class_name in class_names {
+                            if let Some(ty) = self.stubs.lookup_attribute(class_name, attr) {
+                                return Ok(ty.clone());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
         // Check for function call
         if let Some(paren_idx) = text.find('(') {
             let func_name = text[..paren_idx].trim();
+            let args_text = text[paren_idx + 1..].strip_suffix(')').unwrap_or("");
+
             if let Some(sig) = self.stubs.lookup_builtin(func_name) {
-                return Ok(sig.ret.clone());
+                let params: Vec<Type> = sig.params.iter().map(|(_, t)| t.clone()).collect();
+                let ret = sig.ret.clone();
+                return self.instantiate_call(&params, &ret, args_text);
             }
+
+            // Constructors of known classes produce instances
+            if func_name.chars().next().is_some_and(|c| c.is_uppercase())
+                && self.stubs.has_class(func_name)
+            {
+                return Ok(Type::Instance {
+                    class_name: func_name.to_string(),
+                    type_args: vec![],
+                });
+            }
+
+            // User-defined (possibly generic) functions bound in the environment
+            if let Some(Type::Function { params, ret }) = self.env.lookup(func_name).cloned() {
+                return self.instantiate_call(&params, &ret, args_text);
+            }
+
             // Check for method call
             if let Some(dot_idx) = func_name.rfind('.') {
                 let obj_name = &func_name[..dot_idx];
                 let method_name = &func_name[dot_idx + 1..];
 
                 if let Some(obj_type) = self.env.lookup(obj_name) {
-                    let class_name = match obj_type {
-                        Type::String => "str",
-                        Type::List(_) => "list",
-                        Type::Dict(_, _) => "dict",
-                        Type::Instance { class_name, .. } => class_name.as_str(),
+                    // Python and JavaScript stubs name the same structural types
+                    // differently, so try both spellings
+                    let class_names: Vec<&str> = match obj_type {
+                        Type::String => vec!["str", "String"],
+                        Type::List(_) => vec!["list", "Array"],
+                        Type::Dict(_, _) => vec!["dict", "Object"],
+                        Type::Instance { class_name, .. } => vec![class_name.as_str()],
                         _ => return Ok(Type::Unknown),
                     };
-                    if let Some(sig) = self.stubs.lookup_method(class_name, method_name) {
-                        return Ok(sig.ret.clone());
+                    for class_name in class_names {
+                        if let Some(sig) = self.stubs.lookup_method(class_name, method_name) {
+                            return Ok(self.propagate_container_args(
+                                obj_type,
+                                method_name,
+                                &sig.ret,
+                            ));
+                        }
                     }
                 }
             }
@@ -1535,6 +1762,55 @@ impl<'a> TypeInferencer<'a> {
         }
     }
 
+    /// Apply a call to a (possibly generic) signature: instantiate fresh type
+    /// variables, unify generic parameters against the inferred argument
+    /// types, and return the resulting concrete return type
+    fn instantiate_call(&mut self, params: &[Type], ret: &Type, args_text: &str) -> Result<Type> {
+        let mut mapping = HashMap::new();
+        let params: Vec<Type> = params
+            .iter()
+            .map(|t| self.env.instantiate(t, &mut mapping))
+            .collect();
+        let ret = self.env.instantiate(ret, &mut mapping);
+
+        for (param, arg) in params.iter().zip(split_call_args(args_text)) {
+            // Only generic positions constrain the signature; gradual typing
+            // means a failed unification just leaves the parameter unresolved
+            if !param.contains_var() {
+                continue;
+            }
+            let arg_ty = self.infer_expr_from_text(arg)?;
+            let _ = self.unify(param, &arg_ty);
+        }
+
+        Ok(self.env.substitute(&ret))
+    }
+
+    /// Propagate a receiver's type arguments into a container method's return
+    /// type so element types stay concrete at use sites (e.g. `pop` on
+    /// `list[int]` yields `int` rather than `Any`)
+    fn propagate_container_args(&self, obj_type: &Type, method: &str, ret: &Type) -> Type {
+        match obj_type {
+            Type::List(elem) => match method {
+                "pop" => (**elem).clone(),
+                "find" => Type::Optional(elem.clone()),
+                "filter" => Type::List(elem.clone()),
+                _ => ret.clone(),
+            },
+            Type::Dict(key, value) => match method {
+                "get" => Type::Optional(value.clone()),
+                "keys" => Type::List(key.clone()),
+                "values" => Type::List(value.clone()),
+                "items" => Type::List(Box::new(Type::Tuple(vec![
+                    (**key).clone(),
+                    (**value).clone(),
+                ]))),
+                _ => ret.clone(),
+            },
+            _ => ret.clone(),
+        }
+    }
+
     /// Solve accumulated constraints
     fn solve_constraints(&mut self) -> Result<()> {
         // Clone constraints to avoid borrow conflicts
@@ -1544,12 +1820,10 @@ impl<'a> TypeInferencer<'a> {
                 Constraint::Equal(t1, t2) => {
                     self.unify(&t1, &t2)?;
                 }
-                Constraint::Subtype(sub, sup) => {
-                    if !sub.is_subtype_of(&sup) {
-                        // Add substitution for type variables
-                        if let Type::Var(id) = sub {
-                            self.env.add_substitution(id, sup.clone());
-                        }
+                Constraint::Subtype(sub, sup) if !sub.is_subtype_of(&sup) => {
+                    // Add substitution for type variables
+                    if let Type::Var(id) = sub {
+                        self.env.add_substitution(id, sup.clone());
                     }
                 }
                 _ => {}
@@ -1570,12 +1844,33 @@ impl<'a> TypeInferencer<'a> {
                 self.env.add_substitution(*id, t.clone());
                 Ok(())
             }
-            (Type::List(a), Type::List(b)) => self.unify(a, b),
+            (Type::List(a), Type::List(b)) | (Type::Set(a), Type::Set(b)) => self.unify(a, b),
             (Type::Dict(k1, v1), Type::Dict(k2, v2)) => {
                 self.unify(k1, k2)?;
                 self.unify(v1, v2)
             }
             (Type::Optional(a), Type::Optional(b)) => self.unify(a, b),
+            (Type::Tuple(a), Type::Tuple(b)) if a.len() == b.len() => {
+                for (x, y) in a.iter().zip(b.iter()) {
+                    self.unify(x, y)?;
+                }
+                Ok(())
+            }
+            (
+                Type::Instance {
+                    class_name: c1,
+                    type_args: a1,
+                },
+                Type::Instance {
+                    class_name: c2,
+                    type_args: a2,
+                },
+            ) if c1 == c2 && a1.len() == a2.len() => {
+                for (x, y) in a1.iter().zip(a2.iter()) {
+                    self.unify(x, y)?;
+                }
+                Ok(())
+            }
             (
                 Type::Function {
                     params: p1,
@@ -1643,6 +1938,7 @@ impl<'a> TypeInferencer<'a> {
             .into_iter()
             .map(|(n, t)| (n, self.env.substitute(&t)))
             .collect();
+        self.substitute_variable_types(&mut result);
 
         Ok(result)
     }
@@ -1662,9 +1958,11 @@ impl<'a> TypeInferencer<'a> {
                 if kind == "identifier" || kind == "typed_parameter" || kind == "required_parameter"
                 {
                     if let Ok(text) = child.utf8_text(source) {
-                        let name = text.split(':').next().unwrap_or(text).trim().to_string();
+                        let mut parts = text.splitn(2, ':');
+                        let name = parts.next().unwrap_or(text).trim().to_string();
+                        let annotation = parts.next().and_then(parse_annotation);
                         if !name.is_empty() && name != "self" && name != "this" {
-                            let ty = self.env.fresh_var();
+                            let ty = annotation.unwrap_or_else(|| self.env.fresh_var());
                             self.env.bind(name.clone(), ty.clone());
                             result.parameters.push((name, ty));
                         }
@@ -1695,14 +1993,22 @@ impl<'a> TypeInferencer<'a> {
                         let var = text[..eq_idx].trim();
                         let expr = text[eq_idx + 1..].trim();
 
-                        if is_identifier(var) {
-                            let ty = self.infer_expr_from_text(expr)?;
-                            self.env.bind(var.to_string(), ty.clone());
+                        // `name: annotation = value` carries its own type
+                        let (name, annotation) = match var.split_once(':') {
+                            Some((n, ann)) => (n.trim(), parse_annotation(ann)),
+                            None => (var, None),
+                        };
+                        if is_identifier(name) {
+                            let ty = match annotation {
+                                Some(ty) => ty,
+                                None => self.infer_expr_from_text(expr)?,
+                            };
+                            self.env.bind(name.to_string(), ty.clone());
                             result
                                 .variable_types
                                 .entry(line)
                                 .or_default()
-                                .insert(var.to_string(), ty);
+                                .insert(name.to_string(), ty);
                         }
                     }
                 }
@@ -1820,6 +2126,230 @@ fn extract_identifiers(text: &str) -> Vec<String> {
     identifiers
 }
 
+/// Parse a Python type annotation (PEP 484/585 syntax) into a [`Type`]
+///
+/// Also understands the TypeScript primitives (`string`, `number`, `boolean`)
+/// so annotated TS parameters get typed too.
+pub fn parse_annotation(ann: &str) -> Option<Type> {
+    let ann = ann.trim().trim_matches('"').trim_matches('\'');
+    if ann.is_empty() {
+        return None;
+    }
+
+    // Union syntax: `str | None`
+    if ann.contains('|') {
+        let types: Vec<Type> = ann
+            .split('|')
+            .map(|p| parse_annotation(p).unwrap_or(Type::Unknown))
+            .collect();
+        return Some(Type::Union(types).simplify());
+    }
+
+    // Subscripted generics: `list[int]`, `Optional[str]`, `Dict[str, int]`
+    if let Some(open) = ann.find('[') {
+        let base = ann[..open].trim();
+        let args_text = ann[open + 1..].strip_suffix(']')?;
+        let args: Vec<Option<Type>> = split_call_args(args_text)
+            .iter()
+            .map(|a| parse_annotation(a))
+            .collect();
+        let arg = |i: usize| args.get(i).cloned().flatten().unwrap_or(Type::Unknown);
+        return Some(match base {
+            "Optional" => Type::Optional(Box::new(arg(0))),
+            "List" | "list" | "Sequence" | "Iterable" => Type::List(Box::new(arg(0))),
+            "Set" | "set" | "FrozenSet" | "frozenset" => Type::Set(Box::new(arg(0))),
+            "Dict" | "dict" | "Mapping" => Type::Dict(Box::new(arg(0)), Box::new(arg(1))),
+            "Tuple" | "tuple" => Type::Tuple((0..args.len()).map(arg).collect()),
+            "Union" => Type::Union((0..args.len()).map(arg).collect()).simplify(),
+            other => Type::Instance {
+                class_name: other.to_string(),
+                type_args: (0..args.len()).map(arg).collect(),
+            },
+        });
+    }
+
+    Some(match ann {
+        "int" => Type::Int,
+        "float" | "number" => Type::Float,
+        "str" | "string" => Type::String,
+        "bool" | "boolean" => Type::Bool,
+        "bytes" => Type::Bytes,
+        "None" => Type::None,
+        "Any" | "object" | "any" => Type::Unknown,
+        other if is_identifier(other) => Type::Instance {
+            class_name: other.to_string(),
+            type_args: vec![],
+        },
+        _ => return None,
+    })
+}
+
+/// Extract a function's parameter names and annotations from Python source
+pub fn extract_function_params(source: &str, function_name: &str) -> Vec<(String, Option<Type>)> {
+    let mut params = Vec::new();
+    let needle = format!("def {}(", function_name);
+    let Some(start) = source.find(&needle) else {
+        return params;
+    };
+
+    // Accumulate the parameter list until parentheses balance
+    let mut inner = String::new();
+    let mut depth = 1;
+    for c in source[start + needle.len()..].chars() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+            }
+            _ => {}
+        }
+        inner.push(c);
+    }
+
+    for arg in split_call_args(&inner) {
+        // Drop default values and *args/**kwargs
+        let arg = arg.split('=').next().unwrap_or(arg).trim();
+        if arg.is_empty() || arg.starts_with('*') || arg == "self" || arg == "cls" {
+            continue;
+        }
+        if let Some((name, ann)) = arg.split_once(':') {
+            params.push((name.trim().to_string(), parse_annotation(ann)));
+        } else if is_identifier(arg) {
+            params.push((arg.to_string(), None));
+        }
+    }
+    params
+}
+
+/// Extract annotated field types from dataclasses and Pydantic-style models
+/// (`@dataclass`, `BaseModel`, `NamedTuple`, `TypedDict` subclasses)
+pub fn extract_annotated_classes(source: &str) -> Vec<ClassDef> {
+    let mut classes = Vec::new();
+    let mut current: Option<ClassDef> = None;
+    let mut in_fields = false;
+    let mut decorated_dataclass = false;
+
+    for line in source.lines() {
+        let trimmed = line.trim();
+        let indent = line.len() - line.trim_start().len();
+
+        if trimmed.starts_with('@') {
+            if trimmed.contains("dataclass") {
+                decorated_dataclass = true;
+            }
+            continue;
+        }
+
+        if let Some(rest) = trimmed.strip_prefix("class ") {
+            if let Some(finished) = current.take() {
+                if !finished.attributes.is_empty() {
+                    classes.push(finished);
+                }
+            }
+            let name_end = rest.find(['(', ':']).unwrap_or(rest.len());
+            let name = rest[..name_end].trim().to_string();
+            let bases: Vec<String> = rest[name_end..]
+                .trim_start_matches('(')
+                .trim_end_matches(':')
+                .trim_end_matches(')')
+                .split(',')
+                .map(|b| b.trim().to_string())
+                .filter(|b| !b.is_empty())
+                .collect();
+            let is_model = decorated_dataclass
+                || bases
+                    .iter()
+                    .any(|b| matches!(b.as_str(), "BaseModel" | "NamedTuple" | "TypedDict"));
+            decorated_dataclass = false;
+            if is_model {
+                current = Some(ClassDef {
+                    name,
+                    methods: HashMap::new(),
+                    attributes: HashMap::new(),
+                    bases,
+                });
+                in_fields = true;
+            }
+            continue;
+        }
+
+        if indent == 0 && !trimmed.is_empty() {
+            // Back at top level: the class body has ended
+            decorated_dataclass = false;
+            if let Some(finished) = current.take() {
+                if !finished.attributes.is_empty() {
+                    classes.push(finished);
+                }
+            }
+            continue;
+        }
+
+        if let Some(class) = current.as_mut() {
+            if trimmed.starts_with("def ") || trimmed.starts_with("async def ") {
+                // Annotated locals inside methods are not fields
+                in_fields = false;
+            } else if in_fields && !trimmed.is_empty() && !trimmed.starts_with('#') {
+                if let Some((field, ann)) = trimmed.split_once(':') {
+                    let field = field.trim();
+                    let ann = ann.split('=').next().unwrap_or(ann);
+                    if is_identifier(field) && !field.starts_with('_') {
+                        if let Some(ty) = parse_annotation(ann) {
+                            class.attributes.insert(field.to_string(), ty);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some(finished) = current.take() {
+        if !finished.attributes.is_empty() {
+            classes.push(finished);
+        }
+    }
+    classes
+}
+
+/// Split a call's argument text on top-level commas, respecting nesting and strings
+fn split_call_args(text: &str) -> Vec<&str> {
+    let mut args = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+    let mut in_string: Option<char> = None;
+
+    for (i, c) in text.char_indices() {
+        match c {
+            '"' | '\'' | '`' => {
+                if in_string == Some(c) {
+                    in_string = None;
+                } else if in_string.is_none() {
+                    in_string = Some(c);
+                }
+            }
+            _ if in_string.is_some() => {}
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                let arg = text[start..i].trim();
+                if !arg.is_empty() {
+                    args.push(arg);
+                }
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+
+    let last = text[start..].trim();
+    if !last.is_empty() {
+        args.push(last);
+    }
+    args
+}
+
 fn find_child_by_kind<'a>(node: Node<'a>, kind: &str) -> Option<Node<'a>> {
     let mut cursor = node.walk();
     if cursor.goto_first_child() {
@@ -1886,6 +2416,15 @@ mod tests {
         assert!(Type::Int.is_subtype_of(&Type::Optional(Box::new(Type::Int))));
     }
 
+    #[test]
+    fn test_type_is_nullable() {
+        assert!(Type::None.is_nullable());
+        assert!(Type::Optional(Box::new(Type::Int)).is_nullable());
+        assert!(Type::Union(vec![Type::String, Type::None]).is_nullable());
+        assert!(!Type::Int.is_nullable());
+        assert!(!Type::List(Box::new(Type::None)).is_nullable());
+    }
+
     #[test]
     fn test_type_env() {
         let mut env = TypeEnv::new();
@@ -2091,6 +2630,239 @@ mod tests {
         assert!(inferencer.unify(&Type::Int, &Type::String).is_err());
     }
 
+    #[test]
+    fn test_instantiate_fresh_vars() {
+        let mut env = TypeEnv::new();
+
+        // The same source variable maps to the same fresh variable
+        let mut mapping = HashMap::new();
+        let a = env.instantiate(&Type::Var(0), &mut mapping);
+        let b = env.instantiate(&Type::Var(0), &mut mapping);
+        assert_eq!(a, b);
+
+        // A separate instantiation gets its own fresh variable
+        let mut second = HashMap::new();
+        let c = env.instantiate(&Type::Var(0), &mut second);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_infer_generic_call() {
+        let mut inferencer = TypeInferencer::new("", None, "python");
+        inferencer
+            .env
+            .bind("names".to_string(), Type::List(Box::new(Type::String)));
+
+        // Generic builtin: the element type flows through to the return type
+        assert_eq!(
+            inferencer.infer_expr_from_text("sorted(names)").unwrap(),
+            Type::List(Box::new(Type::String))
+        );
+        assert_eq!(
+            inferencer.infer_expr_from_text("list(names)").unwrap(),
+            Type::List(Box::new(Type::String))
+        );
+
+        // User-defined generic function bound in the environment
+        inferencer.env.bind(
+            "identity".to_string(),
+            Type::Function {
+                params: vec![Type::Var(0)],
+                ret: Box::new(Type::Var(0)),
+            },
+        );
+        assert_eq!(
+            inferencer.infer_expr_from_text("identity(42)").unwrap(),
+            Type::Int
+        );
+    }
+
+    #[test]
+    fn test_infer_container_method_element_type() {
+        let mut inferencer = TypeInferencer::new("", None, "python");
+        inferencer
+            .env
+            .bind("xs".to_string(), Type::List(Box::new(Type::Int)));
+        inferencer.env.bind(
+            "d".to_string(),
+            Type::Dict(Box::new(Type::String), Box::new(Type::Int)),
+        );
+
+        assert_eq!(
+            inferencer.infer_expr_from_text("xs.pop()").unwrap(),
+            Type::Int
+        );
+        assert_eq!(
+            inferencer.infer_expr_from_text("d.get(\"k\")").unwrap(),
+            Type::Optional(Box::new(Type::Int))
+        );
+        assert_eq!(
+            inferencer.infer_expr_from_text("d.keys()").unwrap(),
+            Type::List(Box::new(Type::String))
+        );
+    }
+
+    #[test]
+    fn test_infer_promise_types() {
+        let mut inferencer = TypeInferencer::new("", None, "javascript");
+
+        let resolved = inferencer
+            .infer_expr_from_text("Promise.resolve(42)")
+            .unwrap();
+        assert_eq!(
+            resolved,
+            Type::Instance {
+                class_name: "Promise".to_string(),
+                type_args: vec![Type::Int],
+            }
+        );
+
+        // Awaiting the promise yields the resolved type argument
+        inferencer.env.bind("p".to_string(), resolved);
+        assert_eq!(
+            inferencer.infer_expr_from_text("await p").unwrap(),
+            Type::Int
+        );
+    }
+
+    #[test]
+    fn test_infer_js_array_methods() {
+        let mut inferencer = TypeInferencer::new("", None, "javascript");
+        inferencer
+            .env
+            .bind("arr".to_string(), Type::List(Box::new(Type::String)));
+
+        assert_eq!(
+            inferencer
+                .infer_expr_from_text("arr.find(x => x.length > 0)")
+                .unwrap(),
+            Type::Optional(Box::new(Type::String))
+        );
+        assert_eq!(
+            inferencer.infer_expr_from_text("arr.pop()").unwrap(),
+            Type::String
+        );
+    }
+
+    #[test]
+    fn test_parse_annotation() {
+        assert_eq!(parse_annotation("int"), Some(Type::Int));
+        assert_eq!(parse_annotation("str"), Some(Type::String));
+        assert_eq!(
+            parse_annotation("Optional[str]"),
+            Some(Type::Optional(Box::new(Type::String)))
+        );
+        assert_eq!(
+            parse_annotation("list[int]"),
+            Some(Type::List(Box::new(Type::Int)))
+        );
+        assert_eq!(
+            parse_annotation("Dict[str, int]"),
+            Some(Type::Dict(Box::new(Type::String), Box::new(Type::Int)))
+        );
+        assert!(parse_annotation("str | None").unwrap().is_nullable());
+        assert_eq!(
+            parse_annotation("User"),
+            Some(Type::Instance {
+                class_name: "User".to_string(),
+                type_args: vec![],
+            })
+        );
+        assert_eq!(parse_annotation(""), None);
+    }
+
+    #[test]
+    fn test_extract_function_params() {
+        let source = r#"
+def handler(self, user_id: int, name: str = "anon", *args, tags: list[str] = None):
+    pass
+"#;
+        let params = extract_function_params(source, "handler");
+        assert_eq!(params.len(), 3);
+        assert_eq!(params[0], ("user_id".to_string(), Some(Type::Int)));
+        assert_eq!(params[1], ("name".to_string(), Some(Type::String)));
+        assert_eq!(
+            params[2],
+            ("tags".to_string(), Some(Type::List(Box::new(Type::String))))
+        );
+
+        assert!(extract_function_params(source, "missing").is_empty());
+    }
+
+    #[test]
+    fn test_extract_annotated_classes() {
+        let source = r#"
+@dataclass
+class User:
+    name: str
+    age: int
+
+    def greet(self):
+        msg: str = "hi"
+        return msg
+
+class Item(BaseModel):
+    price: float
+    tags: list[str]
+
+class Helper:
+    pass
+"#;
+        let classes = extract_annotated_classes(source);
+        assert_eq!(classes.len(), 2);
+
+        let user = &classes[0];
+        assert_eq!(user.name, "User");
+        assert_eq!(user.attributes.get("name"), Some(&Type::String));
+        assert_eq!(user.attributes.get("age"), Some(&Type::Int));
+        // Annotated locals inside methods are not fields
+        assert!(!user.attributes.contains_key("msg"));
+
+        let item = &classes[1];
+        assert_eq!(item.name, "Item");
+        assert_eq!(item.attributes.get("price"), Some(&Type::Float));
+    }
+
+    #[test]
+    fn test_infer_model_attribute_access() {
+        let source = r#"
+@dataclass
+class User:
+    name: str
+    age: int
+"#;
+        let mut inferencer = TypeInferencer::new(source, None, "python");
+
+        // Constructing a registered class yields an instance
+        let instance = inferencer.infer_expr_from_text("User(1)").unwrap();
+        assert_eq!(
+            instance,
+            Type::Instance {
+                class_name: "User".to_string(),
+                type_args: vec![],
+            }
+        );
+
+        // Attribute access resolves to the annotated field type
+        inferencer.env.bind("user".to_string(), instance);
+        assert_eq!(
+            inferencer.infer_expr_from_text("user.name").unwrap(),
+            Type::String
+        );
+        assert_eq!(
+            inferencer.infer_expr_from_text("user.age").unwrap(),
+            Type::Int
+        );
+    }
+
+    #[test]
+    fn test_split_call_args() {
+        assert_eq!(split_call_args("a, b"), vec!["a", "b"]);
+        assert_eq!(split_call_args("f(x, y), z"), vec!["f(x, y)", "z"]);
+        assert_eq!(split_call_args("\"a, b\", c"), vec!["\"a, b\"", "c"]);
+        assert!(split_call_args("").is_empty());
+    }
+
     #[test]
     fn test_type_error_kinds() {
         let error = TypeError {
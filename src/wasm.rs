@@ -14,6 +14,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use wasm_bindgen::prelude::*;
 
+use crate::callgraph::CallGraph;
 use crate::embeddings::EmbeddingEngine;
 use crate::parser::LanguageParser;
 use crate::search::{DocType, SearchDocument, SearchIndex};
@@ -47,6 +48,35 @@ pub struct WasmSimilarCode {
     pub similarity: f64,
 }
 
+/// Progress report for incremental batch indexing
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WasmIndexProgress {
+    /// Files indexed so far in the current run
+    pub indexed: usize,
+    /// Files still waiting in the queue
+    pub remaining: usize,
+    /// Total files queued in the current run
+    pub total: usize,
+    /// Whether the queue has been fully drained
+    pub done: bool,
+}
+
+/// Serializable snapshot of the engine state for IndexedDB persistence
+///
+/// Files and symbols are stored verbatim; the search index and TF-IDF
+/// embeddings are derived data and are rebuilt from the snapshot on import,
+/// which skips the expensive parsing step.
+#[derive(Debug, Serialize, Deserialize)]
+struct WasmState {
+    /// Snapshot format version; bump when the layout changes
+    version: u32,
+    files: HashMap<String, String>,
+    symbols: Vec<Symbol>,
+}
+
+/// Current snapshot format version
+const STATE_VERSION: u32 = 1;
+
 /// WASM-compatible code intelligence engine
 ///
 /// This provides core code analysis capabilities without file system access.
@@ -58,6 +88,11 @@ pub struct WasmCodeIntel {
     search_index: SearchIndex,
     embeddings: EmbeddingEngine,
     files: HashMap<String, String>,
+    call_graph: Option<CallGraph>,
+    /// Files queued for incremental indexing, drained by `index_batch()`
+    pending: Vec<(String, String)>,
+    /// Total files queued in the current incremental run
+    batch_total: usize,
 }
 
 #[wasm_bindgen]
@@ -77,6 +112,9 @@ impl WasmCodeIntel {
             search_index: SearchIndex::new(),
             embeddings: EmbeddingEngine::new(500), // Larger vocab for better similarity
             files: HashMap::new(),
+            call_graph: None,
+            pending: Vec::new(),
+            batch_total: 0,
         })
     }
 
@@ -101,36 +139,9 @@ impl WasmCodeIntel {
         // Parse and extract symbols
         match self.parser.parse_file(file_path, content) {
             Ok(parsed) => {
-                // Add symbols
-                for symbol in &parsed.symbols {
-                    // Index symbol for similarity search
-                    if let Some(body) = self.get_symbol_body(path, symbol) {
-                        let doc_id = format!("{}:{}:{}", path, symbol.name, symbol.start_line);
-                        self.embeddings.index_snippet(
-                            doc_id,
-                            path.to_string(),
-                            body,
-                            symbol.start_line,
-                            symbol.end_line,
-                        );
-                    }
-                }
-
+                self.index_derived(path, content, &parsed.symbols);
                 self.symbols.extend(parsed.symbols);
 
-                // Index file content for search
-                let doc = SearchDocument {
-                    id: path.to_string(),
-                    file_path: path.to_string(),
-                    content: content.to_string(),
-                    doc_type: DocType::File,
-                    start_line: 1,
-                    end_line: content.lines().count(),
-                    tokens: Vec::new(),
-                    term_freq: HashMap::new(),
-                };
-                self.search_index.add_document(doc);
-
                 Ok(true)
             }
             Err(e) => {
@@ -170,6 +181,83 @@ impl WasmCodeIntel {
         Ok(count)
     }
 
+    /// Queue files for incremental indexing
+    ///
+    /// Unlike `index_files()`, nothing is parsed yet: the files sit in a
+    /// queue until `index_batch()` is called. Intended for web workers that
+    /// index large uploads in slices so the event loop stays responsive.
+    ///
+    /// # Arguments
+    /// * `files_json` - JSON array of {path: string, content: string} objects
+    ///
+    /// # Returns
+    /// Total number of files now queued
+    #[wasm_bindgen]
+    pub fn queue_files(&mut self, files_json: &str) -> Result<usize, JsValue> {
+        #[derive(Deserialize)]
+        struct FileInput {
+            path: String,
+            content: String,
+        }
+
+        let files: Vec<FileInput> =
+            serde_json::from_str(files_json).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        self.batch_total += files.len();
+        self.pending
+            .extend(files.into_iter().map(|f| (f.path, f.content)));
+
+        Ok(self.pending.len())
+    }
+
+    /// Index the next batch of queued files
+    ///
+    /// Call repeatedly (yielding to the event loop in between) until the
+    /// returned progress reports `done: true`. The queue survives across
+    /// calls, so indexing is resumable.
+    ///
+    /// # Arguments
+    /// * `batch_size` - Maximum number of files to index in this call
+    /// * `on_progress` - Optional callback invoked as `(indexed, total)`
+    ///
+    /// # Returns
+    /// JSON progress object: {indexed, remaining, total, done}
+    #[wasm_bindgen]
+    pub fn index_batch(
+        &mut self,
+        batch_size: usize,
+        on_progress: Option<js_sys::Function>,
+    ) -> Result<String, JsValue> {
+        let take = batch_size.max(1).min(self.pending.len());
+        let batch: Vec<(String, String)> = self.pending.drain(..take).collect();
+
+        for (path, content) in &batch {
+            self.index_file(path, content)?;
+        }
+
+        let progress = WasmIndexProgress {
+            indexed: self.batch_total - self.pending.len(),
+            remaining: self.pending.len(),
+            total: self.batch_total,
+            done: self.pending.is_empty(),
+        };
+
+        if let Some(callback) = on_progress {
+            let _ = callback.call2(
+                &JsValue::NULL,
+                &JsValue::from_f64(progress.indexed as f64),
+                &JsValue::from_f64(progress.total as f64),
+            );
+        }
+
+        // A drained queue ends the run; the next queue_files() starts fresh
+        if progress.done {
+            self.batch_total = 0;
+        }
+
+        serde_json::to_string(&progress).map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
     /// Find symbols matching a pattern
     ///
     /// # Arguments
@@ -366,6 +454,9 @@ impl WasmCodeIntel {
         self.symbols.clear();
         self.search_index = SearchIndex::new();
         self.embeddings.clear();
+        self.call_graph = None;
+        self.pending.clear();
+        self.batch_total = 0;
     }
 
     /// Get statistics about the engine state
@@ -398,8 +489,222 @@ impl WasmCodeIntel {
         serde_json::to_string(&extensions).unwrap_or_else(|_| "[]".to_string())
     }
 
+    /// Build the call graph from all indexed files
+    ///
+    /// Must be called before `get_callers()`/`get_callees()`, and again
+    /// after indexing more files to pick up the new definitions.
+    ///
+    /// # Returns
+    /// The number of functions in the graph
+    #[wasm_bindgen]
+    pub fn build_call_graph(&mut self) -> Result<usize, JsValue> {
+        use std::path::Path;
+
+        let mut parsed_files = Vec::new();
+        for (path, content) in &self.files {
+            // Unsupported languages simply don't contribute nodes
+            if let Ok(tree) = self.parser.parse_to_tree(Path::new(path), content) {
+                parsed_files.push((path.clone(), content.clone(), tree));
+            }
+        }
+
+        let graph = CallGraph::new();
+        graph
+            .build_from_files(&parsed_files)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        let count = graph.node_count();
+        self.call_graph = Some(graph);
+        Ok(count)
+    }
+
+    /// Get direct callers of a function
+    ///
+    /// # Arguments
+    /// * `name` - The function name (fuzzy matched)
+    ///
+    /// # Returns
+    /// JSON array of call edges, throws if `build_call_graph()` was not called
+    #[wasm_bindgen]
+    pub fn get_callers(&self, name: &str) -> Result<String, JsValue> {
+        let graph = self.call_graph.as_ref().ok_or_else(|| {
+            JsValue::from_str("Call graph not built. Call build_call_graph() first.")
+        })?;
+
+        let edges = graph.get_callers(name);
+        Ok(serde_json::to_string(&edges).unwrap_or_else(|_| "[]".to_string()))
+    }
+
+    /// Get functions called by a function
+    ///
+    /// # Arguments
+    /// * `name` - The function name (fuzzy matched)
+    ///
+    /// # Returns
+    /// JSON array of call edges, throws if `build_call_graph()` was not called
+    #[wasm_bindgen]
+    pub fn get_callees(&self, name: &str) -> Result<String, JsValue> {
+        let graph = self.call_graph.as_ref().ok_or_else(|| {
+            JsValue::from_str("Call graph not built. Call build_call_graph() first.")
+        })?;
+
+        let edges = graph.get_callees(name);
+        Ok(serde_json::to_string(&edges).unwrap_or_else(|_| "[]".to_string()))
+    }
+
+    /// Get the control flow graph of a function as markdown
+    ///
+    /// # Arguments
+    /// * `path` - The indexed file path containing the function
+    /// * `function` - The function name (exact match)
+    ///
+    /// # Returns
+    /// Markdown rendering of the CFG, throws if the file or function is not found
+    #[wasm_bindgen]
+    pub fn get_control_flow(&self, path: &str, function: &str) -> Result<String, JsValue> {
+        let cfg = self.build_cfg(path, function)?;
+        Ok(cfg.to_markdown())
+    }
+
+    /// Get the control flow graph of a function in Graphviz DOT format
+    ///
+    /// # Arguments
+    /// * `path` - The indexed file path containing the function
+    /// * `function` - The function name (exact match)
+    ///
+    /// # Returns
+    /// DOT source suitable for in-browser rendering (e.g. viz.js),
+    /// throws if the file or function is not found
+    #[wasm_bindgen]
+    pub fn get_control_flow_dot(&self, path: &str, function: &str) -> Result<String, JsValue> {
+        let cfg = self.build_cfg(path, function)?;
+        Ok(cfg.to_dot())
+    }
+
+    /// Serialize the engine state to a compact binary blob
+    ///
+    /// The returned bytes can be stored in IndexedDB and restored later with
+    /// `import_state()`, avoiding a full re-index on the next page load.
+    ///
+    /// # Returns
+    /// Binary snapshot as a byte array
+    #[wasm_bindgen]
+    pub fn export_state(&self) -> Result<Vec<u8>, JsValue> {
+        let state = WasmState {
+            version: STATE_VERSION,
+            files: self.files.clone(),
+            symbols: self.symbols.clone(),
+        };
+
+        bincode::serialize(&state).map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    /// Restore engine state from a blob produced by `export_state()`
+    ///
+    /// Replaces all currently indexed data. The search index and embeddings
+    /// are rebuilt from the snapshot without re-parsing any files.
+    ///
+    /// # Arguments
+    /// * `data` - Binary snapshot from `export_state()`
+    ///
+    /// # Returns
+    /// `true` on success, throws on a corrupt or incompatible snapshot
+    #[wasm_bindgen]
+    pub fn import_state(&mut self, data: &[u8]) -> Result<bool, JsValue> {
+        let state: WasmState =
+            bincode::deserialize(data).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        if state.version != STATE_VERSION {
+            return Err(JsValue::from_str(&format!(
+                "Unsupported snapshot version {} (expected {})",
+                state.version, STATE_VERSION
+            )));
+        }
+
+        self.clear();
+        self.files = state.files;
+        self.symbols = state.symbols;
+
+        // Rebuild derived indexes from the restored files and symbols
+        let files: Vec<(String, String)> = self
+            .files
+            .iter()
+            .map(|(p, c)| (p.clone(), c.clone()))
+            .collect();
+        for (path, content) in files {
+            let symbols: Vec<Symbol> = self
+                .symbols
+                .iter()
+                .filter(|s| s.file_path == path)
+                .cloned()
+                .collect();
+            self.index_derived(&path, &content, &symbols);
+        }
+
+        Ok(true)
+    }
+
     // Private helper methods
 
+    /// Parse an indexed file and build the CFG for a single function
+    fn build_cfg(
+        &self,
+        path: &str,
+        function: &str,
+    ) -> Result<crate::cfg::ControlFlowGraph, JsValue> {
+        use std::path::Path;
+
+        let content = self
+            .files
+            .get(path)
+            .ok_or_else(|| JsValue::from_str(&format!("File '{}' not indexed", path)))?;
+
+        let tree = self
+            .parser
+            .parse_to_tree(Path::new(path), content)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        let cfgs = crate::cfg::analyze_function(&tree, content, path)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        cfgs.into_iter()
+            .find(|c| c.function_name == function)
+            .ok_or_else(|| {
+                JsValue::from_str(&format!("Function '{}' not found in {}", function, path))
+            })
+    }
+
+    /// Index a file's content and symbols into the search index and
+    /// embedding store (derived data, rebuildable from files + symbols)
+    fn index_derived(&mut self, path: &str, content: &str, symbols: &[Symbol]) {
+        for symbol in symbols {
+            // Index symbol for similarity search
+            if let Some(body) = self.get_symbol_body(path, symbol) {
+                let doc_id = format!("{}:{}:{}", path, symbol.name, symbol.start_line);
+                self.embeddings.index_snippet(
+                    doc_id,
+                    path.to_string(),
+                    body,
+                    symbol.start_line,
+                    symbol.end_line,
+                );
+            }
+        }
+
+        // Index file content for search
+        let doc = SearchDocument {
+            id: path.to_string(),
+            file_path: path.to_string(),
+            content: content.to_string(),
+            doc_type: DocType::File,
+            start_line: 1,
+            end_line: content.lines().count(),
+            tokens: Vec::new(),
+            term_freq: HashMap::new(),
+        };
+        self.search_index.add_document(doc);
+    }
+
     fn get_symbol_body(&self, path: &str, symbol: &Symbol) -> Option<String> {
         let content = self.files.get(path)?;
         let lines: Vec<&str> = content.lines().collect();
@@ -458,6 +763,38 @@ pub fn version() -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_state_roundtrip() {
+        let mut files = HashMap::new();
+        files.insert(
+            "lib.rs".to_string(),
+            "pub fn answer() -> i32 { 42 }".to_string(),
+        );
+
+        let state = WasmState {
+            version: STATE_VERSION,
+            files,
+            symbols: vec![Symbol {
+                name: "answer".to_string(),
+                kind: SymbolKind::Function,
+                file_path: "lib.rs".to_string(),
+                start_line: 1,
+                end_line: 1,
+                signature: Some("pub fn answer() -> i32".to_string()),
+                qualified_name: None,
+                doc_comment: None,
+            }],
+        };
+
+        let bytes = bincode::serialize(&state).unwrap();
+        let restored: WasmState = bincode::deserialize(&bytes).unwrap();
+
+        assert_eq!(restored.version, STATE_VERSION);
+        assert_eq!(restored.files.len(), 1);
+        assert_eq!(restored.symbols.len(), 1);
+        assert_eq!(restored.symbols[0].name, "answer");
+    }
+
     #[test]
     fn test_parse_symbol_kind() {
         assert_eq!(parse_symbol_kind("function"), Some(SymbolKind::Function));
@@ -87,6 +87,11 @@ fn test_filter_by_feature_flags_all_enabled() {
         call_graph_enabled: true,
         persist_enabled: true,
         watch_enabled: true,
+        write_enabled: false,
+        lazy_enabled: false,
+        global_ignores: Vec::new(),
+        max_parse_bytes: 0,
+        symlink_policy: Default::default(),
         lsp_config: narsil_mcp::lsp::LspConfig {
             enabled: true,
             ..Default::default()
@@ -386,6 +391,11 @@ fn test_empty_config_with_all_flags_enabled() {
         call_graph_enabled: true,
         persist_enabled: true,
         watch_enabled: true,
+        write_enabled: false,
+        lazy_enabled: false,
+        global_ignores: Vec::new(),
+        max_parse_bytes: 0,
+        symlink_policy: Default::default(),
         lsp_config: narsil_mcp::lsp::LspConfig {
             enabled: true,
             ..Default::default()
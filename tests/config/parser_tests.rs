@@ -163,6 +163,11 @@ fn test_config_roundtrip() {
         },
         performance: Default::default(),
         feature_requirements: HashMap::new(),
+        ignore: Vec::new(),
+        chunking: Default::default(),
+        architecture: Default::default(),
+        profiles: Default::default(),
+        client_mappings: Default::default(),
     };
 
     // Serialize to YAML
@@ -221,7 +226,7 @@ tools:
     let config: ToolConfig = serde_yaml::from_str(yaml).expect("Should parse");
 
     // Performance config should have defaults
-    assert_eq!(config.performance.max_tool_count, 76);
+    assert_eq!(config.performance.max_tool_count, 108);
     assert_eq!(config.performance.startup_latency_ms, 10);
     assert_eq!(config.performance.filtering_latency_ms, 1);
 }
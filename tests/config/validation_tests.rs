@@ -28,6 +28,11 @@ fn test_validate_valid_config() {
         },
         performance: Default::default(),
         feature_requirements: HashMap::new(),
+        ignore: Vec::new(),
+        chunking: Default::default(),
+        architecture: Default::default(),
+        profiles: Default::default(),
+        client_mappings: Default::default(),
     };
 
     let result = validate_config(&config);
@@ -57,6 +62,11 @@ fn test_validate_invalid_version() {
         },
         performance: Default::default(),
         feature_requirements: HashMap::new(),
+        ignore: Vec::new(),
+        chunking: Default::default(),
+        architecture: Default::default(),
+        profiles: Default::default(),
+        client_mappings: Default::default(),
     };
 
     let result = validate_config(&config);
@@ -96,6 +106,11 @@ fn test_validate_unknown_category() {
         },
         performance: Default::default(),
         feature_requirements: HashMap::new(),
+        ignore: Vec::new(),
+        chunking: Default::default(),
+        architecture: Default::default(),
+        profiles: Default::default(),
+        client_mappings: Default::default(),
     };
 
     let result = validate_config(&config);
@@ -128,6 +143,11 @@ fn test_validate_performance_budgets() {
         },
         performance: Default::default(),
         feature_requirements: HashMap::new(),
+        ignore: Vec::new(),
+        chunking: Default::default(),
+        architecture: Default::default(),
+        profiles: Default::default(),
+        client_mappings: Default::default(),
     };
 
     // Invalid performance budget
@@ -152,6 +172,11 @@ fn test_validate_empty_categories() {
         },
         performance: Default::default(),
         feature_requirements: HashMap::new(),
+        ignore: Vec::new(),
+        chunking: Default::default(),
+        architecture: Default::default(),
+        profiles: Default::default(),
+        client_mappings: Default::default(),
     };
 
     let result = validate_config(&config);
@@ -195,6 +220,11 @@ fn test_validate_conflicting_overrides() {
         },
         performance: Default::default(),
         feature_requirements: HashMap::new(),
+        ignore: Vec::new(),
+        chunking: Default::default(),
+        architecture: Default::default(),
+        profiles: Default::default(),
+        client_mappings: Default::default(),
     };
 
     let result = validate_config(&config);
@@ -231,6 +261,11 @@ fn test_validate_unknown_tool_in_override() {
         },
         performance: Default::default(),
         feature_requirements: HashMap::new(),
+        ignore: Vec::new(),
+        chunking: Default::default(),
+        architecture: Default::default(),
+        profiles: Default::default(),
+        client_mappings: Default::default(),
     };
 
     let result = validate_config(&config);
@@ -193,3 +193,283 @@ async fn test_mcp_initialize_responds_quickly() {
         "Should return data even during initialization"
     );
 }
+
+#[tokio::test]
+async fn test_narsilignore_excludes_files_from_index() {
+    // GIVEN: A repo with a .narsilignore excluding generated code
+    let temp_dir = tempfile::tempdir().unwrap();
+    let repo_path = temp_dir.path().join("repo");
+    std::fs::create_dir_all(repo_path.join("generated")).unwrap();
+
+    std::fs::write(repo_path.join("main.rs"), "fn kept_function() {}").unwrap();
+    std::fs::write(
+        repo_path.join("generated/gen.rs"),
+        "fn generated_function() {}",
+    )
+    .unwrap();
+    std::fs::write(repo_path.join(".narsilignore"), "generated/\n").unwrap();
+
+    let index_path = temp_dir.path().join("index");
+    let engine =
+        CodeIntelEngine::with_options(index_path, vec![repo_path], EngineOptions::default())
+            .await
+            .unwrap();
+    engine.complete_initialization().await.unwrap();
+
+    // THEN: Symbols from the ignored directory are not indexed
+    let kept = engine
+        .find_symbols("repo", None, Some("kept_function"), None, None)
+        .await;
+    assert!(kept.is_ok());
+    assert!(kept.unwrap().contains("kept_function"));
+
+    let ignored = engine
+        .find_symbols("repo", None, Some("generated_function"), None, None)
+        .await
+        .unwrap();
+    assert!(
+        !ignored.contains("generated/gen.rs"),
+        "Symbols from .narsilignore'd directories should not be indexed"
+    );
+}
+
+#[tokio::test]
+async fn test_global_ignore_patterns_exclude_files() {
+    // GIVEN: A global ignore list excluding vendored deps
+    let temp_dir = tempfile::tempdir().unwrap();
+    let repo_path = temp_dir.path().join("repo");
+    std::fs::create_dir_all(repo_path.join("vendor")).unwrap();
+
+    std::fs::write(repo_path.join("main.rs"), "fn kept_function() {}").unwrap();
+    std::fs::write(repo_path.join("vendor/dep.rs"), "fn vendored_function() {}").unwrap();
+
+    let options = EngineOptions {
+        global_ignores: vec!["vendor/".to_string()],
+        ..Default::default()
+    };
+
+    let index_path = temp_dir.path().join("index");
+    let engine = CodeIntelEngine::with_options(index_path, vec![repo_path], options)
+        .await
+        .unwrap();
+    engine.complete_initialization().await.unwrap();
+
+    let ignored = engine
+        .find_symbols("repo", None, Some("vendored_function"), None, None)
+        .await
+        .unwrap();
+    assert!(
+        !ignored.contains("vendor/dep.rs"),
+        "Symbols matching the global ignore list should not be indexed"
+    );
+}
+
+#[tokio::test]
+async fn test_binary_and_minified_files_are_skipped() {
+    // GIVEN: A repo containing a binary blob and a minified bundle
+    let temp_dir = tempfile::tempdir().unwrap();
+    let repo_path = temp_dir.path().join("repo");
+    std::fs::create_dir_all(&repo_path).unwrap();
+
+    std::fs::write(repo_path.join("main.rs"), "fn kept_function() {}").unwrap();
+    std::fs::write(repo_path.join("blob.js"), "var x\0\0binary").unwrap();
+    std::fs::write(
+        repo_path.join("bundle.js"),
+        format!("var a={};", "x".repeat(10_000)),
+    )
+    .unwrap();
+
+    let index_path = temp_dir.path().join("index");
+    let engine =
+        CodeIntelEngine::with_options(index_path, vec![repo_path], EngineOptions::default())
+            .await
+            .unwrap();
+    engine.complete_initialization().await.unwrap();
+
+    // THEN: The skipped files are reported in get_index_status
+    let status = engine.get_index_status(Some("repo")).await.unwrap();
+    assert!(
+        status.contains("Skipped: 1 binary, 1 minified"),
+        "Status should report skipped files, got:\n{}",
+        status
+    );
+
+    // AND: Normal source is still indexed
+    let found = engine
+        .find_symbols("repo", None, Some("kept_function"), None, None)
+        .await
+        .unwrap();
+    assert!(found.contains("kept_function"));
+}
+
+#[tokio::test]
+async fn test_max_parse_bytes_truncates_symbol_parse() {
+    // GIVEN: A file larger than the parse limit, with symbols on both sides
+    let temp_dir = tempfile::tempdir().unwrap();
+    let repo_path = temp_dir.path().join("repo");
+    std::fs::create_dir_all(&repo_path).unwrap();
+
+    let mut big = String::from("fn early_function() {}\n");
+    for _ in 0..200 {
+        big.push_str("// padding padding padding padding padding padding\n");
+    }
+    big.push_str("fn late_function() {}\n");
+    std::fs::write(repo_path.join("big.rs"), &big).unwrap();
+
+    let options = EngineOptions {
+        max_parse_bytes: 2048,
+        ..Default::default()
+    };
+
+    let index_path = temp_dir.path().join("index");
+    let engine = CodeIntelEngine::with_options(index_path, vec![repo_path], options)
+        .await
+        .unwrap();
+    engine.complete_initialization().await.unwrap();
+
+    // THEN: Symbols before the cut are indexed, with a truncation note
+    let early = engine
+        .find_symbols("repo", None, Some("early_function"), None, None)
+        .await
+        .unwrap();
+    assert!(early.contains("early_function"));
+    assert!(
+        early.contains("parse limit"),
+        "Results from truncated files should carry a note, got:\n{}",
+        early
+    );
+
+    // AND: Symbols past the cut are not
+    let late = engine
+        .find_symbols("repo", None, Some("late_function"), None, None)
+        .await
+        .unwrap();
+    assert!(!late.contains("big.rs:"));
+
+    // AND: The status reports the truncation
+    let status = engine.get_index_status(Some("repo")).await.unwrap();
+    assert!(status.contains("Truncated: 1 large file(s)"));
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_symlinks_skipped_by_default() {
+    // GIVEN: A repo whose only route to a file is a symlink to an external dir
+    let temp_dir = tempfile::tempdir().unwrap();
+    let outside = temp_dir.path().join("outside");
+    std::fs::create_dir_all(&outside).unwrap();
+    std::fs::write(outside.join("ext.rs"), "fn external_function() {}").unwrap();
+
+    let repo_path = temp_dir.path().join("repo");
+    std::fs::create_dir_all(&repo_path).unwrap();
+    std::fs::write(repo_path.join("main.rs"), "fn local_function() {}").unwrap();
+    std::os::unix::fs::symlink(&outside, repo_path.join("ext_link")).unwrap();
+
+    let index_path = temp_dir.path().join("index");
+    let engine =
+        CodeIntelEngine::with_options(index_path, vec![repo_path], EngineOptions::default())
+            .await
+            .unwrap();
+    engine.complete_initialization().await.unwrap();
+
+    // THEN: The symlinked file is not indexed under the default policy
+    let local = engine
+        .find_symbols("repo", None, Some("local_function"), None, None)
+        .await
+        .unwrap();
+    assert!(local.contains("local_function"));
+
+    let external = engine
+        .find_symbols("repo", None, Some("external_function"), None, None)
+        .await
+        .unwrap();
+    assert!(
+        !external.contains("external_function"),
+        "Symlinks should not be followed by default"
+    );
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_symlink_follow_within_repo() {
+    // GIVEN: One symlink staying inside the repo and one escaping it
+    let temp_dir = tempfile::tempdir().unwrap();
+    let outside = temp_dir.path().join("outside");
+    std::fs::create_dir_all(&outside).unwrap();
+    std::fs::write(outside.join("ext.rs"), "fn external_function() {}").unwrap();
+
+    let repo_path = temp_dir.path().join("repo");
+    std::fs::create_dir_all(repo_path.join("real")).unwrap();
+    std::fs::write(repo_path.join("real/lib.rs"), "fn shared_function() {}").unwrap();
+    std::os::unix::fs::symlink(repo_path.join("real"), repo_path.join("link_dir")).unwrap();
+    std::os::unix::fs::symlink(&outside, repo_path.join("ext_link")).unwrap();
+
+    let options = EngineOptions {
+        symlink_policy: narsil_mcp::repo::SymlinkPolicy::FollowWithinRepo,
+        ..Default::default()
+    };
+
+    let index_path = temp_dir.path().join("index");
+    let engine = CodeIntelEngine::with_options(index_path, vec![repo_path], options)
+        .await
+        .unwrap();
+    engine.complete_initialization().await.unwrap();
+
+    // THEN: The in-repo target is indexed exactly once despite two routes
+    let shared = engine
+        .find_symbols("repo", None, Some("shared_function"), None, None)
+        .await
+        .unwrap();
+    assert_eq!(
+        shared.matches("- **shared_function**").count(),
+        1,
+        "Files reachable through both a symlink and their real path should be deduplicated"
+    );
+
+    // AND: The link escaping the repo is not followed
+    let external = engine
+        .find_symbols("repo", None, Some("external_function"), None, None)
+        .await
+        .unwrap();
+    assert!(
+        !external.contains("external_function"),
+        "follow-within-repo should not follow links out of the repo"
+    );
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_symlink_follow_indexes_external_targets() {
+    // GIVEN: A symlink to an external dir, plus a self-referential cycle
+    let temp_dir = tempfile::tempdir().unwrap();
+    let outside = temp_dir.path().join("outside");
+    std::fs::create_dir_all(&outside).unwrap();
+    std::fs::write(outside.join("ext.rs"), "fn external_function() {}").unwrap();
+
+    let repo_path = temp_dir.path().join("repo");
+    std::fs::create_dir_all(&repo_path).unwrap();
+    std::os::unix::fs::symlink(&outside, repo_path.join("ext_link")).unwrap();
+    std::os::unix::fs::symlink(&repo_path, repo_path.join("loop")).unwrap();
+
+    let options = EngineOptions {
+        symlink_policy: narsil_mcp::repo::SymlinkPolicy::Follow,
+        ..Default::default()
+    };
+
+    let index_path = temp_dir.path().join("index");
+    let engine = CodeIntelEngine::with_options(index_path, vec![repo_path], options)
+        .await
+        .unwrap();
+    engine.complete_initialization().await.unwrap();
+
+    // THEN: Indexing terminates despite the cycle and the external file is in
+    let external = engine
+        .find_symbols("repo", None, Some("external_function"), None, None)
+        .await
+        .unwrap();
+    assert_eq!(
+        external.matches("- **external_function**").count(),
+        1,
+        "follow should index external targets once, without looping"
+    );
+}
@@ -149,12 +149,12 @@ fn test_claude_desktop_gets_full_preset() {
 
     let enabled = filter.get_enabled_tools();
 
-    // Full preset without feature flags: 50-60 tools
+    // Full preset without feature flags: 50-76 tools
     // (All tools that don't require Git, CallGraph, Neural flags)
     // With all flags enabled, would be 70+ tools
     assert!(
-        enabled.len() >= 50 && enabled.len() <= 60,
-        "Claude Desktop should get full preset (50-60 tools without flags), got {}",
+        enabled.len() >= 50 && enabled.len() <= 76,
+        "Claude Desktop should get full preset (50-76 tools without flags), got {}",
         enabled.len()
     );
 
@@ -186,7 +186,7 @@ fn test_claude_alternate_name() {
 
     // "claude" should also map to full preset (without flags)
     assert!(
-        enabled.len() >= 50 && enabled.len() <= 60,
+        enabled.len() >= 50 && enabled.len() <= 76,
         "'claude' editor should map to full preset, got {} tools",
         enabled.len()
     );
@@ -205,9 +205,9 @@ fn test_unknown_editor_gets_full_preset() {
 
     let enabled = filter.get_enabled_tools();
 
-    // Unknown editors should get all tools (full preset, without flags = 50-60)
+    // Unknown editors should get all tools (full preset, without flags = 50-76)
     assert!(
-        enabled.len() >= 50 && enabled.len() <= 60,
+        enabled.len() >= 50 && enabled.len() <= 76,
         "Unknown editor should get full preset by default, got {}",
         enabled.len()
     );
@@ -221,9 +221,9 @@ fn test_no_client_info_gets_full_preset() {
 
     let enabled = filter.get_enabled_tools();
 
-    // No client info = full preset (without flags = 50-60)
+    // No client info = full preset (without flags = 50-76)
     assert!(
-        enabled.len() >= 50 && enabled.len() <= 60,
+        enabled.len() >= 50 && enabled.len() <= 76,
         "No client info should get full preset, got {}",
         enabled.len()
     );
@@ -60,9 +60,16 @@ async fn test_backwards_compatibility_cli_only() -> Result<()> {
         call_graph_enabled: false,
         persist_enabled: false,
         watch_enabled: false,
+        write_enabled: false,
+        lazy_enabled: false,
+        global_ignores: Vec::new(),
+        max_parse_bytes: 0,
+        symlink_policy: Default::default(),
         streaming_config: Default::default(),
         lsp_config: Default::default(),
         neural_config: Default::default(),
+        chunker_config: Default::default(),
+        architecture: Default::default(),
     };
 
     let _engine = create_test_engine(vec![repo_path], options.clone()).await?;
@@ -123,6 +130,11 @@ async fn test_config_priority_cli_flags_override() -> Result<()> {
         },
         performance: PerformanceConfig::default(),
         feature_requirements: HashMap::new(),
+        ignore: Vec::new(),
+        chunking: Default::default(),
+        architecture: Default::default(),
+        profiles: Default::default(),
+        client_mappings: Default::default(),
     };
 
     // BUT: CLI has git_enabled=false (should override config)
@@ -131,9 +143,16 @@ async fn test_config_priority_cli_flags_override() -> Result<()> {
         call_graph_enabled: false,
         persist_enabled: false,
         watch_enabled: false,
+        write_enabled: false,
+        lazy_enabled: false,
+        global_ignores: Vec::new(),
+        max_parse_bytes: 0,
+        symlink_policy: Default::default(),
         streaming_config: Default::default(),
         lsp_config: Default::default(),
         neural_config: Default::default(),
+        chunker_config: Default::default(),
+        architecture: Default::default(),
     };
 
     let _engine = create_test_engine(vec![repo_path], options.clone()).await?;
@@ -286,10 +305,10 @@ async fn test_security_focused_preset() -> Result<()> {
     let enabled_tools = filter.get_enabled_tools();
 
     // Security preset should have ~28 tools
-    // Security (9) + SupplyChain (4) + Analysis (11) + Repository basics (4)
+    // Security (9) + SupplyChain (4) + Analysis (12) + Repository basics (4)
     assert!(
-        enabled_tools.len() >= 26 && enabled_tools.len() <= 32,
-        "Security preset should have 26-32 tools, got {}",
+        enabled_tools.len() >= 26 && enabled_tools.len() <= 33,
+        "Security preset should have 26-33 tools, got {}",
         enabled_tools.len()
     );
 
@@ -347,6 +366,11 @@ async fn test_category_level_filtering() -> Result<()> {
         },
         performance: PerformanceConfig::default(),
         feature_requirements: HashMap::new(),
+        ignore: Vec::new(),
+        chunking: Default::default(),
+        architecture: Default::default(),
+        profiles: Default::default(),
+        client_mappings: Default::default(),
     };
 
     let filter = ToolFilter::new(config, &options, None);
@@ -398,6 +422,11 @@ async fn test_tool_level_override() -> Result<()> {
         },
         performance: PerformanceConfig::default(),
         feature_requirements: HashMap::new(),
+        ignore: Vec::new(),
+        chunking: Default::default(),
+        architecture: Default::default(),
+        profiles: Default::default(),
+        client_mappings: Default::default(),
     };
 
     let filter = ToolFilter::new(config, &options, None);
@@ -437,6 +466,11 @@ async fn test_performance_budget_max_tool_count() -> Result<()> {
             filtering_latency_ms: 10,
         },
         feature_requirements: HashMap::new(),
+        ignore: Vec::new(),
+        chunking: Default::default(),
+        architecture: Default::default(),
+        profiles: Default::default(),
+        client_mappings: Default::default(),
     };
 
     let filter = ToolFilter::new(config, &options, None);
@@ -472,9 +506,16 @@ async fn test_feature_flag_validation() -> Result<()> {
         call_graph_enabled: false,
         persist_enabled: false,
         watch_enabled: false,
+        write_enabled: false,
+        lazy_enabled: false,
+        global_ignores: Vec::new(),
+        max_parse_bytes: 0,
+        symlink_policy: Default::default(),
         streaming_config: Default::default(),
         lsp_config: Default::default(),
         neural_config: Default::default(),
+        chunker_config: Default::default(),
+        architecture: Default::default(),
     };
 
     let _engine = create_test_engine(vec![repo_path], options.clone()).await?;
@@ -501,6 +542,11 @@ async fn test_feature_flag_validation() -> Result<()> {
         },
         performance: PerformanceConfig::default(),
         feature_requirements: HashMap::new(),
+        ignore: Vec::new(),
+        chunking: Default::default(),
+        architecture: Default::default(),
+        profiles: Default::default(),
+        client_mappings: Default::default(),
     };
 
     let filter = ToolFilter::new(config, &options, None);
@@ -516,7 +562,7 @@ async fn test_feature_flag_validation() -> Result<()> {
 #[tokio::test]
 async fn test_metadata_completeness() -> Result<()> {
     // Verify all tools in TOOL_METADATA have required fields
-    assert_eq!(TOOL_METADATA.len(), 75, "Expected 75 tools in metadata");
+    assert_eq!(TOOL_METADATA.len(), 108, "Expected 108 tools in metadata");
 
     for (name, meta) in TOOL_METADATA.iter() {
         // Name should match key
@@ -609,9 +655,16 @@ async fn test_required_flags_validation() -> Result<()> {
                 call_graph_enabled: false,
                 persist_enabled: false,
                 watch_enabled: false,
+                write_enabled: false,
+                lazy_enabled: false,
+                global_ignores: Vec::new(),
+                max_parse_bytes: 0,
+                symlink_policy: Default::default(),
                 streaming_config: Default::default(),
                 lsp_config: Default::default(),
                 neural_config: Default::default(),
+                chunker_config: Default::default(),
+                architecture: Default::default(),
             };
 
             let config = ToolConfig::default();
@@ -631,9 +684,16 @@ async fn test_required_flags_validation() -> Result<()> {
                 call_graph_enabled: false,
                 persist_enabled: false,
                 watch_enabled: false,
+                write_enabled: false,
+                lazy_enabled: false,
+                global_ignores: Vec::new(),
+                max_parse_bytes: 0,
+                symlink_policy: Default::default(),
                 streaming_config: Default::default(),
                 lsp_config: Default::default(),
                 neural_config: Default::default(),
+                chunker_config: Default::default(),
+                architecture: Default::default(),
             };
 
             let config = ToolConfig::default();
@@ -92,10 +92,10 @@ fn test_full_mcp_flow_claude_desktop() {
 
     let enabled_tools = filter.get_enabled_tools();
 
-    // Claude Desktop should get full preset (50-60 tools without feature flags)
+    // Claude Desktop should get full preset (50-76 tools without feature flags)
     assert!(
-        enabled_tools.len() >= 50 && enabled_tools.len() <= 60,
-        "Claude Desktop should get 50-60 tools in full preset (without flags), got {}",
+        enabled_tools.len() >= 50 && enabled_tools.len() <= 76,
+        "Claude Desktop should get 50-76 tools in full preset (without flags), got {}",
         enabled_tools.len()
     );
 
@@ -169,9 +169,9 @@ fn test_mcp_flow_no_client_info() {
     let filter = ToolFilter::new(config, &options, None);
     let enabled_tools = filter.get_enabled_tools();
 
-    // Should default to full preset (50-60 tools without flags)
+    // Should default to full preset (50-76 tools without flags)
     assert!(
-        enabled_tools.len() >= 50 && enabled_tools.len() <= 60,
+        enabled_tools.len() >= 50 && enabled_tools.len() <= 76,
         "No client info should default to full preset, got {}",
         enabled_tools.len()
     );
@@ -325,9 +325,9 @@ fn test_cli_preset_overrides_editor_detection() {
     let filter = ToolFilter::new(config, &options, Some(client_info));
     let enabled_tools = filter.get_enabled_tools();
 
-    // Should get full preset (50-60 tools), NOT minimal preset (20-30)
+    // Should get full preset (50-76 tools), NOT minimal preset (20-30)
     assert!(
-        enabled_tools.len() >= 50 && enabled_tools.len() <= 60,
+        enabled_tools.len() >= 50 && enabled_tools.len() <= 76,
         "CLI preset=full should override Zed's default minimal preset, got {} tools",
         enabled_tools.len()
     );
@@ -374,8 +374,8 @@ fn test_cli_preset_all_values() {
     let filter = ToolFilter::new(config, &options, None);
     let full_tools = filter.get_enabled_tools();
     assert!(
-        full_tools.len() >= 50 && full_tools.len() <= 60,
-        "full preset should have 50-60 tools, got {}",
+        full_tools.len() >= 50 && full_tools.len() <= 76,
+        "full preset should have 50-76 tools, got {}",
         full_tools.len()
     );
 
@@ -409,7 +409,7 @@ fn test_cli_preset_invalid_value_fallback() {
 
     // Invalid preset should fall back to Full
     assert!(
-        enabled_tools.len() >= 50 && enabled_tools.len() <= 60,
+        enabled_tools.len() >= 50 && enabled_tools.len() <= 76,
         "Invalid preset should fall back to Full, got {} tools",
         enabled_tools.len()
     );
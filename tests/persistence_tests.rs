@@ -1,4 +1,5 @@
 use anyhow::Result;
+use narsil_mcp::chunking::ChunkerConfig;
 use narsil_mcp::lsp::LspConfig;
 use narsil_mcp::neural::NeuralConfig;
 use narsil_mcp::streaming::StreamingConfig;
@@ -56,9 +57,16 @@ async fn test_persistence_index_creation() -> Result<()> {
         call_graph_enabled: false,
         persist_enabled: true,
         watch_enabled: false,
+        write_enabled: false,
+        lazy_enabled: false,
+        global_ignores: Vec::new(),
+        max_parse_bytes: 0,
+        symlink_policy: Default::default(),
         streaming_config: StreamingConfig::default(),
         lsp_config: LspConfig::default(),
         neural_config: NeuralConfig::default(),
+        chunker_config: ChunkerConfig::default(),
+        architecture: Default::default(),
     };
 
     let engine = CodeIntelEngine::with_options(
@@ -102,9 +110,16 @@ async fn test_persistence_index_loading() -> Result<()> {
         call_graph_enabled: false,
         persist_enabled: true,
         watch_enabled: false,
+        write_enabled: false,
+        lazy_enabled: false,
+        global_ignores: Vec::new(),
+        max_parse_bytes: 0,
+        symlink_policy: Default::default(),
         streaming_config: StreamingConfig::default(),
         lsp_config: LspConfig::default(),
         neural_config: NeuralConfig::default(),
+        chunker_config: ChunkerConfig::default(),
+        architecture: Default::default(),
     };
 
     // First indexing - creates the persisted index
@@ -186,9 +201,16 @@ async fn test_persistence_stale_file_detection() -> Result<()> {
         call_graph_enabled: false,
         persist_enabled: true,
         watch_enabled: false,
+        write_enabled: false,
+        lazy_enabled: false,
+        global_ignores: Vec::new(),
+        max_parse_bytes: 0,
+        symlink_policy: Default::default(),
         streaming_config: StreamingConfig::default(),
         lsp_config: LspConfig::default(),
         neural_config: NeuralConfig::default(),
+        chunker_config: ChunkerConfig::default(),
+        architecture: Default::default(),
     };
 
     // First indexing
@@ -268,9 +290,16 @@ async fn test_persistence_disabled() -> Result<()> {
         call_graph_enabled: false,
         persist_enabled: false, // Disabled
         watch_enabled: false,
+        write_enabled: false,
+        lazy_enabled: false,
+        global_ignores: Vec::new(),
+        max_parse_bytes: 0,
+        symlink_policy: Default::default(),
         streaming_config: StreamingConfig::default(),
         lsp_config: LspConfig::default(),
         neural_config: NeuralConfig::default(),
+        chunker_config: ChunkerConfig::default(),
+        architecture: Default::default(),
     };
 
     let engine = CodeIntelEngine::with_options(
@@ -312,9 +341,16 @@ async fn test_empty_persisted_index() -> Result<()> {
         call_graph_enabled: false,
         persist_enabled: true,
         watch_enabled: false,
+        write_enabled: false,
+        lazy_enabled: false,
+        global_ignores: Vec::new(),
+        max_parse_bytes: 0,
+        symlink_policy: Default::default(),
         streaming_config: StreamingConfig::default(),
         lsp_config: LspConfig::default(),
         neural_config: NeuralConfig::default(),
+        chunker_config: ChunkerConfig::default(),
+        architecture: Default::default(),
     };
 
     // First time - empty repo
@@ -380,9 +416,16 @@ async fn test_async_watcher_creation() -> Result<()> {
         call_graph_enabled: false,
         persist_enabled: false,
         watch_enabled: true,
+        write_enabled: false,
+        lazy_enabled: false,
+        global_ignores: Vec::new(),
+        max_parse_bytes: 0,
+        symlink_policy: Default::default(),
         streaming_config: StreamingConfig::default(),
         lsp_config: LspConfig::default(),
         neural_config: NeuralConfig::default(),
+        chunker_config: ChunkerConfig::default(),
+        architecture: Default::default(),
     };
 
     let engine = CodeIntelEngine::with_options(
@@ -414,9 +457,16 @@ async fn test_async_watcher_disabled_when_watch_disabled() -> Result<()> {
         call_graph_enabled: false,
         persist_enabled: false,
         watch_enabled: false,
+        write_enabled: false,
+        lazy_enabled: false,
+        global_ignores: Vec::new(),
+        max_parse_bytes: 0,
+        symlink_policy: Default::default(),
         streaming_config: StreamingConfig::default(),
         lsp_config: LspConfig::default(),
         neural_config: NeuralConfig::default(),
+        chunker_config: ChunkerConfig::default(),
+        architecture: Default::default(),
     };
 
     let engine = CodeIntelEngine::with_options(
@@ -448,9 +498,16 @@ async fn test_async_watcher_file_change_detection() -> Result<()> {
         call_graph_enabled: false,
         persist_enabled: false,
         watch_enabled: true,
+        write_enabled: false,
+        lazy_enabled: false,
+        global_ignores: Vec::new(),
+        max_parse_bytes: 0,
+        symlink_policy: Default::default(),
         streaming_config: StreamingConfig::default(),
         lsp_config: LspConfig::default(),
         neural_config: NeuralConfig::default(),
+        chunker_config: ChunkerConfig::default(),
+        architecture: Default::default(),
     };
 
     let engine = CodeIntelEngine::with_options(
@@ -498,9 +555,16 @@ async fn test_async_watcher_debouncing() -> Result<()> {
         call_graph_enabled: false,
         persist_enabled: false,
         watch_enabled: true,
+        write_enabled: false,
+        lazy_enabled: false,
+        global_ignores: Vec::new(),
+        max_parse_bytes: 0,
+        symlink_policy: Default::default(),
         streaming_config: StreamingConfig::default(),
         lsp_config: LspConfig::default(),
         neural_config: NeuralConfig::default(),
+        chunker_config: ChunkerConfig::default(),
+        architecture: Default::default(),
     };
 
     let engine = CodeIntelEngine::with_options(
@@ -560,9 +624,16 @@ async fn test_async_watcher_filters_non_source_files() -> Result<()> {
         call_graph_enabled: false,
         persist_enabled: false,
         watch_enabled: true,
+        write_enabled: false,
+        lazy_enabled: false,
+        global_ignores: Vec::new(),
+        max_parse_bytes: 0,
+        symlink_policy: Default::default(),
         streaming_config: StreamingConfig::default(),
         lsp_config: LspConfig::default(),
         neural_config: NeuralConfig::default(),
+        chunker_config: ChunkerConfig::default(),
+        architecture: Default::default(),
     };
 
     let engine = CodeIntelEngine::with_options(
@@ -602,3 +673,173 @@ async fn test_async_watcher_filters_non_source_files() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_rename_change_carries_symbols_over() -> Result<()> {
+    use narsil_mcp::index::{CodeIntelEngine, EngineOptions};
+    use narsil_mcp::persist::{ChangeType, FileChange};
+
+    let temp = TempDir::new()?;
+    let repo_path = temp.path().join("repo");
+    std::fs::create_dir_all(repo_path.join("src"))?;
+    std::fs::write(
+        repo_path.join("src/old_name.rs"),
+        "pub fn moved_function() {}",
+    )?;
+
+    let engine = CodeIntelEngine::with_options(
+        temp.path().join("index"),
+        vec![repo_path.clone()],
+        EngineOptions::default(),
+    )
+    .await?;
+    engine.complete_initialization().await?;
+
+    // Move the file on disk, then feed the watcher-style rename event
+    std::fs::rename(
+        repo_path.join("src/old_name.rs"),
+        repo_path.join("src/new_name.rs"),
+    )?;
+    let changes = vec![FileChange {
+        path: repo_path.join("src/new_name.rs"),
+        change_type: ChangeType::Renamed {
+            from: repo_path.join("src/old_name.rs"),
+        },
+    }];
+    let count = engine.process_file_changes(&changes).await?;
+    assert_eq!(count, 1);
+
+    // Symbols were carried over under the new path, not dropped
+    let symbols = engine
+        .find_symbols("repo", None, Some("moved_function"), None, None)
+        .await?;
+    assert!(
+        symbols.contains("src/new_name.rs"),
+        "symbols should follow the rename: {}",
+        symbols
+    );
+    assert!(!symbols.contains("src/old_name.rs"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_directory_move_repaths_indexed_files() -> Result<()> {
+    use narsil_mcp::index::{CodeIntelEngine, EngineOptions};
+    use narsil_mcp::persist::{ChangeType, FileChange};
+
+    let temp = TempDir::new()?;
+    let repo_path = temp.path().join("repo");
+    std::fs::create_dir_all(repo_path.join("src/inner"))?;
+    std::fs::write(repo_path.join("src/inner/a.rs"), "pub fn alpha_fn() {}")?;
+    std::fs::write(repo_path.join("src/inner/b.rs"), "pub fn beta_fn() {}")?;
+
+    let engine = CodeIntelEngine::with_options(
+        temp.path().join("index"),
+        vec![repo_path.clone()],
+        EngineOptions::default(),
+    )
+    .await?;
+    engine.complete_initialization().await?;
+
+    std::fs::rename(repo_path.join("src/inner"), repo_path.join("src/renamed"))?;
+    let changes = vec![FileChange {
+        path: repo_path.join("src/renamed"),
+        change_type: ChangeType::Renamed {
+            from: repo_path.join("src/inner"),
+        },
+    }];
+    engine.process_file_changes(&changes).await?;
+
+    // Every file under the moved directory is re-pathed
+    for (name, file) in [
+        ("alpha_fn", "src/renamed/a.rs"),
+        ("beta_fn", "src/renamed/b.rs"),
+    ] {
+        let symbols = engine
+            .find_symbols("repo", None, Some(name), None, None)
+            .await?;
+        assert!(
+            symbols.contains(file),
+            "{} should live under the moved directory: {}",
+            name,
+            symbols
+        );
+        assert!(!symbols.contains("src/inner/"));
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_branch_switch_refreshes_changed_files() -> Result<()> {
+    use narsil_mcp::index::{CodeIntelEngine, EngineOptions};
+    use narsil_mcp::persist::{ChangeType, FileChange};
+    use std::process::Command;
+
+    if narsil_mcp::git::GitRepo::check_git_available().is_err() {
+        return Ok(()); // No git binary in this environment
+    }
+
+    let temp = TempDir::new()?;
+    let repo_path = temp.path().join("repo");
+    std::fs::create_dir_all(repo_path.join("src"))?;
+
+    let git = |args: &[&str]| {
+        let status = Command::new("git")
+            .args(args)
+            .current_dir(&repo_path)
+            .env("GIT_AUTHOR_NAME", "test")
+            .env("GIT_AUTHOR_EMAIL", "test@example.com")
+            .env("GIT_COMMITTER_NAME", "test")
+            .env("GIT_COMMITTER_EMAIL", "test@example.com")
+            .output()
+            .expect("git should run");
+        assert!(status.status.success(), "git {:?} failed", args);
+    };
+
+    // main has one function; the feature branch replaces it
+    git(&["init", "-b", "main"]);
+    std::fs::write(repo_path.join("src/lib.rs"), "pub fn main_branch_fn() {}")?;
+    git(&["add", "."]);
+    git(&["commit", "-m", "main"]);
+    git(&["checkout", "-b", "feature"]);
+    std::fs::write(
+        repo_path.join("src/lib.rs"),
+        "pub fn feature_branch_fn() {}",
+    )?;
+    git(&["add", "."]);
+    git(&["commit", "-m", "feature"]);
+    git(&["checkout", "main"]);
+
+    // Index on main with watch enabled so HEAD is recorded
+    let options = EngineOptions {
+        watch_enabled: true,
+        ..Default::default()
+    };
+    let engine =
+        CodeIntelEngine::with_options(temp.path().join("index"), vec![repo_path.clone()], options)
+            .await?;
+    engine.complete_initialization().await?;
+
+    // Switch branches on disk, then deliver only the .git/HEAD event
+    git(&["checkout", "feature"]);
+    let changes = vec![FileChange {
+        path: repo_path.join(".git/HEAD"),
+        change_type: ChangeType::Modified,
+    }];
+    let refreshed = engine.process_file_changes(&changes).await?;
+    assert_eq!(refreshed, 1, "one file differs between the branches");
+
+    let symbols = engine
+        .find_symbols("repo", None, Some("branch_fn"), None, None)
+        .await?;
+    assert!(
+        symbols.contains("feature_branch_fn"),
+        "index should reflect the checked-out branch: {}",
+        symbols
+    );
+    assert!(!symbols.contains("main_branch_fn"));
+
+    Ok(())
+}
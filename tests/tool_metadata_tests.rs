@@ -1,6 +1,6 @@
 /// Tests for tool metadata registry
 ///
-/// These tests verify that all 76 tools have complete metadata
+/// These tests verify that all 108 tools have complete metadata
 /// and that the metadata system works correctly.
 use narsil_mcp::tool_metadata::{
     FeatureFlag, PerformanceImpact, StabilityLevel, ToolCategory, TOOL_METADATA,
@@ -9,11 +9,11 @@ use std::collections::HashSet;
 
 #[test]
 fn test_tool_metadata_complete() {
-    // All 75 tools should have metadata
+    // All 108 tools should have metadata
     assert_eq!(
         TOOL_METADATA.len(),
-        75,
-        "Expected 75 tools to have metadata"
+        108,
+        "Expected 108 tools to have metadata"
     );
 
     // Each tool should have complete, valid metadata
@@ -105,6 +105,10 @@ fn test_feature_flag_requirements() {
         "find_call_path",
         "get_complexity",
         "get_function_hotspots",
+        "export_call_graph",
+        "get_key_functions",
+        "find_recursion_cycles",
+        "get_impacted_tests",
     ];
 
     for tool_name in callgraph_tools {
@@ -229,43 +233,43 @@ fn test_category_counts() {
 
     assert_eq!(
         count_by_category(ToolCategory::Repository),
-        10,
-        "Repository category should have 10 tools"
+        11,
+        "Repository category should have 11 tools"
     );
     assert_eq!(
         count_by_category(ToolCategory::Symbols),
-        7,
-        "Symbols category should have 7 tools"
+        9,
+        "Symbols category should have 9 tools"
     );
     assert_eq!(
         count_by_category(ToolCategory::Search),
-        12,
-        "Search category should have 12 tools"
+        13,
+        "Search category should have 13 tools"
     );
     assert_eq!(
         count_by_category(ToolCategory::CallGraph),
-        6,
-        "CallGraph category should have 6 tools"
+        10,
+        "CallGraph category should have 10 tools"
     );
     assert_eq!(
         count_by_category(ToolCategory::Git),
-        9,
-        "Git category should have 9 tools"
+        12,
+        "Git category should have 12 tools"
     );
     assert_eq!(
         count_by_category(ToolCategory::Lsp),
-        3,
-        "LSP category should have 3 tools"
+        6,
+        "LSP category should have 6 tools"
     );
     assert_eq!(
         count_by_category(ToolCategory::Remote),
-        3,
-        "Remote category should have 3 tools"
+        4,
+        "Remote category should have 4 tools"
     );
     assert_eq!(
         count_by_category(ToolCategory::Security),
-        9,
-        "Security category should have 9 tools"
+        13,
+        "Security category should have 13 tools"
     );
     assert_eq!(
         count_by_category(ToolCategory::SupplyChain),
@@ -274,8 +278,8 @@ fn test_category_counts() {
     );
     assert_eq!(
         count_by_category(ToolCategory::Analysis),
-        11,
-        "Analysis category should have 11 tools"
+        25,
+        "Analysis category should have 25 tools"
     );
     // Graph category has 1-2 tools
     let graph_count = count_by_category(ToolCategory::Graph);